@@ -1,5 +1,6 @@
 use crate::cgmath64::*;
 use cgmath::AbsDiffEq;
+use std::cell::Cell;
 use std::fmt::Debug;
 
 /// general tolerance
@@ -8,13 +9,89 @@ pub const TOLERANCE: f64 = 1.0e-6;
 /// general tolerance of square order
 pub const TOLERANCE2: f64 = TOLERANCE * TOLERANCE;
 
+thread_local! {
+    static CURRENT_TOLERANCE: Cell<f64> = const { Cell::new(TOLERANCE) };
+}
+
+/// Returns the tolerance of the current context.
+///
+/// The default value is [`TOLERANCE`]. The context can be changed in a scope by [`with_tolerance`].
+#[inline(always)]
+pub fn tolerance() -> f64 { CURRENT_TOLERANCE.with(Cell::get) }
+
+/// Returns the square of the tolerance of the current context.
+#[inline(always)]
+pub fn tolerance2() -> f64 {
+    let tol = tolerance();
+    tol * tol
+}
+
+/// Guard of the tolerance context. Restores the previous tolerance when dropped.
+///
+/// The context is thread-local: it does not propagate to other threads.
+#[derive(Debug)]
+pub struct ToleranceGuard {
+    previous: f64,
+    // the guard must be dropped in the thread where it was created.
+    _marker: std::marker::PhantomData<*const ()>,
+}
+
+impl ToleranceGuard {
+    /// Sets the tolerance of the current thread until the guard is dropped.
+    /// # Panics
+    /// Panic occurs if `tol` is not positive.
+    pub fn new(tol: f64) -> Self {
+        assert!(tol > 0.0, "tolerance must be positive: {tol:e}");
+        let previous = CURRENT_TOLERANCE.with(|cell| cell.replace(tol));
+        Self {
+            previous,
+            _marker: std::marker::PhantomData,
+        }
+    }
+}
+
+impl Drop for ToleranceGuard {
+    fn drop(&mut self) { CURRENT_TOLERANCE.with(|cell| cell.set(self.previous)) }
+}
+
+/// Runs `f` in the context whose tolerance is `tol`.
+/// # Examples
+/// ```
+/// use truck_base::tolerance::*;
+/// assert!(!1.0.near(&1.001));
+/// with_tolerance(1.0e-2, || {
+///     assert_eq!(tolerance(), 1.0e-2);
+///     assert!(1.0.near(&1.001));
+/// });
+/// assert_eq!(tolerance(), TOLERANCE);
+/// ```
+/// # Panics
+/// Panic occurs if `tol` is not positive.
+pub fn with_tolerance<R>(tol: f64, f: impl FnOnce() -> R) -> R {
+    let _guard = ToleranceGuard::new(tol);
+    f()
+}
+
 /// Defines a tolerance in the whole package
 pub trait Tolerance: AbsDiffEq<Epsilon = f64> + Debug {
-    /// The "distance" is less than `TOLERANCE`.
-    fn near(&self, other: &Self) -> bool { self.abs_diff_eq(other, TOLERANCE) }
+    /// The "distance" is less than [`tolerance()`], `TOLERANCE` by default.
+    fn near(&self, other: &Self) -> bool { self.abs_diff_eq(other, tolerance()) }
+
+    /// The "distance" is less than [`tolerance2()`], `TOLERANCE2` by default.
+    fn near2(&self, other: &Self) -> bool { self.abs_diff_eq(other, tolerance2()) }
+}
 
-    /// The "distance" is less than `TOLERANCR2`.
-    fn near2(&self, other: &Self) -> bool { self.abs_diff_eq(other, TOLERANCE2) }
+#[test]
+fn nested_tolerance_context() {
+    with_tolerance(1.0e-3, || {
+        with_tolerance(1.0e-1, || assert!(1.0.near(&1.05)));
+        assert!(!1.0.near(&1.05));
+        assert!(1.0.near2(&1.0000005));
+    });
+    let res = std::panic::catch_unwind(|| with_tolerance(1.0e-1, || panic!()));
+    assert!(res.is_err());
+    assert_eq!(tolerance(), TOLERANCE);
+    assert_eq!(tolerance2(), TOLERANCE2);
 }
 
 impl<T: AbsDiffEq<Epsilon = f64> + Debug> Tolerance for T {}
@@ -175,6 +175,137 @@ where S: Transformed<T>
     }
 }
 
+const PROJECTION_TRIALS: usize = 100;
+
+/// Returns the parameter congruent to `t` modulo `period` which is nearest to `t0`.
+#[inline(always)]
+fn unwrap_periodic(t: f64, t0: f64, period: Option<f64>) -> f64 {
+    match period {
+        Some(period) => t + ((t0 - t) / period).round() * period,
+        None => t,
+    }
+}
+
+/// Projects `point` onto `surface` along `direction`, or to the nearest point if `direction` is `None`.
+fn project_point<S>(
+    surface: &S,
+    point: Point3,
+    direction: Option<Vector3>,
+    hint: Option<Point2>,
+) -> Option<(f64, f64)>
+where
+    S: ParametricSurface3D + SearchNearestParameter<D2, Point = Point3>,
+{
+    let hint = hint.map(|p| (p.x, p.y));
+    let (mut u, mut v) = surface.search_nearest_parameter(point, hint, PROJECTION_TRIALS)?;
+    let dir = match direction {
+        Some(dir) => dir,
+        None => return Some((u, v)),
+    };
+    let mut s = (surface.subs(u, v) - point).dot(dir) / dir.magnitude2();
+    for _ in 0..PROJECTION_TRIALS {
+        let f = surface.subs(u, v) - s * dir - point;
+        if f.so_small() {
+            return Some((u, v));
+        }
+        let jacobian = Matrix3::from_cols(surface.uder(u, v), surface.vder(u, v), -dir);
+        let delta = jacobian.invert()? * f;
+        u -= delta.x;
+        v -= delta.y;
+        s -= delta.z;
+    }
+    None
+}
+
+/// Projects `curve` onto `surface`, and returns the curve on the surface.
+///
+/// If `direction` is `None`, each point of the curve is projected to the nearest point on the surface.
+/// Otherwise, each point is projected along `direction`. The parameter curve is a B-spline curve of
+/// degree one with the same parameter range as `curve`, whose vertices are the projected points
+/// so that the curve on the surface is within `tol` from the projection at the midpoints of segments.
+/// The parameter curve is continuous even if it passes through the seam of a periodic surface,
+/// so it may go out of the parameter range of the surface.
+///
+/// Returns `None` if some point of the curve cannot be projected.
+/// # Examples
+/// ```
+/// use truck_geometry::prelude::*;
+/// let line = BSplineCurve::new(
+///     KnotVec::bezier_knot(1),
+///     vec![Point3::new(2.0, -1.0, 0.5), Point3::new(2.0, 1.0, 0.5)],
+/// );
+///
+/// // normal projection onto the sphere through the seam `v = 0`
+/// let sphere = Sphere::new(Point3::origin(), 1.0);
+/// let pcurve = project_curve_onto_surface(&line, &sphere, None, 0.01).unwrap();
+/// assert_eq!(pcurve.range_tuple(), (0.0, 1.0));
+/// let vs = pcurve.curve().control_points().iter().map(|p| p.y).collect::<Vec<_>>();
+/// assert!(vs.windows(2).all(|v| (v[1] - v[0]).abs() < 0.5));
+/// for i in 0..=10 {
+///     let t = i as f64 / 10.0;
+///     let pt = Point3::from_vec(line.subs(t).to_vec().normalize());
+///     assert!(pcurve.subs(t).distance(pt) < 0.01);
+/// }
+///
+/// // directional projection onto the plane z = 0
+/// let plane = Plane::new(Point3::origin(), Point3::new(1.0, 0.0, 0.0), Point3::new(0.0, 1.0, 0.0));
+/// let direction = Vector3::new(1.0, 0.0, -1.0);
+/// let pcurve = project_curve_onto_surface(&line, &plane, Some(direction), 0.01).unwrap();
+/// for i in 0..=10 {
+///     let t = i as f64 / 10.0;
+///     assert_near!(pcurve.subs(t), line.subs(t) + Vector3::new(0.5, 0.0, -0.5));
+/// }
+/// ```
+pub fn project_curve_onto_surface<C, S>(
+    curve: &C,
+    surface: &S,
+    direction: Option<Vector3>,
+    tol: f64,
+) -> Option<PCurve<BSplineCurve<Point2>, S>>
+where
+    C: ParametricCurve3D + BoundedCurve + ParameterDivision1D<Point = Point3>,
+    S: ParametricSurface3D + SearchNearestParameter<D2, Point = Point3>,
+{
+    nonpositive_tolerance!(tol);
+    let project = |t: f64, hint: Option<Point2>| {
+        let (u, v) = project_point(surface, curve.subs(t), direction, hint)?;
+        Some(match hint {
+            Some(hint) => Point2::new(
+                unwrap_periodic(u, hint.x, surface.u_period()),
+                unwrap_periodic(v, hint.y, surface.v_period()),
+            ),
+            None => Point2::new(u, v),
+        })
+    };
+    let (params, _) = curve.parameter_division(curve.range_tuple(), tol);
+    let mut hint = None;
+    let mut vertices = Vec::with_capacity(params.len());
+    for t in params {
+        let uv = project(t, hint)?;
+        vertices.push((t, uv));
+        hint = Some(uv);
+    }
+    let mut i = 0;
+    while i + 1 < vertices.len() {
+        let ((t0, uv0), (t1, uv1)) = (vertices[i], vertices[i + 1]);
+        let (t, mid) = ((t0 + t1) / 2.0, uv0.midpoint(uv1));
+        let uv = project(t, Some(mid))?;
+        let dist = surface
+            .subs(mid.x, mid.y)
+            .distance(surface.subs(uv.x, uv.y));
+        match dist > tol && t1 - t0 > TOLERANCE {
+            true => vertices.insert(i + 1, (t, uv)),
+            false => i += 1,
+        }
+    }
+    let (params, points): (Vec<_>, Vec<_>) = vertices.into_iter().unzip();
+    let mut knots = vec![params[0]];
+    knots.extend(&params);
+    knots.push(params[params.len() - 1]);
+    let curve = BSplineCurve::new(KnotVec::from(knots), points);
+    Some(PCurve::new(curve, surface.clone()))
+}
+
 #[test]
 fn pcurve_test() {
     let curve = BSplineCurve::new(
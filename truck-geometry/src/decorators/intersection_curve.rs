@@ -157,6 +157,19 @@ where
 {
 }
 
+impl<C, S> BoundingBoxed for IntersectionCurve<C, S>
+where
+    C: ParametricCurve3D + BoundedCurve,
+    S: ParametricSurface3D + SearchNearestParameter<D2, Point = Point3>,
+{
+    type Point = Point3;
+    /// Returns the bounding box by searching the extrema of the coordinates.
+    #[inline]
+    fn bounding_box(&self) -> BoundingBox<Point3> {
+        algo::curve::bounding_box(self, self.range_tuple(), PRESEARCH_DIVISION)
+    }
+}
+
 impl<C, S> ParameterDivision1D for IntersectionCurve<C, S>
 where
     C: ParametricCurve3D,
@@ -146,4 +146,5 @@ mod intersection_curve;
 mod processor;
 mod revolved_curve;
 mod trimmied_curve;
+pub use curve_on_surface::project_curve_onto_surface;
 pub use intersection_curve::double_projection;
@@ -248,6 +248,17 @@ where
 {
 }
 
+impl<S, T> BoundingBoxed for Processor<S, T>
+where Self: ParametricSurface3D + BoundedSurface
+{
+    type Point = Point3;
+    /// Returns the bounding box by searching the extrema of the coordinates.
+    #[inline]
+    fn bounding_box(&self) -> BoundingBox<Point3> {
+        algo::surface::bounding_box(self, self.range_tuple(), PRESEARCH_DIVISION)
+    }
+}
+
 impl<E, T> Deref for Processor<E, T> {
     type Target = E;
     #[inline(always)]
@@ -164,6 +164,39 @@ impl<C: ParametricCurve3D + BoundedCurve> ParametricSurface3D for RevolutedCurve
 
 impl<C: ParametricCurve3D + BoundedCurve> BoundedSurface for RevolutedCurve<C> {}
 
+impl<C: ParametricCurve3D + ExtendCurve> ExtendSurface for RevolutedCurve<C> {
+    /// Extends the entity curve of the revoluted curve.
+    /// # Panics
+    /// The surface is closed in the `v`-direction, so panic occurs if `dv0` or `dv1` is not zero.
+    #[inline(always)]
+    fn extend(
+        &self,
+        du0: f64,
+        du1: f64,
+        dv0: f64,
+        dv1: f64,
+        continuity: ExtensionContinuity,
+    ) -> Self {
+        assert!(
+            dv0 == 0.0 && dv1 == 0.0,
+            "the revoluted curve cannot be extended in the v-direction: dv0 = {dv0}, dv1 = {dv1}"
+        );
+        RevolutedCurve {
+            curve: self.curve.extend(du0, du1, continuity),
+            revolution: self.revolution,
+        }
+    }
+}
+
+impl<C: ParametricCurve3D + BoundedCurve> BoundingBoxed for RevolutedCurve<C> {
+    type Point = Point3;
+    /// Returns the bounding box by searching the extrema of the coordinates.
+    #[inline]
+    fn bounding_box(&self) -> BoundingBox<Point3> {
+        algo::surface::bounding_box(self, self.range_tuple(), PRESEARCH_DIVISION)
+    }
+}
+
 impl<C: Clone> Invertible for RevolutedCurve<C> {
     #[inline(always)]
     fn invert(&mut self) { self.revolution.invert() }
@@ -562,6 +595,37 @@ fn revolve_test() {
     }
 }
 
+#[test]
+fn extend_revoluted_curve() {
+    let line = BSplineCurve::new(
+        KnotVec::bezier_knot(1),
+        vec![Point3::new(1.0, 0.0, 0.0), Point3::new(1.0, 1.0, 0.0)],
+    );
+    let surface = RevolutedCurve::by_revolution(line, Point3::origin(), Vector3::unit_y());
+    let extended = surface.extend(0.5, 1.0, 0.0, 0.0, ExtensionContinuity::C2);
+    assert_eq!(extended.range_tuple(), ((-0.5, 2.0), (0.0, 2.0 * PI)));
+    const N: usize = 10;
+    for i in 0..=N {
+        for j in 0..=N {
+            let u = -0.5 + 2.5 * i as f64 / N as f64;
+            let v = 2.0 * PI * j as f64 / N as f64;
+            let ans = Point3::new(f64::cos(v), u, -f64::sin(v));
+            assert_near!(extended.subs(u, v), ans);
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn extend_revoluted_curve_in_v_direction() {
+    let line = BSplineCurve::new(
+        KnotVec::bezier_knot(1),
+        vec![Point3::new(1.0, 0.0, 0.0), Point3::new(1.0, 1.0, 0.0)],
+    );
+    let surface = RevolutedCurve::by_revolution(line, Point3::origin(), Vector3::unit_y());
+    surface.extend(0.5, 1.0, 1.0, 0.0, ExtensionContinuity::C2);
+}
+
 #[test]
 fn search_parameter() {
     let line = BSplineCurve::new(
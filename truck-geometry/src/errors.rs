@@ -100,6 +100,56 @@ the degree: {1}"
     /// ```
     #[error("The {0}th knot in this knot vector cannot be removed.")]
     CannotRemoveKnot(usize),
+    /// The degree of the curve cannot be reduced within the given tolerance.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// use truck_geometry::errors::Error;
+    /// let knot_vec = KnotVec::bezier_knot(2);
+    /// let ctrl_pts = vec![Point2::new(0.0, 0.0), Point2::new(0.5, 1.0), Point2::new(1.0, 0.0)];
+    /// let mut bspcurve = BSplineCurve::new(knot_vec, ctrl_pts);
+    /// assert!(matches!(bspcurve.try_reduce_degree(0.1), Err(Error::CannotReduceDegree)));
+    /// ```
+    #[error("The degree cannot be reduced within the tolerance.")]
+    CannotReduceDegree,
+    /// The B-spline cannot be regarded as periodic, i.e. the control points do not wrap around,
+    /// the knot intervals do not repeat, or the ends are not connected smoothly.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// use truck_geometry::errors::Error;
+    /// let knot_vec = KnotVec::bezier_knot(2);
+    /// let ctrl_pts = vec![Point2::new(0.0, 0.0), Point2::new(0.5, 1.0), Point2::new(1.0, 0.0)];
+    /// let bspcurve = BSplineCurve::new(knot_vec, ctrl_pts);
+    /// assert!(matches!(
+    ///     PeriodicBSplineCurve::try_from_clamped(bspcurve),
+    ///     Err(Error::NotPeriodic),
+    /// ));
+    /// ```
+    #[error("The B-spline is not periodic.")]
+    NotPeriodic,
+    /// The curves do not form a network for a Gordon surface.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// use truck_geometry::errors::Error;
+    /// let line = |p: Point3, q: Point3| BSplineCurve::new(KnotVec::bezier_knot(1), vec![p, q]);
+    /// let u_curves = vec![
+    ///     line(Point3::new(0.0, 0.0, 0.0), Point3::new(1.0, 0.0, 0.0)),
+    ///     line(Point3::new(0.0, 1.0, 0.0), Point3::new(1.0, 1.0, 0.0)),
+    /// ];
+    /// // the curves do not meet `u_curves`.
+    /// let v_curves = vec![
+    ///     line(Point3::new(0.0, 0.0, 1.0), Point3::new(0.0, 1.0, 1.0)),
+    ///     line(Point3::new(1.0, 0.0, 1.0), Point3::new(1.0, 1.0, 1.0)),
+    /// ];
+    /// assert!(matches!(
+    ///     BSplineSurface::try_gordon(u_curves, v_curves),
+    ///     Err(Error::InvalidCurveNetwork),
+    /// ));
+    /// ```
+    #[error("The curves do not form a network for a Gordon surface.")]
+    InvalidCurveNetwork,
     /// Empty vector of points cannot construct B-spline.
     /// # Examples
     /// ```
@@ -183,6 +233,9 @@ fn print_messages() {
     writeln!(stderr, "{}\n", Error::NotSortedVector).unwrap();
     writeln!(stderr, "{}\n", Error::TooLargeDegree(1, 2)).unwrap();
     writeln!(stderr, "{}\n", Error::CannotRemoveKnot(7)).unwrap();
+    writeln!(stderr, "{}\n", Error::CannotReduceDegree).unwrap();
+    writeln!(stderr, "{}\n", Error::NotPeriodic).unwrap();
+    writeln!(stderr, "{}\n", Error::InvalidCurveNetwork).unwrap();
     writeln!(stderr, "{}\n", Error::EmptyControlPoints).unwrap();
     writeln!(stderr, "{}\n", Error::TooShortKnotVector(1, 2)).unwrap();
     writeln!(stderr, "{}\n", Error::IrregularControlPoints).unwrap();
@@ -485,6 +485,42 @@ impl<P: ControlPoint<f64> + Tolerance> BSplineCurve<P> {
         self
     }
 
+    /// Adds the knots `knots` at once, and do not change `self` as a curve.
+    ///
+    /// The knots in the range of the curve are inserted by one pass of the knot refinement
+    /// algorithm, and the other knots are added by [`BSplineCurve::add_knot`].
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let knot_vec = KnotVec::bezier_knot(2);
+    /// let ctrl_pts = vec![Vector2::new(-1.0, 1.0), Vector2::new(0.0, -1.0), Vector2::new(1.0, 1.0)];
+    /// let mut bspcurve = BSplineCurve::new(knot_vec, ctrl_pts);
+    /// let org_curve = bspcurve.clone();
+    ///
+    /// // the same result as adding the knots one by one
+    /// let mut added_curve = bspcurve.clone();
+    /// added_curve.add_knot(0.5).add_knot(0.5).add_knot(0.25).add_knot(0.75);
+    /// bspcurve.refine_knots(&[0.5, 0.75, 0.25, 0.5]);
+    /// assert_eq!(bspcurve.knot_vec(), added_curve.knot_vec());
+    /// assert!(bspcurve.near2_as_curve(&added_curve));
+    /// assert!(bspcurve.near2_as_curve(&org_curve));
+    /// ```
+    pub fn refine_knots(&mut self, knots: &[f64]) -> &mut Self {
+        let (k, n) = (self.degree(), self.control_points.len());
+        let (t0, t1) = (self.knot_vec[k], self.knot_vec[n]);
+        let (mut inner, outer): (Vec<f64>, Vec<f64>) =
+            knots.iter().partition(|x| t0 <= **x && **x <= t1);
+        inner.sort_by(|x, y| x.partial_cmp(y).unwrap());
+        let (knot_vec, control_points) =
+            refine_knot_vec(&self.knot_vec, k, &self.control_points, &inner);
+        self.knot_vec = knot_vec;
+        self.control_points = control_points;
+        outer.into_iter().for_each(|x| {
+            self.add_knot(x);
+        });
+        self
+    }
+
     /// Removes a knot corresponding to the indices `idx`, and do not change `self` as a curve.
     /// If cannot remove the knot, do not change `self` and return `self`.
     /// # Examples
@@ -1067,6 +1103,66 @@ fn concat_negative_test() {
     );
 }
 
+impl<P: ControlPoint<f64>> BSplineCurve<P> {
+    /// Returns the Bézier curve of the Taylor expansion at `t` whose parameter range is
+    /// `[t, t + h]` if `h > 0` and `[t + h, t]` if `h < 0`.
+    fn taylor_extension(&self, t: f64, h: f64, continuity: ExtensionContinuity) -> BSplineCurve<P> {
+        let (pt, der) = (self.subs(t), self.der(t) * h);
+        let mut control_points = match continuity {
+            ExtensionContinuity::C2 if self.degree() > 1 => {
+                let der2 = self.der2(t) * (h * h / 2.0);
+                vec![pt, pt + der / 2.0, pt + der + der2]
+            }
+            _ => vec![pt, pt + der],
+        };
+        let degree = control_points.len() - 1;
+        let (t0, t1) = match h > 0.0 {
+            true => (t, t + h),
+            false => {
+                control_points.reverse();
+                (t + h, t)
+            }
+        };
+        let knot_vec = KnotVec::from([vec![t0; degree + 1], vec![t1; degree + 1]].concat());
+        BSplineCurve::new_unchecked(knot_vec, control_points)
+    }
+}
+
+impl<P: ControlPoint<f64> + Tolerance> ExtendCurve for BSplineCurve<P> {
+    /// Extends the curve by the Taylor expansions at the ends.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let knot_vec = KnotVec::bezier_knot(2);
+    /// let ctrl_pts = vec![Point2::new(0.0, 0.0), Point2::new(1.0, 1.0), Point2::new(2.0, 0.0)];
+    /// let bspcurve = BSplineCurve::new(knot_vec, ctrl_pts);
+    ///
+    /// // a parabola is extended to itself by C2 extrapolation.
+    /// let extended = bspcurve.extend(1.0, 0.5, ExtensionContinuity::C2);
+    /// assert_near!(extended.range_tuple().0, -1.0);
+    /// assert_near!(extended.range_tuple().1, 1.5);
+    /// for i in 0..=10 {
+    ///     let t = -1.0 + 2.5 * i as f64 / 10.0;
+    ///     assert_near!(extended.subs(t), Point2::new(2.0 * t, 2.0 * t * (1.0 - t)));
+    /// }
+    ///
+    /// // C1 extrapolation is the tangent line.
+    /// let extended = bspcurve.extend(0.0, 0.5, ExtensionContinuity::C1);
+    /// assert_near!(extended.subs(1.5), Point2::new(3.0, -1.0));
+    /// ```
+    fn extend(&self, dt0: f64, dt1: f64, continuity: ExtensionContinuity) -> Self {
+        let (t0, t1) = self.range_tuple();
+        let mut curve = self.clone();
+        if dt1 > 0.0 {
+            curve = curve.concat(&self.taylor_extension(t1, dt1, continuity));
+        }
+        if dt0 > 0.0 {
+            curve = self.taylor_extension(t0, -dt0, continuity).concat(&curve);
+        }
+        curve
+    }
+}
+
 impl<P> ParameterDivision1D for BSplineCurve<P>
 where P: ControlPoint<f64>
         + EuclideanSpace<Scalar = f64, Diff = <P as ControlPoint<f64>>::Diff>
@@ -1486,3 +1582,399 @@ fn cubic_bezier_interpolation_test() {
     assert_near!(der.front(), der0);
     assert_near!(der.back(), der1);
 }
+
+/// Inserts the sorted knots `xs` into `knot_vec` at once, by the knot refinement algorithm
+/// (A5.4 in The NURBS Book). All the knots in `xs` must be in the range of the curve.
+pub(super) fn refine_knot_vec<P: ControlPoint<f64>>(
+    knot_vec: &KnotVec,
+    degree: usize,
+    control_points: &[P],
+    xs: &[f64],
+) -> (KnotVec, Vec<P>) {
+    if xs.is_empty() {
+        return (knot_vec.clone(), control_points.to_vec());
+    }
+    let (p, n, r) = (degree, control_points.len() - 1, xs.len());
+    let span = |x: f64| match x < knot_vec[n + 1] {
+        true => knot_vec.floor(x).unwrap(),
+        false => n,
+    };
+    let a = span(xs[0]);
+    let b = span(xs[r - 1]) + 1;
+    let mut q = vec![P::origin(); n + 1 + r];
+    let mut ubar = vec![0.0; knot_vec.len() + r];
+    q[..=a - p].copy_from_slice(&control_points[..=a - p]);
+    q[b - 1 + r..].copy_from_slice(&control_points[b - 1..]);
+    ubar[..=a].copy_from_slice(&knot_vec[..=a]);
+    ubar[b + p + r..].copy_from_slice(&knot_vec[b + p..]);
+    let (mut i, mut k) = (b + p - 1, b + p - 1 + r);
+    for &x in xs.iter().rev() {
+        while x <= knot_vec[i] && i > a {
+            q[k - p - 1] = control_points[i - p - 1];
+            ubar[k] = knot_vec[i];
+            k -= 1;
+            i -= 1;
+        }
+        q[k - p - 1] = q[k - p];
+        for l in 1..=p {
+            let ind = k - p + l;
+            let alpha = (ubar[k + l] - x) * inv_or_zero(ubar[k + l] - knot_vec[i - p + l]);
+            q[ind - 1] = q[ind] + (q[ind - 1] - q[ind]) * alpha;
+        }
+        ubar[k] = x;
+        k -= 1;
+    }
+    (KnotVec::from(ubar), q)
+}
+
+/// Returns the knots to be inserted so that all the knots in the range of the curve have
+/// the multiplicity at least `degree`.
+pub(super) fn bezier_refinement_knots(knot_vec: &KnotVec, degree: usize, n: usize) -> Vec<f64> {
+    let (t0, t1) = (knot_vec[degree], knot_vec[n]);
+    let (knots, mults) = knot_vec.to_single_multi();
+    knots
+        .into_iter()
+        .zip(mults)
+        .filter(|(t, _)| t0 <= *t && *t <= t1)
+        .flat_map(|(t, mult)| std::iter::repeat(t).take(degree.saturating_sub(mult)))
+        .collect()
+}
+
+/// Returns the pairs of the index of the last control point and the range of each Bézier segment,
+/// for the knot vector refined by [`bezier_refinement_knots`].
+pub(super) fn bezier_spans(
+    knot_vec: &KnotVec,
+    degree: usize,
+    n: usize,
+) -> Vec<(usize, (f64, f64))> {
+    (degree..n)
+        .filter(|j| !knot_vec[*j].near(&knot_vec[j + 1]))
+        .map(|j| (j, (knot_vec[j], knot_vec[j + 1])))
+        .collect()
+}
+
+/// Calculates the control points after removing the `idx`th knot, by solving the knot insertion
+/// relations from both ends. Returns the new control points and the error of the removal.
+/// Returns `None` if `idx` is not the last index of an interior knot.
+fn knot_removal<P>(
+    knot_vec: &KnotVec,
+    degree: usize,
+    control_points: &[P],
+    idx: usize,
+) -> Option<(Vec<P>, f64)>
+where
+    P: ControlPoint<f64>,
+    P::Diff: InnerSpace<Scalar = f64>,
+{
+    let (k, n) = (degree, control_points.len());
+    if idx < k + 1 || idx >= n || knot_vec[idx] == knot_vec[idx + 1] {
+        return None;
+    }
+    let u = knot_vec[idx];
+    let s = (0..=idx).rev().take_while(|i| knot_vec[*i] == u).count();
+    // The knot with multiplicity `k + 1` is removed by merging the two end points of the segments.
+    if s > k + 1 {
+        return None;
+    }
+    let alpha = |i: usize| (u - knot_vec[i]) / (knot_vec[i + k + 1] - knot_vec[i]);
+    let (first, last) = (idx - k, idx - s);
+    let mut fwd = vec![control_points[first - 1]];
+    let mut bwd = vec![control_points[last + 1]];
+    let (mut i, mut j) = (first, last);
+    while j > i {
+        let q = *fwd.last().unwrap();
+        fwd.push(q + (control_points[i] - q) / alpha(i));
+        let q = *bwd.last().unwrap();
+        bwd.push(q + (control_points[j] - q) / (1.0 - alpha(j)));
+        i += 1;
+        j -= 1;
+    }
+    let (q0, q1) = (*fwd.last().unwrap(), *bwd.last().unwrap());
+    let err = if j < i {
+        fwd.pop();
+        bwd.pop();
+        fwd.push(q0 + (q1 - q0) * 0.5);
+        (q1 - q0).magnitude()
+    } else {
+        let a = alpha(i);
+        let p = q0 + (q1 - q0) * a;
+        (control_points[i] - p).magnitude()
+    };
+    let mut new_points = Vec::with_capacity(n - 1);
+    new_points.extend_from_slice(&control_points[..first - 1]);
+    new_points.extend(fwd);
+    new_points.extend(bwd.into_iter().rev());
+    new_points.extend_from_slice(&control_points[last + 2..]);
+    Some((new_points, err))
+}
+
+/// Returns the maximum distance between `curve` and `original` at the sample points.
+/// Each knot span of `original` is divided into `2 * (degree + 1)` parts.
+fn sampled_deviation<P>(curve: &BSplineCurve<P>, original: &BSplineCurve<P>) -> f64
+where
+    P: ControlPoint<f64>,
+    P::Diff: InnerSpace<Scalar = f64>, {
+    let division = 2 * (original.degree() + 1);
+    original
+        .knot_vec
+        .windows(2)
+        .filter(|span| span[0] < span[1])
+        .flat_map(|span| {
+            (0..=division).map(move |i| {
+                let p = i as f64 / division as f64;
+                span[0] * (1.0 - p) + span[1] * p
+            })
+        })
+        .fold(0.0, |max, t| {
+            f64::max(max, (curve.subs(t) - original.subs(t)).magnitude())
+        })
+}
+
+/// Removes the knots from the back in turn, keeping that each control polygon `rows[i]` with
+/// the common knot vector `knot_vec` is within `tol` from the curve `originals[i]`.
+pub(super) fn simplify_knots<P>(
+    knot_vec: &mut KnotVec,
+    degree: usize,
+    rows: &mut [Vec<P>],
+    originals: &[BSplineCurve<P>],
+    tol: f64,
+) where
+    P: ControlPoint<f64>,
+    P::Diff: InnerSpace<Scalar = f64>,
+{
+    loop {
+        let mut removed = false;
+        let mut idx = knot_vec.len() - 1;
+        while idx > 0 {
+            idx -= 1;
+            let new_rows = rows
+                .iter()
+                .map(|row| match knot_removal(knot_vec, degree, row, idx) {
+                    Some((new_row, err)) if err <= tol => Some(new_row),
+                    _ => None,
+                })
+                .collect::<Option<Vec<_>>>();
+            let Some(new_rows) = new_rows else {
+                continue;
+            };
+            let mut new_knot_vec = knot_vec.clone();
+            new_knot_vec.remove(idx);
+            let within_tol = new_rows.iter().zip(originals).all(|(row, original)| {
+                let curve = BSplineCurve::new_unchecked(new_knot_vec.clone(), row.clone());
+                sampled_deviation(&curve, original) <= tol
+            });
+            if within_tol {
+                *knot_vec = new_knot_vec;
+                rows.iter_mut()
+                    .zip(new_rows)
+                    .for_each(|(row, new_row)| *row = new_row);
+                removed = true;
+            }
+        }
+        if !removed {
+            break;
+        }
+    }
+}
+
+impl<P> BSplineCurve<P>
+where
+    P: ControlPoint<f64> + Tolerance,
+    P::Diff: InnerSpace<Scalar = f64>,
+{
+    /// Removes knots as long as the curve stays within `tol` from the original curve.
+    ///
+    /// Unlike [`BSplineCurve::optimize`], the knots which are not exactly removable are also removed
+    /// if the error of the removal is less than `tol`. The distance from the original curve is
+    /// checked at the sample points on each knot span.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let knot_vec = KnotVec::bezier_knot(3);
+    /// let ctrl_pts = vec![
+    ///     Point2::new(0.0, 0.0),
+    ///     Point2::new(1.0, 1.0),
+    ///     Point2::new(2.0, -1.0),
+    ///     Point2::new(3.0, 0.0),
+    /// ];
+    /// let org_curve = BSplineCurve::new(knot_vec, ctrl_pts);
+    ///
+    /// // over-refined and slightly perturbed curve
+    /// let mut bspcurve = org_curve.clone();
+    /// (1..10).for_each(|i| {
+    ///     bspcurve.add_knot(i as f64 / 10.0);
+    /// });
+    /// *bspcurve.control_point_mut(5) += Vector2::new(0.0, 1.0e-4);
+    ///
+    /// bspcurve.simplify(1.0e-3);
+    /// assert!(bspcurve.control_points().len() < 13);
+    /// const N: usize = 100;
+    /// for i in 0..=N {
+    ///     let t = i as f64 / N as f64;
+    ///     assert!(bspcurve.subs(t).distance(org_curve.subs(t)) < 2.0e-3);
+    /// }
+    /// ```
+    pub fn simplify(&mut self, tol: f64) -> &mut Self {
+        let original = self.clone();
+        self.simplify_along(&original, tol)
+    }
+
+    fn simplify_along(&mut self, original: &Self, tol: f64) -> &mut Self {
+        let degree = self.degree();
+        simplify_knots(
+            &mut self.knot_vec,
+            degree,
+            std::slice::from_mut(&mut self.control_points),
+            std::slice::from_ref(original),
+            tol,
+        );
+        self
+    }
+
+    /// Reduces the degree of the Bézier curve. Returns the reduced curve and its distance from `self`.
+    fn reduce_degree_bezier(&self) -> (Self, f64) {
+        let p = self.degree();
+        let pts = &self.control_points;
+        let alpha = |i: usize| i as f64 / p as f64;
+        let mut new_points = vec![pts[0]; p];
+        new_points[p - 1] = pts[p];
+        let r = (p - 1) / 2;
+        (1..=r).for_each(|i| {
+            let q = new_points[i - 1];
+            new_points[i] = q + (pts[i] - q) / (1.0 - alpha(i));
+        });
+        (r + 1..p - 1).rev().for_each(|i| {
+            let q = new_points[i + 1];
+            new_points[i] = q + (pts[i + 1] - q) / alpha(i + 1);
+        });
+        if p % 2 == 1 {
+            let q = new_points[r + 1];
+            let right = q + (pts[r + 1] - q) / alpha(r + 1);
+            let diff = right - new_points[r];
+            new_points[r] += diff * 0.5;
+        }
+        let mut knot_vec = self.knot_vec.clone();
+        knot_vec.remove(0);
+        knot_vec.remove(knot_vec.len() - 1);
+        let bezier = BSplineCurve::new_unchecked(knot_vec, new_points);
+        let err = sampled_deviation(&bezier, self);
+        (bezier, err)
+    }
+
+    /// Reduces the degree by one if the reduced curve is within `tol` from `self`.
+    /// If the degree cannot be reduced, returns [`Error::CannotReduceDegree`].
+    ///
+    /// The curve is decomposed into Bézier curves, each of which is reduced,
+    /// and the knots are removed by [`BSplineCurve::simplify`] within the rest of tolerance.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// use truck_geometry::errors::Error;
+    ///
+    /// // the parabola (t, t^2) represented by degree 3
+    /// let knot_vec = KnotVec::bezier_knot(2);
+    /// let ctrl_pts = vec![Point2::new(0.0, 0.0), Point2::new(0.5, 0.0), Point2::new(1.0, 1.0)];
+    /// let parabola = BSplineCurve::new(knot_vec, ctrl_pts);
+    /// let mut bspcurve = parabola.clone();
+    /// bspcurve.elevate_degree().add_knot(0.5);
+    /// assert_eq!(bspcurve.degree(), 3);
+    ///
+    /// bspcurve.try_reduce_degree(1.0e-6).unwrap();
+    /// assert_eq!(bspcurve.degree(), 2);
+    /// assert_eq!(bspcurve.control_points().len(), 3);
+    /// assert!(bspcurve.near_as_curve(&parabola));
+    ///
+    /// // The parabola is not a line.
+    /// assert_eq!(bspcurve.try_reduce_degree(1.0e-2), Err(Error::CannotReduceDegree));
+    /// ```
+    pub fn try_reduce_degree(&mut self, tol: f64) -> Result<&mut Self> {
+        let (reduced, err) = self.reduced_degree_without_simplify(tol)?;
+        let original = std::mem::replace(self, reduced);
+        self.simplify_along(&original, tol - err);
+        Ok(self)
+    }
+
+    /// Returns the concatenation of the reduced Bézier curves and its distance from `self`.
+    pub(super) fn reduced_degree_without_simplify(&self, tol: f64) -> Result<(Self, f64)> {
+        if self.degree() < 2 {
+            return Err(Error::CannotReduceDegree);
+        }
+        let mut result = CurveCollector::Singleton;
+        let mut max_err = 0.0;
+        for bezier in self.bezier_decomposition() {
+            if bezier.control_points.len() != bezier.degree() + 1 {
+                return Err(Error::CannotReduceDegree);
+            }
+            let (reduced, err) = bezier.reduce_degree_bezier();
+            if err > tol {
+                return Err(Error::CannotReduceDegree);
+            }
+            max_err = f64::max(max_err, err);
+            result.concat(&reduced);
+        }
+        Ok((result.unwrap(), max_err))
+    }
+
+    /// Reduces the degree by one if the reduced curve is within `tol` from `self`.
+    /// If the degree cannot be reduced, does not change `self`. cf.[`BSplineCurve::try_reduce_degree`]
+    #[inline(always)]
+    pub fn reduce_degree(&mut self, tol: f64) -> &mut Self {
+        let _ = self.try_reduce_degree(tol);
+        self
+    }
+}
+
+#[test]
+fn knot_removal_test() {
+    let knot_vec = KnotVec::uniform_knot(3, 4);
+    let ctrl_pts = (0..7)
+        .map(|_| {
+            Point3::new(
+                rand::random::<f64>(),
+                rand::random::<f64>(),
+                rand::random::<f64>(),
+            )
+        })
+        .collect::<Vec<_>>();
+    let org_curve = BSplineCurve::new(knot_vec, ctrl_pts);
+    let mut bspcurve = org_curve.clone();
+    bspcurve.add_knot(0.4).add_knot(0.4).add_knot(0.6);
+    for idx in [6, 8] {
+        let (pts, err) = knot_removal(bspcurve.knot_vec(), 3, bspcurve.control_points(), idx)
+            .unwrap_or_else(|| panic!("cannot remove {idx}"));
+        assert!(err < TOLERANCE);
+        let mut knot_vec = bspcurve.knot_vec().clone();
+        knot_vec.remove(idx);
+        let curve = BSplineCurve::new(knot_vec, pts);
+        assert!(curve.near_as_curve(&org_curve));
+    }
+    // not the last index of the multiple knot
+    assert!(knot_removal(bspcurve.knot_vec(), 3, bspcurve.control_points(), 5).is_none());
+    // the end knots
+    assert!(knot_removal(bspcurve.knot_vec(), 3, bspcurve.control_points(), 3).is_none());
+}
+
+#[test]
+fn refine_knots_random_test() {
+    let knot_vec = KnotVec::from((0..=10).map(|i| i as f64).collect::<Vec<_>>());
+    let ctrl_pts = (0..7)
+        .map(|_| Point2::new(rand::random::<f64>(), rand::random::<f64>()))
+        .collect::<Vec<_>>();
+    let org_curve = BSplineCurve::new(knot_vec, ctrl_pts);
+    let mut knots = (0..10)
+        .map(|_| 3.0 + 4.0 * rand::random::<f64>())
+        .collect::<Vec<_>>();
+    knots.extend([3.0, 5.0, 5.0, 7.0, 1.5, 9.0]);
+    let mut added_curve = org_curve.clone();
+    knots.iter().for_each(|x| {
+        added_curve.add_knot(*x);
+    });
+    let mut refined_curve = org_curve.clone();
+    refined_curve.refine_knots(&knots);
+    assert_eq!(refined_curve.knot_vec(), added_curve.knot_vec());
+    (0..=100).for_each(|i| {
+        let t = 3.0 + 4.0 * i as f64 / 100.0;
+        assert_near!(refined_curve.subs(t), org_curve.subs(t));
+        assert_near!(refined_curve.subs(t), added_curve.subs(t));
+    });
+}
@@ -998,6 +998,144 @@ impl<P: ControlPoint<f64> + Tolerance> BSplineSurface<P> {
         self
     }
 
+    /// Adds the knots `knots` of the first parameter `u` at once, and do not change `self` as a surface.
+    ///
+    /// See [`BSplineCurve::refine_knots`] for details.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let knot_vecs = (KnotVec::bezier_knot(2), KnotVec::bezier_knot(1));
+    /// let ctrl_pts = vec![
+    ///     vec![Vector2::new(0.0, 0.0), Vector2::new(0.0, 1.0)],
+    ///     vec![Vector2::new(0.5, -1.0), Vector2::new(0.5, 2.0)],
+    ///     vec![Vector2::new(1.0, 0.0), Vector2::new(1.0, 1.0)],
+    /// ];
+    /// let mut bspsurface = BSplineSurface::new(knot_vecs, ctrl_pts);
+    /// let org_surface = bspsurface.clone();
+    /// bspsurface.refine_uknots(&[0.3, 0.5, 0.5]);
+    /// assert!(bspsurface.near2_as_surface(&org_surface));
+    /// assert_eq!(bspsurface.uknot_vec().len(), org_surface.uknot_vec().len() + 3);
+    /// ```
+    pub fn refine_uknots(&mut self, knots: &[f64]) -> &mut Self {
+        self.swap_axes();
+        self.refine_vknots(knots);
+        self.swap_axes()
+    }
+
+    /// Adds the knots `knots` of the second parameter `v` at once, and do not change `self` as a surface.
+    ///
+    /// See [`BSplineCurve::refine_knots`] for details.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let knot_vecs = (KnotVec::bezier_knot(1), KnotVec::bezier_knot(2));
+    /// let ctrl_pts = vec![
+    ///     vec![Vector2::new(0.0, 0.0), Vector2::new(0.5, -1.0), Vector2::new(1.0, 0.0)],
+    ///     vec![Vector2::new(0.0, 1.0), Vector2::new(0.5, 2.0), Vector2::new(1.0, 1.0)],
+    /// ];
+    /// let mut bspsurface = BSplineSurface::new(knot_vecs, ctrl_pts);
+    /// let org_surface = bspsurface.clone();
+    /// bspsurface.refine_vknots(&[0.3, 0.5, 0.5]);
+    /// assert!(bspsurface.near2_as_surface(&org_surface));
+    /// assert_eq!(bspsurface.vknot_vec().len(), org_surface.vknot_vec().len() + 3);
+    /// ```
+    pub fn refine_vknots(&mut self, knots: &[f64]) -> &mut Self {
+        let (k, n) = (self.vdegree(), self.control_points[0].len());
+        let (t0, t1) = (self.knot_vecs.1[k], self.knot_vecs.1[n]);
+        let (mut inner, outer): (Vec<f64>, Vec<f64>) =
+            knots.iter().partition(|x| t0 <= **x && **x <= t1);
+        inner.sort_by(|x, y| x.partial_cmp(y).unwrap());
+        let mut refined_knot_vec = None;
+        self.control_points.iter_mut().for_each(|row| {
+            let (knot_vec, control_points) =
+                bspcurve::refine_knot_vec(&self.knot_vecs.1, k, row, &inner);
+            *row = control_points;
+            refined_knot_vec = Some(knot_vec);
+        });
+        if let Some(knot_vec) = refined_knot_vec {
+            self.knot_vecs.1 = knot_vec;
+        }
+        outer.into_iter().for_each(|x| {
+            self.add_vknot(x);
+        });
+        self
+    }
+
+    /// Adds the knots `uknots` and `vknots` at once, and do not change `self` as a surface.
+    ///
+    /// See [`BSplineCurve::refine_knots`] for details.
+    #[inline(always)]
+    pub fn refine_knots(&mut self, uknots: &[f64], vknots: &[f64]) -> &mut Self {
+        self.refine_uknots(uknots).refine_vknots(vknots)
+    }
+
+    /// Separates `self` into Bézier patches by each knots.
+    /// The patch `patches[i][j]` corresponds to the `i`th span of `u` and the `j`th span of `v`.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let knot_vecs = (KnotVec::uniform_knot(2, 3), KnotVec::uniform_knot(1, 2));
+    /// let ctrl_pts = (0..5)
+    ///     .map(|i| (0..3).map(|j| Vector3::new(i as f64, j as f64, (i * j % 3) as f64)).collect())
+    ///     .collect();
+    /// let bspsurface = BSplineSurface::new(knot_vecs, ctrl_pts);
+    /// let patches = bspsurface.bezier_decomposition();
+    /// assert_eq!(patches.len(), 3);
+    /// assert_eq!(patches[0].len(), 2);
+    ///
+    /// const N: usize = 10;
+    /// for (i, row) in patches.iter().enumerate() {
+    ///     for (j, patch) in row.iter().enumerate() {
+    ///         assert_eq!(patch.uknot_vec().len(), 6);
+    ///         assert_eq!(patch.vknot_vec().len(), 4);
+    ///         for (k, l) in (0..=N).flat_map(|k| (0..=N).map(move |l| (k, l))) {
+    ///             let u = (i as f64 + k as f64 / N as f64) / 3.0;
+    ///             let v = (j as f64 + l as f64 / N as f64) / 2.0;
+    ///             assert_near!(patch.subs(u, v), bspsurface.subs(u, v));
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn bezier_decomposition(&self) -> Vec<Vec<BSplineSurface<P>>> {
+        use super::bspcurve::{bezier_refinement_knots, bezier_spans};
+        let (k0, k1) = self.degrees();
+        let (n0, n1) = (self.control_points.len(), self.control_points[0].len());
+        let mut surface = self.clone();
+        surface.refine_knots(
+            &bezier_refinement_knots(&self.knot_vecs.0, k0, n0),
+            &bezier_refinement_knots(&self.knot_vecs.1, k1, n1),
+        );
+        let (n0, n1) = (
+            surface.control_points.len(),
+            surface.control_points[0].len(),
+        );
+        let uspans = bezier_spans(&surface.knot_vecs.0, k0, n0);
+        let vspans = bezier_spans(&surface.knot_vecs.1, k1, n1);
+        let bezier_knot = |degree: usize, (t0, t1): (f64, f64)| {
+            let mut knot_vec = KnotVec::bezier_knot(degree);
+            knot_vec.transform(t1 - t0, t0);
+            knot_vec
+        };
+        uspans
+            .iter()
+            .map(|&(i, urange)| {
+                vspans
+                    .iter()
+                    .map(|&(j, vrange)| {
+                        let control_points = surface.control_points[i - k0..=i]
+                            .iter()
+                            .map(|row| row[j - k1..=j].to_vec())
+                            .collect();
+                        BSplineSurface::new(
+                            (bezier_knot(k0, urange), bezier_knot(k1, vrange)),
+                            control_points,
+                        )
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
     /// Removes the uknot corresponding to the indice `idx`, and do not change `self` as a curve.
     /// If the knot cannot be removed, returns
     /// [`Error::CannotRemoveKnot`](./errors/enum.Error.html#variant.CannotRemoveKnot).
@@ -1696,6 +1834,72 @@ impl<P: ControlPoint<f64> + Tolerance> BSplineSurface<P> {
         BSplineSurface::new(knot_vecs, control_points)
     }
 
+    /// Creates the bilinearly blended Coons patch by its boundary.
+    ///
+    /// The boundary curves are oriented in the same way as [`BSplineSurface::by_boundary`], i.e.
+    /// `curves[0]` is the side `v = 0`, `curves[1]` is the side `u = 1`, `curves[2]` is the side
+    /// `v = 1` in the inverse direction and `curves[3]` is the side `u = 0` in the inverse direction.
+    /// The degrees and the knot vectors of the curves do not need to be compatible.
+    /// The knot vectors of the surface are normalized.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let curves = [
+    ///     BSplineCurve::new(
+    ///         KnotVec::bezier_knot(1),
+    ///         vec![Point3::new(0.0, 0.0, 0.0), Point3::new(1.0, 0.0, 0.0)],
+    ///     ),
+    ///     BSplineCurve::new(
+    ///         KnotVec::bezier_knot(2),
+    ///         vec![Point3::new(1.0, 0.0, 0.0), Point3::new(1.0, 0.5, 1.0), Point3::new(1.0, 1.0, 0.0)],
+    ///     ),
+    ///     BSplineCurve::new(
+    ///         KnotVec::uniform_knot(3, 2),
+    ///         vec![
+    ///             Point3::new(1.0, 1.0, 0.0),
+    ///             Point3::new(0.8, 1.0, -0.5),
+    ///             Point3::new(0.5, 1.0, 0.0),
+    ///             Point3::new(0.2, 1.0, 0.5),
+    ///             Point3::new(0.0, 1.0, 0.0),
+    ///         ],
+    ///     ),
+    ///     BSplineCurve::new(
+    ///         KnotVec::bezier_knot(1),
+    ///         vec![Point3::new(0.0, 1.0, 0.0), Point3::new(0.0, 0.0, 0.0)],
+    ///     ),
+    /// ];
+    /// let surface = BSplineSurface::coons(curves.clone());
+    /// const N: usize = 10;
+    /// for i in 0..=N {
+    ///     let t = i as f64 / N as f64;
+    ///     assert_near!(surface.subs(t, 0.0), curves[0].subs(t));
+    ///     assert_near!(surface.subs(1.0, t), curves[1].subs(t));
+    ///     assert_near!(surface.subs(1.0 - t, 1.0), curves[2].subs(t));
+    ///     assert_near!(surface.subs(0.0, 1.0 - t), curves[3].subs(t));
+    /// }
+    /// ```
+    pub fn coons(curves: [BSplineCurve<P>; 4]) -> BSplineSurface<P> {
+        let [curve0, curve1, mut curve2, mut curve3] = curves;
+        curve2.invert();
+        curve3.invert();
+        let mut u_curves = [curve0, curve2];
+        let mut v_curves = [curve3, curve1];
+        syncro_curves(&mut u_curves);
+        syncro_curves(&mut v_curves);
+        let corner = |p: P, q: P| p + (q - p) / 2.0;
+        let points = vec![
+            vec![
+                corner(u_curves[0].front(), v_curves[0].front()),
+                corner(u_curves[1].front(), v_curves[0].back()),
+            ],
+            vec![
+                corner(u_curves[0].back(), v_curves[1].front()),
+                corner(u_curves[1].back(), v_curves[1].back()),
+            ],
+        ];
+        gordon_by_parameters(&u_curves, &v_curves, &[0.0, 1.0], &[0.0, 1.0], &points)
+    }
+
     /// Normalizes the knot vectors
     #[inline(always)]
     pub fn knot_normalize(&mut self) -> &mut Self {
@@ -1843,6 +2047,242 @@ impl<P: ControlPoint<f64> + Tolerance> BSplineSurface<P> {
     }
 }
 
+impl<P> BSplineSurface<P>
+where
+    P: ControlPoint<f64> + Tolerance,
+    P::Diff: InnerSpace<Scalar = f64>,
+{
+    /// Removes knots as long as the surface stays within `tol` from the original surface.
+    ///
+    /// The knots of each parameter are removed within the half of `tol`. cf.[`BSplineCurve::simplify`]
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let knot_vecs = (KnotVec::bezier_knot(2), KnotVec::bezier_knot(2));
+    /// let ctrl_pts = vec![
+    ///     vec![Point3::new(0.0, 0.0, 0.0), Point3::new(0.5, 0.0, 1.0), Point3::new(1.0, 0.0, 0.0)],
+    ///     vec![Point3::new(0.0, 0.5, 1.0), Point3::new(0.5, 0.5, 2.0), Point3::new(1.0, 0.5, 1.0)],
+    ///     vec![Point3::new(0.0, 1.0, 0.0), Point3::new(0.5, 1.0, 1.0), Point3::new(1.0, 1.0, 0.0)],
+    /// ];
+    /// let org_surface = BSplineSurface::new(knot_vecs, ctrl_pts);
+    /// let mut bspsurface = org_surface.clone();
+    /// bspsurface.add_uknot(0.3).add_uknot(0.6).add_vknot(0.2).add_vknot(0.7);
+    ///
+    /// bspsurface.simplify(1.0e-3);
+    /// assert_eq!(bspsurface.uknot_vec(), org_surface.uknot_vec());
+    /// assert_eq!(bspsurface.vknot_vec(), org_surface.vknot_vec());
+    /// assert!(bspsurface.near_as_surface(&org_surface));
+    /// ```
+    pub fn simplify(&mut self, tol: f64) -> &mut Self {
+        self.simplify_vknots(tol / 2.0);
+        self.swap_axes();
+        self.simplify_vknots(tol / 2.0);
+        self.swap_axes();
+        self
+    }
+
+    fn simplify_vknots(&mut self, tol: f64) {
+        let originals = (0..self.control_points.len())
+            .map(|i| self.column_curve(i))
+            .collect::<Vec<_>>();
+        self.simplify_vknots_along(&originals, tol);
+    }
+
+    fn simplify_vknots_along(&mut self, originals: &[BSplineCurve<P>], tol: f64) {
+        let degree = self.vdegree();
+        bspcurve::simplify_knots(
+            &mut self.knot_vecs.1,
+            degree,
+            &mut self.control_points,
+            originals,
+            tol,
+        );
+    }
+
+    /// Reduces the vdegree by one if the reduced surface is within `tol` from `self`.
+    /// If the degree cannot be reduced, returns [`Error::CannotReduceDegree`].
+    /// cf.[`BSplineCurve::try_reduce_degree`]
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let knot_vecs = (KnotVec::bezier_knot(1), KnotVec::bezier_knot(2));
+    /// let ctrl_pts = vec![
+    ///     vec![Point3::new(0.0, 0.0, 0.0), Point3::new(0.0, 0.5, 1.0), Point3::new(0.0, 1.0, 0.0)],
+    ///     vec![Point3::new(1.0, 0.0, 0.0), Point3::new(1.0, 0.5, 1.0), Point3::new(1.0, 1.0, 0.0)],
+    /// ];
+    /// let org_surface = BSplineSurface::new(knot_vecs, ctrl_pts);
+    /// let mut bspsurface = org_surface.clone();
+    /// bspsurface.elevate_vdegree().elevate_vdegree();
+    /// assert_eq!(bspsurface.vdegree(), 4);
+    ///
+    /// bspsurface.try_reduce_vdegree(1.0e-6).unwrap();
+    /// bspsurface.try_reduce_vdegree(1.0e-6).unwrap();
+    /// assert_eq!(bspsurface.vdegree(), 2);
+    /// assert!(bspsurface.near_as_surface(&org_surface));
+    /// assert!(bspsurface.try_reduce_vdegree(1.0e-2).is_err());
+    /// ```
+    pub fn try_reduce_vdegree(&mut self, tol: f64) -> Result<&mut Self> {
+        let originals = (0..self.control_points.len())
+            .map(|i| self.column_curve(i))
+            .collect::<Vec<_>>();
+        let reduced = originals
+            .iter()
+            .map(|curve| curve.reduced_degree_without_simplify(tol))
+            .collect::<Result<Vec<_>>>()?;
+        let max_err = reduced
+            .iter()
+            .fold(0.0, |max, (_, err)| f64::max(max, *err));
+        self.knot_vecs.1 = reduced[0].0.knot_vec.clone();
+        self.control_points = reduced
+            .into_iter()
+            .map(|(curve, _)| curve.control_points)
+            .collect();
+        self.simplify_vknots_along(&originals, tol - max_err);
+        Ok(self)
+    }
+
+    /// Reduces the udegree by one if the reduced surface is within `tol` from `self`.
+    /// If the degree cannot be reduced, returns [`Error::CannotReduceDegree`].
+    /// cf.[`BSplineSurface::try_reduce_vdegree`]
+    pub fn try_reduce_udegree(&mut self, tol: f64) -> Result<&mut Self> {
+        self.swap_axes();
+        let res = self.try_reduce_vdegree(tol).map(|_| ());
+        self.swap_axes();
+        res.map(move |_| self)
+    }
+
+    /// Reduces the vdegree by one if the reduced surface is within `tol` from `self`.
+    /// If the degree cannot be reduced, does not change `self`.
+    /// cf.[`BSplineSurface::try_reduce_vdegree`]
+    #[inline(always)]
+    pub fn reduce_vdegree(&mut self, tol: f64) -> &mut Self {
+        let _ = self.try_reduce_vdegree(tol);
+        self
+    }
+
+    /// Reduces the udegree by one if the reduced surface is within `tol` from `self`.
+    /// If the degree cannot be reduced, does not change `self`.
+    /// cf.[`BSplineSurface::try_reduce_udegree`]
+    #[inline(always)]
+    pub fn reduce_udegree(&mut self, tol: f64) -> &mut Self {
+        let _ = self.try_reduce_udegree(tol);
+        self
+    }
+}
+
+impl<P> BSplineSurface<P>
+where
+    P: ControlPoint<f64>
+        + EuclideanSpace<Scalar = f64, Diff = <P as ControlPoint<f64>>::Diff>
+        + MetricSpace<Metric = f64>
+        + Tolerance,
+    <P as ControlPoint<f64>>::Diff: InnerSpace<Scalar = f64> + Tolerance,
+{
+    /// Creates the Gordon surface interpolating the network of curves.
+    /// # Panics
+    /// There are the same conditions for panic as [`BSplineSurface::try_gordon`].
+    #[inline(always)]
+    pub fn gordon(
+        u_curves: Vec<BSplineCurve<P>>,
+        v_curves: Vec<BSplineCurve<P>>,
+    ) -> BSplineSurface<P> {
+        Self::try_gordon(u_curves, v_curves).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Creates the Gordon surface interpolating the network of curves.
+    ///
+    /// `u_curves` are the curves with respect to `u` ordered by `v`, and `v_curves` are
+    /// the curves with respect to `v` ordered by `u`. The first and the last curves of each family
+    /// have to be the boundary of the surface, and each curve of a family has to intersect all curves
+    /// of the other family. The degrees and the knot vectors of the curves do not need to be compatible.
+    /// The knot vectors of the surface are normalized.
+    /// # Failures
+    /// If the curves do not form such a network, returns [`Error::InvalidCurveNetwork`].
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// // the network on the surface z = u(1 - u) + v(1 - v)
+    /// let u_curves = [0.0, 0.5, 1.0]
+    ///     .iter()
+    ///     .map(|&v| {
+    ///         let z = v * (1.0 - v);
+    ///         BSplineCurve::new(
+    ///             KnotVec::bezier_knot(2),
+    ///             vec![Point3::new(0.0, v, z), Point3::new(0.5, v, 0.5 + z), Point3::new(1.0, v, z)],
+    ///         )
+    ///     })
+    ///     .collect();
+    /// let v_curves = [0.0, 0.5, 1.0]
+    ///     .iter()
+    ///     .map(|&u| {
+    ///         let z = u * (1.0 - u);
+    ///         BSplineCurve::new(
+    ///             KnotVec::bezier_knot(2),
+    ///             vec![Point3::new(u, 0.0, z), Point3::new(u, 0.5, 0.5 + z), Point3::new(u, 1.0, z)],
+    ///         )
+    ///     })
+    ///     .collect();
+    /// let surface = BSplineSurface::try_gordon(u_curves, v_curves).unwrap();
+    /// const N: usize = 10;
+    /// for i in 0..=N {
+    ///     for j in 0..=N {
+    ///         let (u, v) = (i as f64 / N as f64, j as f64 / N as f64);
+    ///         let z = u * (1.0 - u) + v * (1.0 - v);
+    ///         assert_near!(surface.subs(u, v), Point3::new(u, v, z));
+    ///     }
+    /// }
+    /// ```
+    pub fn try_gordon(
+        mut u_curves: Vec<BSplineCurve<P>>,
+        mut v_curves: Vec<BSplineCurve<P>>,
+    ) -> Result<BSplineSurface<P>> {
+        if u_curves.len() < 2 || v_curves.len() < 2 {
+            return Err(Error::InvalidCurveNetwork);
+        }
+        syncro_curves(&mut u_curves);
+        syncro_curves(&mut v_curves);
+        let intersection_parameters = |curve: &BSplineCurve<P>, curves: &[BSplineCurve<P>]| {
+            let params = curves
+                .iter()
+                .map(|c| curve.search_nearest_parameter(c.front(), SPHint1D::None, 100))
+                .collect::<Option<Vec<f64>>>()?;
+            let valid = params[0].near(&0.0)
+                && params[params.len() - 1].near(&1.0)
+                && params.windows(2).all(|a| a[0] + TOLERANCE < a[1]);
+            match valid {
+                true => Some(params),
+                false => None,
+            }
+        };
+        let u_params = intersection_parameters(&u_curves[0], &v_curves);
+        let v_params = intersection_parameters(&v_curves[0], &u_curves);
+        let (u_params, v_params) = match (u_params, v_params) {
+            (Some(u_params), Some(v_params)) => (u_params, v_params),
+            _ => return Err(Error::InvalidCurveNetwork),
+        };
+        let points = v_curves
+            .iter()
+            .zip(&u_params)
+            .map(|(v_curve, u)| {
+                u_curves
+                    .iter()
+                    .zip(&v_params)
+                    .map(|(u_curve, v)| {
+                        let (p, q) = (u_curve.subs(*u), v_curve.subs(*v));
+                        match p.near(&q) {
+                            true => Ok(p + (q - p) / 2.0),
+                            false => Err(Error::InvalidCurveNetwork),
+                        }
+                    })
+                    .collect::<Result<Vec<_>>>()
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(gordon_by_parameters(
+            &u_curves, &v_curves, &u_params, &v_params, &points,
+        ))
+    }
+}
+
 impl<V: Bounded> BSplineSurface<V> {
     /// Returns the bounding box including all control points.
     #[inline(always)]
@@ -1870,6 +2310,59 @@ impl ParametricSurface3D for BSplineSurface<Point3> {}
 
 impl<V> BoundedSurface for BSplineSurface<V> where BSplineSurface<V>: ParametricSurface {}
 
+impl<P: ControlPoint<f64> + Tolerance> BSplineSurface<P> {
+    /// Extends the surface in the `u`-direction by extending the row curves.
+    fn uextend(&self, du0: f64, du1: f64, continuity: ExtensionContinuity) -> BSplineSurface<P> {
+        // the knot vectors of the extended curves coincide since they depend only on the knot vector.
+        let curves: Vec<_> = (0..self.control_points[0].len())
+            .map(|j| self.row_curve(j).extend(du0, du1, continuity))
+            .collect();
+        let uknot_vec = curves[0].knot_vec().clone();
+        let control_points = (0..curves[0].control_points().len())
+            .map(|i| curves.iter().map(|curve| *curve.control_point(i)).collect())
+            .collect();
+        BSplineSurface::new_unchecked((uknot_vec, self.vknot_vec().clone()), control_points)
+    }
+}
+
+impl<P: ControlPoint<f64> + Tolerance> ExtendSurface for BSplineSurface<P> {
+    /// Extends the surface by the Taylor expansions at the boundaries.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let knot_vecs = (KnotVec::bezier_knot(2), KnotVec::bezier_knot(1));
+    /// let ctrl_pts = vec![
+    ///     vec![Point3::new(0.0, 0.0, 0.0), Point3::new(0.0, 1.0, 0.0)],
+    ///     vec![Point3::new(1.0, 0.0, 1.0), Point3::new(1.0, 1.0, 1.0)],
+    ///     vec![Point3::new(2.0, 0.0, 0.0), Point3::new(2.0, 1.0, 0.0)],
+    /// ];
+    /// let bspsurface = BSplineSurface::new(knot_vecs, ctrl_pts);
+    /// let extended = bspsurface.extend(0.5, 0.5, 1.0, 0.0, ExtensionContinuity::C2);
+    /// assert_eq!(extended.range_tuple(), ((-0.5, 1.5), (-1.0, 1.0)));
+    /// for i in 0..=10 {
+    ///     for j in 0..=10 {
+    ///         let (u, v) = (-0.5 + 2.0 * i as f64 / 10.0, -1.0 + 2.0 * j as f64 / 10.0);
+    ///         let pt = Point3::new(2.0 * u, v, 2.0 * u * (1.0 - u));
+    ///         assert_near!(extended.subs(u, v), pt);
+    ///     }
+    /// }
+    /// ```
+    fn extend(
+        &self,
+        du0: f64,
+        du1: f64,
+        dv0: f64,
+        dv1: f64,
+        continuity: ExtensionContinuity,
+    ) -> Self {
+        let mut surface = self.uextend(du0, du1, continuity);
+        surface.swap_axes();
+        let mut surface = surface.uextend(dv0, dv1, continuity);
+        surface.swap_axes();
+        surface
+    }
+}
+
 impl<V: Clone> Invertible for BSplineSurface<V> {
     #[inline(always)]
     fn invert(&mut self) { self.swap_axes(); }
@@ -2131,6 +2624,178 @@ fn combinatorial(n: usize) -> Vec<usize> {
     res
 }
 
+/// Normalizes the knot vectors of `curves` and makes them have the same degree and knot vector.
+fn syncro_curves<P: ControlPoint<f64> + Tolerance>(curves: &mut [BSplineCurve<P>]) {
+    let degree = curves.iter().map(BSplineCurve::degree).max().unwrap();
+    curves.iter_mut().for_each(|curve| {
+        (curve.degree()..degree).for_each(|_| {
+            curve.elevate_degree();
+        });
+    });
+    let (first, others) = curves.split_first_mut().unwrap();
+    first.knot_normalize();
+    // The second loop adds the knots of the later curves to the former ones.
+    for _ in 0..2 {
+        others
+            .iter_mut()
+            .for_each(|curve| first.syncro_knots(curve));
+    }
+}
+
+/// Returns the knot vector and the control points of the B-spline curve interpolating `points` at
+/// `params` (A9.1 in The NURBS Book). The degree is `min(3, points.len() - 1)` and the knots are
+/// determined by averaging the parameters.
+fn interpolation<P: ControlPoint<f64>>(params: &[f64], points: &[P]) -> (KnotVec, Vec<P>) {
+    let (n, p) = (params.len(), usize::min(3, params.len() - 1));
+    let mut knots = vec![params[0]; p + 1];
+    knots.extend((1..n - p).map(|j| params[j..j + p].iter().sum::<f64>() / p as f64));
+    knots.extend(vec![params[n - 1]; p + 1]);
+    let knot_vec = KnotVec::from(knots);
+    let mut matrix = params
+        .iter()
+        .map(|t| knot_vec.bspline_basis_functions(p, *t))
+        .collect::<Vec<_>>();
+    let mut rhs = points.iter().map(|pt| pt.to_vec()).collect::<Vec<_>>();
+    for k in 0..n {
+        let pivot = (k..n)
+            .max_by(|i, j| {
+                matrix[*i][k]
+                    .abs()
+                    .partial_cmp(&matrix[*j][k].abs())
+                    .unwrap()
+            })
+            .unwrap();
+        matrix.swap(k, pivot);
+        rhs.swap(k, pivot);
+        let (row, vec) = (matrix[k].clone(), rhs[k]);
+        for i in k + 1..n {
+            let r = matrix[i][k] / row[k];
+            matrix[i]
+                .iter_mut()
+                .zip(&row)
+                .for_each(|(a, b)| *a -= r * b);
+            rhs[i] -= vec * r;
+        }
+    }
+    let mut sol = vec![P::Diff::zero(); n];
+    for k in (0..n).rev() {
+        let sum = (k + 1..n).fold(rhs[k], |sum, j| sum - sol[j] * matrix[k][j]);
+        sol[k] = sum / matrix[k][k];
+    }
+    (
+        knot_vec,
+        sol.into_iter().map(|vec| P::origin() + vec).collect(),
+    )
+}
+
+/// Returns the knot vector with the maximum multiplicities of all knots in `knot_vecs`,
+/// by the pairs of knots and multiplicities.
+fn union_knots<'a>(knot_vecs: impl IntoIterator<Item = &'a KnotVec>) -> Vec<(f64, usize)> {
+    let mut union = Vec::<(f64, usize)>::new();
+    knot_vecs.into_iter().for_each(|knot_vec| {
+        let (knots, mults) = knot_vec.to_single_multi();
+        knots.into_iter().zip(mults).for_each(|(x, m)| {
+            match union.iter_mut().find(|(y, _)| x.near(y)) {
+                Some((_, n)) => *n = usize::max(*n, m),
+                None => union.push((x, m)),
+            }
+        });
+    });
+    union
+}
+
+/// Returns the knots which are to be added to `knot_vec` for containing `union`.
+fn missing_knots(knot_vec: &KnotVec, union: &[(f64, usize)]) -> Vec<f64> {
+    union
+        .iter()
+        .flat_map(|&(x, m)| {
+            let count = knot_vec.iter().filter(|&&y| y.near(&x)).count();
+            std::iter::repeat(x).take(m.saturating_sub(count))
+        })
+        .collect()
+}
+
+/// Makes the surfaces have the same degrees and knot vectors.
+/// The ranges of knot vectors have to be the same.
+fn syncro_surfaces<P: ControlPoint<f64> + Tolerance>(surfaces: &mut [BSplineSurface<P>]) {
+    let udegree = surfaces.iter().map(BSplineSurface::udegree).max().unwrap();
+    let vdegree = surfaces.iter().map(BSplineSurface::vdegree).max().unwrap();
+    surfaces.iter_mut().for_each(|surface| {
+        (surface.udegree()..udegree).for_each(|_| {
+            surface.elevate_udegree();
+        });
+        (surface.vdegree()..vdegree).for_each(|_| {
+            surface.elevate_vdegree();
+        });
+    });
+    let uunion = union_knots(surfaces.iter().map(|surface| &surface.knot_vecs.0));
+    let vunion = union_knots(surfaces.iter().map(|surface| &surface.knot_vecs.1));
+    surfaces.iter_mut().for_each(|surface| {
+        let uknots = missing_knots(&surface.knot_vecs.0, &uunion);
+        let vknots = missing_knots(&surface.knot_vecs.1, &vunion);
+        surface.refine_knots(&uknots, &vknots);
+    });
+}
+
+/// Creates the Gordon surface by the Boolean sum of the skinning surfaces of both families and
+/// the tensor product surface interpolating the intersection points.
+///
+/// The curves of each family have to be compatible with the normalized knot vector.
+/// `u_params` are the parameters of `v_curves` with respect to `u`, and `v_params` are ones
+/// of `u_curves` with respect to `v`. `points[i][j]` is the intersection of `v_curves[i]` and `u_curves[j]`.
+fn gordon_by_parameters<P: ControlPoint<f64> + Tolerance>(
+    u_curves: &[BSplineCurve<P>],
+    v_curves: &[BSplineCurve<P>],
+    u_params: &[f64],
+    v_params: &[f64],
+    points: &[Vec<P>],
+) -> BSplineSurface<P> {
+    let skinning = |curves: &[BSplineCurve<P>], params: &[f64]| {
+        let (mut knot_vec, mut control_points) = (KnotVec::new(), Vec::new());
+        (0..curves[0].control_points.len()).for_each(|i| {
+            let pts = curves
+                .iter()
+                .map(|curve| curve.control_points[i])
+                .collect::<Vec<_>>();
+            let (vec, row) = interpolation(params, &pts);
+            knot_vec = vec;
+            control_points.push(row);
+        });
+        BSplineSurface::new_unchecked((curves[0].knot_vec.clone(), knot_vec), control_points)
+    };
+    let surface0 = skinning(u_curves, v_params);
+    let mut surface1 = skinning(v_curves, u_params);
+    surface1.swap_axes();
+
+    let (mut uknot_vec, mut columns) = (KnotVec::new(), Vec::new());
+    (0..v_params.len()).for_each(|j| {
+        let pts = points.iter().map(|pts| pts[j]).collect::<Vec<_>>();
+        let (vec, column) = interpolation(u_params, &pts);
+        uknot_vec = vec;
+        columns.push(column);
+    });
+    let (mut vknot_vec, mut control_points) = (KnotVec::new(), Vec::new());
+    (0..columns[0].len()).for_each(|i| {
+        let pts = columns.iter().map(|column| column[i]).collect::<Vec<_>>();
+        let (vec, row) = interpolation(v_params, &pts);
+        vknot_vec = vec;
+        control_points.push(row);
+    });
+    let surface2 = BSplineSurface::new_unchecked((uknot_vec, vknot_vec), control_points);
+
+    let mut surfaces = [surface0, surface1, surface2];
+    syncro_surfaces(&mut surfaces);
+    let [mut surface0, surface1, surface2] = surfaces;
+    surface0
+        .control_points
+        .iter_mut()
+        .flatten()
+        .zip(surface1.control_points.iter().flatten())
+        .zip(surface2.control_points.iter().flatten())
+        .for_each(|((p, q), r)| *p += *q - *r);
+    surface0
+}
+
 #[test]
 fn test_include_bspcurve2() {
     let knot_vec = KnotVec::uniform_knot(2, 3);
@@ -2228,3 +2893,37 @@ fn test_include_bspcurve3() {
     *curve.control_point_mut(2) += Vector3::new(0.0, 0.0, 0.001);
     assert!(!surface.include(&curve));
 }
+
+#[test]
+fn refine_knots_and_bezier_decomposition_random_test() {
+    (1..=4).for_each(|degree| {
+        let knot_vecs = (
+            KnotVec::uniform_knot(degree, 4),
+            KnotVec::uniform_knot(degree, 3),
+        );
+        let ctrl_pts = (0..degree + 4)
+            .map(|_| {
+                (0..degree + 3)
+                    .map(|_| Point3::new(rand::random(), rand::random(), rand::random()))
+                    .collect()
+            })
+            .collect();
+        let org_surface = BSplineSurface::new(knot_vecs, ctrl_pts);
+        let uknots: Vec<f64> = (0..6).map(|_| rand::random()).chain([0.5, 0.5]).collect();
+        let vknots: Vec<f64> = (0..6).map(|_| rand::random()).chain([0.0, 1.0]).collect();
+        let mut refined_surface = org_surface.clone();
+        refined_surface.refine_knots(&uknots, &vknots);
+        let patches = org_surface.bezier_decomposition();
+        assert_eq!(patches.len(), 4);
+        assert_eq!(patches[0].len(), 3);
+        (0..=20)
+            .flat_map(|i| (0..=20).map(move |j| (i, j)))
+            .for_each(|(i, j)| {
+                let (u, v) = (i as f64 / 20.0, j as f64 / 20.0);
+                let pt = org_surface.subs(u, v);
+                assert_near!(refined_surface.subs(u, v), pt);
+                let (k, l) = (usize::min(i * 4 / 20, 3), usize::min(j * 3 / 20, 2));
+                assert_near!(patches[k][l].subs(u, v), pt);
+            });
+    });
+}
@@ -118,11 +118,54 @@ pub struct NurbsCurve<V>(BSplineCurve<V>);
 #[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
 pub struct NurbsSurface<V>(BSplineSurface<V>);
 
+/// Periodic B-spline curve
+///
+/// The curve is held in the clamped form on one period, whose both ends are connected
+/// with the continuity of class C<sup>degree - 1</sup>.
+/// The parameter out of the range is regarded modulo the period.
+/// # Examples
+/// ```
+/// use truck_geometry::prelude::*;
+///
+/// // the uniform periodic quadratic B-spline whose control polygon is a square
+/// let ctrl_pts = vec![
+///     Point2::new(1.0, 1.0),
+///     Point2::new(-1.0, 1.0),
+///     Point2::new(-1.0, -1.0),
+///     Point2::new(1.0, -1.0),
+/// ];
+/// let knots = vec![0.0, 1.0, 2.0, 3.0, 4.0];
+/// let curve = PeriodicBSplineCurve::new(2, knots, ctrl_pts);
+/// assert_eq!(curve.period(), Some(4.0));
+///
+/// assert_near!(curve.subs(-0.5), curve.subs(3.5));
+///
+/// // the clamped form is smooth across the seam.
+/// let clamped = curve.clamped();
+/// assert_near!(clamped.front(), clamped.back());
+/// assert_near!(clamped.der(0.0), clamped.der(4.0));
+/// ```
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct PeriodicBSplineCurve<P>(BSplineCurve<P>);
+
+/// Periodic B-spline surface
+///
+/// The surface is held in the clamped form on one period, and is periodic with respect to
+/// the parameters specified by `periodic`.
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct PeriodicBSplineSurface<P> {
+    surface: BSplineSurface<P>,
+    periodic: (bool, bool),
+}
+
 mod bspcurve;
 mod bspsurface;
 mod knot_vec;
 mod nurbscurve;
 mod nurbssurface;
+mod periodiccurve;
+mod periodicsurface;
+mod tight_bounding_box;
 
 #[doc(hidden)]
 #[inline(always)]
@@ -221,6 +221,12 @@ impl<V: Homogeneous<f64> + ControlPoint<f64, Diff = V> + Tolerance> NurbsCurve<V
         self
     }
 
+    /// Adds the knots `knots` at once, and do not change `self` as a curve. cf.[`BSplineCurve::refine_knots`]
+    pub fn refine_knots(&mut self, knots: &[f64]) -> &mut Self {
+        self.0.refine_knots(knots);
+        self
+    }
+
     /// Removes a knot corresponding to the indices `idx`, and do not change `self` as a curve.
     /// If cannot remove the knot, do not change `self` and return `self`.
     /// cf.[`BSplineCurve::remove_knot`]
@@ -272,6 +278,74 @@ impl<V: Homogeneous<f64> + ControlPoint<f64, Diff = V> + Tolerance> NurbsCurve<V
     pub fn syncro_knots(&mut self, other: &mut Self) { self.0.syncro_knots(&mut other.0) }
 }
 
+/// Converts the tolerance of the rational curves or surfaces to the one of the homogeneous coordinates,
+/// by the minimum weight and the maximum norm of the control points.
+pub(super) fn homogeneous_tolerance<'a, V>(
+    control_points: impl IntoIterator<Item = &'a V>,
+    tol: f64,
+) -> f64
+where
+    V: Homogeneous<f64> + InnerSpace<Scalar = f64> + 'a,
+{
+    let (w_min, norm_max) = control_points.into_iter().fold(
+        (f64::INFINITY, 0.0),
+        |(w_min, norm_max): (f64, f64), v| {
+            let w = v.weight();
+            (f64::min(w_min, w), f64::max(norm_max, v.magnitude() / w))
+        },
+    );
+    tol * w_min / (1.0 + norm_max)
+}
+
+impl<V> NurbsCurve<V>
+where V: Homogeneous<f64> + ControlPoint<f64, Diff = V> + InnerSpace<Scalar = f64> + Tolerance
+{
+    /// Removes knots as long as the curve stays within `tol` from the original curve.
+    /// cf.[`BSplineCurve::simplify`]
+    ///
+    /// The tolerance is converted to the one of the homogeneous coordinates.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let knot_vec = KnotVec::bezier_knot(2);
+    /// let ctrl_pts = vec![
+    ///     Vector3::new(1.0, 0.0, 1.0),
+    ///     Vector3::new(1.0, 1.0, 1.0),
+    ///     Vector3::new(0.0, 2.0, 2.0),
+    /// ];
+    /// // a quarter of the unit circle
+    /// let org_curve = NurbsCurve::new(BSplineCurve::new(knot_vec, ctrl_pts));
+    /// let mut curve = org_curve.clone();
+    /// curve.add_knot(0.25).add_knot(0.5).add_knot(0.75);
+    ///
+    /// curve.simplify(1.0e-3);
+    /// assert_eq!(curve.knot_vec(), org_curve.knot_vec());
+    /// assert!(curve.near_as_curve(&org_curve));
+    /// ```
+    pub fn simplify(&mut self, tol: f64) -> &mut Self {
+        let tol = homogeneous_tolerance(&self.0.control_points, tol);
+        self.0.simplify(tol);
+        self
+    }
+
+    /// Reduces the degree by one if the reduced curve is within `tol` from `self`.
+    /// If the degree cannot be reduced, returns [`Error::CannotReduceDegree`].
+    /// cf.[`BSplineCurve::try_reduce_degree`]
+    pub fn try_reduce_degree(&mut self, tol: f64) -> Result<&mut Self> {
+        let tol = homogeneous_tolerance(&self.0.control_points, tol);
+        self.0.try_reduce_degree(tol)?;
+        Ok(self)
+    }
+
+    /// Reduces the degree by one if the reduced curve is within `tol` from `self`.
+    /// If the degree cannot be reduced, does not change `self`. cf.[`BSplineCurve::reduce_degree`]
+    #[inline(always)]
+    pub fn reduce_degree(&mut self, tol: f64) -> &mut Self {
+        let _ = self.try_reduce_degree(tol);
+        self
+    }
+}
+
 impl<V: Homogeneous<f64> + ControlPoint<f64, Diff = V> + Tolerance> ParameterTransform
     for NurbsCurve<V>
 {
@@ -308,6 +382,36 @@ where <V as Homogeneous<f64>>::Point: Debug
     }
 }
 
+impl<V: Homogeneous<f64> + ControlPoint<f64, Diff = V> + Tolerance> ExtendCurve for NurbsCurve<V> {
+    /// Extends the curve by the Taylor expansions of the homogeneous curve at the ends.
+    /// # Remarks
+    /// The weights are also extrapolated, e.g. the last weight is `w(t1) + w'(t1) * dt1` in the
+    /// case of C1 extrapolation. Hence, if the weight function decreases toward an end, a long
+    /// extension may have non-positive weights, and the extended curve passes through the infinity.
+    /// Check the weights of the control points of the result if the extension is long.
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// // a conic whose weight function `1 + 2t(1 - t)` decreases toward the ends.
+    /// let curve = NurbsCurve::new(BSplineCurve::new(
+    ///     KnotVec::bezier_knot(2),
+    ///     vec![
+    ///         Vector3::new(1.0, 0.0, 1.0),
+    ///         Vector3::new(2.0, 2.0, 2.0),
+    ///         Vector3::new(0.0, 1.0, 1.0),
+    ///     ],
+    /// ));
+    /// let positive = |curve: &NurbsCurve<Vector3>| {
+    ///     curve.control_points().iter().all(|v| v.weight() > 0.0)
+    /// };
+    /// assert!(positive(&curve.extend(0.25, 0.25, ExtensionContinuity::C1)));
+    /// assert!(!positive(&curve.extend(5.0, 5.0, ExtensionContinuity::C1)));
+    /// ```
+    #[inline(always)]
+    fn extend(&self, dt0: f64, dt1: f64, continuity: ExtensionContinuity) -> Self {
+        NurbsCurve::new(self.0.extend(dt0, dt1, continuity))
+    }
+}
+
 #[test]
 fn concat_positive_test() {
     let mut part0 = NurbsCurve::new(BSplineCurve::new(
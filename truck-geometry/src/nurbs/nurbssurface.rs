@@ -371,6 +371,33 @@ impl<V: Homogeneous<f64> + ControlPoint<f64, Diff = V> + Tolerance> NurbsSurface
         self.0.add_vknot(x);
         self
     }
+    /// Adds the knots `knots` of the first parameter `u` at once, and do not change `self` as a surface.
+    #[inline(always)]
+    pub fn refine_uknots(&mut self, knots: &[f64]) -> &mut Self {
+        self.0.refine_uknots(knots);
+        self
+    }
+    /// Adds the knots `knots` of the second parameter `v` at once, and do not change `self` as a surface.
+    #[inline(always)]
+    pub fn refine_vknots(&mut self, knots: &[f64]) -> &mut Self {
+        self.0.refine_vknots(knots);
+        self
+    }
+    /// Adds the knots `uknots` and `vknots` at once, and do not change `self` as a surface.
+    #[inline(always)]
+    pub fn refine_knots(&mut self, uknots: &[f64], vknots: &[f64]) -> &mut Self {
+        self.0.refine_knots(uknots, vknots);
+        self
+    }
+    /// Separates `self` into Bézier patches by each knots. cf.[`BSplineSurface::bezier_decomposition`]
+    #[inline(always)]
+    pub fn bezier_decomposition(&self) -> Vec<Vec<Self>> {
+        self.0
+            .bezier_decomposition()
+            .into_iter()
+            .map(|row| row.into_iter().map(NurbsSurface::new).collect())
+            .collect()
+    }
     /// Removes the uknot corresponding to the indice `idx`, and do not change `self` as a curve.
     /// If the knot cannot be removed, returns [`Error::CannotRemoveKnot`].
     #[inline(always)]
@@ -522,6 +549,54 @@ impl<V: Homogeneous<f64> + ControlPoint<f64, Diff = V> + Tolerance> NurbsSurface
     pub fn boundary(&self) -> NurbsCurve<V> { NurbsCurve::new(self.0.boundary()) }
 }
 
+impl<V> NurbsSurface<V>
+where V: Homogeneous<f64> + ControlPoint<f64, Diff = V> + InnerSpace<Scalar = f64> + Tolerance
+{
+    /// Removes knots as long as the surface stays within `tol` from the original surface.
+    /// cf.[`BSplineSurface::simplify`]
+    ///
+    /// The tolerance is converted to the one of the homogeneous coordinates.
+    pub fn simplify(&mut self, tol: f64) -> &mut Self {
+        let tol = nurbscurve::homogeneous_tolerance(self.0.control_points.iter().flatten(), tol);
+        self.0.simplify(tol);
+        self
+    }
+
+    /// Reduces the udegree by one if the reduced surface is within `tol` from `self`.
+    /// If the degree cannot be reduced, returns [`Error::CannotReduceDegree`].
+    /// cf.[`BSplineSurface::try_reduce_udegree`]
+    pub fn try_reduce_udegree(&mut self, tol: f64) -> Result<&mut Self> {
+        let tol = nurbscurve::homogeneous_tolerance(self.0.control_points.iter().flatten(), tol);
+        self.0.try_reduce_udegree(tol)?;
+        Ok(self)
+    }
+
+    /// Reduces the vdegree by one if the reduced surface is within `tol` from `self`.
+    /// If the degree cannot be reduced, returns [`Error::CannotReduceDegree`].
+    /// cf.[`BSplineSurface::try_reduce_vdegree`]
+    pub fn try_reduce_vdegree(&mut self, tol: f64) -> Result<&mut Self> {
+        let tol = nurbscurve::homogeneous_tolerance(self.0.control_points.iter().flatten(), tol);
+        self.0.try_reduce_vdegree(tol)?;
+        Ok(self)
+    }
+
+    /// Reduces the udegree by one if the reduced surface is within `tol` from `self`.
+    /// If the degree cannot be reduced, does not change `self`.
+    #[inline(always)]
+    pub fn reduce_udegree(&mut self, tol: f64) -> &mut Self {
+        let _ = self.try_reduce_udegree(tol);
+        self
+    }
+
+    /// Reduces the vdegree by one if the reduced surface is within `tol` from `self`.
+    /// If the degree cannot be reduced, does not change `self`.
+    #[inline(always)]
+    pub fn reduce_vdegree(&mut self, tol: f64) -> &mut Self {
+        let _ = self.try_reduce_vdegree(tol);
+        self
+    }
+}
+
 impl<V: Homogeneous<f64>> SearchNearestParameter<D2> for NurbsSurface<V>
 where
     Self: ParametricSurface<Point = V::Point, Vector = <V::Point as EuclideanSpace>::Diff>,
@@ -680,6 +755,26 @@ where V::Point: MetricSpace<Metric = f64> + HashGen<f64>
 
 impl<V> BoundedSurface for NurbsSurface<V> where Self: ParametricSurface {}
 
+impl<V: Homogeneous<f64> + ControlPoint<f64, Diff = V> + Tolerance> ExtendSurface
+    for NurbsSurface<V>
+{
+    /// Extends the surface by the Taylor expansions of the homogeneous surface at the boundaries.
+    /// # Remarks
+    /// The weights are also extrapolated, so a long extension may have non-positive weights.
+    /// See the remarks of the implementation of [`ExtendCurve`] for [`NurbsCurve`].
+    #[inline(always)]
+    fn extend(
+        &self,
+        du0: f64,
+        du1: f64,
+        dv0: f64,
+        dv1: f64,
+        continuity: ExtensionContinuity,
+    ) -> Self {
+        NurbsSurface::new(self.0.extend(du0, du1, dv0, dv1, continuity))
+    }
+}
+
 impl IncludeCurve<NurbsCurve<Vector3>> for NurbsSurface<Vector3> {
     #[inline(always)]
     fn include(&self, curve: &NurbsCurve<Vector3>) -> bool {
@@ -918,3 +1013,41 @@ fn test_include3d() {
     *curve.control_point_mut(1) += Vector4::new(0.0, 0.0, 0.00001, 0.0);
     assert!(!surface.include(&curve));
 }
+
+#[test]
+fn search_parameter_on_sphere_octant() {
+    // the octant of the unit sphere whose side u = 0 degenerates to the north pole
+    let meridian = [(0.0, 1.0, 1.0), (1.0, 1.0, 0.5_f64.sqrt()), (1.0, 0.0, 1.0)];
+    let ctrl_pts = meridian
+        .into_iter()
+        .map(|(r, z, w)| {
+            vec![
+                Vector4::new(r * w, 0.0, z * w, w),
+                Vector4::new(r * w, r * w, z * w, w).map(|x| x * 0.5_f64.sqrt()),
+                Vector4::new(0.0, r * w, z * w, w),
+            ]
+        })
+        .collect();
+    let knot_vec = KnotVec::bezier_knot(2);
+    let surface = NurbsSurface::new(BSplineSurface::new((knot_vec.clone(), knot_vec), ctrl_pts));
+    assert_near!(surface.subs(0.0, 0.3), Point3::new(0.0, 0.0, 1.0));
+
+    // from the hints on the pole
+    let pt = surface.subs(0.6, 0.3);
+    let (u, v) = surface.search_parameter(pt, Some((0.0, 0.8)), 100).unwrap();
+    assert_near!(Vector2::new(u, v), Vector2::new(0.6, 0.3));
+    // to the pole
+    let (u, _) = surface
+        .search_parameter(Point3::new(0.0, 0.0, 1.0), Some((0.3, 0.5)), 100)
+        .unwrap();
+    assert_near!(u, 0.0);
+    // the regular points
+    let pt = surface.subs(0.4, 0.7);
+    let (u, v) = surface.search_parameter(pt, None, 100).unwrap();
+    assert_near!(Vector2::new(u, v), Vector2::new(0.4, 0.7));
+    assert!(surface.search_parameter(pt * 1.1, None, 100).is_none());
+    let (u, v) = surface
+        .search_nearest_parameter(pt * 1.1, Some((0.5, 0.5)), 100)
+        .unwrap();
+    assert_near!(Vector2::new(u, v), Vector2::new(0.4, 0.7));
+}
@@ -0,0 +1,402 @@
+use super::*;
+
+/// Restricts the B-spline to the range `[knot_vec[degree], knot_vec[n]]` with `n = control_points.len()`,
+/// and returns the clamped knot vector and control points.
+pub(super) fn clamp_to_domain<P: ControlPoint<f64>>(
+    knot_vec: &KnotVec,
+    degree: usize,
+    control_points: &[P],
+) -> (KnotVec, Vec<P>) {
+    let (p, n) = (degree, control_points.len());
+    let (a, b) = (knot_vec[p], knot_vec[n]);
+    let count = |t: f64| knot_vec.iter().filter(|&&x| x.near(&t)).count();
+    let mut xs = vec![a; p.saturating_sub(count(a))];
+    xs.extend(vec![b; p.saturating_sub(count(b))]);
+    let (knot_vec, control_points) = bspcurve::refine_knot_vec(knot_vec, p, control_points, &xs);
+    let ia = knot_vec.iter().rposition(|x| x.near(&a)).unwrap();
+    let ib = knot_vec.iter().position(|x| x.near(&b)).unwrap();
+    let knots = std::iter::repeat(a)
+        .take(p + 1)
+        .chain(knot_vec[ia + 1..ib].iter().copied())
+        .chain(std::iter::repeat(b).take(p + 1))
+        .collect::<Vec<_>>();
+    (KnotVec::from(knots), control_points[ia - p..ib].to_vec())
+}
+
+/// Unclamps the both ends of the clamped B-spline, by extending the knot vector periodically
+/// (A12.1 in The NURBS Book).
+pub(super) fn unclamp<P: ControlPoint<f64>>(
+    knot_vec: &KnotVec,
+    degree: usize,
+    control_points: &[P],
+) -> (KnotVec, Vec<P>) {
+    let mut u: Vec<f64> = knot_vec.clone().into();
+    let mut pts = control_points.to_vec();
+    let (p, n) = (degree, control_points.len() - 1);
+    if p == 0 {
+        return (KnotVec::from(u), pts);
+    }
+    for i in 0..p - 1 {
+        u[p - i - 1] = u[p - i] - (u[n - i + 1] - u[n - i]);
+        for (j, k) in (0..=i).rev().zip((0..p).rev()) {
+            let alpha = (u[p] - u[k]) * inv_or_zero(u[p + j + 1] - u[k]);
+            pts[j] = pts[j + 1] + (pts[j] - pts[j + 1]) * inv_or_zero(1.0 - alpha);
+        }
+    }
+    u[0] = u[1] - (u[n - p + 2] - u[n - p + 1]);
+    for i in 0..p - 1 {
+        u[n + i + 2] = u[n + i + 1] + (u[p + i + 1] - u[p + i]);
+        for j in (0..=i).rev() {
+            let alpha = (u[n + 1] - u[n - j]) * inv_or_zero(u[n - j + i + 2] - u[n - j]);
+            pts[n - j] = pts[n - j - 1] + (pts[n - j] - pts[n - j - 1]) * inv_or_zero(alpha);
+        }
+    }
+    u[n + p + 1] = u[n + p] + (u[2 * p] - u[2 * p - 1]);
+    (KnotVec::from(u), pts)
+}
+
+/// Returns whether the unclamped B-spline is periodic, i.e. the knot intervals repeat and
+/// the last `degree` control points coincide with the first ones.
+pub(super) fn is_periodic<P: Tolerance>(
+    knot_vec: &KnotVec,
+    degree: usize,
+    control_points: &[P],
+) -> bool {
+    let (p, n) = (degree, control_points.len());
+    if n <= p {
+        return false;
+    }
+    let period = knot_vec[n] - knot_vec[p];
+    !period.so_small()
+        && (0..p).all(|i| control_points[i].near(&control_points[n - p + i]))
+        && (0..knot_vec.len() - (n - p)).all(|i| (knot_vec[i + n - p] - knot_vec[i]).near(&period))
+}
+
+impl<P: ControlPoint<f64> + Tolerance> PeriodicBSplineCurve<P> {
+    /// Constructs a periodic B-spline curve by the degree, the knots of one period and
+    /// the control points of one period.
+    ///
+    /// The period is `knots[n] - knots[0]` with `n = control_points.len()`.
+    /// # Panics
+    /// There are the same conditions for panic as [`PeriodicBSplineCurve::try_new`].
+    #[inline(always)]
+    pub fn new(degree: usize, knots: Vec<f64>, control_points: Vec<P>) -> Self {
+        Self::try_new(degree, knots, control_points).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Constructs a periodic B-spline curve by the degree, the knots of one period and
+    /// the control points of one period.
+    ///
+    /// The period is `knots[n] - knots[0]` with `n = control_points.len()`.
+    /// # Failures
+    /// - If `control_points` is empty, returns [`Error::EmptyControlPoints`].
+    /// - If `knots` is shorter than `control_points.len() + 1`, returns [`Error::TooShortKnotVector`].
+    /// - If `knots` is longer than `control_points.len() + 1`, returns [`Error::IrregularControlPoints`].
+    /// - If `knots` is not sorted, returns [`Error::NotSortedVector`].
+    /// - If the period is zero, returns [`Error::ZeroRange`].
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// use truck_geometry::errors::Error;
+    /// let ctrl_pts = vec![Point2::new(1.0, 0.0), Point2::new(0.0, 1.0), Point2::new(-1.0, 0.0)];
+    /// assert_eq!(
+    ///     PeriodicBSplineCurve::try_new(2, vec![0.0, 1.0, 2.0], ctrl_pts.clone()),
+    ///     Err(Error::TooShortKnotVector(3, 4)),
+    /// );
+    /// let curve = PeriodicBSplineCurve::try_new(2, vec![0.0, 1.0, 2.0, 3.0], ctrl_pts).unwrap();
+    /// assert_eq!(curve.period(), Some(3.0));
+    /// ```
+    pub fn try_new(degree: usize, knots: Vec<f64>, control_points: Vec<P>) -> Result<Self> {
+        let (p, n) = (degree, control_points.len());
+        if n == 0 {
+            return Err(Error::EmptyControlPoints);
+        } else if knots.len() < n + 1 {
+            return Err(Error::TooShortKnotVector(knots.len(), n + 1));
+        } else if knots.len() > n + 1 {
+            return Err(Error::IrregularControlPoints);
+        }
+        let period = knots[n] - knots[0];
+        let knots = (0..n + 2 * p + 1)
+            .map(|i| {
+                let i = i as isize - p as isize;
+                let (q, r) = (i.div_euclid(n as isize), i.rem_euclid(n as isize));
+                knots[r as usize] + q as f64 * period
+            })
+            .collect::<Vec<_>>();
+        let control_points = control_points.into_iter().cycle().take(n + p).collect();
+        let curve = BSplineCurve::try_new(KnotVec::try_from(knots)?, control_points)?;
+        Self::try_from_unclamped(curve)
+    }
+
+    /// Constructs a periodic B-spline curve from the unclamped form, i.e. the B-spline curve whose knot
+    /// intervals repeat and the last `degree` control points coincide with the first ones.
+    /// The range of the periodic curve is `[knot_vec[degree], knot_vec[n]]` with `n = control_points.len()`.
+    /// # Failures
+    /// If the curve is not in the unclamped periodic form, returns [`Error::NotPeriodic`].
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let knot_vec = KnotVec::from(vec![-2.0, -1.0, 0.0, 1.0, 2.0, 3.0, 4.0, 5.0]);
+    /// let ctrl_pts = vec![
+    ///     Point2::new(1.0, 0.0),
+    ///     Point2::new(0.0, 1.0),
+    ///     Point2::new(-1.0, 0.0),
+    ///     Point2::new(1.0, 0.0),
+    ///     Point2::new(0.0, 1.0),
+    /// ];
+    /// let bspcurve = BSplineCurve::new(knot_vec, ctrl_pts);
+    /// let curve = PeriodicBSplineCurve::try_from_unclamped(bspcurve.clone()).unwrap();
+    /// assert_eq!(curve.range_tuple(), (0.0, 3.0));
+    /// for i in 0..=10 {
+    ///     let t = 3.0 * i as f64 / 10.0;
+    ///     assert_near!(curve.subs(t), bspcurve.subs(t));
+    /// }
+    /// ```
+    pub fn try_from_unclamped(curve: BSplineCurve<P>) -> Result<Self> {
+        let degree = curve.degree();
+        match is_periodic(&curve.knot_vec, degree, &curve.control_points) {
+            true => {
+                let (knot_vec, control_points) =
+                    clamp_to_domain(&curve.knot_vec, degree, &curve.control_points);
+                Ok(Self(BSplineCurve::new_unchecked(knot_vec, control_points)))
+            }
+            false => Err(Error::NotPeriodic),
+        }
+    }
+
+    /// Constructs a periodic B-spline curve from the clamped closed curve whose both ends are connected
+    /// with the continuity of class C<sup>degree - 1</sup>.
+    /// # Failures
+    /// - If the knot vector is not clamped, returns [`Error::NotClampedKnotVector`].
+    /// - If the ends are not connected smoothly, returns [`Error::NotPeriodic`].
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let ctrl_pts = vec![Point2::new(1.0, 0.0), Point2::new(0.0, 1.0), Point2::new(-1.0, 0.0)];
+    /// let curve = PeriodicBSplineCurve::new(2, vec![0.0, 1.0, 2.0, 3.0], ctrl_pts);
+    /// let clamped = curve.clone().into_clamped();
+    /// assert!(clamped.is_clamped());
+    ///
+    /// let curve0 = PeriodicBSplineCurve::try_from_clamped(clamped).unwrap();
+    /// assert_eq!(curve0, curve);
+    /// ```
+    pub fn try_from_clamped(curve: BSplineCurve<P>) -> Result<Self> {
+        let degree = curve.degree();
+        if !curve.is_clamped() {
+            return Err(Error::NotClampedKnotVector);
+        } else if degree == 0 || curve.control_points.len() <= degree {
+            return Err(Error::NotPeriodic);
+        }
+        let (knot_vec, control_points) = unclamp(&curve.knot_vec, degree, &curve.control_points);
+        match is_periodic(&knot_vec, degree, &control_points) {
+            true => Ok(Self(curve)),
+            false => Err(Error::NotPeriodic),
+        }
+    }
+
+    /// Returns the unclamped form, i.e. the B-spline curve whose knot intervals repeat and
+    /// the last `degree` control points coincide with the first ones.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let ctrl_pts = vec![Point2::new(1.0, 0.0), Point2::new(0.0, 1.0), Point2::new(-1.0, 0.0)];
+    /// let curve = PeriodicBSplineCurve::new(2, vec![0.0, 1.0, 2.0, 3.0], ctrl_pts.clone());
+    /// let unclamped = curve.to_unclamped();
+    /// assert_eq!(
+    ///     unclamped.knot_vec(),
+    ///     &KnotVec::from(vec![-2.0, -1.0, 0.0, 1.0, 2.0, 3.0, 4.0, 5.0]),
+    /// );
+    /// ctrl_pts.iter().cycle().zip(unclamped.control_points()).for_each(|(p, q)| {
+    ///     assert_near!(*p, *q);
+    /// });
+    /// ```
+    pub fn to_unclamped(&self) -> BSplineCurve<P> {
+        let degree = self.0.degree();
+        let (knot_vec, mut control_points) =
+            unclamp(&self.0.knot_vec, degree, &self.0.control_points);
+        let n = control_points.len();
+        (0..degree).for_each(|i| control_points[n - degree + i] = control_points[i]);
+        BSplineCurve::new_unchecked(knot_vec, control_points)
+    }
+}
+
+impl<P> PeriodicBSplineCurve<P> {
+    /// Returns the clamped form on one period.
+    #[inline(always)]
+    pub const fn clamped(&self) -> &BSplineCurve<P> { &self.0 }
+
+    /// Returns the clamped form on one period.
+    #[inline(always)]
+    pub fn into_clamped(self) -> BSplineCurve<P> { self.0 }
+
+    /// Returns the degree of the curve.
+    #[inline(always)]
+    pub fn degree(&self) -> usize { self.0.degree() }
+
+    /// Returns the parameter in the range `[t0, t1)` which is congruent to `t` modulo the period.
+    #[inline(always)]
+    pub fn normalize_parameter(&self, t: f64) -> f64 {
+        let t0 = self.0.knot_vec[0];
+        t0 + (t - t0).rem_euclid(self.0.knot_vec.range_length())
+    }
+}
+
+impl<P: ControlPoint<f64>> ParametricCurve for PeriodicBSplineCurve<P> {
+    type Point = P;
+    type Vector = P::Diff;
+    #[inline(always)]
+    fn subs(&self, t: f64) -> P { self.0.subs(self.normalize_parameter(t)) }
+    #[inline(always)]
+    fn der(&self, t: f64) -> P::Diff { self.0.der(self.normalize_parameter(t)) }
+    #[inline(always)]
+    fn der2(&self, t: f64) -> P::Diff { self.0.der2(self.normalize_parameter(t)) }
+    #[inline(always)]
+    fn parameter_range(&self) -> ParameterRange {
+        let knot_vec = &self.0.knot_vec;
+        (
+            Bound::Included(knot_vec[0]),
+            Bound::Excluded(knot_vec[knot_vec.len() - 1]),
+        )
+    }
+    #[inline(always)]
+    fn period(&self) -> Option<f64> { Some(self.0.knot_vec.range_length()) }
+}
+
+impl<P: ControlPoint<f64>> BoundedCurve for PeriodicBSplineCurve<P> {}
+
+impl<P> ParameterDivision1D for PeriodicBSplineCurve<P>
+where P: ControlPoint<f64>
+        + EuclideanSpace<Scalar = f64, Diff = <P as ControlPoint<f64>>::Diff>
+        + MetricSpace<Metric = f64>
+        + HashGen<f64>
+{
+    type Point = P;
+    fn parameter_division(&self, range: (f64, f64), tol: f64) -> (Vec<f64>, Vec<P>) {
+        algo::curve::parameter_division(self, range, tol)
+    }
+}
+
+impl<P> SearchNearestParameter<D1> for PeriodicBSplineCurve<P>
+where
+    P: ControlPoint<f64>
+        + EuclideanSpace<Scalar = f64, Diff = <P as ControlPoint<f64>>::Diff>
+        + MetricSpace<Metric = f64>,
+    <P as ControlPoint<f64>>::Diff: InnerSpace<Scalar = f64> + Tolerance,
+{
+    type Point = P;
+    /// Searches the nearest parameter by Newton's method, which may go across the seam.
+    /// The returned parameter is normalized into the range of the curve.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let ctrl_pts = vec![
+    ///     Point2::new(1.0, 1.0),
+    ///     Point2::new(-1.0, 1.0),
+    ///     Point2::new(-1.0, -1.0),
+    ///     Point2::new(1.0, -1.0),
+    /// ];
+    /// let curve = PeriodicBSplineCurve::new(2, vec![0.0, 1.0, 2.0, 3.0, 4.0], ctrl_pts);
+    /// // the hint is on the other side of the seam.
+    /// let t = curve.search_nearest_parameter(curve.subs(0.1), Some(3.9), 100).unwrap();
+    /// assert_near!(t, 0.1);
+    /// ```
+    #[inline(always)]
+    fn search_nearest_parameter<H: Into<SPHint1D>>(
+        &self,
+        point: P,
+        hint: H,
+        trial: usize,
+    ) -> Option<f64> {
+        let hint = match hint.into() {
+            SPHint1D::Parameter(hint) => hint,
+            SPHint1D::Range(x, y) => {
+                algo::curve::presearch(self, point, (x, y), PRESEARCH_DIVISION)
+            }
+            SPHint1D::None => {
+                algo::curve::presearch(self, point, self.range_tuple(), PRESEARCH_DIVISION)
+            }
+        };
+        algo::curve::search_nearest_parameter(self, point, hint, trial)
+            .map(|t| self.normalize_parameter(t))
+    }
+}
+
+impl<P> SearchParameter<D1> for PeriodicBSplineCurve<P>
+where
+    P: ControlPoint<f64>
+        + EuclideanSpace<Scalar = f64, Diff = <P as ControlPoint<f64>>::Diff>
+        + MetricSpace<Metric = f64>,
+    <P as ControlPoint<f64>>::Diff: InnerSpace<Scalar = f64> + Tolerance,
+{
+    type Point = P;
+    #[inline(always)]
+    fn search_parameter<H: Into<SPHint1D>>(&self, point: P, hint: H, trial: usize) -> Option<f64> {
+        let hint = match hint.into() {
+            SPHint1D::Parameter(hint) => hint,
+            SPHint1D::Range(x, y) => {
+                algo::curve::presearch(self, point, (x, y), PRESEARCH_DIVISION)
+            }
+            SPHint1D::None => {
+                algo::curve::presearch(self, point, self.range_tuple(), PRESEARCH_DIVISION)
+            }
+        };
+        algo::curve::search_parameter(self, point, hint, trial).map(|t| self.normalize_parameter(t))
+    }
+}
+
+impl<P: Clone> Invertible for PeriodicBSplineCurve<P> {
+    #[inline(always)]
+    fn invert(&mut self) { self.0.invert(); }
+}
+
+impl<M, P> Transformed<M> for PeriodicBSplineCurve<P>
+where
+    P: EuclideanSpace,
+    M: Transform<P>,
+{
+    #[inline(always)]
+    fn transform_by(&mut self, trans: M) { self.0.transform_by(trans) }
+}
+
+impl<P> From<PeriodicBSplineCurve<P>> for BSplineCurve<P> {
+    #[inline(always)]
+    fn from(curve: PeriodicBSplineCurve<P>) -> Self { curve.0 }
+}
+
+#[test]
+fn periodic_random_test() {
+    let degree = 3;
+    let ctrl_pts = (0..7)
+        .map(|_| Point3::new(rand::random(), rand::random(), rand::random()))
+        .collect::<Vec<_>>();
+    let mut knots = (0..8).map(|_| rand::random::<f64>()).collect::<Vec<_>>();
+    knots.sort_by(|x, y| x.partial_cmp(y).unwrap());
+    let knots = knots
+        .into_iter()
+        .enumerate()
+        .map(|(i, t)| i as f64 + 0.5 * t)
+        .collect();
+    let curve = PeriodicBSplineCurve::new(degree, knots, ctrl_pts);
+    let clamped = curve.clamped();
+    let (t0, t1) = curve.range_tuple();
+    for i in 1..degree {
+        let (mut der0, mut der1) = (clamped.derivation(), clamped.derivation());
+        (1..i).for_each(|_| {
+            der0 = der0.derivation();
+            der1 = der1.derivation();
+        });
+        assert_near!(der0.subs(t0), der1.subs(t1));
+    }
+    assert_near!(clamped.subs(t0), clamped.subs(t1));
+
+    let unclamped = curve.to_unclamped();
+    let curve0 = PeriodicBSplineCurve::try_from_unclamped(unclamped.clone()).unwrap();
+    let curve1 = PeriodicBSplineCurve::try_from_clamped(clamped.clone()).unwrap();
+    for i in 0..=20 {
+        let t = t0 + (t1 - t0) * i as f64 / 20.0;
+        assert_near!(unclamped.subs(t), curve.subs(t));
+        assert_near!(curve0.subs(t), curve.subs(t));
+        assert_near!(curve1.subs(t), curve.subs(t));
+        assert_near!(curve.subs(t + 2.0 * (t1 - t0)), curve.subs(t));
+    }
+}
@@ -0,0 +1,409 @@
+use super::periodiccurve::{clamp_to_domain, is_periodic, unclamp};
+use super::*;
+
+/// Applies `f` to all rows of the control points, i.e. to the B-spline curves with respect to `v`.
+fn map_vcurves<P>(
+    surface: &BSplineSurface<P>,
+    f: impl Fn(&KnotVec, usize, &[P]) -> (KnotVec, Vec<P>),
+) -> BSplineSurface<P> {
+    let (uknot_vec, vknot_vec) = &surface.knot_vecs;
+    let degree = surface.vdegree();
+    let (knot_vecs, control_points): (Vec<_>, Vec<_>) = surface
+        .control_points
+        .iter()
+        .map(|row| f(vknot_vec, degree, row))
+        .unzip();
+    let vknot_vec = knot_vecs.into_iter().next().unwrap();
+    BSplineSurface::new_unchecked((uknot_vec.clone(), vknot_vec), control_points)
+}
+
+/// Returns the surface in the same form as `surface` whose `u`-direction is processed by `f`
+/// as the `v`-direction.
+fn with_swapped_axes<P: Clone, T>(
+    surface: &BSplineSurface<P>,
+    f: impl FnOnce(&BSplineSurface<P>) -> Result<T>,
+) -> Result<T> {
+    let mut surface = surface.clone();
+    surface.swap_axes();
+    f(&surface)
+}
+
+/// Clamps the surface periodic with respect to `v` in the unclamped form.
+fn vclamp<P: ControlPoint<f64> + Tolerance>(
+    surface: &BSplineSurface<P>,
+) -> Result<BSplineSurface<P>> {
+    let (knot_vec, degree) = (&surface.knot_vecs.1, surface.vdegree());
+    match surface
+        .control_points
+        .iter()
+        .all(|row| is_periodic(knot_vec, degree, row))
+    {
+        true => Ok(map_vcurves(surface, clamp_to_domain)),
+        false => Err(Error::NotPeriodic),
+    }
+}
+
+/// Checks the clamped surface is smooth across the seam of `v`.
+fn vcheck_clamped<P: ControlPoint<f64> + Tolerance>(surface: &BSplineSurface<P>) -> Result<()> {
+    let (knot_vec, degree) = (&surface.knot_vecs.1, surface.vdegree());
+    if !knot_vec.is_clamped(degree) {
+        return Err(Error::NotClampedKnotVector);
+    } else if degree == 0 || surface.control_points[0].len() <= degree {
+        return Err(Error::NotPeriodic);
+    }
+    let unclamped = map_vcurves(surface, unclamp);
+    let knot_vec = &unclamped.knot_vecs.1;
+    match unclamped
+        .control_points
+        .iter()
+        .all(|row| is_periodic(knot_vec, degree, row))
+    {
+        true => Ok(()),
+        false => Err(Error::NotPeriodic),
+    }
+}
+
+/// Unclamps the surface with respect to `v`.
+fn vunclamp<P: ControlPoint<f64>>(surface: &BSplineSurface<P>) -> BSplineSurface<P> {
+    map_vcurves(surface, |knot_vec, degree, row| {
+        let (knot_vec, mut row) = unclamp(knot_vec, degree, row);
+        let n = row.len();
+        (0..degree).for_each(|i| row[n - degree + i] = row[i]);
+        (knot_vec, row)
+    })
+}
+
+impl<P: ControlPoint<f64> + Tolerance> PeriodicBSplineSurface<P> {
+    /// Constructs a periodic B-spline surface from the unclamped form.
+    ///
+    /// For each periodic direction, all the B-spline curves along the direction have to be
+    /// in the unclamped periodic form. See also [`PeriodicBSplineCurve::try_from_unclamped`].
+    /// # Failures
+    /// If the surface is not in the unclamped periodic form, returns [`Error::NotPeriodic`].
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// // the uniform quadratic tube with the square section
+    /// let uknot_vec = KnotVec::bezier_knot(1);
+    /// let vknot_vec = KnotVec::from(vec![-2.0, -1.0, 0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    /// let square = [(1.0, 1.0), (-1.0, 1.0), (-1.0, -1.0), (1.0, -1.0), (1.0, 1.0), (-1.0, 1.0)];
+    /// let ctrl_pts = (0..2)
+    ///     .map(|i| square.iter().map(|&(x, y)| Point3::new(x, y, i as f64)).collect())
+    ///     .collect();
+    /// let bspsurface = BSplineSurface::new((uknot_vec, vknot_vec), ctrl_pts);
+    /// let surface =
+    ///     PeriodicBSplineSurface::try_from_unclamped(bspsurface.clone(), (false, true)).unwrap();
+    /// assert_eq!(surface.range_tuple(), ((0.0, 1.0), (0.0, 4.0)));
+    /// assert_eq!(surface.u_period(), None);
+    /// assert_eq!(surface.v_period(), Some(4.0));
+    /// for i in 0..=10 {
+    ///     for j in 0..=10 {
+    ///         let (u, v) = (i as f64 / 10.0, 4.0 * j as f64 / 10.0);
+    ///         assert_near!(surface.subs(u, v), bspsurface.subs(u, v));
+    ///         assert_near!(surface.subs(u, v + 4.0), bspsurface.subs(u, v));
+    ///     }
+    /// }
+    ///
+    /// // not periodic with respect to `u`.
+    /// assert!(PeriodicBSplineSurface::try_from_unclamped(bspsurface, (true, true)).is_err());
+    /// ```
+    pub fn try_from_unclamped(surface: BSplineSurface<P>, periodic: (bool, bool)) -> Result<Self> {
+        let mut surface = surface;
+        if periodic.1 {
+            surface = vclamp(&surface)?;
+        }
+        if periodic.0 {
+            surface = with_swapped_axes(&surface, vclamp)?;
+            surface.swap_axes();
+        }
+        Ok(Self { surface, periodic })
+    }
+
+    /// Constructs a periodic B-spline surface from the clamped closed surface.
+    ///
+    /// For each periodic direction, the knot vector has to be clamped and the both ends of
+    /// all the B-spline curves along the direction have to be connected with the continuity
+    /// of class C<sup>degree - 1</sup>.
+    /// # Failures
+    /// - If the knot vector of a periodic direction is not clamped, returns [`Error::NotClampedKnotVector`].
+    /// - If the ends are not connected smoothly, returns [`Error::NotPeriodic`].
+    pub fn try_from_clamped(surface: BSplineSurface<P>, periodic: (bool, bool)) -> Result<Self> {
+        if periodic.1 {
+            vcheck_clamped(&surface)?;
+        }
+        if periodic.0 {
+            with_swapped_axes(&surface, vcheck_clamped)?;
+        }
+        Ok(Self { surface, periodic })
+    }
+
+    /// Returns the unclamped form with respect to the periodic directions.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let uknot_vec = KnotVec::bezier_knot(1);
+    /// let vknot_vec = KnotVec::from(vec![-2.0, -1.0, 0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    /// let square = [(1.0, 1.0), (-1.0, 1.0), (-1.0, -1.0), (1.0, -1.0), (1.0, 1.0), (-1.0, 1.0)];
+    /// let ctrl_pts = (0..2)
+    ///     .map(|i| square.iter().map(|&(x, y)| Point3::new(x, y, i as f64)).collect())
+    ///     .collect();
+    /// let bspsurface = BSplineSurface::new((uknot_vec, vknot_vec), ctrl_pts);
+    /// let surface =
+    ///     PeriodicBSplineSurface::try_from_unclamped(bspsurface.clone(), (false, true)).unwrap();
+    /// let unclamped = surface.to_unclamped();
+    /// assert_eq!(unclamped.knot_vecs(), bspsurface.knot_vecs());
+    /// unclamped
+    ///     .control_points()
+    ///     .iter()
+    ///     .flatten()
+    ///     .zip(bspsurface.control_points().iter().flatten())
+    ///     .for_each(|(p, q)| assert_near!(*p, *q));
+    /// ```
+    pub fn to_unclamped(&self) -> BSplineSurface<P> {
+        let mut surface = self.surface.clone();
+        if self.periodic.1 {
+            surface = vunclamp(&surface);
+        }
+        if self.periodic.0 {
+            surface.swap_axes();
+            surface = vunclamp(&surface);
+            surface.swap_axes();
+        }
+        surface
+    }
+}
+
+impl<P> PeriodicBSplineSurface<P> {
+    /// Returns the clamped form on one period.
+    #[inline(always)]
+    pub const fn clamped(&self) -> &BSplineSurface<P> { &self.surface }
+
+    /// Returns the clamped form on one period.
+    #[inline(always)]
+    pub fn into_clamped(self) -> BSplineSurface<P> { self.surface }
+
+    /// Returns whether the surface is periodic with respect to `u` and `v`, respectively.
+    #[inline(always)]
+    pub const fn periodic(&self) -> (bool, bool) { self.periodic }
+
+    /// Returns the parameters in the range of the surface which are congruent to `(u, v)`
+    /// modulo the periods. The parameter of a non-periodic direction is not changed.
+    #[inline(always)]
+    pub fn normalize_parameters(&self, u: f64, v: f64) -> (f64, f64) {
+        let normalize = |t: f64, knot_vec: &KnotVec, periodic: bool| match periodic {
+            true => knot_vec[0] + (t - knot_vec[0]).rem_euclid(knot_vec.range_length()),
+            false => t,
+        };
+        let (uknot_vec, vknot_vec) = &self.surface.knot_vecs;
+        (
+            normalize(u, uknot_vec, self.periodic.0),
+            normalize(v, vknot_vec, self.periodic.1),
+        )
+    }
+}
+
+impl<P: ControlPoint<f64>> ParametricSurface for PeriodicBSplineSurface<P> {
+    type Point = P;
+    type Vector = P::Diff;
+    #[inline(always)]
+    fn subs(&self, u: f64, v: f64) -> P {
+        let (u, v) = self.normalize_parameters(u, v);
+        self.surface.subs(u, v)
+    }
+    #[inline(always)]
+    fn uder(&self, u: f64, v: f64) -> P::Diff {
+        let (u, v) = self.normalize_parameters(u, v);
+        self.surface.uder(u, v)
+    }
+    #[inline(always)]
+    fn vder(&self, u: f64, v: f64) -> P::Diff {
+        let (u, v) = self.normalize_parameters(u, v);
+        self.surface.vder(u, v)
+    }
+    #[inline(always)]
+    fn uuder(&self, u: f64, v: f64) -> P::Diff {
+        let (u, v) = self.normalize_parameters(u, v);
+        self.surface.uuder(u, v)
+    }
+    #[inline(always)]
+    fn uvder(&self, u: f64, v: f64) -> P::Diff {
+        let (u, v) = self.normalize_parameters(u, v);
+        self.surface.uvder(u, v)
+    }
+    #[inline(always)]
+    fn vvder(&self, u: f64, v: f64) -> P::Diff {
+        let (u, v) = self.normalize_parameters(u, v);
+        self.surface.vvder(u, v)
+    }
+    #[inline(always)]
+    fn parameter_range(&self) -> (ParameterRange, ParameterRange) {
+        let range = |knot_vec: &KnotVec, periodic: bool| {
+            let t1 = knot_vec[knot_vec.len() - 1];
+            let end = match periodic {
+                true => Bound::Excluded(t1),
+                false => Bound::Included(t1),
+            };
+            (Bound::Included(knot_vec[0]), end)
+        };
+        let (uknot_vec, vknot_vec) = &self.surface.knot_vecs;
+        (
+            range(uknot_vec, self.periodic.0),
+            range(vknot_vec, self.periodic.1),
+        )
+    }
+    #[inline(always)]
+    fn u_period(&self) -> Option<f64> {
+        match self.periodic.0 {
+            true => Some(self.surface.knot_vecs.0.range_length()),
+            false => None,
+        }
+    }
+    #[inline(always)]
+    fn v_period(&self) -> Option<f64> {
+        match self.periodic.1 {
+            true => Some(self.surface.knot_vecs.1.range_length()),
+            false => None,
+        }
+    }
+}
+
+impl ParametricSurface3D for PeriodicBSplineSurface<Point3> {}
+
+impl<P> BoundedSurface for PeriodicBSplineSurface<P> where PeriodicBSplineSurface<P>: ParametricSurface {}
+
+impl<P: ControlPoint<f64>> ParameterDivision2D for PeriodicBSplineSurface<P>
+where P: EuclideanSpace<Scalar = f64, Diff = <P as ControlPoint<f64>>::Diff>
+        + MetricSpace<Metric = f64>
+        + HashGen<f64>
+{
+    #[inline(always)]
+    fn parameter_division(
+        &self,
+        range: ((f64, f64), (f64, f64)),
+        tol: f64,
+    ) -> (Vec<f64>, Vec<f64>) {
+        algo::surface::parameter_division(self, range, tol)
+    }
+}
+
+impl SearchParameter<D2> for PeriodicBSplineSurface<Point3> {
+    type Point = Point3;
+    /// Searches the parameter by Newton's method, which may go across the seams.
+    /// The returned parameters are normalized into the range of the surface.
+    fn search_parameter<H: Into<SPHint2D>>(
+        &self,
+        point: Point3,
+        hint: H,
+        trials: usize,
+    ) -> Option<(f64, f64)> {
+        let hint = match hint.into() {
+            SPHint2D::Parameter(x, y) => (x, y),
+            SPHint2D::Range(range0, range1) => {
+                algo::surface::presearch(self, point, (range0, range1), PRESEARCH_DIVISION)
+            }
+            SPHint2D::None => {
+                algo::surface::presearch(self, point, self.range_tuple(), PRESEARCH_DIVISION)
+            }
+        };
+        algo::surface::search_parameter3d(self, point, hint, trials)
+            .map(|(u, v)| self.normalize_parameters(u, v))
+    }
+}
+
+impl<P> SearchNearestParameter<D2> for PeriodicBSplineSurface<P>
+where
+    P: ControlPoint<f64>
+        + EuclideanSpace<Scalar = f64, Diff = <P as ControlPoint<f64>>::Diff>
+        + MetricSpace<Metric = f64>,
+    <P as ControlPoint<f64>>::Diff: InnerSpace<Scalar = f64> + Tolerance,
+{
+    type Point = P;
+    /// Searches the nearest parameter by Newton's method, which may go across the seams.
+    /// The returned parameters are normalized into the range of the surface.
+    fn search_nearest_parameter<H: Into<SPHint2D>>(
+        &self,
+        point: P,
+        hint: H,
+        trials: usize,
+    ) -> Option<(f64, f64)> {
+        let hint = match hint.into() {
+            SPHint2D::Parameter(x, y) => (x, y),
+            SPHint2D::Range(range0, range1) => {
+                algo::surface::presearch(self, point, (range0, range1), PRESEARCH_DIVISION)
+            }
+            SPHint2D::None => {
+                algo::surface::presearch(self, point, self.range_tuple(), PRESEARCH_DIVISION)
+            }
+        };
+        algo::surface::search_nearest_parameter(self, point, hint, trials)
+            .map(|(u, v)| self.normalize_parameters(u, v))
+    }
+}
+
+impl<P: Clone> Invertible for PeriodicBSplineSurface<P> {
+    #[inline(always)]
+    fn invert(&mut self) {
+        self.surface.swap_axes();
+        self.periodic = (self.periodic.1, self.periodic.0);
+    }
+}
+
+impl<M, P> Transformed<M> for PeriodicBSplineSurface<P>
+where
+    P: EuclideanSpace<Scalar = f64>,
+    M: Transform<P>,
+{
+    #[inline(always)]
+    fn transform_by(&mut self, trans: M) { self.surface.transform_by(trans) }
+}
+
+impl<P> From<PeriodicBSplineSurface<P>> for BSplineSurface<P> {
+    #[inline(always)]
+    fn from(surface: PeriodicBSplineSurface<P>) -> Self { surface.surface }
+}
+
+#[test]
+fn periodic_surface_search_across_seam() {
+    let uknot_vec = KnotVec::from(vec![-2.0, -1.0, 0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    let vknot_vec = KnotVec::bezier_knot(2);
+    let square = [
+        (1.0, 1.0),
+        (-1.0, 1.0),
+        (-1.0, -1.0),
+        (1.0, -1.0),
+        (1.0, 1.0),
+        (-1.0, 1.0),
+    ];
+    let ctrl_pts = square
+        .iter()
+        .map(|&(x, y)| {
+            (0..3)
+                .map(|j| Point3::new(x, y, j as f64 * j as f64))
+                .collect()
+        })
+        .collect();
+    let bspsurface = BSplineSurface::new((uknot_vec, vknot_vec), ctrl_pts);
+    let surface = PeriodicBSplineSurface::try_from_unclamped(bspsurface, (true, false)).unwrap();
+    assert_eq!(surface.u_period(), Some(4.0));
+    assert_eq!(surface.v_period(), None);
+
+    let pt = surface.subs(0.1, 0.5);
+    let (u, v) = surface.search_parameter(pt, (3.9, 0.5), 100).unwrap();
+    assert_near!(Vector2::new(u, v), Vector2::new(0.1, 0.5));
+
+    let clamped = surface.clamped().clone();
+    let surface0 = PeriodicBSplineSurface::try_from_clamped(clamped, (true, false)).unwrap();
+    assert_eq!(surface0, surface);
+    let unclamped = surface.to_unclamped();
+    for i in 0..=10 {
+        for j in 0..=10 {
+            let (u, v) = (4.0 * i as f64 / 10.0, j as f64 / 10.0);
+            assert_near!(unclamped.subs(u, v), surface.subs(u, v));
+        }
+    }
+
+    let mut inverted = surface.clone();
+    inverted.invert();
+    assert_eq!(inverted.periodic(), (false, true));
+    assert_near!(inverted.subs(0.5, -0.5), surface.subs(3.5, 0.5));
+}
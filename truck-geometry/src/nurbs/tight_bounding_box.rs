@@ -0,0 +1,220 @@
+use super::*;
+
+/// The maximum depth of the subdivision of Bézier curves and surfaces.
+const SUBDIVISION_DEPTH: usize = 16;
+
+/// Returns how far the box `hull` extends beyond the box `inner` included in `hull`.
+fn excess<P: Bounded<Scalar = f64>>(hull: BoundingBox<P>, inner: BoundingBox<P>) -> f64 {
+    f64::max(
+        P::max_component(hull.max().diagonal(inner.max())),
+        P::max_component(inner.min().diagonal(hull.min())),
+    )
+}
+
+/// Adds the bounding box of the clamped Bézier curve to `bdb`.
+///
+/// The curve is subdivided until the box of the control points is as tight as the one of the end points.
+fn bezier_curve_bounding_box<V, P>(
+    mut bezier: BSplineCurve<V>,
+    project: &impl Fn(&V) -> P,
+    depth: usize,
+    bdb: &mut BoundingBox<P>,
+) where
+    V: ControlPoint<f64> + Tolerance,
+    P: Bounded<Scalar = f64>,
+{
+    let ctrl_pts = bezier.control_points();
+    let hull: BoundingBox<P> = ctrl_pts.iter().map(project).collect();
+    let ends: BoundingBox<P> = [&ctrl_pts[0], &ctrl_pts[ctrl_pts.len() - 1]]
+        .into_iter()
+        .map(project)
+        .collect();
+    match depth == 0 || excess(hull, ends) < tolerance() {
+        true => *bdb += hull,
+        false => {
+            let (t0, t1) = bezier.range_tuple();
+            let bezier1 = bezier.cut((t0 + t1) / 2.0);
+            bezier_curve_bounding_box(bezier, project, depth - 1, bdb);
+            bezier_curve_bounding_box(bezier1, project, depth - 1, bdb);
+        }
+    }
+}
+
+/// Adds the bounding box of the clamped Bézier surface to `bdb`.
+///
+/// The surface is subdivided until the box of the control points is as tight as the one of the boundary.
+fn bezier_surface_bounding_box<V, P>(
+    mut bezier: BSplineSurface<V>,
+    project: &impl Fn(&V) -> P,
+    depth: usize,
+    bdb: &mut BoundingBox<P>,
+) where
+    V: ControlPoint<f64> + Tolerance,
+    P: Bounded<Scalar = f64>,
+{
+    let hull: BoundingBox<P> = bezier
+        .control_points()
+        .iter()
+        .flatten()
+        .map(project)
+        .collect();
+    let mut boundary = BoundingBox::new();
+    bezier.splitted_boundary().into_iter().for_each(|curve| {
+        bezier_curve_bounding_box(curve, project, depth, &mut boundary);
+    });
+    match depth == 0 || excess(hull, boundary) < tolerance() {
+        true => *bdb += hull,
+        false => {
+            let ((u0, u1), (v0, v1)) = bezier.range_tuple();
+            let mut bezier1 = bezier.ucut((u0 + u1) / 2.0);
+            let bezier2 = bezier.vcut((v0 + v1) / 2.0);
+            let bezier3 = bezier1.vcut((v0 + v1) / 2.0);
+            [bezier, bezier1, bezier2, bezier3]
+                .into_iter()
+                .for_each(|bezier| bezier_surface_bounding_box(bezier, project, depth - 1, bdb));
+        }
+    }
+}
+
+impl<P> BoundingBoxed for BSplineCurve<P>
+where P: ControlPoint<f64> + Tolerance + Bounded<Scalar = f64>
+{
+    type Point = P;
+    /// Returns the bounding box by the subdivision of the Bézier decomposition.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let knot_vec = KnotVec::bezier_knot(2);
+    /// let ctrl_pts = vec![Point2::new(0.0, 0.0), Point2::new(1.0, 2.0), Point2::new(2.0, 0.0)];
+    /// let bspcurve = BSplineCurve::new(knot_vec, ctrl_pts);
+    ///
+    /// // the control points are far from the curve.
+    /// assert_eq!(bspcurve.roughly_bounding_box().max(), Point2::new(2.0, 2.0));
+    ///
+    /// let bdb = bspcurve.bounding_box();
+    /// assert_near!(bdb.min(), Point2::new(0.0, 0.0));
+    /// assert_near!(bdb.max(), Point2::new(2.0, 1.0));
+    /// ```
+    fn bounding_box(&self) -> BoundingBox<P> {
+        let mut bdb = BoundingBox::new();
+        self.bezier_decomposition().into_iter().for_each(|bezier| {
+            bezier_curve_bounding_box(bezier, &|p: &P| *p, SUBDIVISION_DEPTH, &mut bdb)
+        });
+        bdb
+    }
+}
+
+impl<V> BoundingBoxed for NurbsCurve<V>
+where
+    V: Homogeneous<f64> + ControlPoint<f64, Diff = V> + Tolerance,
+    V::Point: Bounded<Scalar = f64>,
+{
+    type Point = V::Point;
+    /// Returns the bounding box by the subdivision of the Bézier decomposition.
+    /// # Remarks
+    /// All weights are assumed to be positive.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// // the quarter of the unit circle
+    /// let knot_vec = KnotVec::bezier_knot(2);
+    /// let ctrl_pts = vec![
+    ///     Vector3::new(1.0, 0.0, 1.0),
+    ///     Vector3::new(1.0, 1.0, 1.0) / f64::sqrt(2.0),
+    ///     Vector3::new(0.0, 1.0, 1.0),
+    /// ];
+    /// let arc = NurbsCurve::new(BSplineCurve::new(knot_vec, ctrl_pts));
+    /// let bdb = arc.bounding_box();
+    /// assert_near!(bdb.min(), Point2::new(0.0, 0.0));
+    /// assert_near!(bdb.max(), Point2::new(1.0, 1.0));
+    /// ```
+    fn bounding_box(&self) -> BoundingBox<V::Point> {
+        let mut bdb = BoundingBox::new();
+        self.0
+            .bezier_decomposition()
+            .into_iter()
+            .for_each(|bezier| {
+                bezier_curve_bounding_box(
+                    bezier,
+                    &|v: &V| v.to_point(),
+                    SUBDIVISION_DEPTH,
+                    &mut bdb,
+                )
+            });
+        bdb
+    }
+}
+
+impl<P> BoundingBoxed for BSplineSurface<P>
+where P: ControlPoint<f64> + Tolerance + Bounded<Scalar = f64>
+{
+    type Point = P;
+    /// Returns the bounding box by the subdivision of the Bézier decomposition.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let knot_vecs = (KnotVec::bezier_knot(2), KnotVec::bezier_knot(2));
+    /// let ctrl_pts = vec![
+    ///     vec![Point3::new(0.0, 0.0, 0.0), Point3::new(0.0, 1.0, 0.0), Point3::new(0.0, 2.0, 0.0)],
+    ///     vec![Point3::new(1.0, 0.0, 0.0), Point3::new(1.0, 1.0, 4.0), Point3::new(1.0, 2.0, 0.0)],
+    ///     vec![Point3::new(2.0, 0.0, 0.0), Point3::new(2.0, 1.0, 0.0), Point3::new(2.0, 2.0, 0.0)],
+    /// ];
+    /// let bspsurface = BSplineSurface::new(knot_vecs, ctrl_pts);
+    /// let bdb = bspsurface.bounding_box();
+    /// assert_near!(bdb.min(), Point3::new(0.0, 0.0, 0.0));
+    /// assert_near!(bdb.max(), Point3::new(2.0, 2.0, 1.0));
+    /// ```
+    fn bounding_box(&self) -> BoundingBox<P> {
+        let mut bdb = BoundingBox::new();
+        self.bezier_decomposition()
+            .into_iter()
+            .flatten()
+            .for_each(|bezier| {
+                bezier_surface_bounding_box(bezier, &|p: &P| *p, SUBDIVISION_DEPTH, &mut bdb)
+            });
+        bdb
+    }
+}
+
+impl<V> BoundingBoxed for NurbsSurface<V>
+where
+    V: Homogeneous<f64> + ControlPoint<f64, Diff = V> + Tolerance,
+    V::Point: Bounded<Scalar = f64>,
+{
+    type Point = V::Point;
+    /// Returns the bounding box by the subdivision of the Bézier decomposition.
+    /// # Remarks
+    /// All weights are assumed to be positive.
+    fn bounding_box(&self) -> BoundingBox<V::Point> {
+        let mut bdb = BoundingBox::new();
+        self.0
+            .bezier_decomposition()
+            .into_iter()
+            .flatten()
+            .for_each(|bezier| {
+                bezier_surface_bounding_box(
+                    bezier,
+                    &|v: &V| v.to_point(),
+                    SUBDIVISION_DEPTH,
+                    &mut bdb,
+                )
+            });
+        bdb
+    }
+}
+
+impl<P> BoundingBoxed for PeriodicBSplineCurve<P>
+where P: ControlPoint<f64> + Tolerance + Bounded<Scalar = f64>
+{
+    type Point = P;
+    #[inline(always)]
+    fn bounding_box(&self) -> BoundingBox<P> { self.clamped().bounding_box() }
+}
+
+impl<P> BoundingBoxed for PeriodicBSplineSurface<P>
+where P: ControlPoint<f64> + Tolerance + Bounded<Scalar = f64>
+{
+    type Point = P;
+    #[inline(always)]
+    fn bounding_box(&self) -> BoundingBox<P> { self.clamped().bounding_box() }
+}
@@ -143,6 +143,12 @@ impl<P: Copy> Invertible for Line<P> {
     fn inverse(&self) -> Self { Self(self.1, self.0) }
 }
 
+impl<P: Bounded> BoundingBoxed for Line<P> {
+    type Point = P;
+    #[inline]
+    fn bounding_box(&self) -> BoundingBox<P> { [self.0, self.1].into_iter().collect() }
+}
+
 impl<P> SearchNearestParameter<D1> for Line<P>
 where
     P: ControlPoint<f64>,
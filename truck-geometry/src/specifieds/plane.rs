@@ -156,6 +156,36 @@ impl ParametricSurface3D for Plane {
 
 impl BoundedSurface for Plane {}
 
+impl BoundingBoxed for Plane {
+    type Point = Point3;
+    /// Returns the bounding box of the whole plane, which is infinite except for the coordinates
+    /// orthogonal to the plane. Bound planar faces by their boundaries instead.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let plane = Plane::new(
+    ///     Point3::new(0.0, 0.0, 1.0),
+    ///     Point3::new(1.0, 0.0, 1.0),
+    ///     Point3::new(0.0, 1.0, 1.0),
+    /// );
+    /// let bdb = plane.bounding_box();
+    /// assert_eq!(bdb.min(), Point3::new(f64::NEG_INFINITY, f64::NEG_INFINITY, 1.0));
+    /// assert_eq!(bdb.max(), Point3::new(f64::INFINITY, f64::INFINITY, 1.0));
+    /// ```
+    #[inline]
+    fn bounding_box(&self) -> BoundingBox<Point3> {
+        let (uder, vder) = (self.p - self.o, self.q - self.o);
+        let (mut min, mut max) = (self.o, self.o);
+        (0..3).for_each(|i| {
+            if !uder[i].so_small() || !vder[i].so_small() {
+                min[i] = f64::NEG_INFINITY;
+                max[i] = f64::INFINITY;
+            }
+        });
+        [min, max].into_iter().collect()
+    }
+}
+
 impl Invertible for Plane {
     #[inline(always)]
     fn inverse(&self) -> Self {
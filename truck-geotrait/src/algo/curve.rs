@@ -126,3 +126,21 @@ where
         (params, pts)
     }
 }
+
+/// Returns the tight bounding box of the curve on `range` by searching the extrema of each coordinate.
+/// The extrema are detected by dividing the range into `division` parts.
+pub fn bounding_box<C: ParametricCurve3D>(
+    curve: &C,
+    range: (f64, f64),
+    division: usize,
+) -> BoundingBox<Point3> {
+    let mut bdb: BoundingBox<Point3> = [curve.subs(range.0), curve.subs(range.1)]
+        .into_iter()
+        .collect();
+    (0..3).for_each(|k| {
+        sign_changes(|t| curve.der(t)[k], range, division)
+            .into_iter()
+            .for_each(|t| bdb.push(curve.subs(t)))
+    });
+    bdb
+}
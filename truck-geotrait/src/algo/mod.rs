@@ -1,7 +1,7 @@
 #![allow(clippy::many_single_char_names)]
 
 use crate::traits::*;
-use truck_base::{cgmath64::*, hash::HashGen, tolerance::*};
+use truck_base::{bounding_box::BoundingBox, cgmath64::*, hash::HashGen, tolerance::*};
 
 /// A structure that stores logs for debugging.
 #[doc(hidden)]
@@ -27,6 +27,36 @@ impl<T: std::fmt::Debug> NewtonLog<T> {
     }
 }
 
+/// Returns the parameters in `range` at which `f` changes its sign.
+/// The sign changes are detected by dividing the range into `division` parts, and refined by bisection.
+fn sign_changes(f: impl Fn(f64) -> f64, (t0, t1): (f64, f64), division: usize) -> Vec<f64> {
+    let params: Vec<f64> = (0..=division)
+        .map(|i| t0 + (t1 - t0) * i as f64 / division as f64)
+        .collect();
+    let values: Vec<f64> = params.iter().map(|t| f(*t)).collect();
+    let mut res: Vec<f64> = params
+        .iter()
+        .zip(&values)
+        .filter(|(_, x)| **x == 0.0)
+        .map(|(t, _)| *t)
+        .collect();
+    params.windows(2).zip(values.windows(2)).for_each(|(t, x)| {
+        if x[0] * x[1] < 0.0 {
+            let ((mut a, mut b), mut fa) = ((t[0], t[1]), x[0]);
+            while b - a > tolerance() {
+                let m = (a + b) / 2.0;
+                let fm = f(m);
+                match fa * fm <= 0.0 {
+                    true => b = m,
+                    false => (a, fa) = (m, fm),
+                }
+            }
+            res.push((a + b) / 2.0);
+        }
+    });
+    res
+}
+
 /// curve algorithms
 pub mod curve;
 /// surface algorithms
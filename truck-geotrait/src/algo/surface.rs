@@ -85,8 +85,11 @@ pub fn search_parameter2d<S: ParametricSurface<Point = Point2, Vector = Vector2>
         let pt = surface.subs(u0, v0);
         let uder = surface.uder(u0, v0);
         let vder = surface.vder(u0, v0);
-        let dermag2 = f64::min(0.05, uder.magnitude2());
-        let dermag2 = f64::min(dermag2, vder.magnitude2());
+        // The vanishing derivatives on the degenerate sides, e.g. poles, are not regarded.
+        let dermag2 = [uder.magnitude2(), vder.magnitude2()]
+            .into_iter()
+            .filter(|mag2| *mag2 > tolerance2())
+            .fold(0.05, f64::min);
         if pt.distance2(point) < TOLERANCE2 * dermag2 {
             return Some(hint);
         }
@@ -165,6 +168,25 @@ pub fn search_parameter3d<S: ParametricSurface3D>(
     (u0, v0): (f64, f64),
     trials: usize,
 ) -> Option<(f64, f64)> {
+    let pt = surface.subs(u0, v0);
+    if pt.near(&point) {
+        return Some((u0, v0));
+    }
+    // The normal is not defined on the degenerate sides, e.g. poles, so the hint there is moved
+    // along the non-vanishing derivatives in advance.
+    let (u0, v0) = match surface.normal(u0, v0).magnitude2().is_finite() {
+        true => (u0, v0),
+        false => {
+            let step = |der: Vector3| match der.magnitude2() > tolerance2() {
+                true => der.dot(point - pt) / der.magnitude2(),
+                false => 0.0,
+            };
+            (
+                u0 + step(surface.uder(u0, v0)),
+                v0 + step(surface.vder(u0, v0)),
+            )
+        }
+    };
     let proj = ProjectedSurface::new(surface, (u0, v0));
     search_parameter2d(&proj, proj.point_proj(point), (u0, v0), trials).and_then(|(u, v)| {
         match surface.subs(u, v).near(&point) {
@@ -262,3 +284,110 @@ where
         sub_parameter_division(surface, (udiv, vdiv), tol);
     }
 }
+
+/// Returns the tight bounding box of the surface on `range` by searching the extrema of each coordinate
+/// on the boundary and in the interior. The extrema are detected by dividing the range into `division` parts.
+pub fn bounding_box<S: ParametricSurface3D>(
+    surface: &S,
+    range: ((f64, f64), (f64, f64)),
+    division: usize,
+) -> BoundingBox<Point3> {
+    let ((u0, u1), (v0, v1)) = range;
+    let mut bdb: BoundingBox<Point3> = [(u0, v0), (u0, v1), (u1, v0), (u1, v1)]
+        .into_iter()
+        .map(|(u, v)| surface.subs(u, v))
+        .collect();
+    (0..3).for_each(|k| {
+        [v0, v1].into_iter().for_each(|v| {
+            sign_changes(|u| surface.uder(u, v)[k], range.0, division)
+                .into_iter()
+                .for_each(|u| bdb.push(surface.subs(u, v)))
+        });
+        [u0, u1].into_iter().for_each(|u| {
+            sign_changes(|v| surface.vder(u, v)[k], range.1, division)
+                .into_iter()
+                .for_each(|v| bdb.push(surface.subs(u, v)))
+        });
+    });
+    coordinate_critical_points(surface, range, division)
+        .into_iter()
+        .for_each(|(u, v)| bdb.push(surface.subs(u, v)));
+    bdb
+}
+
+/// Returns the parameters of the local extrema of the coordinates in the interior of `range`.
+///
+/// The local extrema on the grid dividing the range into `division` parts are refined by Newton's method.
+/// If Newton's method does not converge, the parameter on the grid is returned as it is.
+pub fn coordinate_critical_points<S: ParametricSurface3D>(
+    surface: &S,
+    ((u0, u1), (v0, v1)): ((f64, f64), (f64, f64)),
+    division: usize,
+) -> Vec<(f64, f64)> {
+    let param = |i: usize, j: usize| {
+        let (p, q) = (i as f64 / division as f64, j as f64 / division as f64);
+        (u0 + (u1 - u0) * p, v0 + (v1 - v0) * q)
+    };
+    let grid: Vec<Vec<Point3>> = (0..=division)
+        .map(|i| {
+            (0..=division)
+                .map(|j| {
+                    let (u, v) = param(i, j);
+                    surface.subs(u, v)
+                })
+                .collect()
+        })
+        .collect();
+    let mut res = Vec::new();
+    (0..3).for_each(|k| {
+        let interior = (1..division).flat_map(|i| (1..division).map(move |j| (i, j)));
+        interior.for_each(|(i, j)| {
+            let x = grid[i][j][k];
+            let neighbors = (i - 1..=i + 1)
+                .flat_map(|a| (j - 1..=j + 1).map(move |b| (a, b)))
+                .filter(|&(a, b)| (a, b) != (i, j))
+                .map(|(a, b)| grid[a][b][k]);
+            let (mut is_max, mut is_min, mut strict) = (true, true, false);
+            neighbors.for_each(|y| {
+                is_max = is_max && y <= x;
+                is_min = is_min && y >= x;
+                strict = strict || y != x;
+            });
+            if strict && (is_max || is_min) {
+                let hint = param(i, j);
+                let range = ((u0, u1), (v0, v1));
+                res.push(coordinate_critical_point(surface, k, hint, range).unwrap_or(hint));
+            }
+        });
+    });
+    res
+}
+
+fn coordinate_critical_point<S: ParametricSurface3D>(
+    surface: &S,
+    k: usize,
+    (mut u, mut v): (f64, f64),
+    ((u0, u1), (v0, v1)): ((f64, f64), (f64, f64)),
+) -> Option<(f64, f64)> {
+    const TRIALS: usize = 20;
+    for _ in 0..TRIALS {
+        let (fu, fv) = (surface.uder(u, v)[k], surface.vder(u, v)[k]);
+        let (fuu, fuv, fvv) = (
+            surface.uuder(u, v)[k],
+            surface.uvder(u, v)[k],
+            surface.vvder(u, v)[k],
+        );
+        let det = fuu * fvv - fuv * fuv;
+        if det.so_small() {
+            return None;
+        }
+        let (du, dv) = ((fvv * fu - fuv * fv) / det, (fuu * fv - fuv * fu) / det);
+        (u, v) = (u - du, v - dv);
+        if u < u0 || u1 < u || v < v0 || v1 < v {
+            return None;
+        } else if du.so_small() && dv.so_small() {
+            return Some((u, v));
+        }
+    }
+    None
+}
@@ -0,0 +1,134 @@
+use super::*;
+use truck_base::tolerance::*;
+
+/// Differential geometric quantities of 3D curves.
+///
+/// All methods are provided by the derivations `der` and `der2` of [`ParametricCurve3D`],
+/// and the trait is implemented for all 3D curves.
+pub trait CurveCurvature: ParametricCurve3D {
+    /// Returns the curvature at the parameter `t`.
+    ///
+    /// Returns `0.0` if the derivation at `t` vanishes.
+    #[inline(always)]
+    fn curvature(&self, t: f64) -> f64 {
+        let der = self.der(t);
+        let mag = der.magnitude();
+        if mag.so_small() {
+            return 0.0;
+        }
+        der.cross(self.der2(t)).magnitude() / (mag * mag * mag)
+    }
+    /// Returns the torsion at the parameter `t`.
+    ///
+    /// The 3rd-order derivation is approximated by the central difference of `der2`.
+    /// Returns `0.0` if the curvature at `t` vanishes.
+    fn torsion(&self, t: f64) -> f64 {
+        let der = self.der(t);
+        let der2 = self.der2(t);
+        let cross = der.cross(der2);
+        let mag2 = cross.magnitude2();
+        if mag2.so_small2() {
+            return 0.0;
+        }
+        cross.dot(der3_approx(self, t)) / mag2
+    }
+    /// Returns the Frenet frame `[tangent, normal, binormal]` at the parameter `t`.
+    ///
+    /// Returns `None` if the curvature at `t` vanishes, i.e. the principal normal is not defined.
+    fn frenet_frame(&self, t: f64) -> Option<[Vector3; 3]> {
+        let der = self.der(t);
+        if der.so_small() {
+            return None;
+        }
+        let binormal = der.cross(self.der2(t));
+        if binormal.so_small() {
+            return None;
+        }
+        let tangent = der.normalize();
+        let binormal = binormal.normalize();
+        Some([tangent, binormal.cross(tangent), binormal])
+    }
+}
+
+impl<C: ParametricCurve3D> CurveCurvature for C {}
+
+fn der3_approx<C: ParametricCurve3D>(curve: &C, t: f64) -> Vector3 {
+    const H: f64 = 1.0e-4;
+    let (t0, t1) = match curve.try_range_tuple() {
+        Some((t0, t1)) => (f64::max(t - H, t0), f64::min(t + H, t1)),
+        None => (t - H, t + H),
+    };
+    (curve.der2(t1) - curve.der2(t0)) / (t1 - t0)
+}
+
+/// Differential geometric quantities of 3D surfaces.
+///
+/// All methods are provided by the derivations and `normal` of [`ParametricSurface3D`],
+/// and the trait is implemented for all 3D surfaces.
+/// The signs of the curvatures depend on the orientation of `normal`:
+/// e.g. the principal curvatures of the sphere with outward normals are negative.
+pub trait SurfaceCurvature: ParametricSurface3D {
+    /// Returns the coefficients `(E, F, G)` of the first fundamental form at `(u, v)`.
+    #[inline(always)]
+    fn first_fundamental_form(&self, u: f64, v: f64) -> (f64, f64, f64) {
+        let (uder, vder) = (self.uder(u, v), self.vder(u, v));
+        (uder.dot(uder), uder.dot(vder), vder.dot(vder))
+    }
+    /// Returns the coefficients `(L, M, N)` of the second fundamental form at `(u, v)`.
+    #[inline(always)]
+    fn second_fundamental_form(&self, u: f64, v: f64) -> (f64, f64, f64) {
+        let n = self.normal(u, v);
+        (
+            self.uuder(u, v).dot(n),
+            self.uvder(u, v).dot(n),
+            self.vvder(u, v).dot(n),
+        )
+    }
+    /// Returns the Gaussian curvature at `(u, v)`.
+    #[inline(always)]
+    fn gaussian_curvature(&self, u: f64, v: f64) -> f64 {
+        let (e, f, g) = self.first_fundamental_form(u, v);
+        let (l, m, n) = self.second_fundamental_form(u, v);
+        (l * n - m * m) / (e * g - f * f)
+    }
+    /// Returns the mean curvature at `(u, v)`.
+    #[inline(always)]
+    fn mean_curvature(&self, u: f64, v: f64) -> f64 {
+        let (e, f, g) = self.first_fundamental_form(u, v);
+        let (l, m, n) = self.second_fundamental_form(u, v);
+        (e * n + g * l - 2.0 * f * m) / (2.0 * (e * g - f * f))
+    }
+    /// Returns the principal curvatures `(k0, k1)` with `k0 >= k1` at `(u, v)`.
+    #[inline(always)]
+    fn principal_curvatures(&self, u: f64, v: f64) -> (f64, f64) {
+        let h = self.mean_curvature(u, v);
+        let k = self.gaussian_curvature(u, v);
+        let disc = f64::max(h * h - k, 0.0).sqrt();
+        (h + disc, h - disc)
+    }
+    /// Returns the principal curvatures and the corresponding unit principal directions
+    /// `((k0, d0), (k1, d1))` with `k0 >= k1` at `(u, v)`.
+    ///
+    /// At an umbilical point, the directions are the normalized `uder` and its rotation around the normal.
+    fn principal_directions(&self, u: f64, v: f64) -> ((f64, Vector3), (f64, Vector3)) {
+        let (uder, vder) = (self.uder(u, v), self.vder(u, v));
+        let (e, f, g) = self.first_fundamental_form(u, v);
+        let (l, m, n) = self.second_fundamental_form(u, v);
+        let (k0, k1) = self.principal_curvatures(u, v);
+        let normal = self.normal(u, v);
+        let d0 = if (k0 - k1).so_small() {
+            uder.normalize()
+        } else {
+            let (a, b, c) = (l - k0 * e, m - k0 * f, n - k0 * g);
+            // the kernel of the matrix [[a, b], [b, c]]
+            let (du, dv) = match a * a + b * b > b * b + c * c {
+                true => (-b, a),
+                false => (c, -b),
+            };
+            (uder * du + vder * dv).normalize()
+        };
+        ((k0, d0), (k1, normal.cross(d0)))
+    }
+}
+
+impl<S: ParametricSurface3D> SurfaceCurvature for S {}
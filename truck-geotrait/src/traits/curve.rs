@@ -248,6 +248,9 @@ pub enum ConcatError<Point: Debug> {
     /// Failed to concat curves since the end point of the first curve is different from the start point of the second curve.
     #[error("The end point {0:?} of the first curve is different from the start point {1:?} of the second curve.")]
     DisconnectedPoints(Point, Point),
+    /// Failed to concat curves since the types of the curves cannot be concatenated.
+    #[error("The curves cannot be concatenated.")]
+    UnsupportedCurves,
 }
 
 impl<T: Debug> ConcatError<T> {
@@ -258,6 +261,7 @@ impl<T: Debug> ConcatError<T> {
         match self {
             ConcatError::DisconnectedParameters(a, b) => ConcatError::DisconnectedParameters(a, b),
             ConcatError::DisconnectedPoints(p, q) => ConcatError::DisconnectedPoints(f(p), f(q)),
+            ConcatError::UnsupportedCurves => ConcatError::UnsupportedCurves,
         }
     }
 }
@@ -336,6 +340,29 @@ pub trait Cut: BoundedCurve {
     fn cut(&mut self, t: f64) -> Self;
 }
 
+/// Continuity at the joints between curves or surfaces and their extrapolations.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ExtensionContinuity {
+    /// The extrapolation is the tangent line at the end.
+    C1,
+    /// The extrapolation is the Taylor expansion of order two at the end.
+    C2,
+}
+
+/// Extends curves beyond their parameter ranges.
+pub trait ExtendCurve: BoundedCurve {
+    /// Returns the curve extrapolated by `dt0` before the front and by `dt1` after the back.
+    /// The parameter range of the result is `(t0 - dt0, t1 + dt1)` where `(t0, t1)` is the one of `self`.
+    fn extend(&self, dt0: f64, dt1: f64, continuity: ExtensionContinuity) -> Self;
+}
+
+impl<C: ExtendCurve> ExtendCurve for Box<C> {
+    #[inline(always)]
+    fn extend(&self, dt0: f64, dt1: f64, continuity: ExtensionContinuity) -> Self {
+        Box::new((**self).extend(dt0, dt1, continuity))
+    }
+}
+
 /// positive test implementation for `ParameterTransform` by random transformation
 pub fn parameter_transform_random_test<C>(curve: &C, trials: usize)
 where
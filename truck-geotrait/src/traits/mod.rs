@@ -1,4 +1,5 @@
 use std::ops::Bound;
+use truck_base::bounding_box::{Bounded, BoundingBox};
 use truck_base::cgmath64::*;
 
 mod curve;
@@ -7,6 +8,8 @@ mod surface;
 pub use surface::*;
 mod search_parameter;
 pub use search_parameter::*;
+mod curvature;
+pub use curvature::*;
 
 /// parameter range
 pub type ParameterRange = (Bound<f64>, Bound<f64>);
@@ -71,3 +74,24 @@ impl<S: Transformed<T>, T> Transformed<T> for Box<S> {
     #[inline(always)]
     fn transformed(&self, trans: T) -> Self { Box::new((**self).transformed(trans)) }
 }
+
+/// Geometric elements with tight bounding boxes.
+pub trait BoundingBoxed {
+    /// The type of the corners of the bounding box
+    type Point: Bounded;
+    /// Returns the bounding box which is tight up to the tolerance,
+    /// in contrast to the one of the control points.
+    fn bounding_box(&self) -> BoundingBox<Self::Point>;
+}
+
+impl<'a, T: BoundingBoxed> BoundingBoxed for &'a T {
+    type Point = T::Point;
+    #[inline(always)]
+    fn bounding_box(&self) -> BoundingBox<T::Point> { (*self).bounding_box() }
+}
+
+impl<T: BoundingBoxed> BoundingBoxed for Box<T> {
+    type Point = T::Point;
+    #[inline(always)]
+    fn bounding_box(&self) -> BoundingBox<T::Point> { (**self).bounding_box() }
+}
@@ -129,6 +129,34 @@ impl<'a, S: BoundedSurface> BoundedSurface for &'a S {}
 
 impl<S: BoundedSurface> BoundedSurface for Box<S> {}
 
+/// Extends surfaces beyond their parameter ranges.
+pub trait ExtendSurface: BoundedSurface {
+    /// Returns the surface extrapolated by `du0`, `du1` before and after the `u`-range and
+    /// by `dv0`, `dv1` before and after the `v`-range.
+    fn extend(
+        &self,
+        du0: f64,
+        du1: f64,
+        dv0: f64,
+        dv1: f64,
+        continuity: ExtensionContinuity,
+    ) -> Self;
+}
+
+impl<S: ExtendSurface> ExtendSurface for Box<S> {
+    #[inline(always)]
+    fn extend(
+        &self,
+        du0: f64,
+        du1: f64,
+        dv0: f64,
+        dv1: f64,
+        continuity: ExtensionContinuity,
+    ) -> Self {
+        Box::new((**self).extend(du0, du1, dv0, dv1, continuity))
+    }
+}
+
 /// Whether the surface includes the boundary curve.
 pub trait IncludeCurve<C: ParametricCurve> {
     /// Returns whether the curve `curve` is included in the surface `self`.
@@ -1,4 +1,4 @@
-use truck_base::{cgmath64::*, tolerance::*};
+use truck_base::{assert_near, cgmath64::*, tolerance::*};
 use truck_geotrait::*;
 mod polynomial;
 use polynomial::PolyCurve;
@@ -107,3 +107,30 @@ fn polycurve_division() {
     println!("division error: {}", 100 - count);
     assert!(count > 98);
 }
+
+#[test]
+fn polycurve_curvature() {
+    // (t, t^2, t^3)
+    let coef = vec![
+        Vector3::new(0.0, 0.0, 0.0),
+        Vector3::new(1.0, 0.0, 0.0),
+        Vector3::new(0.0, 1.0, 0.0),
+        Vector3::new(0.0, 0.0, 1.0),
+    ];
+    let poly = PolyCurve::<Point3>(coef);
+    assert_near!(poly.curvature(0.0), 2.0);
+    assert!(f64::abs(poly.torsion(0.0) - 3.0) < 1.0e-4);
+    let [tangent, normal, binormal] = poly.frenet_frame(0.0).unwrap();
+    assert_near!(tangent, Vector3::unit_x());
+    assert_near!(normal, Vector3::unit_y());
+    assert_near!(binormal, Vector3::unit_z());
+
+    // straight line
+    let line = PolyCurve::<Point3>(vec![
+        Vector3::new(1.0, 2.0, 3.0),
+        Vector3::new(1.0, 1.0, 0.0),
+    ]);
+    assert_near!(line.curvature(1.0), 0.0);
+    assert_near!(line.torsion(1.0), 0.0);
+    assert!(line.frenet_frame(1.0).is_none());
+}
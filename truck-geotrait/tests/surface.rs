@@ -1,4 +1,4 @@
-use truck_base::{cgmath64::*, tolerance::*};
+use truck_base::{assert_near, cgmath64::*, tolerance::*};
 use truck_geotrait::*;
 mod polynomial;
 use polynomial::{PolyCurve, PolySurface};
@@ -213,3 +213,32 @@ fn polysurface_division() {
     let count = (0..10).filter(|_| exec_polysurface_division()).count();
     assert!(count > 8, "wrong answer: {:?}", 10 - count);
 }
+
+#[test]
+fn polysurface_curvature() {
+    // z = (1 + 3u^2/2)(1 + v^2/2) ~ 1 + 3u^2/2 + v^2/2 near the origin
+    let coef0 = vec![
+        Vector3::new(0.0, 1.0, 1.0),
+        Vector3::new(1.0, 0.0, 0.0),
+        Vector3::new(0.0, 0.0, 1.5),
+    ];
+    let coef1 = vec![
+        Vector3::new(1.0, 0.0, 1.0),
+        Vector3::new(0.0, 1.0, 0.0),
+        Vector3::new(0.0, 0.0, 0.5),
+    ];
+    let poly = PolySurface(PolyCurve(coef0), PolyCurve(coef1));
+    let (e, f, g) = poly.first_fundamental_form(0.0, 0.0);
+    assert_near!(Vector3::new(e, f, g), Vector3::new(1.0, 0.0, 1.0));
+    let (l, m, n) = poly.second_fundamental_form(0.0, 0.0);
+    assert_near!(Vector3::new(l, m, n), Vector3::new(3.0, 0.0, 1.0));
+    assert_near!(poly.gaussian_curvature(0.0, 0.0), 3.0);
+    assert_near!(poly.mean_curvature(0.0, 0.0), 2.0);
+    let (k0, k1) = poly.principal_curvatures(0.0, 0.0);
+    assert_near!(Vector2::new(k0, k1), Vector2::new(3.0, 1.0));
+    let ((k0, d0), (k1, d1)) = poly.principal_directions(0.0, 0.0);
+    assert_near!(k0, 3.0);
+    assert_near!(k1, 1.0);
+    assert_near!(d0.dot(Vector3::unit_x()).abs(), 1.0);
+    assert_near!(d1.dot(Vector3::unit_y()).abs(), 1.0);
+}
@@ -1,6 +1,6 @@
 use crate::*;
 use spade::{iterators::*, *};
-use truck_topology::{compress::*, *};
+use truck_topology::{assembly::Assembly, compress::*, *};
 
 #[cfg(not(target_arch = "wasm32"))]
 mod parallelizable {
@@ -125,6 +125,46 @@ where CompressedShell<P, C, S>: MeshedShape
     }
 }
 
+impl<T: MeshedShape> MeshedShape for Assembly<T> {
+    /// Merges the polygons of the instances placed in the coordinate of the root.
+    /// The polygon of each shared part is created only once, and the faces of the instances
+    /// placed with reflections are reversed so that they keep facing outward.
+    fn to_polygon(&self) -> PolygonMesh {
+        let mut polygons = Vec::<(*const T, PolygonMesh)>::new();
+        let mut polygon = PolygonMesh::default();
+        self.instances().into_iter().for_each(|instance| {
+            let ptr = std::sync::Arc::as_ptr(instance.part);
+            let idx = match polygons.iter().position(|(p, _)| *p == ptr) {
+                Some(idx) => idx,
+                None => {
+                    polygons.push((ptr, instance.part.to_polygon()));
+                    polygons.len() - 1
+                }
+            };
+            let mut part_polygon = polygons[idx].1.clone();
+            let mat = instance.placement;
+            part_polygon
+                .positions_mut()
+                .iter_mut()
+                .for_each(|p| *p = mat.transform_point(*p));
+            let linear =
+                Matrix3::from_cols(mat[0].truncate(), mat[1].truncate(), mat[2].truncate());
+            if let Some(normal_mat) = linear.invert().map(|mat| mat.transpose()) {
+                part_polygon
+                    .normals_mut()
+                    .iter_mut()
+                    .for_each(|n| *n = (normal_mat * *n).normalize());
+            }
+            // a placement with a reflection turns the faces inside out.
+            if linear.determinant() < 0.0 {
+                part_polygon.face_iter_mut().for_each(|face| face.reverse());
+            }
+            polygon.merge(part_polygon);
+        });
+        polygon
+    }
+}
+
 /// Trait for tessellating `Shell` and `Solid`.
 pub trait MeshableShape {
     /// Shape whose edges are made polylines and faces polygon surface.
@@ -357,4 +397,20 @@ impl<C: PolylineableCurve, S: RobustMeshableSurface> RobustMeshableShape
     }
 }
 
+impl<T: MeshableShape> MeshableShape for Assembly<T> {
+    type MeshedShape = Assembly<T::MeshedShape>;
+    /// Tessellates each shared part only once, so that the meshes are instanced, not copied.
+    fn triangulation(&self, tol: f64) -> Self::MeshedShape {
+        self.map_parts(|part| part.triangulation(tol))
+    }
+}
+
+impl<T: RobustMeshableShape> RobustMeshableShape for Assembly<T> {
+    type MeshedShape = Assembly<T::MeshedShape>;
+    /// Tessellates each shared part only once, so that the meshes are instanced, not copied.
+    fn robust_triangulation(&self, tol: f64) -> Self::MeshedShape {
+        self.map_parts(|part| part.robust_triangulation(tol))
+    }
+}
+
 mod triangulation;
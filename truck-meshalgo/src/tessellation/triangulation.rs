@@ -59,6 +59,8 @@ where
     C: PolylineableCurve + 'a,
     S: PreMeshableSurface + 'a,
 {
+    // the tolerance context is thread-local, so it is passed to the worker threads explicitly.
+    let context = tolerance();
     let vmap: HashMap<_, _> = shell
         .vertex_par_iter()
         .map(|v| (v.id(), v.mapped(Point3::clone)))
@@ -69,8 +71,11 @@ where
         .map(move |(id, edge)| {
             let v0 = vmap.get(&edge.absolute_front().id()).unwrap();
             let v1 = vmap.get(&edge.absolute_back().id()).unwrap();
-            let curve = edge.curve();
-            let poly = PolylineCurve::from_curve(&curve, curve.range_tuple(), tol);
+            let poly = edge.with_curve(|curve| {
+                with_tolerance(context, || {
+                    PolylineCurve::from_curve(curve, curve.range_tuple(), tol)
+                })
+            });
             (id, Edge::debug_new(v0, v1, poly))
         })
         .collect();
@@ -89,7 +94,11 @@ where
             .iter()
             .map(create_boundary)
             .collect();
-        shell_create_polygon(&face.surface(), wires, face.orientation(), tol, &sp)
+        face.with_surface(|surface| {
+            with_tolerance(context, || {
+                shell_create_polygon(surface, wires, face.orientation(), tol, &sp)
+            })
+        })
     };
     shell.face_par_iter().map(create_face).collect()
 }
@@ -118,8 +127,8 @@ where
             let v0 = vmap.entry_or_insert(vf).clone();
             let vb = edge.absolute_back();
             let v1 = vmap.entry_or_insert(vb).clone();
-            let curve = edge.curve();
-            let poly = PolylineCurve::from_curve(&curve, curve.range_tuple(), tol);
+            let poly =
+                edge.with_curve(|curve| PolylineCurve::from_curve(curve, curve.range_tuple(), tol));
             Edge::debug_new(&v0, &v1, poly)
         },
     );
@@ -139,7 +148,9 @@ where
             .iter()
             .map(&mut create_boundary)
             .collect();
-        shell_create_polygon(&face.surface(), wires, face.orientation(), tol, &sp)
+        face.with_surface(|surface| {
+            shell_create_polygon(surface, wires, face.orientation(), tol, &sp)
+        })
     };
     shell.face_iter().map(create_face).collect()
 }
@@ -155,11 +166,14 @@ where
     S: PreMeshableSurface + 'a,
 {
     let vertices = shell.vertices.clone();
+    let context = tolerance();
     let tessellate_edge = |edge: &CompressedEdge<C>| {
         let curve = &edge.curve;
         CompressedEdge {
             vertices: edge.vertices,
-            curve: PolylineCurve::from_curve(curve, curve.range_tuple(), tol),
+            curve: with_tolerance(context, || {
+                PolylineCurve::from_curve(curve, curve.range_tuple(), tol)
+            }),
         }
     };
     #[cfg(not(target_arch = "wasm32"))]
@@ -167,26 +181,28 @@ where
     #[cfg(target_arch = "wasm32")]
     let edges: Vec<_> = shell.edges.iter().map(tessellate_edge).collect();
     let tessellate_face = |face: &CompressedFace<S>| {
-        let boundaries = face.boundaries.clone();
-        let surface = &face.surface;
-        let create_edge = |edge_idx: &CompressedEdgeIndex| match edge_idx.orientation {
-            true => Some(edges.get(edge_idx.index)?.curve.clone()),
-            false => Some(edges.get(edge_idx.index)?.curve.inverse()),
-        };
-        let create_boundary = |wire: &Vec<CompressedEdgeIndex>| {
-            let wire_iter = wire.iter().filter_map(create_edge);
-            PolyBoundaryPiece::try_new(surface, wire_iter, &sp)
-        };
-        let preboundary: Option<Vec<_>> = boundaries.iter().map(create_boundary).collect();
-        let polygon: Option<PolygonMesh> = (|| {
-            let boundary = PolyBoundary::new(preboundary?, &surface, tol);
-            Some(trimming_tessellation(&surface, &boundary, tol))
-        })();
-        CompressedFace {
-            boundaries,
-            orientation: face.orientation,
-            surface: polygon,
-        }
+        with_tolerance(context, || {
+            let boundaries = face.boundaries.clone();
+            let surface = &face.surface;
+            let create_edge = |edge_idx: &CompressedEdgeIndex| match edge_idx.orientation {
+                true => Some(edges.get(edge_idx.index)?.curve.clone()),
+                false => Some(edges.get(edge_idx.index)?.curve.inverse()),
+            };
+            let create_boundary = |wire: &Vec<CompressedEdgeIndex>| {
+                let wire_iter = wire.iter().filter_map(create_edge);
+                PolyBoundaryPiece::try_new(surface, wire_iter, &sp)
+            };
+            let preboundary: Option<Vec<_>> = boundaries.iter().map(create_boundary).collect();
+            let polygon: Option<PolygonMesh> = (|| {
+                let boundary = PolyBoundary::new(preboundary?, &surface, tol);
+                Some(trimming_tessellation(&surface, &boundary, tol))
+            })();
+            CompressedFace {
+                boundaries,
+                orientation: face.orientation,
+                surface: polygon,
+            }
+        })
     };
     #[cfg(not(target_arch = "wasm32"))]
     let faces = shell.faces.par_iter().map(tessellate_face).collect();
@@ -305,7 +321,7 @@ struct PolyBoundary(Vec<Vec<Point2>>);
 fn normalize_range(curve: &mut Vec<Point2>, compidx: usize, (u0, u1): (f64, f64)) {
     let p = curve[0];
     let q = curve[curve.len() - 1];
-    let tmp = f64::min(p[compidx], q[compidx]) + TOLERANCE;
+    let tmp = f64::min(p[compidx], q[compidx]) + tolerance();
     let del = f64::floor((tmp - u0) / (u1 - u0)) * (u1 - u0);
     curve.iter_mut().for_each(|p| p[compidx] -= del);
     let Some(i) = curve
@@ -359,7 +375,7 @@ impl PolyBoundary {
                 let p = curve[0];
                 let q = curve[curve.len() - 1];
                 if let (Some((u0, u1)), Some((v0, v1))) = surface.try_range_tuple() {
-                    if p.x < q.x - TOLERANCE {
+                    if p.x < q.x - tolerance() {
                         normalize_range(&mut curve, 0, (u0, u1));
                         let p = curve[0];
                         let q = curve[curve.len() - 1];
@@ -369,7 +385,7 @@ impl PolyBoundary {
                         let vec1 = polyline_on_surface(surface, y, x, tol);
                         let vec2 = polyline_on_surface(surface, x, p, tol);
                         closed.push(connect_edges([vec0, vec1, vec2, curve]));
-                    } else if q.x < p.x - TOLERANCE {
+                    } else if q.x < p.x - tolerance() {
                         normalize_range(&mut curve, 0, (u0, u1));
                         let p = curve[0];
                         let q = curve[curve.len() - 1];
@@ -379,7 +395,7 @@ impl PolyBoundary {
                         let vec1 = polyline_on_surface(surface, y, x, tol);
                         let vec2 = polyline_on_surface(surface, x, p, tol);
                         closed.push(connect_edges([vec0, vec1, vec2, curve]));
-                    } else if p.y < q.y - TOLERANCE {
+                    } else if p.y < q.y - tolerance() {
                         normalize_range(&mut curve, 1, (v0, v1));
                         let p = curve[0];
                         let q = curve[curve.len() - 1];
@@ -389,7 +405,7 @@ impl PolyBoundary {
                         let vec1 = polyline_on_surface(surface, y, x, tol);
                         let vec2 = polyline_on_surface(surface, x, p, tol);
                         closed.push(connect_edges([vec0, vec1, vec2, curve]));
-                    } else if q.y < p.y - TOLERANCE {
+                    } else if q.y < p.y - tolerance() {
                         normalize_range(&mut curve, 1, (v0, v1));
                         let p = curve[0];
                         let q = curve[curve.len() - 1];
@@ -471,6 +487,26 @@ impl PolyBoundary {
             .unwrap_or(false)
     }
 
+    /// whether the interior of the rectangle `urange` × `vrange` meets the domain with
+    /// boundary = `self`. The rectangles only touching the boundary from outside do not.
+    fn meets(&self, (u0, u1): (f64, f64), (v0, v1): (f64, f64)) -> bool {
+        let (u0, u1) = (u0 + TOLERANCE, u1 - TOLERANCE);
+        let (v0, v1) = (v0 + TOLERANCE, v1 - TOLERANCE);
+        let corners = [
+            Point2::new(u0, v0),
+            Point2::new(u1, v0),
+            Point2::new(u1, v1),
+            Point2::new(u0, v1),
+            Point2::new((u0 + u1) / 2.0, (v0 + v1) / 2.0),
+        ];
+        corners.into_iter().any(|c| self.include(c))
+            || self
+                .0
+                .iter()
+                .flat_map(|vec| vec.iter().circular_tuple_windows())
+                .any(|(p, q)| segment_meets_rect(*p, *q, (u0, u1), (v0, v1)))
+    }
+
     /// Inserts points and adds constraint into triangulation.
     fn insert_to(&self, triangulation: &mut Cdt) {
         let poly2tri: Vec<_> = self
@@ -527,9 +563,7 @@ fn insert_surface(
     polyline: &PolyBoundary,
     tol: f64,
 ) {
-    let bdb: BoundingBox<Point2> = polyline.0.iter().flatten().collect();
-    let range = ((bdb.min()[0], bdb.max()[0]), (bdb.min()[1], bdb.max()[1]));
-    let (udiv, vdiv) = surface.parameter_division(range, tol);
+    let (udiv, vdiv) = face_parameter_division(&surface, polyline, tol);
     let insert_res: Vec<Vec<Option<_>>> = udiv
         .into_iter()
         .map(|u| {
@@ -565,6 +599,149 @@ fn insert_surface(
     });
 }
 
+/// Divides the parameter domain of the surface trimmed by `polyline`.
+///
+/// The division starts from the bounding box of the boundary as one cell, and only the cells
+/// meeting the trimmed domain are bisected along the directions in which they are too curved,
+/// so that a small face on a large surface is not divided by the curvature outside the face.
+fn face_parameter_division(
+    surface: &impl ParametricSurface3D,
+    polyline: &PolyBoundary,
+    tol: f64,
+) -> (Vec<f64>, Vec<f64>) {
+    nonpositive_tolerance!(tol);
+    let bdb: BoundingBox<Point2> = polyline.0.iter().flatten().collect();
+    let (min, max) = (bdb.min(), bdb.max());
+    let (mut udiv, mut vdiv) = (vec![min.x, max.x], vec![min.y, max.y]);
+    loop {
+        let mut uflags = vec![false; udiv.len() - 1];
+        let mut vflags = vec![false; vdiv.len() - 1];
+        for (u, ub) in udiv.windows(2).zip(&mut uflags) {
+            for (v, vb) in vdiv.windows(2).zip(&mut vflags) {
+                let urange = (u[0], u[1]);
+                let vrange = (v[0], v[1]);
+                if (*ub && *vb)
+                    || (urange.1 - urange.0).so_small()
+                    || (vrange.1 - vrange.0).so_small()
+                {
+                    continue;
+                }
+                let (udivide, vdivide) = too_curved(surface, urange, vrange, tol);
+                if ((udivide && !*ub) || (vdivide && !*vb)) && polyline.meets(urange, vrange) {
+                    *ub = *ub || udivide;
+                    *vb = *vb || vdivide;
+                }
+            }
+        }
+        if !uflags.contains(&true) && !vflags.contains(&true) {
+            return (udiv, vdiv);
+        }
+        udiv = bisect_flagged(&udiv, &uflags);
+        vdiv = bisect_flagged(&vdiv, &vflags);
+    }
+}
+
+/// whether the sagittae of the cell along the u- and v-directions, estimated by the second
+/// fundamental forms at the corners and the center, are greater than `tol`.
+/// If only the sagitta along a diagonal is greater than `tol`, both are regarded as too curved.
+/// The cell is also too curved in the directions along which it is far from the interpolation
+/// of its corners, e.g. by the non-uniform speed of the parametrization.
+fn too_curved(
+    surface: &impl ParametricSurface3D,
+    (u0, u1): (f64, f64),
+    (v0, v1): (f64, f64),
+    tol: f64,
+) -> (bool, bool) {
+    let (du, dv) = (u1 - u0, v1 - v0);
+    let params = [
+        (u0, v0),
+        (u1, v0),
+        (u1, v1),
+        (u0, v1),
+        ((u0 + u1) / 2.0, (v0 + v1) / 2.0),
+    ];
+    let sagittae = params.into_iter().fold([0.0; 3], |sagittae, (u, v)| {
+        let (l, m, n) = surface.second_fundamental_form(u, v);
+        let diagonal = f64::max(
+            (l * du * du + 2.0 * m * du * dv + n * dv * dv).abs(),
+            (l * du * du - 2.0 * m * du * dv + n * dv * dv).abs(),
+        );
+        let current = [l.abs() * du * du, n.abs() * dv * dv, diagonal].map(|x| x / 8.0);
+        // The forms are not defined at the degenerate points, e.g. poles.
+        match current.iter().all(|x| x.is_finite()) {
+            true => [0, 1, 2].map(|i| f64::max(sagittae[i], current[i])),
+            false => sagittae,
+        }
+    });
+    let (ufar, vfar) = far_from_interpolation(surface, (u0, u1), (v0, v1), tol);
+    match sagittae.map(|x| x > tol) {
+        [false, false, true] => (true, true),
+        [udivide, vdivide, _] => (udivide || ufar, vdivide || vfar),
+    }
+}
+
+/// whether the surface is far from the linear interpolations along the u- and v-directions
+/// at a hashed point near the center, as in `algo::surface::parameter_division`.
+/// If only the bilinear interpolation of the corners is far, both are regarded as far.
+fn far_from_interpolation(
+    surface: &impl ParametricSurface3D,
+    (u0, u1): (f64, f64),
+    (v0, v1): (f64, f64),
+    tol: f64,
+) -> (bool, bool) {
+    let gen = surface.subs((u0 + u1) / 2.0, (v0 + v1) / 2.0);
+    let p = 0.5 + (0.2 * HashGen::hash1(gen) - 0.1);
+    let q = 0.5 + (0.2 * HashGen::hash1(gen) - 0.1);
+    let (u, v) = (u0 * (1.0 - p) + u1 * p, v0 * (1.0 - q) + v1 * q);
+    let pt = surface.subs(u, v);
+    let far = |vec: Vector3| pt.distance2(Point3::from_vec(vec)) > tol * tol;
+    let ufar = far(surface.subs(u0, v).to_vec() * (1.0 - p) + surface.subs(u1, v).to_vec() * p);
+    let vfar = far(surface.subs(u, v0).to_vec() * (1.0 - q) + surface.subs(u, v1).to_vec() * q);
+    let bilinear = surface.subs(u0, v0).to_vec() * (1.0 - p) * (1.0 - q)
+        + surface.subs(u0, v1).to_vec() * (1.0 - p) * q
+        + surface.subs(u1, v0).to_vec() * p * (1.0 - q)
+        + surface.subs(u1, v1).to_vec() * p * q;
+    match (ufar, vfar) {
+        (false, false) if far(bilinear) => (true, true),
+        _ => (ufar, vfar),
+    }
+}
+
+fn bisect_flagged(div: &[f64], flags: &[bool]) -> Vec<f64> {
+    let mut res = vec![div[0]];
+    div.windows(2).zip(flags).for_each(|(t, flag)| {
+        if *flag {
+            res.push((t[0] + t[1]) / 2.0);
+        }
+        res.push(t[1]);
+    });
+    res
+}
+
+/// whether the segment `pq` meets the rectangle `urange` × `vrange`, by the Liang-Barsky clipping.
+fn segment_meets_rect(p: Point2, q: Point2, (u0, u1): (f64, f64), (v0, v1): (f64, f64)) -> bool {
+    let d = q - p;
+    let (mut t0, mut t1) = (0.0_f64, 1.0_f64);
+    [
+        (-d.x, p.x - u0),
+        (d.x, u1 - p.x),
+        (-d.y, p.y - v0),
+        (d.y, v1 - p.y),
+    ]
+    .into_iter()
+    .all(|(a, b)| {
+        if a == 0.0 {
+            b >= 0.0
+        } else {
+            match a < 0.0 {
+                true => t0 = f64::max(t0, b / a),
+                false => t1 = f64::min(t1, b / a),
+            }
+            t0 <= t1
+        }
+    })
+}
+
 /// Converts triangulation into `PolygonMesh`.
 fn triangulation_into_polymesh<'a>(
     vertices: VertexIterator<'a, SPoint2, (), CdtEdge<()>, ()>,
@@ -575,6 +752,8 @@ fn triangulation_into_polymesh<'a>(
     let mut positions = Vec::<Point3>::new();
     let mut uv_coords = Vec::<Vector2>::new();
     let mut normals = Vec::<Vector3>::new();
+    let bdb: BoundingBox<Point2> = polyline.0.iter().flatten().collect();
+    let center = bdb.center().to_vec();
     let vmap: HashMap<_, _> = vertices
         .enumerate()
         .map(|(i, v)| {
@@ -582,7 +761,17 @@ fn triangulation_into_polymesh<'a>(
             let uv = Vector2::new(p.x, p.y);
             positions.push(surface.subs(uv[0], uv[1]));
             uv_coords.push(uv);
-            normals.push(surface.normal(uv[0], uv[1]));
+            let normal = surface.normal(uv[0], uv[1]);
+            // The normal is not defined at the degenerate points, e.g. poles,
+            // so the one at the nearby point in the domain is taken.
+            let normal = match normal.magnitude2().is_finite() {
+                true => normal,
+                false => {
+                    let uv = uv + (center - uv) * TOLERANCE.sqrt();
+                    surface.normal(uv[0], uv[1])
+                }
+            };
+            normals.push(normal);
             (v.fix(), i)
         })
         .collect();
@@ -133,3 +133,143 @@ fn special_cylinder_csolid() {
         .remove_unused_attrs();
     assert_eq!(mesh.shell_condition(), ShellCondition::Closed);
 }
+
+#[test]
+fn filled_triangle() {
+    // the triangle filled by the surface which degenerates at `v0`, where the normal is not defined
+    let v0 = builder::vertex(Point3::new(0.0, 0.0, 0.0));
+    let v1 = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+    let v2 = builder::vertex(Point3::new(0.0, 1.0, 0.0));
+    let wire: Wire = vec![
+        builder::line(&v0, &v1),
+        builder::circle_arc(&v1, &v2, Point3::new(0.5, 0.5, f64::sqrt(0.5))),
+        builder::line(&v2, &v0),
+    ]
+    .into();
+    let shell: Shell = vec![builder::try_fill_wire(&wire).unwrap()].into();
+    let mut mesh = shell.triangulation(0.01).to_polygon();
+    mesh.put_together_same_attrs(TOLERANCE)
+        .remove_degenerate_faces()
+        .remove_unused_attrs();
+    assert!(!mesh.faces().is_empty());
+    assert!(mesh.positions().iter().any(|p| p.near(&Point3::origin())));
+    mesh.normals()
+        .iter()
+        .for_each(|n| assert_near!(n.magnitude(), 1.0));
+}
+
+#[test]
+fn small_face_on_large_surface() {
+    // a large surface curved along v, with a bump in the upper right quarter of the domain
+    let knot_vec = KnotVec::uniform_knot(3, 4);
+    // the control points on the Greville abscissae make x and y affine in the parameters.
+    let greville = (0..7)
+        .map(|i| (1..4).map(|k| knot_vec[i + k]).sum::<f64>() / 3.0)
+        .collect::<Vec<_>>();
+    let ctrl_pts = (0..7)
+        .map(|i| {
+            (0..7)
+                .map(|j| {
+                    let bump = if i >= 5 && j >= 5 { 20.0 } else { 0.0 };
+                    let z = 5.0 * (j as f64 - 3.0).powi(2) + bump;
+                    Point3::new(100.0 * greville[i], 100.0 * greville[j], z)
+                })
+                .collect()
+        })
+        .collect();
+    let surface = BSplineSurface::new((knot_vec.clone(), knot_vec), ctrl_pts);
+    // an L-shaped face avoiding the bump, whose bounding box contains the bump
+    let params = [
+        (0.1, 0.1),
+        (0.9, 0.1),
+        (0.9, 0.5),
+        (0.5, 0.5),
+        (0.5, 0.9),
+        (0.1, 0.9),
+    ];
+    let v = params.map(|(u, v)| builder::vertex(surface.subs(u, v)));
+    let wire: Wire = (0..6)
+        .map(|i| builder::line(&v[i], &v[(i + 1) % 6]))
+        .collect();
+    let shell: Shell = vec![Face::new(
+        vec![wire],
+        Surface::BSplineSurface(surface.clone()),
+    )]
+    .into();
+    let mesh = shell.triangulation(0.01).to_polygon();
+
+    let in_face = |u: f64, v: f64| {
+        let inside = |t: f64, t0: f64, t1: f64| t0 - TOLERANCE <= t && t <= t1 + TOLERANCE;
+        (inside(u, 0.1, 0.9) && inside(v, 0.1, 0.5)) || (inside(u, 0.1, 0.5) && inside(v, 0.1, 0.9))
+    };
+    mesh.uv_coords()
+        .iter()
+        .for_each(|uv| assert!(in_face(uv.x, uv.y), "{uv:?}"));
+    // the division of the bounding box is refined by the bump out of the face,
+    // which gives more than ten times as many vertices
+    let (udiv, vdiv) = surface.parameter_division(((0.1, 0.9), (0.1, 0.9)), 0.01);
+    let bdb_division = udiv
+        .iter()
+        .flat_map(|u| vdiv.iter().map(move |v| (*u, *v)))
+        .filter(|(u, v)| in_face(*u, *v))
+        .count();
+    assert!(!mesh.positions().is_empty());
+    assert!(
+        mesh.positions().len() * 10 < bdb_division,
+        "{} {bdb_division}",
+        mesh.positions().len()
+    );
+}
+
+#[test]
+fn instanced_assembly() {
+    let json = std::fs::read(concat!(dir!(), "punched-cube.json")).unwrap();
+    let part: Solid = serde_json::from_reader(json.as_slice()).unwrap();
+    let part = std::sync::Arc::new(part);
+    let mut assembly = Assembly::new("root");
+    (0..3).for_each(|i| {
+        let mut node = Assembly::with_part(format!("cube{i}"), std::sync::Arc::clone(&part));
+        node.set_placement(Matrix4::from_translation(Vector3::new(
+            2.0 * i as f64,
+            0.0,
+            0.0,
+        )));
+        assembly.push(node);
+    });
+    let meshed = assembly.triangulation(0.01);
+    assert_eq!(meshed.parts().len(), 1);
+
+    let single = part.triangulation(0.01).to_polygon();
+    let poly = meshed.to_polygon();
+    assert_eq!(poly.positions().len(), 3 * single.positions().len());
+    let (bdd0, bdd1) = (single.bounding_box(), poly.bounding_box());
+    assert_near!(bdd1.min(), bdd0.min());
+    assert_near!(bdd1.max(), bdd0.max() + Vector3::new(4.0, 0.0, 0.0));
+}
+
+#[test]
+fn mirrored_assembly() {
+    let json = std::fs::read(concat!(dir!(), "punched-cube.json")).unwrap();
+    let part: Solid = serde_json::from_reader(json.as_slice()).unwrap();
+    let mut assembly = Assembly::new("root");
+    let mut node = Assembly::with_part("mirrored", std::sync::Arc::new(part.clone()));
+    node.set_placement(Matrix4::from_nonuniform_scale(-1.0, 1.0, 1.0));
+    assembly.push(node);
+    let meshed = assembly.triangulation(0.01);
+
+    // the signed volume is positive if the faces are facing outward.
+    let volume = |poly: &PolygonMesh| {
+        let positions = poly.positions();
+        poly.face_iter()
+            .flat_map(|face| (2..face.len()).map(move |i| (face[0], face[i - 1], face[i])))
+            .map(|(v0, v1, v2)| {
+                let (p0, p1, p2) = (positions[v0.pos], positions[v1.pos], positions[v2.pos]);
+                p0.to_vec().dot(p1.to_vec().cross(p2.to_vec())) / 6.0
+            })
+            .sum::<f64>()
+    };
+    let single = part.triangulation(0.01).to_polygon();
+    let poly = meshed.to_polygon();
+    assert!(volume(&single) > 0.0);
+    assert_near!(volume(&poly), volume(&single));
+}
@@ -421,6 +421,128 @@ pub fn try_attach_plane(wires: &[Wire]) -> Result<Face> {
     Ok(Face::try_new(wires.to_owned(), plane.into())?)
 }
 
+/// Returns a face filling a wire with three or four edges by the Coons patch.
+/// # Panics
+/// There are the same conditions for panic as [`try_fill_wire`].
+#[inline(always)]
+pub fn fill_wire(wire: &Wire) -> Face { try_fill_wire(wire).unwrap_or_else(|e| panic!("{}", e)) }
+
+/// Returns a face filling a wire with three or four edges by the Coons patch.
+///
+/// The wire does not need to be planar. In the case of three edges, the surface degenerates
+/// at the front vertex of the wire.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+///
+/// // a saddle bounded by four lines
+/// let v0 = builder::vertex(Point3::new(0.0, 0.0, 0.0));
+/// let v1 = builder::vertex(Point3::new(1.0, 0.0, 1.0));
+/// let v2 = builder::vertex(Point3::new(1.0, 1.0, 0.0));
+/// let v3 = builder::vertex(Point3::new(0.0, 1.0, 1.0));
+/// let wire: Wire = vec![
+///     builder::line(&v0, &v1),
+///     builder::line(&v1, &v2),
+///     builder::line(&v2, &v3),
+///     builder::line(&v3, &v0),
+/// ]
+/// .into();
+/// let face = builder::try_fill_wire(&wire).unwrap();
+/// let surface = face.oriented_surface();
+/// assert_near!(surface.subs(0.5, 0.5), Point3::new(0.5, 0.5, 0.5));
+/// # const N: usize = 10;
+/// # for i in 0..=N {
+/// #     let t = i as f64 / N as f64;
+/// #     assert_near!(surface.subs(t, 0.0), Point3::new(t, 0.0, t));
+/// #     assert_near!(surface.subs(1.0, t), Point3::new(1.0, t, 1.0 - t));
+/// # }
+///
+/// // a triangle with a circle arc
+/// let v0 = builder::vertex(Point3::new(0.0, 0.0, 0.0));
+/// let v1 = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+/// let v2 = builder::vertex(Point3::new(0.0, 1.0, 0.0));
+/// let wire: Wire = vec![
+///     builder::line(&v0, &v1),
+///     builder::circle_arc(&v1, &v2, Point3::new(0.5, 0.5, 0.5_f64.sqrt())),
+///     builder::line(&v2, &v0),
+/// ]
+/// .into();
+/// let face = builder::try_fill_wire(&wire).unwrap();
+/// let surface = face.oriented_surface();
+/// assert_near!(surface.subs(0.0, 0.5), Point3::new(0.0, 0.0, 0.0));
+/// ```
+/// # Failures
+/// If the wire does not consist of three or four edges, returns `Error::NotThreeOrFourEdges`.
+/// ```
+/// use truck_modeling::{*, errors::Error};
+///
+/// let v0 = builder::vertex(Point3::new(0.0, 0.0, 0.0));
+/// let v1 = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+/// let wire: Wire = vec![
+///     builder::line(&v0, &v1),
+///     builder::circle_arc(&v1, &v0, Point3::new(0.5, 0.5, 0.0)),
+/// ]
+/// .into();
+/// assert!(matches!(
+///     builder::try_fill_wire(&wire),
+///     Err(Error::NotThreeOrFourEdges),
+/// ));
+/// ```
+///
+/// The patch is constructed in the homogeneous space, so the end weights of NURBS edges, e.g.
+/// the ones by [`circle_arc`], must be one. Otherwise, returns `Error::NotUnitEndWeights`.
+/// ```
+/// use truck_modeling::{*, errors::Error};
+///
+/// let v0 = builder::vertex(Point3::new(0.0, 0.0, 0.0));
+/// let v1 = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+/// let v2 = builder::vertex(Point3::new(0.0, 1.0, 0.0));
+/// let conic = NurbsCurve::new(BSplineCurve::new(
+///     KnotVec::bezier_knot(2),
+///     vec![
+///         Vector4::new(0.0, 0.0, 0.0, 2.0),
+///         Vector4::new(0.5, -0.5, 0.0, 1.0),
+///         Vector4::new(1.0, 0.0, 0.0, 1.0),
+///     ],
+/// ));
+/// let wire: Wire = vec![
+///     Edge::new(&v0, &v1, Curve::NurbsCurve(conic)),
+///     builder::line(&v1, &v2),
+///     builder::line(&v2, &v0),
+/// ]
+/// .into();
+/// assert!(matches!(
+///     builder::try_fill_wire(&wire),
+///     Err(Error::NotUnitEndWeights),
+/// ));
+/// ```
+pub fn try_fill_wire(wire: &Wire) -> Result<Face> {
+    let mut curves = wire
+        .edge_iter()
+        .map(|edge| edge.oriented_curve().lift_up())
+        .collect::<Vec<_>>();
+    let unit_weights = curves
+        .iter()
+        .all(|curve| curve.front().weight().near(&1.0) && curve.back().weight().near(&1.0));
+    if !unit_weights {
+        return Err(Error::NotUnitEndWeights);
+    }
+    match curves.len() {
+        3 => {
+            let pt = curves[0].front();
+            curves.push(BSplineCurve::new(KnotVec::bezier_knot(1), vec![pt, pt]));
+        }
+        4 => {}
+        _ => return Err(Error::NotThreeOrFourEdges),
+    }
+    let curves: [BSplineCurve<Vector4>; 4] = curves.try_into().unwrap();
+    let surface = BSplineSurface::coons(curves);
+    Ok(Face::try_new(
+        vec![wire.clone()],
+        Surface::NurbsSurface(NurbsSurface::new(surface)),
+    )?)
+}
+
 /// Returns another topology whose points, curves, and surfaces are cloned.
 #[inline(always)]
 pub fn clone<T: Mapped<Point3, Curve, Surface>>(elem: &T) -> T { elem.topological_clone() }
@@ -639,6 +761,78 @@ pub fn rsweep<T: ClosedSweep<Point3, Curve, Surface>, R: Into<Rad<f64>>>(
     }
 }
 
+/// Sweeps a vertex, an edge, a wire, a face, or a shell by the vector, and returns the history.
+/// # Details
+/// Each element of `elem` generates the elements swept by it: a vertex generates the lateral edge
+/// and the copied vertex, an edge generates the lateral face and the copied edge, and a face
+/// generates the copied face. The elements of `elem` are kept in the result.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// let vertex: Vertex = builder::vertex(Point3::new(0.0, 0.0, 0.0));
+/// let line: Edge = builder::tsweep(&vertex, Vector3::unit_x());
+/// let square: Face = builder::tsweep(&line, Vector3::unit_y());
+/// let (cube, history): (Solid, _) = builder::tsweep_with_history(&square, Vector3::unit_z());
+///
+/// // the lateral face swept by the line
+/// let generated = history.generated(ShapeID::Edge(line.id()));
+/// assert_eq!(generated.len(), 2);
+/// let lateral = cube.face_iter().find(|face| generated.contains(&ShapeID::Face(face.id())));
+/// assert!(lateral.is_some());
+///
+/// // the top face copied from the square
+/// let top = history.generated(ShapeID::Face(square.id()));
+/// assert_eq!(top.len(), 1);
+/// let top = cube.face_iter().find(|face| ShapeID::Face(face.id()) == top[0]).unwrap();
+/// assert_near!(top.bounding_box().center(), Point3::new(0.5, 0.5, 1.0));
+/// ```
+pub fn tsweep_with_history<T>(elem: &T, vector: Vector3) -> (T::Swept, ShapeHistory)
+where
+    T: Sweep<Point3, Curve, Surface> + TopologicalElements<Point3, Curve, Surface>,
+    T::Swept: TopologicalElements<Point3, Curve, Surface>, {
+    let swept = tsweep(elem, vector);
+    let history = sweep_history::sweep_history(&elem.elements(), &swept.elements(), 1);
+    (swept, history)
+}
+
+/// Sweeps a vertex, an edge, a wire, a face, or a shell by the rotation, and returns the history.
+/// # Details
+/// The history is the same as [`tsweep_with_history`]. Since a rotation sweep is divided into
+/// several steps, an element generates the elements of all steps. If the result is closed, the
+/// faces of `elem` are deleted.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// const PI: Rad<f64> = Rad(std::f64::consts::PI);
+/// let v: Vertex = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+/// let (circle, history): (Wire, _) =
+///     builder::rsweep_with_history(&v, Point3::origin(), Vector3::unit_z(), PI * 2.0);
+///
+/// // all edges of the circle are generated by the vertex.
+/// let generated = history.generated(ShapeID::Vertex(v.id()));
+/// assert!(circle.edge_iter().all(|edge| generated.contains(&ShapeID::Edge(edge.id()))));
+/// ```
+pub fn rsweep_with_history<T, R>(
+    elem: &T,
+    origin: Point3,
+    axis: Vector3,
+    angle: R,
+) -> (T::Swept, ShapeHistory)
+where
+    T: ClosedSweep<Point3, Curve, Surface> + TopologicalElements<Point3, Curve, Surface>,
+    T::Swept: TopologicalElements<Point3, Curve, Surface>,
+    R: Into<Rad<f64>>,
+{
+    let angle = angle.into();
+    let steps = match angle.0.abs() < PI.0 {
+        true => 2,
+        false => 3,
+    };
+    let swept = rsweep(elem, origin, axis, angle);
+    let history = sweep_history::sweep_history(&elem.elements(), &swept.elements(), steps);
+    (swept, history)
+}
+
 fn partial_rsweep<T: MultiSweep<Point3, Curve, Surface>>(
     elem: &T,
     origin: Point3,
@@ -14,6 +14,14 @@ pub enum Error {
     /// cf. [`builder::try_wire_homotopy`](../builder/fn.try_wire_homotopy.html)
     #[error("The wires must contain the same number of edges to create a homotopy.")]
     NotSameNumberOfEdges,
+    /// tried to fill a wire which does not consist of three or four edges.
+    /// cf. [`builder::try_fill_wire`](../builder/fn.try_fill_wire.html)
+    #[error("The wire must consist of three or four edges to be filled.")]
+    NotThreeOrFourEdges,
+    /// tried to fill a wire with a rational edge whose end weights are not one.
+    /// cf. [`builder::try_fill_wire`](../builder/fn.try_fill_wire.html)
+    #[error("The end weights of the edges must be one to fill the wire.")]
+    NotUnitEndWeights,
 }
 
 #[test]
@@ -31,6 +39,8 @@ fn print_messages() {
     )
     .unwrap();
     writeln!(&mut std::io::stderr(), "{}\n", Error::WireNotInOnePlane).unwrap();
+    writeln!(&mut std::io::stderr(), "{}\n", Error::NotThreeOrFourEdges).unwrap();
+    writeln!(&mut std::io::stderr(), "{}\n", Error::NotUnitEndWeights).unwrap();
     writeln!(
         &mut std::io::stderr(),
         "*******************************************************"
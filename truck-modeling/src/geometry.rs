@@ -102,6 +102,74 @@ impl Transformed<Matrix4> for Curve {
     }
 }
 
+impl BoundingBoxed for Curve {
+    type Point = Point3;
+    #[inline(always)]
+    fn bounding_box(&self) -> BoundingBox<Point3> {
+        derive_curve_method!(self, BoundingBoxed::bounding_box,)
+    }
+}
+
+/// The lines are converted into B-spline curves, since the parameter range of a line is fixed.
+/// The intersection curves are not transformed.
+impl ParameterTransform for Curve {
+    fn parameter_transform(&mut self, scalar: f64, r#move: f64) -> &mut Self {
+        match self {
+            Curve::Line(line) => {
+                let mut curve = line.to_bspline();
+                curve.parameter_transform(scalar, r#move);
+                *self = Curve::BSplineCurve(curve);
+            }
+            Curve::BSplineCurve(curve) => {
+                curve.parameter_transform(scalar, r#move);
+            }
+            Curve::NurbsCurve(curve) => {
+                curve.parameter_transform(scalar, r#move);
+            }
+            Curve::IntersectionCurve(_) => {}
+        }
+        self
+    }
+}
+
+/// The collinear straight curves are concatenated into a line. The other curves are concatenated
+/// as B-spline curves or NURBS curves. The intersection curves cannot be concatenated.
+impl Concat<Curve> for Curve {
+    type Output = Curve;
+    fn try_concat(&self, rhs: &Curve) -> std::result::Result<Curve, ConcatError<Point3>> {
+        let (t0, t1) = (self.range_tuple().1, rhs.range_tuple().0);
+        if !t0.near(&t1) {
+            return Err(ConcatError::DisconnectedParameters(t0, t1));
+        }
+        let (p, q) = (self.back(), rhs.front());
+        if !p.near(&q) {
+            return Err(ConcatError::DisconnectedPoints(p, q));
+        }
+        if let (Some(line0), Some(line1)) = (self.straight(), rhs.straight()) {
+            let (v0, v1) = (line0.1 - line0.0, line1.1 - line1.0);
+            if v0.normalize().near(&v1.normalize()) {
+                return Ok(Curve::Line(Line(line0.0, line1.1)));
+            }
+        }
+        let bspline = |curve: &Curve| match curve {
+            Curve::Line(line) => Some(line.to_bspline()),
+            Curve::BSplineCurve(curve) => Some(curve.clone()),
+            _ => None,
+        };
+        if let (Some(curve0), Some(curve1)) = (bspline(self), bspline(rhs)) {
+            return curve0.try_concat(&curve1).map(Curve::BSplineCurve);
+        }
+        let nurbs = |curve: &Curve| match curve {
+            Curve::NurbsCurve(curve) => Some(curve.clone()),
+            _ => bspline(curve).map(NurbsCurve::from),
+        };
+        match (nurbs(self), nurbs(rhs)) {
+            (Some(curve0), Some(curve1)) => curve0.try_concat(&curve1).map(Curve::NurbsCurve),
+            _ => Err(ConcatError::UnsupportedCurves),
+        }
+    }
+}
+
 impl From<IntersectionCurve<PolylineCurve<Point3>, Surface>> for Curve {
     fn from(x: IntersectionCurve<PolylineCurve<Point3>, Surface>) -> Curve {
         Curve::IntersectionCurve(x.change_leader(Leader::Polyline))
@@ -109,6 +177,18 @@ impl From<IntersectionCurve<PolylineCurve<Point3>, Surface>> for Curve {
 }
 
 impl Curve {
+    /// Returns the line if the curve is a line or a straight B-spline curve.
+    fn straight(&self) -> Option<Line<Point3>> {
+        match self {
+            Curve::Line(line) => Some(*line),
+            Curve::BSplineCurve(curve)
+                if curve.degree() == 1 && curve.control_points().len() == 2 =>
+            {
+                Some(Line(curve.control_points()[0], curve.control_points()[1]))
+            }
+            _ => None,
+        }
+    }
     /// Into non-ratinalized 4-dimensinal B-spline curve
     pub fn lift_up(self) -> BSplineCurve<Vector4> {
         match self {
@@ -202,6 +282,14 @@ impl ParametricSurface3D for Surface {
     }
 }
 
+impl BoundingBoxed for Surface {
+    type Point = Point3;
+    #[inline(always)]
+    fn bounding_box(&self) -> BoundingBox<Point3> {
+        derive_surface_method!(self, BoundingBoxed::bounding_box,)
+    }
+}
+
 impl Transformed<Matrix4> for Surface {
     fn transform_by(&mut self, trans: Matrix4) {
         derive_surface_method!(self, Transformed::transform_by, trans);
@@ -43,6 +43,8 @@ pub mod topology {
     pub type Shell = truck_topology::Shell<Point3, Curve, Surface>;
     /// Solid, attached to a closed shells.
     pub type Solid = truck_topology::Solid<Point3, Curve, Surface>;
+    /// Compound, a collection of solids, shells, wires and vertices.
+    pub type Compound = truck_topology::Compound<Point3, Curve, Surface>;
 
     /// The id of vertex. `Copy` trait is implemented.
     pub type VertexID = truck_topology::VertexID<Point3>;
@@ -50,6 +52,26 @@ pub mod topology {
     pub type EdgeID = truck_topology::EdgeID<Curve>;
     /// The id that does not depend on the direction of the face.
     pub type FaceID = truck_topology::FaceID<Surface>;
+    /// Typed attributes attached to vertices, edges and faces.
+    pub type AttributeStore<A> =
+        truck_topology::attributes::AttributeStore<Point3, Curve, Surface, A>;
+    /// The id of a vertex, an edge or a face recorded in [`ShapeHistory`].
+    pub type ShapeID = truck_topology::history::ShapeID<VertexID, EdgeID, FaceID>;
+    /// Generated, modified and deleted elements by a modeling operation.
+    pub type ShapeHistory = truck_topology::history::TopologyHistory<Point3, Curve, Surface>;
+
+    /// A defect of a shell or a solid found by the validity check.
+    pub type Defect = truck_topology::validity::Defect<Point3, Curve, Surface>;
+    /// The defects of a shell or a solid found by the validity check.
+    pub type ValidityReport = truck_topology::validity::ValidityReport<Point3, Curve, Surface>;
+    /// The added, removed and modified faces between two versions of a shape.
+    pub type ShapeDiff = truck_topology::fingerprint::ShapeDiff<Surface>;
+    /// The new geometries of the elements, applied to shapes copy-on-write.
+    pub type Transaction = truck_topology::snapshot::Transaction<Point3, Curve, Surface>;
+
+    pub use truck_topology::assembly::{Assembly, AssemblyInstance};
+    pub use truck_topology::fingerprint::{FaceFingerprint, ShapeFingerprint};
+    pub use truck_topology::history::TopologicalElements;
 
     pub use truck_topology::shell::ShellCondition;
 }
@@ -161,4 +183,5 @@ mod geom_impls;
 mod mapped;
 mod multi_sweep;
 mod sweep;
+mod sweep_history;
 mod topo_impls;
@@ -0,0 +1,156 @@
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+use truck_topology::{history::*, *};
+
+type Key<P, C, S> = ShapeID<VertexID<P>, EdgeID<C>, FaceID<S>>;
+
+/// Creates the history of the sweep from `input` to `output` divided into `steps` steps.
+///
+/// The history is read from the topology of `output`: the lateral face of an edge is the
+/// quadrangle attached to it, and the opposite side of the quadrangle is the copy of the edge.
+pub(super) fn sweep_history<P, C, S>(
+    input: &Elements<P, C, S>,
+    output: &Elements<P, C, S>,
+    steps: usize,
+) -> TopologyHistory<P, C, S> {
+    let mut history = TopologyHistory::new();
+    let input_faces: HashSet<_> = input.faces.iter().map(Face::id).collect();
+    let mut edge_faces = HashMap::<EdgeID<C>, Vec<Face<P, C, S>>>::default();
+    output.faces.iter().for_each(|face| {
+        face.edge_iter()
+            .for_each(|edge| edge_faces.entry(edge.id()).or_default().push(face.clone()))
+    });
+    let mut origins: HashMap<_, _> = input.vertices.iter().map(|v| (v.id(), v.id())).collect();
+    let mut lateral_faces = HashSet::default();
+    let mut copies = HashMap::default();
+    input.edges.iter().for_each(|edge| {
+        let mut cursor = edge.clone();
+        for _ in 0..steps {
+            let is_new = |face: &&Face<P, C, S>| {
+                !input_faces.contains(&face.id()) && !lateral_faces.contains(&face.id())
+            };
+            let faces = edge_faces.get(&cursor.id());
+            let Some(face) = faces.and_then(|faces| faces.iter().find(is_new)) else {
+                break;
+            };
+            let wire = &face.absolute_boundaries()[0];
+            let Some(i) = wire.iter().position(|e| e.id() == cursor.id()) else {
+                break;
+            };
+            if wire.len() != 4 {
+                break;
+            }
+            [&wire[(i + 1) % 4], &wire[(i + 3) % 4]]
+                .into_iter()
+                .for_each(|lateral| {
+                    let (v0, v1) = lateral.absolute_ends();
+                    let (origin, new_vertex) = match (origins.get(&v0.id()), origins.get(&v1.id()))
+                    {
+                        (Some(o), None) => (*o, v1),
+                        (None, Some(o)) => (*o, v0),
+                        (Some(o), Some(_)) => {
+                            history.add_generated(Key::Vertex(*o), Key::Edge(lateral.id()));
+                            return;
+                        }
+                        (None, None) => return,
+                    };
+                    origins.insert(new_vertex.id(), origin);
+                    history.add_generated(Key::Vertex(origin), Key::Edge(lateral.id()));
+                    history.add_generated(Key::Vertex(origin), Key::Vertex(new_vertex.id()));
+                });
+            lateral_faces.insert(face.id());
+            history.add_generated(Key::Edge(edge.id()), Key::Face(face.id()));
+            cursor = wire[(i + 2) % 4].clone();
+            if cursor.id() != edge.id() {
+                history.add_generated(Key::Edge(edge.id()), Key::Edge(cursor.id()));
+            }
+        }
+        copies.insert(edge.id(), cursor);
+    });
+
+    let edge_vertices: HashSet<_> = input
+        .edges
+        .iter()
+        .flat_map(|edge| [edge.front().id(), edge.back().id()])
+        .collect();
+    let mut vertex_edges = HashMap::<VertexID<P>, Vec<&Edge<P, C>>>::default();
+    output.edges.iter().for_each(|edge| {
+        let front = edge.absolute_front().id();
+        vertex_edges.entry(front).or_default().push(edge);
+    });
+    input
+        .vertices
+        .iter()
+        .filter(|v| !edge_vertices.contains(&v.id()))
+        .for_each(|vertex| {
+            let mut cursor = vertex.id();
+            for _ in 0..steps {
+                let Some(edge) = vertex_edges.get(&cursor).and_then(|edges| edges.first()) else {
+                    break;
+                };
+                history.add_generated(Key::Vertex(vertex.id()), Key::Edge(edge.id()));
+                cursor = edge.absolute_back().id();
+                if cursor == vertex.id() {
+                    break;
+                }
+                history.add_generated(Key::Vertex(vertex.id()), Key::Vertex(cursor));
+            }
+        });
+
+    let mut tops = HashMap::<FaceID<S>, Face<P, C, S>>::default();
+    let mut stack: Vec<(Face<P, C, S>, Face<P, C, S>)> = Vec::new();
+    let find_top = |copy: &Edge<P, C>, tops: &HashMap<FaceID<S>, Face<P, C, S>>| {
+        let faces = edge_faces.get(&copy.id())?;
+        let is_top = |face: &&Face<P, C, S>| {
+            !input_faces.contains(&face.id())
+                && !lateral_faces.contains(&face.id())
+                && !tops.values().any(|top| top.id() == face.id())
+        };
+        faces.iter().find(is_top).cloned()
+    };
+    input.faces.iter().for_each(|face| {
+        if tops.contains_key(&face.id()) {
+            return;
+        }
+        let top = face.edge_iter().find_map(|edge| {
+            let copy = copies.get(&edge.id())?;
+            match copy.id() == edge.id() {
+                true => None,
+                false => find_top(copy, &tops),
+            }
+        });
+        if let Some(top) = top {
+            tops.insert(face.id(), top.clone());
+            stack.push((face.clone(), top));
+        }
+        while let Some((base, top)) = stack.pop() {
+            history.add_generated(Key::Face(base.id()), Key::Face(top.id()));
+            let edges0 = base.absolute_boundaries().iter().flatten();
+            let edges1 = top.absolute_boundaries().iter().flatten();
+            edges0.zip(edges1).for_each(|(edge0, edge1)| {
+                history.add_generated(Key::Edge(edge0.id()), Key::Edge(edge1.id()));
+                let (v0, v1) = (edge0.absolute_front(), edge1.absolute_front());
+                history.add_generated(Key::Vertex(v0.id()), Key::Vertex(v1.id()));
+                let neighbors = input.faces.iter().filter(|f| {
+                    f.id() != base.id()
+                        && !tops.contains_key(&f.id())
+                        && f.edge_iter().any(|e| e.id() == edge0.id())
+                });
+                let neighbors: Vec<_> = neighbors.cloned().collect();
+                neighbors.into_iter().for_each(|neighbor| {
+                    if let Some(top) = find_top(edge1, &tops) {
+                        tops.insert(neighbor.id(), top.clone());
+                        stack.push((neighbor, top));
+                    }
+                });
+            });
+        }
+    });
+
+    let output_ids = output.ids();
+    input
+        .ids()
+        .into_iter()
+        .filter(|id| !output_ids.contains(id))
+        .for_each(|id| history.add_deleted(id));
+    history
+}
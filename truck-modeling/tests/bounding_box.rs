@@ -0,0 +1,64 @@
+use truck_modeling::*;
+const PI: Rad<f64> = Rad(std::f64::consts::PI);
+
+#[test]
+fn torus_bounding_box() {
+    let v = builder::vertex(Point3::new(
+        2.0 + f64::cos(PI.0 / 4.0),
+        f64::sin(PI.0 / 4.0),
+        0.0,
+    ));
+    let circle = builder::rsweep(&v, Point3::new(2.0, 0.0, 0.0), Vector3::unit_z(), PI * 2.0);
+    let torus: Shell = builder::rsweep(&circle, Point3::origin(), Vector3::unit_y(), PI * 2.0);
+    let solid = Solid::new(vec![torus]);
+
+    let bdb = solid.bounding_box();
+    assert_near!(bdb.min(), Point3::new(-3.0, -1.0, -3.0));
+    assert_near!(bdb.max(), Point3::new(3.0, 1.0, 3.0));
+
+    let union = solid.face_iter().fold(BoundingBox::new(), |union, face| {
+        union + face.bounding_box()
+    });
+    assert_eq!(union, bdb);
+}
+
+#[test]
+fn trimmed_cylinder_bounding_box() {
+    let v = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+    let edge = builder::tsweep(&v, Vector3::unit_z());
+    // the rotation sweep is divided into two faces
+    let shell: Shell = builder::rsweep(&edge, Point3::origin(), Vector3::unit_z(), PI / 2.0);
+    assert_eq!(shell.len(), 2);
+    let face = &shell[0];
+    let bdb = face.bounding_box();
+    let c = f64::cos(PI.0 / 4.0);
+    assert_near!(bdb.min(), Point3::new(c, 0.0, 0.0));
+    assert_near!(bdb.max(), Point3::new(1.0, c, 1.0));
+
+    let union = shell.face_iter().fold(BoundingBox::new(), |union, face| {
+        union + face.bounding_box()
+    });
+    assert_near!(union.min(), Point3::new(0.0, 0.0, 0.0));
+    assert_near!(union.max(), Point3::new(1.0, 1.0, 1.0));
+
+    let curve: Curve = face.edge_iter().next().unwrap().curve();
+    let curve_bdb = curve.bounding_box();
+    assert!(bdb.min()[0] <= curve_bdb.min()[0] && curve_bdb.max()[0] <= bdb.max()[0]);
+}
+
+#[test]
+fn planar_face_bounding_box() {
+    let v = builder::vertex(Point3::new(2.0, 0.0, 1.0));
+    let edge = builder::tsweep(&v, Vector3::unit_x());
+    let face: Face = builder::tsweep(&edge, Vector3::new(0.0, 3.0, 0.0));
+    let bdb = face.bounding_box();
+    assert_near!(bdb.min(), Point3::new(2.0, 0.0, 1.0));
+    assert_near!(bdb.max(), Point3::new(3.0, 3.0, 1.0));
+
+    // the plane itself is unbounded.
+    let surface_bdb = face.surface().bounding_box();
+    assert_eq!(surface_bdb.min()[0], f64::NEG_INFINITY);
+    assert_eq!(surface_bdb.max()[1], f64::INFINITY);
+    assert_near!(surface_bdb.min()[2], 1.0);
+    assert_near!(surface_bdb.max()[2], 1.0);
+}
@@ -0,0 +1,49 @@
+use truck_modeling::*;
+
+#[test]
+fn cube_bvh() {
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::unit_y());
+    let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+    let mut shell = cube.into_boundaries().pop().unwrap();
+
+    let mut bvh = shell.bvh();
+    assert_eq!(bvh.faces().len(), 6);
+    assert_eq!(bvh.edges().len(), 12);
+    assert_near!(bvh.faces().bounding_box().max(), Point3::new(1.0, 1.0, 1.0));
+
+    // the ray passes through the bottom and the top.
+    let hits = bvh
+        .faces()
+        .ray_cast(Point3::new(0.5, 0.5, -1.0), Vector3::unit_z());
+    assert_eq!(hits.len(), 2);
+    assert_near!(hits[0].0, 1.0);
+    assert_near!(hits[1].0, 2.0);
+
+    // the faces around the vertex at the origin
+    let bdb: BoundingBox<Point3> = [Point3::new(-0.1, -0.1, -0.1), Point3::new(0.1, 0.1, 0.1)]
+        .into_iter()
+        .collect();
+    assert_eq!(bvh.faces().overlaps(bdb).len(), 3);
+    assert_eq!(bvh.edges().overlaps(bdb).len(), 3);
+
+    let point = Point3::new(0.5, 0.5, 3.0);
+    let (dist, face) = bvh
+        .faces()
+        .nearest(point, |face| face.bounding_box().center().distance(point))
+        .unwrap();
+    assert_near!(dist, 2.0);
+    assert_near!(face.bounding_box().center(), Point3::new(0.5, 0.5, 1.0));
+
+    // cut an edge, and rebuild the hierarchies.
+    let edge = shell.edge_iter().next().unwrap();
+    let vertex = builder::vertex(edge.curve().subs(0.5));
+    shell.cut_edge(edge.id(), &vertex).unwrap();
+    bvh.rebuild(shell.face_iter());
+    assert_eq!(bvh.faces().len(), 6);
+    assert_eq!(bvh.edges().len(), 13);
+    assert_eq!(bvh.edges().overlaps(vertex_box(&vertex)).len(), 2);
+}
+
+fn vertex_box(vertex: &Vertex) -> BoundingBox<Point3> { [vertex.point()].into_iter().collect() }
@@ -0,0 +1,51 @@
+use truck_modeling::*;
+const PI: Rad<f64> = Rad(std::f64::consts::PI);
+
+#[test]
+fn cube_continuity() {
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::unit_y());
+    let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+    let report = cube.boundaries()[0].continuity_report(10);
+    assert_eq!(report.len(), 12);
+    report.iter().for_each(|continuity| {
+        assert!(continuity.gap.max < TOLERANCE);
+        assert_near!(continuity.angle.max, PI.0 / 2.0);
+        assert_near!(continuity.angle.mean, PI.0 / 2.0);
+        assert!(continuity.curvature.max < TOLERANCE);
+    });
+}
+
+#[test]
+fn pipe_continuity() {
+    let v = builder::vertex(Point3::new(1.0, 0.0, 4.0));
+    let circle = builder::rsweep(&v, Point3::new(2.0, 0.0, 4.0), -Vector3::unit_z(), PI * 2.0);
+    let mut pipe: Shell = builder::tsweep(&circle, Vector3::new(0.0, 0.0, -4.0));
+    let line_faces = pipe.len();
+    let boundaries = pipe.extract_boundaries();
+    let another_circle = boundaries.into_iter().find(|wire| wire != &circle).unwrap();
+    let mut bend_part: Shell = builder::rsweep(
+        &another_circle.inverse(),
+        Point3::origin(),
+        Vector3::unit_y(),
+        PI / 2.0,
+    );
+    pipe.append(&mut bend_part);
+
+    let report = pipe.continuity_report(10);
+    assert!(!report.is_empty());
+    report.iter().for_each(|continuity| {
+        assert!(continuity.gap.max < 1.0e-4, "{continuity:?}");
+        assert!(continuity.angle.max < 1.0e-4, "{continuity:?}");
+        // the cylinder is curvature continuous, but the bend is not.
+        if continuity.faces.1 < line_faces {
+            assert!(continuity.curvature.max < 1.0e-4, "{continuity:?}");
+        }
+    });
+    assert!(report
+        .iter()
+        .any(|continuity| continuity.faces.0 < line_faces
+            && line_faces <= continuity.faces.1
+            && continuity.curvature.max > 0.1));
+}
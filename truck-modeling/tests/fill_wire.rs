@@ -0,0 +1,45 @@
+use std::f64::consts::PI;
+use truck_modeling::*;
+
+#[test]
+fn fill_wire_with_circle_arcs() {
+    // a quarter of the cylinder bounded by two circle arcs and two lines
+    let transit = |z: f64| Point3::new(f64::cos(PI / 4.0), f64::sin(PI / 4.0), z);
+    let v0 = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+    let v1 = builder::vertex(Point3::new(0.0, 1.0, 0.0));
+    let v2 = builder::vertex(Point3::new(0.0, 1.0, 1.0));
+    let v3 = builder::vertex(Point3::new(1.0, 0.0, 1.0));
+    let wire: Wire = vec![
+        builder::circle_arc(&v0, &v1, transit(0.0)),
+        builder::line(&v1, &v2),
+        builder::circle_arc(&v2, &v3, transit(1.0)),
+        builder::line(&v3, &v0),
+    ]
+    .into();
+    let face = builder::try_fill_wire(&wire).unwrap();
+    assert!(face.is_geometric_consistent());
+
+    let surface = face.oriented_surface();
+    const N: usize = 10;
+    (0..=N)
+        .flat_map(|i| (0..=N).map(move |j| (i, j)))
+        .for_each(|(i, j)| {
+            let (u, v) = (i as f64 / N as f64, j as f64 / N as f64);
+            let pt = surface.subs(u, v);
+            assert_near!(Vector2::new(pt.x, pt.y).magnitude(), 1.0);
+            assert!(-TOLERANCE < pt.z && pt.z < 1.0 + TOLERANCE);
+        });
+
+    // a triangle with a circle arc
+    let v0 = builder::vertex(Point3::new(0.0, 0.0, 0.0));
+    let v1 = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+    let v2 = builder::vertex(Point3::new(0.0, 1.0, 0.0));
+    let wire: Wire = vec![
+        builder::line(&v0, &v1),
+        builder::circle_arc(&v1, &v2, Point3::new(0.5, 0.5, 0.5_f64.sqrt())),
+        builder::line(&v2, &v0),
+    ]
+    .into();
+    let face = builder::try_fill_wire(&wire).unwrap();
+    assert!(face.is_geometric_consistent());
+}
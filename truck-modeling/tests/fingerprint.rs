@@ -0,0 +1,134 @@
+use truck_modeling::*;
+
+fn cube(height: f64) -> Solid {
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::unit_y());
+    builder::tsweep(&f, Vector3::new(0.0, 0.0, height))
+}
+
+#[test]
+fn invariant_to_face_order() {
+    let cube0 = cube(1.0);
+    let mut shell = cube0.boundaries()[0].clone();
+    shell.reverse();
+    let cube1 = Solid::new(vec![shell]);
+    assert!(cube0.fingerprint().matches(&cube1.fingerprint(), TOLERANCE));
+    assert!(cube0.diff(&cube1, TOLERANCE).is_empty());
+    assert!(!cube0
+        .fingerprint()
+        .matches(&cube(2.0).fingerprint(), TOLERANCE));
+}
+
+#[test]
+fn invariant_to_parametrization() {
+    let v: Vec<Vertex> = [(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)]
+        .into_iter()
+        .map(|(x, y)| builder::vertex(Point3::new(x, y, 0.0)))
+        .collect();
+    let wire0: Wire = (0..4)
+        .map(|i| builder::line(&v[i], &v[(i + 1) % 4]))
+        .collect();
+    let face0 = builder::try_attach_plane(&[wire0]).unwrap();
+
+    // the same square whose edges are non-uniformly parametrized
+    let wire1: Wire = (0..4)
+        .map(|i| {
+            let (p, q) = (v[i].point(), v[(i + 1) % 4].point());
+            builder::bezier(&v[i], &v[(i + 1) % 4], vec![p + (q - p) * 0.1])
+        })
+        .collect();
+    let face1 = builder::try_attach_plane(&[wire1]).unwrap();
+    assert!(face0.fingerprint().matches(&face1.fingerprint(), TOLERANCE));
+    assert!(!face0
+        .fingerprint()
+        .matches(&face1.inverse().fingerprint(), TOLERANCE));
+}
+
+#[test]
+fn diff_of_extended_cube() {
+    let cube0 = cube(1.0);
+    let cube1 = cube(2.0);
+    let diff = cube0.diff(&cube1, TOLERANCE);
+    assert_eq!(diff.modified.len(), 4);
+    assert_eq!(diff.removed.len(), 1);
+    assert_eq!(diff.added.len(), 1);
+    let removed = cube0
+        .face_iter()
+        .find(|face| face.id() == diff.removed[0])
+        .unwrap();
+    let added = cube1
+        .face_iter()
+        .find(|face| face.id() == diff.added[0])
+        .unwrap();
+    assert_near!(
+        removed.boundaries()[0].front_vertex().unwrap().point().z,
+        1.0
+    );
+    assert_near!(added.boundaries()[0].front_vertex().unwrap().point().z, 2.0);
+}
+
+/// the NURBS curve of the circle through `center + radius * e0`, with the normal `e0 × e1`
+fn circle(center: Point3, radius: f64, e0: Vector3, e1: Vector3, range: usize) -> Curve {
+    let w = f64::sqrt(0.5);
+    let corners = [(1.0, 0.0), (1.0, 1.0), (0.0, 1.0), (-1.0, 1.0), (-1.0, 0.0)];
+    let corners = corners
+        .iter()
+        .chain(&[(-1.0, -1.0), (0.0, -1.0), (1.0, -1.0), (1.0, 0.0)]);
+    let control_points = corners
+        .take(2 * range + 1)
+        .enumerate()
+        .map(|(i, (x, y))| {
+            let p = center + radius * (*x * e0 + *y * e1);
+            let w = if i % 2 == 0 { 1.0 } else { w };
+            Vector4::new(p.x * w, p.y * w, p.z * w, w)
+        })
+        .collect::<Vec<_>>();
+    let mut knots = vec![0.0; 3];
+    (1..range).for_each(|i| knots.extend([i as f64; 2]));
+    knots.extend([range as f64; 3]);
+    Curve::NurbsCurve(NurbsCurve::new(BSplineCurve::new(
+        KnotVec::from(knots),
+        control_points,
+    )))
+}
+
+#[test]
+fn closed_edges() {
+    // two disks with the same vertex, bounded by the circles with different radii
+    let disk = |radius: f64| {
+        let center = Point3::new(1.0 - radius, 0.0, 0.0);
+        let v = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+        let curve = circle(center, radius, Vector3::unit_x(), Vector3::unit_y(), 4);
+        let wire = Wire::from(vec![Edge::new_unchecked(&v, &v, curve)]);
+        let plane = Plane::new(
+            center,
+            center + Vector3::unit_x(),
+            center + Vector3::unit_y(),
+        );
+        Face::new(vec![wire], Surface::Plane(plane))
+    };
+    let fingerprint = disk(1.0).fingerprint();
+    assert_near!(fingerprint.edge_points[0], Point3::new(-1.0, 0.0, 0.0));
+    assert!(fingerprint.matches(&disk(1.0).fingerprint(), TOLERANCE));
+    assert!(!fingerprint.matches(&disk(2.0).fingerprint(), TOLERANCE));
+}
+
+#[test]
+fn faces_without_boundaries() {
+    let sphere = |center: Point3, radius: f64| {
+        let curve = circle(center, radius, -Vector3::unit_z(), Vector3::unit_x(), 2);
+        let surface = RevolutedCurve::by_revolution(curve, center, Vector3::unit_z());
+        Face::new(vec![], Surface::RevolutedCurve(Processor::new(surface)))
+    };
+    let sphere0 = sphere(Point3::origin(), 1.0);
+    let fingerprint = sphere0.fingerprint();
+    assert_eq!(fingerprint.surface_points.len(), 4);
+    assert!(fingerprint.matches(&sphere(Point3::origin(), 1.0).fingerprint(), TOLERANCE));
+    assert!(!fingerprint.matches(&sphere(Point3::origin(), 2.0).fingerprint(), TOLERANCE));
+    assert!(!fingerprint.matches(
+        &sphere(Point3::new(0.5, 0.0, 0.0), 1.0).fingerprint(),
+        TOLERANCE
+    ));
+    assert!(!fingerprint.matches(&sphere0.inverse().fingerprint(), TOLERANCE));
+}
@@ -0,0 +1,66 @@
+use truck_modeling::*;
+const PI: Rad<f64> = Rad(std::f64::consts::PI);
+
+#[test]
+fn cube_history() {
+    let v = builder::vertex(Point3::origin());
+    let (edge, history0): (Edge, _) = builder::tsweep_with_history(&v, Vector3::unit_x());
+    let (face, history1): (Face, _) = builder::tsweep_with_history(&edge, Vector3::unit_y());
+    let (cube, history2): (Solid, _) = builder::tsweep_with_history(&face, Vector3::unit_z());
+    let shell = &cube.boundaries()[0];
+
+    // each boundary edge of the square generates a side face and a copied edge.
+    face.edge_iter().for_each(|edge| {
+        let generated = history2.generated(ShapeID::Edge(edge.id()));
+        assert_eq!(generated.len(), 2);
+        let faces = generated
+            .iter()
+            .filter(|id| matches!(id, ShapeID::Face(_)))
+            .count();
+        assert_eq!(faces, 1);
+    });
+    // each vertex of the square generates a vertical edge and a copied vertex.
+    face.vertex_iter().for_each(|vertex| {
+        let generated = history2.generated(ShapeID::Vertex(vertex.id()));
+        assert_eq!(generated.len(), 2);
+    });
+    let top = history2.generated(ShapeID::Face(face.id()));
+    assert_eq!(top.len(), 1);
+    let top = shell
+        .iter()
+        .find(|f| ShapeID::Face(f.id()) == top[0])
+        .unwrap();
+    assert_near!(top.bounding_box().center(), Point3::new(0.5, 0.5, 1.0));
+    assert!(!history2.is_deleted(ShapeID::Face(face.id())));
+
+    // the whole cube is generated by the first vertex through the three sweeps.
+    let history = history0.then(&history1).then(&history2);
+    let generated = history.generated(ShapeID::Vertex(v.id()));
+    let faces: Vec<_> = shell
+        .iter()
+        .filter(|f| generated.contains(&ShapeID::Face(f.id())))
+        .collect();
+    assert_eq!(faces.len(), 6);
+}
+
+#[test]
+fn torus_history() {
+    let v = builder::vertex(Point3::new(2.5, 0.0, 0.0));
+    let circle = builder::rsweep(&v, Point3::new(2.0, 0.0, 0.0), Vector3::unit_y(), PI * 2.0);
+    let disk = builder::try_attach_plane(&[circle]).unwrap();
+    let (torus, history): (Solid, _) =
+        builder::rsweep_with_history(&disk, Point3::origin(), Vector3::unit_z(), PI * 2.0);
+
+    // the disk does not remain in the closed solid.
+    assert!(history.is_deleted(ShapeID::Face(disk.id())));
+    assert!(history.generated(ShapeID::Face(disk.id())).is_empty());
+    // all faces of the torus are generated by the edges of the disk.
+    let generated: Vec<_> = disk
+        .edge_iter()
+        .flat_map(|edge| history.generated(ShapeID::Edge(edge.id())).to_vec())
+        .collect();
+    assert!(torus
+        .face_iter()
+        .all(|face| generated.contains(&ShapeID::Face(face.id()))));
+    assert_eq!(torus.face_iter().count(), 9);
+}
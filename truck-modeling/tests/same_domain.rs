@@ -0,0 +1,60 @@
+use rustc_hash::FxHashSet as HashSet;
+use truck_modeling::*;
+
+#[test]
+fn unify_split_rectangle() {
+    let v: Vec<Vertex> = [(0.0, 0.0, 0.0), (1.0, 0.0, 0.0), (2.0, 0.0, 0.0)]
+        .into_iter()
+        .map(|(x, y, z)| builder::vertex(Point3::new(x, y, z)))
+        .collect();
+    let wire: Wire = vec![builder::line(&v[0], &v[1]), builder::line(&v[1], &v[2])].into();
+    let mut shell: Shell = builder::tsweep(&wire, Vector3::unit_y());
+    assert_eq!(shell.len(), 2);
+
+    shell.unify_same_domain(TOLERANCE);
+    assert_eq!(shell.len(), 1);
+    assert_eq!(shell.shell_condition(), ShellCondition::Oriented);
+    let boundary = &shell[0].boundaries()[0];
+    assert_eq!(boundary.len(), 4);
+    assert!(boundary
+        .edge_iter()
+        .all(|edge| matches!(edge.curve(), Curve::Line(_))));
+    assert!(boundary
+        .vertex_iter()
+        .all(|vertex| !vertex.point().near(&Point3::new(1.0, 0.0, 0.0))));
+}
+
+#[test]
+fn unify_split_box() {
+    let v: Vec<Vertex> = [(0.0, 0.0), (1.0, 0.0), (2.0, 0.0), (2.0, 1.0), (0.0, 1.0)]
+        .into_iter()
+        .map(|(x, y)| builder::vertex(Point3::new(x, y, 0.0)))
+        .collect();
+    let wire: Wire = (0..5)
+        .map(|i| builder::line(&v[i], &v[(i + 1) % 5]))
+        .collect();
+    let face = builder::try_attach_plane(&[wire]).unwrap();
+    let mut solid: Solid = builder::tsweep(&face, Vector3::unit_z());
+    assert_eq!(solid.boundaries()[0].len(), 7);
+
+    solid.unify_same_domain(TOLERANCE);
+    let shell = &solid.boundaries()[0];
+    assert_eq!(shell.len(), 6);
+    let edges: HashSet<_> = shell.edge_iter().map(|edge| edge.id()).collect();
+    assert_eq!(edges.len(), 12);
+    let vertices: HashSet<_> = shell.vertex_iter().map(|vertex| vertex.id()).collect();
+    assert_eq!(vertices.len(), 8);
+    assert!(Solid::try_new(solid.into_boundaries()).is_ok());
+}
+
+#[test]
+fn keep_different_domains() {
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::unit_y());
+    let mut cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+    cube.unify_same_domain(TOLERANCE);
+    assert_eq!(cube.boundaries()[0].len(), 6);
+    let edges: HashSet<_> = cube.edge_iter().map(|edge| edge.id()).collect();
+    assert_eq!(edges.len(), 12);
+}
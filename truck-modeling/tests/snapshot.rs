@@ -0,0 +1,66 @@
+use truck_modeling::*;
+
+#[test]
+fn undo_and_redo_vertex_move() {
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::unit_y());
+    let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+    let mut versions = vec![cube.snapshot()];
+
+    // lift the top face
+    let top = cube
+        .face_iter()
+        .find(|face| face.vertex_iter().all(|v| v.point().z.near(&1.0)))
+        .unwrap();
+    let mut transaction = Transaction::new();
+    let lift = |v: &Vertex| match v.point().z.near(&1.0) {
+        true => v.point() + Vector3::unit_z(),
+        false => v.point(),
+    };
+    top.vertex_iter().for_each(|v| {
+        transaction.set_point(&v, lift(&v));
+    });
+    // the curves on the lifted vertices are not changed by the points.
+    cube.edge_iter()
+        .map(|edge| edge.absolute_clone())
+        .filter(|edge| edge.front().point().z.near(&1.0) || edge.back().point().z.near(&1.0))
+        .for_each(|edge| {
+            let curve = Curve::Line(Line(lift(edge.front()), lift(edge.back())));
+            transaction.set_curve(&edge, curve);
+        });
+    let surface = top
+        .surface()
+        .transformed(Matrix4::from_translation(Vector3::unit_z()));
+    transaction.set_surface(&top, surface);
+    let (lifted, history) = versions[0].apply(&transaction);
+    versions.push(lifted);
+
+    // the bottom face is shared, the others are replaced.
+    let modified_faces = history
+        .modified_iter()
+        .filter(|(id, _)| matches!(id, ShapeID::Face(_)))
+        .count();
+    assert_eq!(modified_faces, 5);
+    assert_eq!(history.modified_iter().count(), 5 + 8 + 4);
+
+    let height = |solid: &Solid| {
+        solid
+            .vertex_iter()
+            .map(|v| v.point().z)
+            .fold(f64::NEG_INFINITY, f64::max)
+    };
+    assert_near!(height(&versions[0]), 1.0);
+    assert_near!(height(&versions[1]), 2.0);
+    assert!(Solid::try_new(versions[1].boundaries().clone()).is_ok());
+    assert!(versions[0].is_geometric_consistent());
+    assert!(versions[1].is_geometric_consistent());
+
+    // the points without the curves make the shape inconsistent.
+    let mut transaction = Transaction::new();
+    top.vertex_iter().for_each(|v| {
+        transaction.set_point(&v, lift(&v));
+    });
+    let (lifted, _) = versions[0].apply(&transaction);
+    assert!(!lifted.is_geometric_consistent());
+}
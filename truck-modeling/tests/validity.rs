@@ -0,0 +1,148 @@
+use truck_modeling::*;
+
+const TOL: f64 = 1.0e-6;
+
+fn cube() -> Solid {
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::unit_y());
+    builder::tsweep(&f, Vector3::unit_z())
+}
+
+fn count(report: &ValidityReport, pred: impl Fn(&Defect) -> bool) -> usize {
+    report.defects.iter().filter(|defect| pred(defect)).count()
+}
+
+fn square(points: [(f64, f64); 4]) -> Wire {
+    let v: Vec<_> = points
+        .iter()
+        .map(|(x, y)| builder::vertex(Point3::new(*x, *y, 0.0)))
+        .collect();
+    (0..4)
+        .map(|i| builder::line(&v[i], &v[(i + 1) % 4]))
+        .collect()
+}
+
+fn xy_plane() -> Surface {
+    Plane::new(
+        Point3::origin(),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+    )
+    .into()
+}
+
+#[test]
+fn valid_solids() {
+    assert!(cube().check(TOL).is_valid());
+
+    let v = builder::vertex(Point3::new(0.0, 0.5, 0.0));
+    let w = builder::rsweep(
+        &v,
+        Point3::origin(),
+        Vector3::unit_z(),
+        Rad(2.0 * std::f64::consts::PI),
+    );
+    let f = builder::try_attach_plane(&[w]).unwrap();
+    let cylinder: Solid = builder::tsweep(&f, Vector3::new(0.0, 0.0, 2.0));
+    assert!(cylinder.check(TOL).is_valid());
+}
+
+#[test]
+fn vertex_off_curve() {
+    let cube = cube();
+    let vertex = cube.boundaries()[0].vertex_iter().next().unwrap();
+    vertex.set_point(vertex.point() + Vector3::new(0.0, 0.0, -0.1));
+    let report = cube.check(TOL);
+    let off = |defect: &Defect| match defect {
+        Defect::VertexOffCurve {
+            vertex: id,
+            distance,
+            ..
+        } => {
+            assert_eq!(*id, vertex.id());
+            assert_near!(*distance, 0.1);
+            true
+        }
+        _ => false,
+    };
+    assert_eq!(count(&report, off), 3);
+}
+
+#[test]
+fn inconsistent_and_non_manifold_shells() {
+    let mut shell = cube().into_boundaries().pop().unwrap();
+    shell[0].invert();
+    let report = shell.check(TOL);
+    let inconsistent = |defect: &Defect| matches!(defect, Defect::InconsistentOrientation { .. });
+    assert_eq!(count(&report, inconsistent), 4);
+
+    let mut shell = cube().into_boundaries().pop().unwrap();
+    // another face sharing the boundary with the first face
+    let mut face = Face::new(shell[0].absolute_boundaries().clone(), shell[0].surface());
+    if !shell[0].orientation() {
+        face.invert();
+    }
+    shell.push(face);
+    let report = shell.check(TOL);
+    let non_manifold = |defect: &Defect| match defect {
+        Defect::NonManifoldEdge { faces, .. } => faces.len() == 3,
+        _ => false,
+    };
+    assert_eq!(count(&report, non_manifold), 4);
+    let overlapping = |defect: &Defect| matches!(defect, Defect::OverlappingFaces { .. });
+    assert_eq!(count(&report, overlapping), 1);
+}
+
+#[test]
+fn open_edges() {
+    let mut shell = cube().into_boundaries().pop().unwrap();
+    let face = shell.pop().unwrap();
+    let solid = Solid::new_unchecked(vec![shell]);
+    let report = solid.check(TOL);
+    let open = |defect: &Defect| match defect {
+        Defect::OpenEdge { edge, .. } => face.edge_iter().any(|e| e.id() == *edge),
+        _ => false,
+    };
+    assert_eq!(count(&report, open), 4);
+    assert_eq!(report.defects.len(), 4);
+}
+
+#[test]
+fn wrong_loops() {
+    let outer = square([(0.0, 0.0), (4.0, 0.0), (4.0, 4.0), (0.0, 4.0)]);
+    // counter-clockwise inner boundary
+    let inner = square([(1.0, 1.0), (3.0, 1.0), (3.0, 3.0), (1.0, 3.0)]);
+    let face = Face::new(vec![outer.clone(), inner], xy_plane());
+    let shell: Shell = vec![face.clone()].into();
+    let report = shell.check(TOL);
+    assert_eq!(report.defects.len(), 1);
+    assert!(matches!(
+        report.defects[0],
+        Defect::WrongLoopOrientation { face: id, wire: 1 } if id == face.id(),
+    ));
+
+    // clockwise inner boundary outside the outer boundary
+    let inner = square([(5.0, 5.0), (5.0, 6.0), (6.0, 6.0), (6.0, 5.0)]);
+    let face = Face::new(vec![outer, inner], xy_plane());
+    let shell: Shell = vec![face.clone()].into();
+    let report = shell.check(TOL);
+    assert_eq!(report.defects.len(), 1);
+    assert!(matches!(
+        report.defects[0],
+        Defect::InnerLoopOutside { face: id, wire: 1 } if id == face.id(),
+    ));
+}
+
+#[test]
+fn self_intersecting_wire() {
+    let bowtie = square([(0.0, 0.0), (1.0, 1.0), (1.0, 0.0), (0.0, 1.0)]);
+    let face = Face::new(vec![bowtie.clone()], xy_plane());
+    let shell: Shell = vec![face].into();
+    let report = shell.check(TOL);
+    let intersecting = |defect: &Defect| match defect {
+        Defect::SelfIntersectingWire { edges, .. } => *edges == (bowtie[0].id(), bowtie[2].id()),
+        _ => false,
+    };
+    assert_eq!(count(&report, intersecting), 1);
+}
@@ -0,0 +1,108 @@
+use super::*;
+use truck_topology::history::{CompressedHistory, ShapeID};
+
+/// The edges and the faces of a compressed shell before healing.
+pub(super) struct Snapshot<C> {
+    vertices_len: usize,
+    edges: Vec<Edge<C>>,
+    boundaries: Vec<Vec<Wire>>,
+}
+
+impl<C: Clone> Snapshot<C> {
+    pub(super) fn new<S>(shell: &Shell<Point3, C, S>) -> Self {
+        Self {
+            vertices_len: shell.vertices.len(),
+            edges: shell.edges.clone(),
+            boundaries: shell
+                .faces
+                .iter()
+                .map(|face| face.boundaries.clone())
+                .collect(),
+        }
+    }
+}
+
+/// Creates the history from `snapshot` to `shell`.
+///
+/// The vertices, edges and faces keep their indices, and the new ones are appended by healing.
+/// `face_origins` is the indices of the faces from which the appended faces are divided.
+pub(super) fn healing_history<C, S>(
+    snapshot: &Snapshot<C>,
+    shell: &Shell<Point3, C, S>,
+    face_origins: &[usize],
+    tol: f64,
+) -> CompressedHistory
+where
+    C: ParametricCurve3D + BoundedCurve + SearchNearestParameter<D1, Point = Point3>,
+{
+    let mut history = CompressedHistory::new();
+    let faces_len = snapshot.boundaries.len();
+    let face_origin = |i: usize| match i < faces_len {
+        true => i,
+        false => face_origins[i - faces_len],
+    };
+    snapshot
+        .boundaries
+        .iter()
+        .zip(&shell.faces)
+        .enumerate()
+        .filter(|(_, (boundaries, face))| *boundaries != &face.boundaries)
+        .for_each(|(i, _)| history.add_modified(ShapeID::Face(i), ShapeID::Face(i)));
+    (faces_len..shell.faces.len()).for_each(|i| {
+        let origin = face_origin(i);
+        history.add_modified(ShapeID::Face(origin), ShapeID::Face(origin));
+        history.add_modified(ShapeID::Face(origin), ShapeID::Face(i));
+    });
+
+    let edges_len = snapshot.edges.len();
+    let divided_edges: Vec<_> = (0..edges_len)
+        .filter(|i| snapshot.edges[*i].vertices != shell.edges[*i].vertices)
+        .collect();
+    divided_edges
+        .iter()
+        .for_each(|i| history.add_modified(ShapeID::Edge(*i), ShapeID::Edge(*i)));
+    let on_edge = |i: usize, point: Point3| {
+        let curve = &snapshot.edges[i].curve;
+        let t = curve.search_nearest_parameter(point, None, 100);
+        t.map_or(false, |t| curve.subs(t).distance(point) < tol)
+    };
+    let faces_around = |pred: &dyn Fn(&EdgeIndex) -> bool| {
+        let mut faces: Vec<_> = (0..shell.faces.len())
+            .filter(|i| shell.faces[*i].boundaries.iter().flatten().any(pred))
+            .map(face_origin)
+            .collect();
+        faces.sort();
+        faces.dedup();
+        faces
+    };
+    (edges_len..shell.edges.len()).for_each(|k| {
+        let curve = &shell.edges[k].curve;
+        let (t0, t1) = curve.range_tuple();
+        let middle = curve.subs((t0 + t1) / 2.0);
+        let original = divided_edges.iter().find(|i| on_edge(**i, middle));
+        match original {
+            Some(i) => history.add_modified(ShapeID::Edge(*i), ShapeID::Edge(k)),
+            None => faces_around(&|edge| edge.index == k)
+                .into_iter()
+                .for_each(|i| history.add_generated(ShapeID::Face(i), ShapeID::Edge(k))),
+        }
+    });
+
+    (snapshot.vertices_len..shell.vertices.len()).for_each(|v| {
+        let point = shell.vertices[v];
+        let original = divided_edges.iter().find(|i| on_edge(**i, point));
+        match original {
+            Some(i) => history.add_generated(ShapeID::Edge(*i), ShapeID::Vertex(v)),
+            None => {
+                let has_vertex = |edge: &EdgeIndex| {
+                    let (v0, v1) = shell.edges[edge.index].vertices;
+                    v0 == v || v1 == v
+                };
+                faces_around(&has_vertex)
+                    .into_iter()
+                    .for_each(|i| history.add_generated(ShapeID::Face(i), ShapeID::Vertex(v)))
+            }
+        }
+    });
+    history
+}
@@ -1,7 +1,7 @@
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 use truck_geometry::prelude::*;
 use truck_meshalgo::rexport_polymesh::*;
-use truck_topology::compress::*;
+use truck_topology::{compress::*, history::CompressedHistory};
 
 type Edge<C> = CompressedEdge<C>;
 type EdgeIndex = CompressedEdgeIndex;
@@ -18,6 +18,32 @@ use split_closed_edges::split_closed_edges;
 mod split_closed_faces;
 use split_closed_faces::split_closed_faces;
 
+mod history;
+use history::{healing_history, Snapshot};
+
+mod sew;
+pub use sew::{sew, sew_with_report, SewingReport};
+
+mod repair;
+pub use repair::{
+    close_gaps, collapse_short_edges, remove_sliver_faces, reorient_faces, GapReport,
+    OrientationReport, ShortEdgeReport, SliverReport,
+};
+
+fn sp<S>(surface: &S, point: Point3, hint: Option<(f64, f64)>) -> Option<(f64, f64)>
+where S: SearchParameter<D2, Point = Point3> {
+    surface.search_parameter(point, hint, 100)
+}
+
+fn robust_sp<S>(surface: &S, point: Point3, hint: Option<(f64, f64)>) -> Option<(f64, f64)>
+where S: SearchParameter<D2, Point = Point3> + SearchNearestParameter<D2, Point = Point3> {
+    surface
+        .search_parameter(point, hint, 100)
+        .or_else(|| surface.search_parameter(point, None, 100))
+        .or_else(|| surface.search_nearest_parameter(point, hint, 100))
+        .or_else(|| surface.search_nearest_parameter(point, None, 100))
+}
+
 /// Splits closed edges and faces
 ///
 /// # Details
@@ -29,11 +55,24 @@ use split_closed_faces::split_closed_faces;
 /// of `CompressedShell` and `CompressedSolid`, which are intermediate forms.
 ///
 /// # Remarks
-/// Boundary simplification is still only implemented for cylinders.
-/// It has not yet been implemented for cases involving singularities, such as spherical surfaces.
+/// Boundary simplification is implemented for the faces on periodic surfaces such as cylinders,
+/// spheres, cones and tori. The boundaries through the poles of spheres and the apexes of cones
+/// are connected along the singular lines in the parameter space, and a face with the double
+/// seams of a torus is split by a closed divisor and then split again as a cylinder.
+/// It has not yet been implemented for a face whose boundary goes around a pole, such as
+/// a hemisphere bounded by its equator, or whose closed divisor crosses the boundary.
 pub trait SplitClosedEdgesAndFaces {
+    /// The history of splitting: [`CompressedHistory`] for a shell, and the vector of the
+    /// histories of the boundary shells for a solid.
+    type History;
     /// Splits closed edges and faces
     fn split_closed_edges_and_faces(&mut self, tol: f64);
+    /// Splits closed edges and faces, and returns the history.
+    ///
+    /// The elements are specified by their indices. The divided edges and faces keep their
+    /// indices and are recorded as modified, together with the appended pieces. The new seam
+    /// edges and vertices are generated from the faces or the edges on which they lie.
+    fn split_closed_edges_and_faces_with_history(&mut self, tol: f64) -> Self::History;
 }
 
 impl<C, S> SplitClosedEdgesAndFaces for CompressedShell<Point3, C, S>
@@ -46,14 +85,17 @@ where
         + TryFrom<PCurve<Line<Point2>, S>>,
     S: ParametricSurface3D + SearchParameter<D2, Point = Point3>,
 {
+    type History = CompressedHistory;
     fn split_closed_edges_and_faces(&mut self, tol: f64) {
-        fn sp<S>(surface: &S, point: Point3, hint: Option<(f64, f64)>) -> Option<(f64, f64)>
-        where S: SearchParameter<D2, Point = Point3> {
-            surface.search_parameter(point, hint, 100)
-        }
         split_closed_edges(self);
         split_closed_faces(self, tol, sp);
     }
+    fn split_closed_edges_and_faces_with_history(&mut self, tol: f64) -> CompressedHistory {
+        let snapshot = Snapshot::new(self);
+        split_closed_edges(self);
+        let face_origins = split_closed_faces(self, tol, sp);
+        healing_history(&snapshot, self, &face_origins, tol)
+    }
 }
 
 impl<C, S> SplitClosedEdgesAndFaces for CompressedSolid<Point3, C, S>
@@ -66,11 +108,18 @@ where
         + TryFrom<PCurve<Line<Point2>, S>>,
     S: ParametricSurface3D + SearchParameter<D2, Point = Point3>,
 {
+    type History = Vec<CompressedHistory>;
     fn split_closed_edges_and_faces(&mut self, tol: f64) {
         self.boundaries
             .iter_mut()
             .for_each(|shell| shell.split_closed_edges_and_faces(tol))
     }
+    fn split_closed_edges_and_faces_with_history(&mut self, tol: f64) -> Vec<CompressedHistory> {
+        self.boundaries
+            .iter_mut()
+            .map(|shell| shell.split_closed_edges_and_faces_with_history(tol))
+            .collect()
+    }
 }
 
 /// robust version of splitting closed edges and faces.
@@ -78,8 +127,16 @@ where
 /// # Details
 /// Robust version of [`SplitClosedEdgesAndFaces`] based on [`SearchNearestParameter`].
 pub trait RobustSplitClosedEdgesAndFaces {
+    /// The history of splitting: [`CompressedHistory`] for a shell, and the vector of the
+    /// histories of the boundary shells for a solid.
+    type History;
     /// Splits closed edges and faces
     fn robust_split_closed_edges_and_faces(&mut self, tol: f64);
+    /// Splits closed edges and faces, and returns the history.
+    ///
+    /// The history is recorded in the same way as
+    /// [`SplitClosedEdgesAndFaces::split_closed_edges_and_faces_with_history`].
+    fn robust_split_closed_edges_and_faces_with_history(&mut self, tol: f64) -> Self::History;
 }
 
 impl<C, S> RobustSplitClosedEdgesAndFaces for CompressedShell<Point3, C, S>
@@ -94,18 +151,16 @@ where
         + SearchParameter<D2, Point = Point3>
         + SearchNearestParameter<D2, Point = Point3>,
 {
+    type History = CompressedHistory;
     fn robust_split_closed_edges_and_faces(&mut self, tol: f64) {
-        fn sp<S>(surface: &S, point: Point3, hint: Option<(f64, f64)>) -> Option<(f64, f64)>
-        where S: SearchParameter<D2, Point = Point3> + SearchNearestParameter<D2, Point = Point3>
-        {
-            surface
-                .search_parameter(point, hint, 100)
-                .or_else(|| surface.search_parameter(point, None, 100))
-                .or_else(|| surface.search_nearest_parameter(point, hint, 100))
-                .or_else(|| surface.search_nearest_parameter(point, None, 100))
-        }
         split_closed_edges(self);
-        split_closed_faces(self, tol, sp);
+        split_closed_faces(self, tol, robust_sp);
+    }
+    fn robust_split_closed_edges_and_faces_with_history(&mut self, tol: f64) -> CompressedHistory {
+        let snapshot = Snapshot::new(self);
+        split_closed_edges(self);
+        let face_origins = split_closed_faces(self, tol, robust_sp);
+        healing_history(&snapshot, self, &face_origins, tol)
     }
 }
 
@@ -121,10 +176,21 @@ where
         + SearchParameter<D2, Point = Point3>
         + SearchNearestParameter<D2, Point = Point3>,
 {
+    type History = Vec<CompressedHistory>;
     fn robust_split_closed_edges_and_faces(&mut self, tol: f64) {
         let fs = RobustSplitClosedEdgesAndFaces::robust_split_closed_edges_and_faces;
         self.boundaries.iter_mut().for_each(|shell| fs(shell, tol))
     }
+    fn robust_split_closed_edges_and_faces_with_history(
+        &mut self,
+        tol: f64,
+    ) -> Vec<CompressedHistory> {
+        let fs = RobustSplitClosedEdgesAndFaces::robust_split_closed_edges_and_faces_with_history;
+        self.boundaries
+            .iter_mut()
+            .map(|shell| fs(shell, tol))
+            .collect()
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,455 @@
+use super::sew::{find_root, orient_faces, polyline, vertex_clusters};
+use super::*;
+
+/// The report of [`close_gaps`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct GapReport {
+    /// the pairs of the vertices merged into other vertices and the vertices into which they are merged
+    pub merged_vertices: Vec<(usize, usize)>,
+    /// the edges whose curves are re-fitted to their end vertices
+    pub refitted_edges: Vec<usize>,
+}
+
+/// The report of [`collapse_short_edges`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ShortEdgeReport {
+    /// the edges removed from the boundaries since they are shorter than the tolerance
+    pub collapsed_edges: Vec<usize>,
+    /// the pairs of the vertices merged into other vertices and the vertices into which they are merged
+    pub merged_vertices: Vec<(usize, usize)>,
+    /// the edges whose curves are re-fitted to the merged vertices
+    pub refitted_edges: Vec<usize>,
+}
+
+/// The report of [`remove_sliver_faces`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SliverReport {
+    /// the indices of the removed faces before the removal
+    pub removed_faces: Vec<usize>,
+    /// the edges of the removed faces replaced by the other edges of the faces in the neighbours
+    pub absorbed_edges: Vec<usize>,
+    /// the edges of the removed faces removed from the boundaries since they are shorter than the tolerance
+    pub collapsed_edges: Vec<usize>,
+    /// the pairs of the vertices merged into other vertices and the vertices into which they are merged
+    pub merged_vertices: Vec<(usize, usize)>,
+    /// the edges whose curves are re-fitted to the merged vertices
+    pub refitted_edges: Vec<usize>,
+}
+
+/// The report of [`reorient_faces`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct OrientationReport {
+    /// the inverted faces
+    pub inverted_faces: Vec<usize>,
+}
+
+/// Closes the gaps smaller than `tol` between the vertices and the edges.
+///
+/// # Details
+/// - The vertices nearer than `tol` are merged into the one at their barycenter.
+/// - The curves of the edges whose ends are not on their vertices are re-fitted to the vertices
+///   by B-spline curves.
+///
+/// The vertices and the edges keep their indices. The merged vertices remain in the shell
+/// but are not used by the edges.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// use truck_shapeops::close_gaps;
+/// let v = builder::vertex(Point3::origin());
+/// let e = builder::tsweep(&v, Vector3::unit_x());
+/// let face: Face = builder::tsweep(&e, Vector3::unit_y());
+/// let mut shell = Shell::from(vec![face]).compress();
+///
+/// // move a vertex away from the ends of the edges
+/// shell.vertices[0] += Vector3::new(1.0e-3, 0.0, 0.0);
+/// let report = close_gaps(&mut shell, 1.0e-2);
+/// assert!(report.merged_vertices.is_empty());
+/// assert_eq!(report.refitted_edges.len(), 2);
+/// assert!(Shell::extract(shell).is_ok());
+/// ```
+pub fn close_gaps<C, S>(shell: &mut CompressedShell<Point3, C, S>, tol: f64) -> GapReport
+where C: ParametricCurve3D
+        + BoundedCurve
+        + ParameterDivision1D<Point = Point3>
+        + From<BSplineCurve<Point3>> {
+    nonpositive_tolerance!(tol);
+    let roots = vertex_clusters(&shell.vertices, tol);
+    let merged_vertices = merge_clusters(shell, &roots);
+    let refitted_edges = refit_edges(shell, tol);
+    GapReport {
+        merged_vertices,
+        refitted_edges,
+    }
+}
+
+/// Collapses the edges shorter than `tol`.
+///
+/// # Details
+/// The short edges are removed from the boundaries of the faces, and the vertices of each
+/// short edge are merged into the one at their barycenter. The curves of the edges adjacent to
+/// the merged vertices are re-fitted to the vertices by B-spline curves.
+///
+/// The vertices and the edges keep their indices. The collapsed edges and the merged vertices
+/// remain in the shell but are not used by the faces.
+pub fn collapse_short_edges<C, S>(
+    shell: &mut CompressedShell<Point3, C, S>,
+    tol: f64,
+) -> ShortEdgeReport
+where
+    C: ParametricCurve3D
+        + BoundedCurve
+        + ParameterDivision1D<Point = Point3>
+        + From<BSplineCurve<Point3>>,
+{
+    nonpositive_tolerance!(tol);
+    let collapsed_edges: Vec<usize> = used_edges(shell)
+        .into_iter()
+        .filter(|i| {
+            let points = polyline(&shell.edges[*i].curve, tol).1;
+            let length: f64 = points.windows(2).map(|p| p[0].distance(p[1])).sum();
+            length < tol
+        })
+        .collect();
+    let mut roots: Vec<usize> = (0..shell.vertices.len()).collect();
+    collapsed_edges.iter().for_each(|i| {
+        let (v0, v1) = shell.edges[*i].vertices;
+        let (r0, r1) = (find_root(&mut roots, v0), find_root(&mut roots, v1));
+        roots[usize::max(r0, r1)] = usize::min(r0, r1);
+    });
+    let roots: Vec<usize> = (0..roots.len()).map(|v| find_root(&mut roots, v)).collect();
+    let collapsed: HashSet<usize> = collapsed_edges.iter().copied().collect();
+    shell.faces.iter_mut().for_each(|face| {
+        face.boundaries
+            .iter_mut()
+            .for_each(|wire| wire.retain(|edge| !collapsed.contains(&edge.index)));
+        face.boundaries.retain(|wire| !wire.is_empty());
+    });
+    let merged_vertices = merge_clusters(shell, &roots);
+    let refitted_edges = refit_edges(shell, tol);
+    ShortEdgeReport {
+        collapsed_edges,
+        merged_vertices,
+        refitted_edges,
+    }
+}
+
+/// Removes the sliver faces narrower than `tol` by merging them into their neighbours.
+///
+/// # Details
+/// A face with one boundary wire is a sliver if the wire has an edge whose Hausdorff distance
+/// from the other edges of the wire is less than `tol`. The face is removed, and the edge is
+/// replaced by the other edges of the wire in the boundaries of the neighbour faces.
+/// - The vertices of the sliver nearer than `tol` are merged into the one at their barycenter,
+///   and the edges of the sliver shorter than `tol` are removed from the boundaries. Hence,
+///   the edges of the sliver coincident with the replaced edge are merged into it.
+/// - The pairs of an edge and its inverse which are adjacent in a boundary after the replacement
+///   are removed.
+/// - The curves of the edges adjacent to the merged vertices are re-fitted to the vertices
+///   by B-spline curves.
+///
+/// The vertices and the edges keep their indices, and the faces after the removed ones are
+/// shifted. The absorbed and the collapsed edges and the merged vertices remain in the shell
+/// but are not used by the faces.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// use truck_shapeops::remove_sliver_faces;
+/// let v = builder::vertex(Point3::origin());
+/// let e = builder::tsweep(&v, Vector3::unit_x());
+/// let f = builder::tsweep(&e, Vector3::unit_y());
+/// let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+/// let thin: Solid = builder::tsweep(&f, Vector3::new(0.0, 0.0, 1.0e-3));
+///
+/// let mut shell = cube.into_boundaries().pop().unwrap().compress();
+/// assert!(remove_sliver_faces(&mut shell, 1.0e-2).removed_faces.is_empty());
+///
+/// // all faces are slivers except for the top and the bottom
+/// let mut shell = thin.into_boundaries().pop().unwrap().compress();
+/// let report = remove_sliver_faces(&mut shell, 1.0e-2);
+/// assert_eq!(report.removed_faces.len(), 4);
+/// assert_eq!(report.merged_vertices.len(), 4);
+/// assert_eq!(shell.faces.len(), 2);
+/// ```
+pub fn remove_sliver_faces<C, S>(
+    shell: &mut CompressedShell<Point3, C, S>,
+    tol: f64,
+) -> SliverReport
+where
+    C: ParametricCurve3D
+        + BoundedCurve
+        + ParameterDivision1D<Point = Point3>
+        + From<BSplineCurve<Point3>>,
+{
+    nonpositive_tolerance!(tol);
+    let polys: Vec<Vec<Point3>> = shell
+        .edges
+        .iter()
+        .map(|edge| polyline(&edge.curve, tol).1)
+        .collect();
+    let mut removed = vec![false; shell.faces.len()];
+    let mut roots: Vec<usize> = (0..shell.vertices.len()).collect();
+    let mut absorbed_edges = Vec::new();
+    let mut collapsed_edges = Vec::new();
+    (0..shell.faces.len()).for_each(|i| {
+        let boundaries = &shell.faces[i].boundaries;
+        if boundaries.len() != 1 {
+            return;
+        }
+        let wire = &boundaries[0];
+        let Some(k) = (0..wire.len()).find(|k| absorbable(wire, *k, &polys, tol)) else {
+            return;
+        };
+        let edge = wire[k];
+        let take_front = |edge: &EdgeIndex| match edge.orientation {
+            true => shell.edges[edge.index].vertices.0,
+            false => shell.edges[edge.index].vertices.1,
+        };
+        let sliver_vertices: Vec<usize> = wire.iter().map(take_front).collect();
+        sliver_vertices.iter().enumerate().for_each(|(j, v)| {
+            sliver_vertices[j + 1..].iter().for_each(|w| {
+                if shell.vertices[*v].distance(shell.vertices[*w]) < tol {
+                    let (r0, r1) = (find_root(&mut roots, *v), find_root(&mut roots, *w));
+                    roots[usize::max(r0, r1)] = usize::min(r0, r1);
+                }
+            });
+        });
+        wire.iter().for_each(|edge| {
+            let (v0, v1) = shell.edges[edge.index].vertices;
+            let short = polys[edge.index]
+                .windows(2)
+                .map(|p| p[0].distance(p[1]))
+                .sum::<f64>()
+                < tol;
+            if short && find_root(&mut roots, v0) == find_root(&mut roots, v1) {
+                collapsed_edges.push(edge.index);
+            }
+        });
+        // the other edges from the back of the edge to the front of the edge
+        let mut rest = wire.clone();
+        rest.rotate_left(k + 1);
+        rest.pop();
+        let inversed: Wire = rest
+            .iter()
+            .rev()
+            .map(|edge| EdgeIndex {
+                index: edge.index,
+                orientation: !edge.orientation,
+            })
+            .collect();
+        removed[i] = true;
+        absorbed_edges.push(edge.index);
+        shell
+            .faces
+            .iter_mut()
+            .zip(&removed)
+            .filter(|(_, removed)| !**removed)
+            .flat_map(|(face, _)| &mut face.boundaries)
+            .for_each(|wire| {
+                let new_wire = wire
+                    .iter()
+                    .flat_map(|e| match e.index == edge.index {
+                        true => match e.orientation == edge.orientation {
+                            true => inversed.clone(),
+                            false => rest.clone(),
+                        },
+                        false => vec![*e],
+                    })
+                    .collect();
+                *wire = new_wire;
+            });
+    });
+    let mut iter = removed.iter();
+    shell.faces.retain(|_| !*iter.next().unwrap());
+    let removed_faces = (0..removed.len()).filter(|i| removed[*i]).collect();
+    collapsed_edges.sort();
+    collapsed_edges.dedup();
+    let collapsed: HashSet<usize> = collapsed_edges.iter().copied().collect();
+    shell.faces.iter_mut().for_each(|face| {
+        face.boundaries.iter_mut().for_each(|wire| {
+            wire.retain(|edge| !collapsed.contains(&edge.index));
+            remove_spikes(wire);
+        });
+        face.boundaries.retain(|wire| !wire.is_empty());
+    });
+    let roots: Vec<usize> = (0..roots.len()).map(|v| find_root(&mut roots, v)).collect();
+    let merged_vertices = merge_clusters(shell, &roots);
+    let refitted_edges = refit_edges(shell, tol);
+    SliverReport {
+        removed_faces,
+        absorbed_edges,
+        collapsed_edges,
+        merged_vertices,
+        refitted_edges,
+    }
+}
+
+/// Inverts the faces so that each edge shared by two faces is oriented oppositely.
+///
+/// The faces connected by the edges are oriented as the first face in them.
+pub fn reorient_faces<C, S>(shell: &mut CompressedShell<Point3, C, S>) -> OrientationReport {
+    let mut inverted_faces = orient_faces(shell);
+    inverted_faces.sort();
+    OrientationReport { inverted_faces }
+}
+
+/// Returns the sorted indices of the edges used by the faces.
+fn used_edges<C, S>(shell: &Shell<Point3, C, S>) -> Vec<usize> {
+    let mut vec: Vec<usize> = shell
+        .faces
+        .iter()
+        .flat_map(|face| face.boundaries.iter().flatten())
+        .map(|edge| edge.index)
+        .collect();
+    vec.sort();
+    vec.dedup();
+    vec
+}
+
+/// Moves the roots of the clusters to the barycenters and replaces the vertices of the edges
+/// used by the faces by the roots. Returns the pairs of the merged vertices and their roots.
+///
+/// The unused edges keep their vertices, since the collapsed ones would be closed.
+fn merge_clusters<C, S>(shell: &mut Shell<Point3, C, S>, roots: &[usize]) -> Vec<(usize, usize)> {
+    let mut sums = HashMap::<usize, (Vector3, usize)>::default();
+    roots.iter().enumerate().for_each(|(v, root)| {
+        let (sum, count) = sums.entry(*root).or_insert((Vector3::zero(), 0));
+        *sum += shell.vertices[v].to_vec();
+        *count += 1;
+    });
+    sums.into_iter()
+        .filter(|(_, (_, count))| *count > 1)
+        .for_each(|(root, (sum, count))| {
+            shell.vertices[root] = Point3::from_vec(sum / count as f64);
+        });
+    used_edges(shell).into_iter().for_each(|i| {
+        let (v0, v1) = shell.edges[i].vertices;
+        shell.edges[i].vertices = (roots[v0], roots[v1]);
+    });
+    roots
+        .iter()
+        .enumerate()
+        .filter(|(v, root)| *v != **root)
+        .map(|(v, root)| (v, *root))
+        .collect()
+}
+
+/// Re-fits the curves of the edges used by the faces whose ends are not on their vertices, and
+/// returns the indices of the re-fitted edges. The degenerate edges shorter than `tol` are skipped.
+fn refit_edges<C, S>(shell: &mut Shell<Point3, C, S>, tol: f64) -> Vec<usize>
+where C: ParametricCurve3D
+        + BoundedCurve
+        + ParameterDivision1D<Point = Point3>
+        + From<BSplineCurve<Point3>> {
+    used_edges(shell)
+        .into_iter()
+        .filter(|i| {
+            let Edge {
+                vertices: (v0, v1),
+                curve,
+            } = &shell.edges[*i];
+            let (p0, p1) = (shell.vertices[*v0], shell.vertices[*v1]);
+            let (q0, q1) = (curve.front(), curve.back());
+            if p0.near(&q0) && p1.near(&q1) {
+                return false;
+            }
+            let degenerate =
+                v0 == v1 && polyline(curve, tol).1.iter().all(|q| q.distance(p0) < tol);
+            if degenerate {
+                return false;
+            }
+            let range = curve.range_tuple();
+            let refitted = Refitted {
+                curve,
+                range,
+                displacements: (p0 - q0, p1 - q1),
+            };
+            let Some(bspcurve) =
+                BSplineCurve::cubic_approximation(&refitted, range, tolerance(), tol, 10)
+            else {
+                return false;
+            };
+            shell.edges[*i].curve = C::from(bspcurve);
+            true
+        })
+        .collect()
+}
+
+/// The curve moved by the linear interpolation of the displacements of the ends.
+#[derive(Clone)]
+struct Refitted<'a, C> {
+    curve: &'a C,
+    range: (f64, f64),
+    displacements: (Vector3, Vector3),
+}
+
+impl<C: ParametricCurve3D> ParametricCurve for Refitted<'_, C> {
+    type Point = Point3;
+    type Vector = Vector3;
+    fn subs(&self, t: f64) -> Point3 {
+        let (t0, t1) = self.range;
+        let s = (t - t0) / (t1 - t0);
+        let (d0, d1) = self.displacements;
+        self.curve.subs(t) + d0 * (1.0 - s) + d1 * s
+    }
+    fn der(&self, t: f64) -> Vector3 {
+        let (t0, t1) = self.range;
+        let (d0, d1) = self.displacements;
+        self.curve.der(t) + (d1 - d0) / (t1 - t0)
+    }
+    fn der2(&self, t: f64) -> Vector3 { self.curve.der2(t) }
+}
+
+/// Returns whether the `k`th edge of `wire` is within `tol` from the other edges by the Hausdorff distance.
+fn absorbable(wire: &[EdgeIndex], k: usize, polys: &[Vec<Point3>], tol: f64) -> bool {
+    let index = wire[k].index;
+    if wire.len() < 2 || wire.iter().filter(|edge| edge.index == index).count() > 1 {
+        return false;
+    }
+    let edge_poly = &polys[index];
+    let others = || {
+        wire.iter()
+            .filter(move |edge| edge.index != index)
+            .map(|edge| &polys[edge.index])
+    };
+    let near_others = |p: &Point3| others().any(|poly| distance_to_polyline(*p, poly) < tol);
+    edge_poly.iter().all(near_others)
+        && others()
+            .flatten()
+            .all(|p| distance_to_polyline(*p, edge_poly) < tol)
+}
+
+/// Removes the pairs of an edge and its inverse which are adjacent in the cyclic `wire`.
+fn remove_spikes(wire: &mut Wire) {
+    let is_spike =
+        |e0: &EdgeIndex, e1: &EdgeIndex| e0.index == e1.index && e0.orientation != e1.orientation;
+    let mut stack: Wire = Vec::with_capacity(wire.len());
+    wire.iter().for_each(|edge| match stack.last() {
+        Some(last) if is_spike(last, edge) => {
+            stack.pop();
+        }
+        _ => stack.push(*edge),
+    });
+    let mut front = 0;
+    while stack.len() >= front + 2 && is_spike(&stack[front], stack.last().unwrap()) {
+        front += 1;
+        stack.pop();
+    }
+    *wire = stack.split_off(front);
+}
+
+fn distance_to_polyline(p: Point3, poly: &[Point3]) -> f64 {
+    let segment_distance = |seg: &[Point3]| {
+        let (a, b) = (seg[0], seg[1]);
+        let d = b - a;
+        let t = match d.magnitude2().so_small() {
+            true => 0.0,
+            false => f64::clamp((p - a).dot(d) / d.magnitude2(), 0.0, 1.0),
+        };
+        (a + d * t).distance(p)
+    };
+    poly.windows(2)
+        .map(segment_distance)
+        .chain(poly.first().map(|a| a.distance(p)))
+        .fold(f64::INFINITY, f64::min)
+}
@@ -0,0 +1,495 @@
+use super::*;
+use truck_topology::{Edge as TEdge, Face as TFace, Result, Shell as TShell};
+
+/// The report of [`sew_with_report`].
+#[derive(Clone, Debug)]
+pub struct SewingReport<C> {
+    /// the number of the vertices merged into other vertices
+    pub merged_vertices: usize,
+    /// the number of the edges split at the vertices of other edges
+    pub split_edges: usize,
+    /// the number of the edges merged into other edges
+    pub merged_edges: usize,
+    /// the number of the edges removed since they are shorter than the tolerance
+    pub removed_edges: usize,
+    /// the number of the faces inverted to orient the shell
+    pub inverted_faces: usize,
+    /// the edges bounding only one face of the result
+    pub free_edges: Vec<TEdge<Point3, C>>,
+}
+
+/// Sews the faces into a shell by the tolerance `tol`.
+///
+/// # Details
+/// - The vertices nearer than `tol` are merged.
+/// - The edges are split at the vertices of the other edges lying on them, so that the edges
+///   partially overlapping with each other are separated into the coincident parts.
+/// - The coincident edges with the same end vertices are merged. The closed edges, whose end
+///   vertices are the same, are merged in the same way, and split into two edges afterward.
+/// - The faces are inverted so that each edge shared by two faces is oriented oppositely.
+///
+/// See [`sew_with_report`] for the free edges of the result.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// use truck_shapeops::sew;
+/// let v = builder::vertex(Point3::origin());
+/// let e = builder::tsweep(&v, Vector3::unit_x());
+/// let f = builder::tsweep(&e, Vector3::unit_y());
+/// let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+///
+/// // disconnected faces, which have their own vertices and edges.
+/// let faces: Vec<Face> = cube
+///     .face_iter()
+///     .map(|face| builder::translated(face, Vector3::zero()))
+///     .collect();
+///
+/// let shell = sew(faces, 1.0e-3).unwrap();
+/// assert_eq!(shell.shell_condition(), ShellCondition::Closed);
+/// let solid = Solid::new(vec![shell]);
+/// assert_eq!(solid.face_iter().count(), 6);
+/// ```
+pub fn sew<C, S>(faces: Vec<TFace<Point3, C, S>>, tol: f64) -> Result<TShell<Point3, C, S>>
+where
+    C: ParametricCurve3D
+        + BoundedCurve
+        + Cut
+        + ParameterDivision1D<Point = Point3>
+        + SearchNearestParameter<D1, Point = Point3>,
+    S: Clone, {
+    sew_with_report(faces, tol).map(|(shell, _)| shell)
+}
+
+/// Sews the faces into a shell by the tolerance `tol`, and reports the modification.
+///
+/// The faces are sewn in the same way as [`sew`]. The free edges in the report, which bound only
+/// one face, are the boundary of the shell. If there is no free edge, the shell is closed.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// use truck_shapeops::sew_with_report;
+/// let v = builder::vertex(Point3::origin());
+/// let e = builder::tsweep(&v, Vector3::unit_x());
+/// let f = builder::tsweep(&e, Vector3::unit_y());
+/// let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+///
+/// // remove the top face, and make the other faces disconnected.
+/// let faces: Vec<Face> = cube
+///     .face_iter()
+///     .take(5)
+///     .map(|face| builder::translated(face, Vector3::zero()))
+///     .collect();
+/// let (shell, report) = sew_with_report(faces, 1.0e-3).unwrap();
+/// assert_eq!(report.merged_vertices, 12);
+/// assert_eq!(report.merged_edges, 8);
+/// assert_eq!(report.free_edges.len(), 4);
+/// assert_eq!(shell.shell_condition(), ShellCondition::Oriented);
+/// ```
+pub fn sew_with_report<C, S>(
+    faces: Vec<TFace<Point3, C, S>>,
+    tol: f64,
+) -> Result<(TShell<Point3, C, S>, SewingReport<C>)>
+where
+    C: ParametricCurve3D
+        + BoundedCurve
+        + Cut
+        + ParameterDivision1D<Point = Point3>
+        + SearchNearestParameter<D1, Point = Point3>,
+    S: Clone,
+{
+    nonpositive_tolerance!(tol);
+    let mut shell = TShell::from(faces).compress();
+    let merged_vertices = merge_vertices(&mut shell, tol);
+    let split_edges = split_edges_at_vertices(&mut shell, tol);
+    let merged_edges = merge_edges(&mut shell, tol);
+    let removed_edges = remove_degenerate_edges(&mut shell, tol);
+    split_closed_edges(&mut shell);
+    let inverted_faces = orient_faces(&mut shell).len();
+    let shell = TShell::extract(compaction(shell))?;
+    let mut counts = HashMap::<_, (usize, TEdge<Point3, C>)>::default();
+    shell.edge_iter().for_each(|edge| {
+        counts.entry(edge.id()).or_insert((0, edge)).0 += 1;
+    });
+    let free_edges = counts
+        .into_values()
+        .filter_map(|(count, edge)| match count {
+            1 => Some(edge),
+            _ => None,
+        })
+        .collect();
+    let report = SewingReport {
+        merged_vertices,
+        split_edges,
+        merged_edges,
+        removed_edges,
+        inverted_faces,
+        free_edges,
+    };
+    Ok((shell, report))
+}
+
+pub(super) fn find_root(roots: &mut [usize], i: usize) -> usize {
+    let mut root = i;
+    while roots[root] != root {
+        root = roots[root];
+    }
+    let mut j = i;
+    while roots[j] != root {
+        let next = roots[j];
+        roots[j] = root;
+        j = next;
+    }
+    root
+}
+
+/// Merges the vertices nearer than `tol`, and returns the number of merged vertices.
+fn merge_vertices<C, S>(shell: &mut Shell<Point3, C, S>, tol: f64) -> usize {
+    let roots = vertex_clusters(&shell.vertices, tol);
+    shell.edges.iter_mut().for_each(|edge| {
+        let (v0, v1) = edge.vertices;
+        edge.vertices = (roots[v0], roots[v1]);
+    });
+    roots
+        .iter()
+        .enumerate()
+        .filter(|(i, root)| *i != **root)
+        .count()
+}
+
+/// Returns the roots of the clusters of the vertices connected by the distances less than `tol`.
+/// The root of each cluster is the minimum index in the cluster.
+pub(super) fn vertex_clusters(vertices: &[Point3], tol: f64) -> Vec<usize> {
+    let cell = |p: Point3| {
+        let f = |x: f64| (x / tol).floor() as i64;
+        [f(p.x), f(p.y), f(p.z)]
+    };
+    let mut roots: Vec<usize> = (0..vertices.len()).collect();
+    let mut cells = HashMap::<[i64; 3], Vec<usize>>::default();
+    vertices.iter().enumerate().for_each(|(i, p)| {
+        let [x, y, z] = cell(*p);
+        (0..27).for_each(|k| {
+            let key = [x + k % 3 - 1, y + (k / 3) % 3 - 1, z + k / 9 - 1];
+            let Some(vec) = cells.get(&key) else {
+                return;
+            };
+            vec.iter()
+                .filter(|j| vertices[**j].distance(*p) < tol)
+                .for_each(|j| {
+                    let (r0, r1) = (find_root(&mut roots, i), find_root(&mut roots, *j));
+                    roots[usize::max(r0, r1)] = usize::min(r0, r1);
+                });
+        });
+        cells.entry([x, y, z]).or_default().push(i);
+    });
+    (0..roots.len()).map(|i| find_root(&mut roots, i)).collect()
+}
+
+pub(super) fn polyline<C>(curve: &C, tol: f64) -> (Vec<f64>, Vec<Point3>)
+where C: BoundedCurve<Point = Point3> + ParameterDivision1D<Point = Point3> {
+    curve.parameter_division(curve.range_tuple(), tol)
+}
+
+/// Returns the parameter of the nearest point on `curve` to `point` if the distance is less than `tol`.
+fn parameter_on_curve<C>(
+    curve: &C,
+    (params, points): &(Vec<f64>, Vec<Point3>),
+    point: Point3,
+    tol: f64,
+) -> Option<f64>
+where
+    C: ParametricCurve3D + SearchNearestParameter<D1, Point = Point3>,
+{
+    let hint = params
+        .iter()
+        .zip(points)
+        .min_by(|(_, p), (_, q)| {
+            point
+                .distance2(**p)
+                .partial_cmp(&point.distance2(**q))
+                .unwrap()
+        })
+        .map(|(t, _)| *t);
+    let t = curve.search_nearest_parameter(point, hint, 100)?;
+    match curve.subs(t).distance(point) < tol {
+        true => Some(t),
+        false => None,
+    }
+}
+
+/// Splits the edges at the vertices lying on them, and returns the number of split edges.
+fn split_edges_at_vertices<C, S>(shell: &mut Shell<Point3, C, S>, tol: f64) -> usize
+where C: ParametricCurve3D
+        + BoundedCurve
+        + Cut
+        + ParameterDivision1D<Point = Point3>
+        + SearchNearestParameter<D1, Point = Point3> {
+    let used_vertices: Vec<usize> = {
+        let mut vec: Vec<_> = shell
+            .edges
+            .iter()
+            .flat_map(|edge| [edge.vertices.0, edge.vertices.1])
+            .collect();
+        vec.sort();
+        vec.dedup();
+        vec
+    };
+    let len = shell.edges.len();
+    let mut added = HashMap::<usize, Vec<usize>>::default();
+    (0..len).for_each(|i| {
+        let Edge {
+            vertices: (v0, v1),
+            curve,
+        } = &shell.edges[i];
+        let poly = polyline(curve, tol);
+        let bdb: BoundingBox<Point3> = poly.1.iter().collect();
+        let (min, max) = (bdb.min(), bdb.max());
+        let in_box = |p: &Point3| (0..3).all(|k| min[k] - tol <= p[k] && p[k] <= max[k] + tol);
+        let (t0, t1) = curve.range_tuple();
+        let (p0, p1) = (shell.vertices[*v0], shell.vertices[*v1]);
+        let mut cuts: Vec<(f64, usize)> = used_vertices
+            .iter()
+            .filter(|v| **v != *v0 && **v != *v1)
+            .filter_map(|v| {
+                let p = shell.vertices[*v];
+                if !in_box(&p) || p.distance(p0) < tol || p.distance(p1) < tol {
+                    return None;
+                }
+                let t = parameter_on_curve(curve, &poly, p, tol)?;
+                match t0 < t && t < t1 {
+                    true => Some((t, *v)),
+                    false => None,
+                }
+            })
+            .collect();
+        if cuts.is_empty() {
+            return;
+        }
+        cuts.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+        let v1 = *v1;
+        let edge = &mut shell.edges[i];
+        let mut pieces: Vec<Edge<C>> = cuts
+            .iter()
+            .rev()
+            .scan(v1, |back, (t, v)| {
+                let piece = Edge {
+                    vertices: (*v, *back),
+                    curve: edge.curve.cut(*t),
+                };
+                *back = *v;
+                Some(piece)
+            })
+            .collect();
+        pieces.reverse();
+        edge.vertices.1 = cuts[0].1;
+        let first = shell.edges.len();
+        shell.edges.extend(pieces);
+        added.insert(i, (first..shell.edges.len()).collect());
+    });
+    shell
+        .faces
+        .iter_mut()
+        .flat_map(|face| &mut face.boundaries)
+        .for_each(|wire| {
+            let new_wire = wire
+                .iter()
+                .flat_map(|edge| {
+                    let Some(pieces) = added.get(&edge.index) else {
+                        return vec![*edge];
+                    };
+                    let ei = |index: &usize| EdgeIndex {
+                        index: *index,
+                        orientation: edge.orientation,
+                    };
+                    let iter = std::iter::once(*edge).chain(pieces.iter().map(ei));
+                    match edge.orientation {
+                        true => iter.collect(),
+                        false => iter.rev().collect(),
+                    }
+                })
+                .collect();
+            *wire = new_wire;
+        });
+    added.len()
+}
+
+fn replace_edge_indices<S>(faces: &mut [Face<S>], map: &HashMap<usize, EdgeIndex>) {
+    faces
+        .iter_mut()
+        .flat_map(|face| &mut face.boundaries)
+        .flatten()
+        .for_each(|edge| {
+            if let Some(new_edge) = map.get(&edge.index) {
+                *edge = EdgeIndex {
+                    index: new_edge.index,
+                    orientation: edge.orientation == new_edge.orientation,
+                };
+            }
+        });
+}
+
+/// Merges the coincident edges with the same end vertices, and returns the number of merged edges.
+///
+/// The closed edges are also grouped by their vertices, and oriented by the directions of the curves.
+fn merge_edges<C, S>(shell: &mut Shell<Point3, C, S>, tol: f64) -> usize
+where C: ParametricCurve3D
+        + BoundedCurve
+        + ParameterDivision1D<Point = Point3>
+        + SearchNearestParameter<D1, Point = Point3> {
+    let mut groups = HashMap::<(usize, usize), Vec<usize>>::default();
+    shell.edges.iter().enumerate().for_each(|(i, edge)| {
+        let (v0, v1) = edge.vertices;
+        let key = (usize::min(v0, v1), usize::max(v0, v1));
+        groups.entry(key).or_default().push(i);
+    });
+    let mut map = HashMap::<usize, EdgeIndex>::default();
+    groups.into_values().for_each(|group| {
+        let mut representatives = Vec::<usize>::new();
+        group.into_iter().for_each(|i| {
+            let curve = &shell.edges[i].curve;
+            let (t0, t1) = curve.range_tuple();
+            let quarter = curve.subs(t0 + (t1 - t0) / 4.0);
+            let middle = curve.subs((t0 + t1) / 2.0);
+            // the representative and whether the curves have the same direction
+            let coincident = representatives.iter().find_map(|j| {
+                let curve = &shell.edges[*j].curve;
+                let polyline = polyline(curve, tol);
+                let s0 = parameter_on_curve(curve, &polyline, quarter, tol)?;
+                let s1 = parameter_on_curve(curve, &polyline, middle, tol)?;
+                Some((*j, s0 < s1))
+            });
+            match coincident {
+                Some((j, same_direction)) => {
+                    let (v0, v1) = shell.edges[i].vertices;
+                    let orientation = match v0 == v1 {
+                        true => same_direction,
+                        false => shell.edges[i].vertices == shell.edges[j].vertices,
+                    };
+                    map.insert(
+                        i,
+                        EdgeIndex {
+                            index: j,
+                            orientation,
+                        },
+                    );
+                }
+                None => representatives.push(i),
+            }
+        });
+    });
+    replace_edge_indices(&mut shell.faces, &map);
+    map.len()
+}
+
+/// Removes the edges shorter than `tol` from the boundaries, and returns the number of removed edges.
+fn remove_degenerate_edges<C, S>(shell: &mut Shell<Point3, C, S>, tol: f64) -> usize
+where C: BoundedCurve<Point = Point3> + ParameterDivision1D<Point = Point3> {
+    let vertices = &shell.vertices;
+    let degenerate: HashSet<usize> = (0..shell.edges.len())
+        .filter(|i| {
+            let Edge {
+                vertices: (v0, v1),
+                curve,
+            } = &shell.edges[*i];
+            let p = vertices[*v0];
+            v0 == v1 && polyline(curve, tol).1.iter().all(|q| q.distance(p) < tol)
+        })
+        .collect();
+    shell.faces.iter_mut().for_each(|face| {
+        face.boundaries
+            .iter_mut()
+            .for_each(|wire| wire.retain(|edge| !degenerate.contains(&edge.index)));
+        face.boundaries.retain(|wire| !wire.is_empty());
+    });
+    degenerate.len()
+}
+
+/// Inverts the faces so that each edge shared by two faces is oriented oppositely,
+/// and returns the indices of the inverted faces.
+pub(super) fn orient_faces<C, S>(shell: &mut Shell<Point3, C, S>) -> Vec<usize> {
+    let mut edge_faces = HashMap::<usize, Vec<usize>>::default();
+    shell.faces.iter().enumerate().for_each(|(i, face)| {
+        face.boundaries.iter().flatten().for_each(|edge| {
+            edge_faces.entry(edge.index).or_default().push(i);
+        });
+    });
+    // whether the face goes along the edge in the direction of the curve
+    let direction = |face: &Face<S>, index: usize| {
+        let mut iter = face.boundaries.iter().flatten();
+        let edge = iter.find(|edge| edge.index == index).unwrap();
+        edge.orientation == face.orientation
+    };
+    let mut visited = vec![false; shell.faces.len()];
+    let mut inverted = Vec::new();
+    (0..shell.faces.len()).for_each(|start| {
+        if visited[start] {
+            return;
+        }
+        visited[start] = true;
+        let mut stack = vec![start];
+        while let Some(i) = stack.pop() {
+            let edges: Vec<usize> = shell.faces[i]
+                .boundaries
+                .iter()
+                .flatten()
+                .map(|edge| edge.index)
+                .collect();
+            edges.into_iter().for_each(|index| {
+                let faces = &edge_faces[&index];
+                if faces.len() != 2 || faces[0] == faces[1] {
+                    return;
+                }
+                let j = if faces[0] == i { faces[1] } else { faces[0] };
+                if visited[j] {
+                    return;
+                }
+                visited[j] = true;
+                if direction(&shell.faces[i], index) == direction(&shell.faces[j], index) {
+                    shell.faces[j].orientation = !shell.faces[j].orientation;
+                    inverted.push(j);
+                }
+                stack.push(j);
+            });
+        }
+    });
+    inverted
+}
+
+/// Removes the vertices and edges which are not used by faces.
+fn compaction<C, S>(shell: Shell<Point3, C, S>) -> Shell<Point3, C, S> {
+    let CompressedShell {
+        vertices,
+        edges,
+        mut faces,
+    } = shell;
+    let mut edge_map = HashMap::<usize, usize>::default();
+    let mut vertex_map = HashMap::<usize, usize>::default();
+    let mut new_edges = Vec::new();
+    let mut new_vertices = Vec::new();
+    let mut edges: Vec<_> = edges.into_iter().map(Some).collect();
+    faces
+        .iter_mut()
+        .flat_map(|face| &mut face.boundaries)
+        .flatten()
+        .for_each(|edge| {
+            edge.index = *edge_map.entry(edge.index).or_insert_with(|| {
+                let Edge {
+                    vertices: (v0, v1),
+                    curve,
+                } = edges[edge.index].take().unwrap();
+                let mut index = |v: usize| {
+                    *vertex_map.entry(v).or_insert_with(|| {
+                        new_vertices.push(vertices[v]);
+                        new_vertices.len() - 1
+                    })
+                };
+                let vertices = (index(v0), index(v1));
+                new_edges.push(Edge { vertices, curve });
+                new_edges.len() - 1
+            });
+        });
+    CompressedShell {
+        vertices: new_vertices,
+        edges: new_edges,
+        faces,
+    }
+}
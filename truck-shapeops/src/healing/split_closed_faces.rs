@@ -1,7 +1,11 @@
 use super::*;
 use std::ops::Range;
 
-pub(super) fn split_closed_faces<C, S>(shell: &mut Shell<Point3, C, S>, tol: f64, sp: impl SP<S>)
+pub(super) fn split_closed_faces<C, S>(
+    shell: &mut Shell<Point3, C, S>,
+    tol: f64,
+    sp: impl SP<S>,
+) -> Vec<usize>
 where
     C: ParametricCurve3D
         + BoundedCurve
@@ -9,7 +13,8 @@ where
         + ParameterDivision1D<Point = Point3>
         + SearchNearestParameter<D1, Point = Point3>
         + TryFrom<PCurve<Line<Point2>, S>>,
-    S: ParametricSurface3D, {
+    S: ParametricSurface3D,
+{
     let to_poly = closure_to_poly(tol);
     let mut poly_edges: Vec<_> = shell.edges.iter().map(to_poly).collect();
     let len = shell.faces.len();
@@ -17,9 +22,24 @@ where
         split_face_with_non_closed_boundary(i, shell, &mut poly_edges, &sp, tol);
     });
     let len = shell.faces.len();
-    let closure = |i| split_face_with_non_simple_wire(i, shell, &mut poly_edges, &sp, tol);
-    let new_faces: Vec<_> = (0..len).filter_map(closure).flatten().collect();
-    shell.faces.extend(new_faces);
+    let mut origins = Vec::new();
+    // The divided faces are split again, e.g. a torus face is divided into two cylindrical faces,
+    // both of which keep the seam.
+    let mut i = 0;
+    while i < shell.faces.len() {
+        match split_face_with_non_simple_wire(i, shell, &mut poly_edges, &sp, tol) {
+            Some(new_faces) => {
+                let origin = match i < len {
+                    true => i,
+                    false => origins[i - len],
+                };
+                origins.extend(std::iter::repeat(origin).take(new_faces.len()));
+                shell.faces.extend(new_faces);
+            }
+            None => i += 1,
+        }
+    }
+    origins
 }
 
 fn split_face_with_non_closed_boundary<C, S>(
@@ -42,12 +62,17 @@ where
         non_closed_wires_in_param_divisor(face_index, shell, poly_edges, &sp)?;
     debug_assert_eq!(open.len(), 2);
     take_vertices_to_intersections(divisor, face_index, shell, poly_edges, &sp, tol);
-    let Shell { faces, edges, .. } = shell;
+    let Shell {
+        faces,
+        edges,
+        vertices,
+    } = shell;
     let new_boundaries = split_boundaries_by_divisor(
         &faces[face_index],
         &closed,
         divisor,
         edges,
+        vertices,
         poly_edges,
         &sp,
         tol,
@@ -106,7 +131,7 @@ fn non_simple_wire_divisor<C>(
 
     let (k0, k1) = ((j0 + 1) / 2, (i1 + j1 + 1) / 2);
     let f = closure_take_front(edges);
-    let pre_divisor = (f(boundary[k0]), f(boundary[k1]));
+    let pre_divisor = (f(boundary[k0]), f(boundary[k1 % boundary.len()]));
     nearest_correction(
         pre_divisor,
         &boundary[1..j0],
@@ -243,6 +268,7 @@ where
         &face.boundaries,
         divisor,
         &mut shell.edges,
+        &mut shell.vertices,
         poly_edges,
         &sp,
         tol,
@@ -283,23 +309,24 @@ where
         ..
     } = faces[face_index];
     let param_boundaries = create_param_boundaries(boundaries, surface, poly_edges, &sp)?;
+    let fronts = front_parameters(&param_boundaries, surface);
     let (v0, v1) = divisor;
-    let p = zip_boundaries(boundaries, &param_boundaries)
-        .find_map(closure_find_vertex_parameter(v0, edges))?;
-    let q = zip_boundaries(boundaries, &param_boundaries)
-        .find_map(closure_find_vertex_parameter(v1, edges))?;
+    let p = find_vertex_parameter(v0, boundaries, &fronts, edges)?;
+    let q = find_vertex_parameter(v1, boundaries, &fronts, edges)?;
     let Face {
         ref boundaries,
         ref surface,
         ..
     } = faces[face_index];
     let periods = (surface.u_period(), surface.v_period());
-    let q = periodic_iterator(q, periods)
-        .min_by(|q, r| p.distance2(*q).partial_cmp(&p.distance2(*r)).unwrap())?;
+    let q = divisor_end(p, q, v0 == v1, periods, &param_boundaries)?;
     let pcurve = PCurve::new(Line(p, q), surface.clone());
     let cut_edge = |(edge_index, param_edge): ZippedEdge<'_>| {
         let index = edge_index.index;
-        let vec = enumerate_intersections(&edges[index], param_edge, &pcurve)?;
+        let mut vec = enumerate_intersections(&edges[index], param_edge, &pcurve)?;
+        // The intersections at the ends, e.g. at the poles, do not cut the edge.
+        let (w0, w1) = edges[index].vertices;
+        vec.retain(|(_, p)| !p.near(&vertices[w0]) && !p.near(&vertices[w1]));
         if vec.is_empty() {
             return Some(None);
         }
@@ -319,15 +346,18 @@ where
     Some(())
 }
 
-fn closure_find_vertex_parameter<C>(
+fn find_vertex_parameter<C>(
     v: usize,
+    boundaries: &[Wire],
+    fronts: &[Point2],
     edges: &[Edge<C>],
-) -> impl Fn(ZippedEdge<'_>) -> Option<Point2> + '_ {
+) -> Option<Point2> {
     let take_front = closure_take_front(edges);
-    move |(edge_index, param_edge): ZippedEdge<'_>| match v == take_front(*edge_index) {
-        true => Some(param_edge[0]),
+    let closure = |(edge_index, uv): (&EdgeIndex, &Point2)| match v == take_front(*edge_index) {
+        true => Some(*uv),
         false => None,
-    }
+    };
+    boundaries.iter().flatten().zip(fronts).find_map(closure)
 }
 
 fn enumerate_intersections<C, S>(
@@ -485,6 +515,7 @@ fn split_boundaries_by_divisor<C, S>(
     closed: &[Wire],
     divisor: (usize, usize),
     edges: &mut Vec<Edge<C>>,
+    vertices: &mut Vec<Point3>,
     poly_edges: &mut Vec<PolylineCurve<Point3>>,
     sp: impl SP<S>,
     tol: f64,
@@ -494,15 +525,50 @@ where
     S: ParametricSurface3D,
 {
     let param_boundaries = create_param_boundaries(boundaries, surface, poly_edges, &sp)?;
-    let param_vertices = create_param_vertices(boundaries, &param_boundaries, edges);
+    let param_vertices = create_param_vertices(boundaries, &param_boundaries, edges, surface);
+    let mut params = create_param_ends(boundaries, &param_boundaries);
     let mut duplicated_edges = duplicated_edges(boundaries.iter().flatten().map(|ei| ei.index));
-    let vertices_on_divisor = enumerate_vertices_on_divisor(divisor, &param_vertices, surface)?;
-    let new_edges = create_new_edges(&vertices_on_divisor, poly_edges, surface, tol)?;
+    let mut vertices_on_divisor =
+        enumerate_vertices_on_divisor(divisor, &param_vertices, &param_boundaries, surface)?;
+    let closed_divisor = divisor.0 == divisor.1;
+    if closed_divisor {
+        // The closed divisor is divided at its middle, since the edges must not be closed.
+        if vertices_on_divisor.len() != 2 {
+            return None;
+        }
+        let uv = vertices_on_divisor[0]
+            .1
+             .1
+            .midpoint(vertices_on_divisor[1].1 .1);
+        vertices.push(surface.subs(uv.x, uv.y));
+        vertices_on_divisor.insert(1, (0.5, (vertices.len() - 1, uv)));
+    }
+    let new_edges = create_new_edges(
+        &vertices_on_divisor,
+        closed_divisor,
+        poly_edges,
+        surface,
+        tol,
+    )?;
+    let (new_edges, new_params): (Vec<_>, Vec<_>) = new_edges.into_iter().unzip();
     let (new_edge_range, new_edge_indices) = signup_new_edges(edges, new_edges);
+    new_edge_range
+        .clone()
+        .zip(new_params)
+        .for_each(|(index, (uv0, uv1))| {
+            let ei = |orientation: bool| EdgeIndex { index, orientation };
+            params.insert(ei(true), (uv0, uv1));
+            params.insert(ei(false), (uv1, uv0));
+        });
     duplicated_edges.extend(new_edge_range);
     let edge_iter = closed.iter().flatten().copied().chain(new_edge_indices);
     let vemap = create_vemap(edges, edge_iter);
-    Some(construct_boundaries(vemap, edges, &duplicated_edges))
+    Some(construct_boundaries(
+        vemap,
+        edges,
+        &duplicated_edges,
+        &params,
+    ))
 }
 
 // --- create_param_boundaries ---
@@ -539,7 +605,9 @@ fn boundary_to_param_polys<S: ParametricSurface3D>(
         long_poly = latter;
         PolylineCurve(res)
     };
-    Some(poly_boundary.iter().map(split_into_2dpoly).collect())
+    let mut param_polys: Vec<_> = poly_boundary.iter().map(split_into_2dpoly).collect();
+    walk_around_poles(&mut param_polys, surface);
+    Some(param_polys)
 }
 
 struct PolyWireIter<'a, P> {
@@ -578,7 +646,9 @@ fn poly_project_to_uv<'a, S: ParametricSurface3D>(
     let (up, vp) = (surface.u_period(), surface.v_period());
     let mut previous = None;
     move |pt| {
-        let (mut u, mut v) = sp(surface, pt, previous)?;
+        // The poles are not used as the hints, since the derivations there are degenerate.
+        let hint = previous.filter(|&(u, v)| pole_line(surface, Point2::new(u, v)).is_none());
+        let (mut u, mut v) = sp(surface, pt, hint)?;
         if let (Some(up), Some((u0, _))) = (up, previous) {
             u = get_mindiff(u, u0, up);
         }
@@ -620,16 +690,134 @@ fn boundary_into_domain<S: ParametricSurface3D>(vec: &mut Vec<Point2>, surface:
     }
 }
 
+/// Returns the coordinate which is not determined at the singular point `uv` of the surface,
+/// e.g. `Some(1)` at the poles of spheres.
+///
+/// The derivations are compared with zero by the tolerance of the current context,
+/// cf. `with_tolerance`.
+fn pole_line<S: ParametricSurface3D>(surface: &S, uv: Point2) -> Option<usize> {
+    match (
+        surface.uder(uv.x, uv.y).so_small(),
+        surface.vder(uv.x, uv.y).so_small(),
+    ) {
+        (true, _) => Some(0),
+        (_, true) => Some(1),
+        _ => None,
+    }
+}
+
+/// Connects the parameter polylines of the edges at the poles by the walks along the pole lines.
+///
+/// The edges after the walks are shifted, so the polygon is closed if and only if the boundary
+/// does not go around the pole.
+fn walk_around_poles<S: ParametricSurface3D>(polys: &mut [PolylineCurve<Point2>], surface: &S) {
+    let periods = [surface.u_period(), surface.v_period()];
+    // The undetermined coordinates of the poles are taken from the adjacent points.
+    polys.iter_mut().for_each(|PolylineCurve(poly)| {
+        let n = poly.len();
+        if let Some(k) = pole_line(surface, poly[0]) {
+            poly[0][k] = poly[1][k];
+        }
+        if let Some(k) = pole_line(surface, poly[n - 1]) {
+            poly[n - 1][k] = poly[n - 2][k];
+        }
+    });
+    let len = polys.len();
+    for i in 0..len {
+        let next = (i + 1) % len;
+        let n = polys[i].len();
+        let a = polys[i][n - 1];
+        let Some(k) = pole_line(surface, a) else {
+            continue;
+        };
+        let Some(period) = periods[k] else {
+            continue;
+        };
+        let b = polys[next][0];
+        // The face is on the left of the walk.
+        let (d, e) = (a - polys[i][n - 2], polys[next][1] - b);
+        let sign = match k {
+            0 => match d.y.so_small() {
+                true => e.y,
+                false => -d.y,
+            },
+            _ => match d.x.so_small() {
+                true => -e.x,
+                false => d.x,
+            },
+        };
+        let s = f64::signum(sign);
+        let mut r = ((b[k] - a[k]) * s).rem_euclid(period);
+        if r.so_small() || (period - r).so_small() {
+            r = period;
+        }
+        let mut w = a;
+        w[k] = a[k] + s * r;
+        let shift = w - b;
+        if next != 0 {
+            polys[next..]
+                .iter_mut()
+                .flat_map(|PolylineCurve(poly)| poly.iter_mut())
+                .for_each(|p| *p += shift);
+        }
+        polys[i].push(w);
+    }
+}
+
 // --- create_param_vertices ---
 
-fn create_param_vertices<C>(
+fn create_param_vertices<C, S: ParametricSurface3D>(
     boundaries: &[Wire],
     param_boundaries: &[Vec<PolylineCurve<Point2>>],
     edges: &[Edge<C>],
+    surface: &S,
 ) -> HashMap<usize, Point2> {
     let take_front = closure_take_front(edges);
+    let fronts = front_parameters(param_boundaries, surface);
+    boundaries
+        .iter()
+        .flatten()
+        .zip(fronts)
+        .map(|(edge_index, uv)| (take_front(*edge_index), uv))
+        .collect()
+}
+
+/// Returns the parameters of the fronts of the edges, in the order of `zip_boundaries`.
+///
+/// The parameter of a pole is the midpoint of the walk along the pole line.
+fn front_parameters<S: ParametricSurface3D>(
+    param_boundaries: &[Vec<PolylineCurve<Point2>>],
+    surface: &S,
+) -> Vec<Point2> {
+    param_boundaries
+        .iter()
+        .flat_map(|param_boundary| {
+            let len = param_boundary.len();
+            (0..len).map(move |i| {
+                let front = param_boundary[i][0];
+                let previous = &param_boundary[(i + len - 1) % len];
+                let n = previous.len();
+                let walked = n > 2 && pole_line(surface, previous[n - 2]).is_some();
+                match pole_line(surface, front).is_some() && walked {
+                    true => previous[n - 2].midpoint(previous[n - 1]),
+                    false => front,
+                }
+            })
+        })
+        .collect()
+}
+
+// --- create_param_ends ---
+
+fn create_param_ends(
+    boundaries: &[Wire],
+    param_boundaries: &[Vec<PolylineCurve<Point2>>],
+) -> HashMap<EdgeIndex, (Point2, Point2)> {
     zip_boundaries(boundaries, param_boundaries)
-        .map(|(edge_index, param_edge)| (take_front(*edge_index), param_edge[0]))
+        .map(|(edge_index, param_edge)| {
+            let ends = (param_edge[0], *param_edge.last().unwrap());
+            (*edge_index, ends)
+        })
         .collect()
 }
 
@@ -645,20 +833,27 @@ fn duplicated_edges(edges: impl Iterator<Item = usize>) -> HashSet<usize> {
 fn enumerate_vertices_on_divisor<S: ParametricSurface>(
     divisor: (usize, usize),
     param_vertices: &HashMap<usize, Point2>,
+    param_boundaries: &[Vec<PolylineCurve<Point2>>],
     surface: &S,
 ) -> Option<Vec<(f64, (usize, Point2))>> {
     let (v0, v1) = divisor;
     let (p, q) = (*param_vertices.get(&v0)?, *param_vertices.get(&v1)?);
     let periods = (surface.u_period(), surface.v_period());
-    let q = periodic_iterator(q, periods)
-        .min_by(|q, r| p.distance2(*q).partial_cmp(&p.distance2(*r)).unwrap())?;
+    let closed = v0 == v1;
+    let q = divisor_end(p, q, closed, periods, param_boundaries)?;
     let line = Line(p, q);
     let iter = param_vertices.iter().filter_map(move |(v, uv)| {
+        if closed && *v == v0 {
+            return None;
+        }
         periodic_iterator(*uv, periods)
             .find(move |uv| line.distance_to_point_as_segment(*uv).so_small())
             .map(move |uv| Some((line.search_nearest_parameter(uv, None, 1)?, (*v, uv))))
     });
     let mut vertices_on_divisor = iter.collect::<Option<Vec<_>>>()?;
+    if closed {
+        vertices_on_divisor.extend([(0.0, (v0, p)), (1.0, (v0, q))]);
+    }
     vertices_on_divisor.sort_by(|(s, _), (t, _)| s.partial_cmp(t).unwrap());
     Some(vertices_on_divisor)
 }
@@ -667,10 +862,11 @@ fn enumerate_vertices_on_divisor<S: ParametricSurface>(
 
 fn create_new_edges<C, S>(
     vertices_on_divisor: &[(f64, (usize, Point2))],
+    closed: bool,
     poly_edges: &mut Vec<PolylineCurve<Point3>>,
     surface: &S,
     tol: f64,
-) -> Option<Vec<Edge<C>>>
+) -> Option<Vec<(Edge<C>, (Point2, Point2))>>
 where
     C: TryFrom<PCurve<Line<Point2>, S>>,
     S: ParametricSurface3D,
@@ -683,12 +879,17 @@ where
         let ((_, (v0, uv0)), (_, (v1, uv1))) = (p[0], p[1]);
         let pcurve = PCurve::new(Line(uv0, uv1), surface.clone());
         poly_edges.push(PolylineCurve::from_curve(&pcurve, (0.0, 1.0), tol));
-        Some(Edge {
+        let edge = Edge {
             vertices: (v0, v1),
             curve: C::try_from(pcurve).ok()?,
-        })
+        };
+        Some((edge, (uv0, uv1)))
     };
-    vertices_on_divisor.chunks(2).map(make_edge).collect()
+    // The closed divisor lies inside the face entirely.
+    match closed {
+        true => vertices_on_divisor.windows(2).map(make_edge).collect(),
+        false => vertices_on_divisor.chunks(2).map(make_edge).collect(),
+    }
 }
 
 // --- signup new edges ---
@@ -727,37 +928,69 @@ fn construct_boundaries<C>(
     mut vemap: HashMap<usize, Vec<EdgeIndex>>,
     edges: &[Edge<C>],
     new_edge_range: &HashSet<usize>,
+    params: &HashMap<EdgeIndex, (Point2, Point2)>,
 ) -> Vec<Wire> {
     let take_back = closure_take_back(edges);
+    let is_new = |edge_index: EdgeIndex| new_edge_range.contains(&edge_index.index);
+    // The vertices passed many times, e.g. the end of a closed divisor, are distinguished
+    // by their copies in the parameter space.
+    let multiple: HashSet<usize> = vemap
+        .iter()
+        .filter(|(_, vec)| vec.len() > 2)
+        .map(|(v, _)| *v)
+        .collect();
+    let connected = |e0: EdgeIndex, e1: EdgeIndex| match (params.get(&e0), params.get(&e1)) {
+        (Some((_, p)), Some((q, _))) => p.distance2(*q) < 1.0e-3,
+        _ => true,
+    };
     let mut new_boundaries = Vec::new();
     while let Some((start, vec)) = vemap.iter_mut().next() {
         let start = *start;
-        let mut edge_index = vec.pop().unwrap();
+        let first = vec.pop().unwrap();
         if vec.is_empty() {
             vemap.remove(&start);
         }
+        let mut edge_index = first;
         let mut wire = Vec::new();
         loop {
             wire.push(edge_index);
             let v = take_back(edge_index);
-            if v == start {
+            if v == start && (!multiple.contains(&v) || connected(edge_index, first)) {
                 break;
             }
             let Some(vec) = vemap.get_mut(&v) else {
                 unreachable!();
             };
-            match vec.len() {
-                1 => {
-                    edge_index = vec.pop().unwrap();
-                    vemap.remove(&v);
-                }
-                2 => {
-                    let is_new0 = new_edge_range.contains(&edge_index.index);
-                    let is_new1 = new_edge_range.contains(&vec[0].index);
-                    let i = if is_new0 != is_new1 { 0 } else { 1 };
-                    edge_index = vec.remove(i);
+            let candidates: Vec<usize> = match multiple.contains(&v) {
+                true => (0..vec.len())
+                    .filter(|i| connected(edge_index, vec[*i]))
+                    .collect(),
+                false => (0..vec.len()).collect(),
+            };
+            let i = match (candidates.len(), multiple.contains(&v)) {
+                (1, _) => candidates[0],
+                // The face is on the left, so the sharpest left turn is taken, e.g. from a seam
+                // to the closed divisor ending on it.
+                (_, true) => leftmost_turn(edge_index, &candidates, vec, params),
+                (2, false) => {
+                    let (e0, e1) = (vec[candidates[0]], vec[candidates[1]]);
+                    match (is_new(e0), is_new(e1)) {
+                        // the middle of a closed divisor
+                        (true, true) => match e0.index == edge_index.index {
+                            true => candidates[1],
+                            false => candidates[0],
+                        },
+                        _ => match is_new(edge_index) != is_new(e0) {
+                            true => candidates[0],
+                            false => candidates[1],
+                        },
+                    }
                 }
                 _ => panic!("something wrong!"),
+            };
+            edge_index = vec.remove(i);
+            if vec.is_empty() {
+                vemap.remove(&v);
             }
         }
         new_boundaries.push(wire);
@@ -765,6 +998,24 @@ fn construct_boundaries<C>(
     new_boundaries
 }
 
+fn leftmost_turn(
+    edge_index: EdgeIndex,
+    candidates: &[usize],
+    vec: &[EdgeIndex],
+    params: &HashMap<EdgeIndex, (Point2, Point2)>,
+) -> usize {
+    let chord = |edge_index: EdgeIndex| params.get(&edge_index).map(|(p, q)| q - p);
+    let Some(dir) = chord(edge_index) else {
+        return candidates[0];
+    };
+    let angle = |i: &usize| match (vec[*i].index == edge_index.index, chord(vec[*i])) {
+        (false, Some(next)) => f64::atan2(dir.perp_dot(next), dir.dot(next)),
+        _ => f64::NEG_INFINITY,
+    };
+    let cmp = |i: &usize, j: &usize| angle(i).partial_cmp(&angle(j)).unwrap();
+    candidates.iter().copied().max_by(cmp).unwrap()
+}
+
 // --- divide_face ---
 
 fn divide_face<S: ParametricSurface3D>(
@@ -951,13 +1202,61 @@ fn periodic_iterator(
     itertools::iproduct!(up_range, vp_range).map(move |(dx, dy)| p + Vector2::new(dx, dy))
 }
 
+/// Returns the copy of `q` at which the divisor from `p` ends.
+///
+/// The nearest copy through the inside of the face is taken, e.g. the copies of the both sides
+/// of a cylindrical band on a torus are equidistant. A closed divisor, i.e. `p` and `q` are
+/// the parameters of the same vertex, goes around the period through the inside of the face.
+fn divisor_end(
+    p: Point2,
+    q: Point2,
+    closed: bool,
+    periods: (Option<f64>, Option<f64>),
+    param_boundaries: &[Vec<PolylineCurve<Point2>>],
+) -> Option<Point2> {
+    let cmp = |q: &Point2, r: &Point2| p.distance2(*q).partial_cmp(&p.distance2(*r)).unwrap();
+    let inside = periodic_iterator(q, periods)
+        .filter(|r| !r.near(&p) && inside_face(p.midpoint(*r), param_boundaries))
+        .min_by(cmp);
+    match closed {
+        true => inside,
+        false => inside.or_else(|| periodic_iterator(q, periods).min_by(cmp)),
+    }
+}
+
+/// Returns whether `uv` is strictly inside the domain bounded by the parameter polylines.
+fn inside_face(uv: Point2, param_boundaries: &[Vec<PolylineCurve<Point2>>]) -> bool {
+    let segments = || {
+        param_boundaries
+            .iter()
+            .flatten()
+            .flat_map(|poly| poly.windows(2))
+    };
+    let on_boundary = segments().any(|seg| {
+        Line(seg[0], seg[1])
+            .distance_to_point_as_segment(uv)
+            .so_small()
+    });
+    let crossings = segments()
+        .filter(|seg| {
+            let (a, b) = (seg[0], seg[1]);
+            (a.y > uv.y) != (b.y > uv.y) && uv.x < a.x + (uv.y - a.y) * (b.x - a.x) / (b.y - a.y)
+        })
+        .count();
+    !on_boundary && crossings % 2 == 1
+}
+
 fn nearest_correction(
-    (v0, _v1): (usize, usize),
+    (v0, v1): (usize, usize),
     wire0: &[EdgeIndex],
     wire1: &[EdgeIndex],
     vertices: &[Point3],
     take_front: impl Fn(EdgeIndex) -> usize,
 ) -> Option<(usize, usize)> {
+    // An empty side shrinks to a pole, from which all vertices on the other side are equidistant.
+    if wire0.is_empty() || wire1.is_empty() {
+        return Some((v0, v1));
+    }
     let p0 = vertices[v0];
     let v1 = wire1.iter().copied().map(&take_front).min_by(|v, w| {
         p0.distance2(vertices[*v])
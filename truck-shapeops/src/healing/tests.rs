@@ -1052,6 +1052,360 @@ fn too_simple_cylinder() {
     );
 }
 
+#[test]
+fn sphere_with_one_seam() {
+    #[derive(
+        Clone,
+        Debug,
+        ParametricCurve,
+        BoundedCurve,
+        ParameterDivision1D,
+        Cut,
+        SearchNearestParameterD1,
+    )]
+    enum Curve {
+        Arc(TrimmedCurve<Processor<UnitCircle<Point3>, Matrix4>>),
+        PCurve(PCurve<Line<Point2>, Sphere>),
+    }
+    impl From<PCurve<Line<Point2>, Sphere>> for Curve {
+        fn from(value: PCurve<Line<Point2>, Sphere>) -> Self { Curve::PCurve(value) }
+    }
+
+    let vertices = vec![Point3::new(0.0, 0.0, -1.0), Point3::new(0.0, 0.0, 1.0)];
+
+    let rotation = Matrix4::from_angle_x(Rad(PI / 2.0));
+    let meridian = TrimmedCurve::new(
+        Processor::new(UnitCircle::new()).transformed(rotation),
+        (-PI / 2.0, PI / 2.0),
+    );
+    let edges = vec![CompressedEdge {
+        vertices: (0, 1),
+        curve: Curve::Arc(meridian),
+    }];
+
+    let surface = Sphere::new(Point3::origin(), 1.0);
+    let faces = vec![CompressedFace {
+        boundaries: vec![vec![
+            CompressedEdgeIndex {
+                index: 0,
+                orientation: true,
+            },
+            CompressedEdgeIndex {
+                index: 0,
+                orientation: false,
+            },
+        ]],
+        surface,
+        orientation: true,
+    }];
+
+    let mut shell = CompressedShell {
+        vertices,
+        edges,
+        faces,
+    };
+
+    split_closed_edges(&mut shell);
+    split_closed_faces(&mut shell, 0.01, sp);
+    assert!(Shell::extract(shell.clone()).is_ok());
+
+    let CompressedShell {
+        ref vertices,
+        ref edges,
+        ref faces,
+    } = shell;
+
+    assert_eq!(vertices.len(), 2);
+    assert_eq!(edges.len(), 2);
+    let (v0, v1) = edges[1].vertices;
+    assert!(v0 != v1 && v0 < 2 && v1 < 2);
+    assert_near!(edges[1].curve.subs(0.5), Point3::new(-1.0, 0.0, 0.0));
+
+    assert_eq!(faces.len(), 2);
+    faces.iter().for_each(|face| {
+        assert_eq!(face.boundaries.len(), 1);
+        let mut indices: Vec<_> = face.boundaries[0].iter().map(|ei| ei.index).collect();
+        indices.sort();
+        assert_eq!(indices, vec![0, 1]);
+    });
+}
+
+#[test]
+fn cone_with_apex() {
+    #[derive(
+        Clone,
+        Debug,
+        ParametricCurve,
+        BoundedCurve,
+        ParameterDivision1D,
+        Cut,
+        SearchNearestParameterD1,
+    )]
+    enum Curve {
+        Line(Line<Point3>),
+        Arc(TrimmedCurve<Processor<UnitCircle<Point3>, Matrix4>>),
+        PCurve(PCurve<Line<Point2>, Surface>),
+    }
+    impl From<PCurve<Line<Point2>, Surface>> for Curve {
+        fn from(value: PCurve<Line<Point2>, Surface>) -> Self { Curve::PCurve(value) }
+    }
+    type Surface = RevolutedCurve<Line<Point3>>;
+
+    let vertices = vec![Point3::new(0.0, 0.0, 1.0), Point3::new(1.0, 0.0, 0.0)];
+
+    let circle = TrimmedCurve::new(Processor::new(UnitCircle::new()), (0.0, 2.0 * PI));
+    let edges = vec![
+        CompressedEdge {
+            vertices: (0, 1),
+            curve: Curve::Line(Line(vertices[0], vertices[1])),
+        },
+        CompressedEdge {
+            vertices: (1, 1),
+            curve: Curve::Arc(circle),
+        },
+    ];
+
+    let surface = RevolutedCurve::by_revolution(
+        Line(vertices[0], vertices[1]),
+        Point3::origin(),
+        Vector3::unit_z(),
+    );
+    let faces = vec![CompressedFace {
+        boundaries: vec![vec![
+            CompressedEdgeIndex {
+                index: 0,
+                orientation: true,
+            },
+            CompressedEdgeIndex {
+                index: 1,
+                orientation: true,
+            },
+            CompressedEdgeIndex {
+                index: 0,
+                orientation: false,
+            },
+        ]],
+        surface,
+        orientation: true,
+    }];
+
+    let mut shell = CompressedShell {
+        vertices,
+        edges,
+        faces,
+    };
+
+    split_closed_edges(&mut shell);
+    split_closed_faces(&mut shell, 0.01, sp);
+    assert!(Shell::extract(shell.clone()).is_ok());
+
+    let CompressedShell {
+        ref vertices,
+        ref edges,
+        ref faces,
+    } = shell;
+
+    assert_eq!(vertices.len(), 3);
+    assert_near!(vertices[2], Point3::new(-1.0, 0.0, 0.0));
+    assert_eq!(edges.len(), 4);
+    assert!(edges.iter().all(|edge| edge.vertices.0 != edge.vertices.1));
+    assert_eq!(faces.len(), 2);
+    faces.iter().for_each(|face| {
+        assert_eq!(face.boundaries.len(), 1);
+        assert_eq!(face.boundaries[0].len(), 3);
+    });
+}
+
+#[test]
+fn torus_with_two_seams() {
+    #[derive(
+        Clone,
+        Debug,
+        ParametricCurve,
+        BoundedCurve,
+        ParameterDivision1D,
+        Cut,
+        SearchNearestParameterD1,
+    )]
+    enum Curve {
+        Arc(TrimmedCurve<Processor<UnitCircle<Point3>, Matrix4>>),
+        PCurve(PCurve<Line<Point2>, Torus>),
+    }
+    impl From<PCurve<Line<Point2>, Torus>> for Curve {
+        fn from(value: PCurve<Line<Point2>, Torus>) -> Self { Curve::PCurve(value) }
+    }
+
+    let vertices = vec![Point3::new(3.0, 0.0, 0.0)];
+
+    let large = Matrix4::from_scale(3.0);
+    let small = Matrix4::from_translation(Vector3::new(2.0, 0.0, 0.0))
+        * Matrix4::from_angle_x(Rad(PI / 2.0));
+    let edges = vec![
+        CompressedEdge {
+            vertices: (0, 0),
+            curve: Curve::Arc(TrimmedCurve::new(
+                Processor::new(UnitCircle::new()).transformed(large),
+                (0.0, 2.0 * PI),
+            )),
+        },
+        CompressedEdge {
+            vertices: (0, 0),
+            curve: Curve::Arc(TrimmedCurve::new(
+                Processor::new(UnitCircle::new()).transformed(small),
+                (0.0, 2.0 * PI),
+            )),
+        },
+    ];
+
+    let surface = Torus::new(Point3::origin(), 2.0, 1.0);
+    let faces = vec![CompressedFace {
+        boundaries: vec![vec![
+            CompressedEdgeIndex {
+                index: 0,
+                orientation: true,
+            },
+            CompressedEdgeIndex {
+                index: 1,
+                orientation: true,
+            },
+            CompressedEdgeIndex {
+                index: 0,
+                orientation: false,
+            },
+            CompressedEdgeIndex {
+                index: 1,
+                orientation: false,
+            },
+        ]],
+        surface,
+        orientation: true,
+    }];
+
+    let mut shell = CompressedShell {
+        vertices,
+        edges,
+        faces,
+    };
+
+    split_closed_edges(&mut shell);
+    split_closed_faces(&mut shell, 0.01, sp);
+    assert!(Shell::extract(shell.clone()).is_ok());
+
+    let CompressedShell {
+        ref vertices,
+        ref edges,
+        ref faces,
+    } = shell;
+
+    assert_eq!(vertices.len(), 4);
+    assert_near!(vertices[1], Point3::new(-3.0, 0.0, 0.0));
+    assert_near!(vertices[2], Point3::new(1.0, 0.0, 0.0));
+    assert_near!(vertices[3], Point3::new(-1.0, 0.0, 0.0));
+    assert_eq!(edges.len(), 8);
+    assert!(edges.iter().all(|edge| edge.vertices.0 != edge.vertices.1));
+    // The seams of the both cylindrical faces join the inner and the outer equators.
+    assert_eq!(edges[6].vertices, (3, 1));
+    assert_eq!(edges[7].vertices, (3, 1));
+    let (p, q) = (edges[6].curve.subs(0.5), edges[7].curve.subs(0.5));
+    assert!(!p.near(&q));
+    assert_near!(p.midpoint(q), Point3::new(-2.0, 0.0, 0.0));
+
+    assert_eq!(faces.len(), 4);
+    faces.iter().for_each(|face| {
+        assert_eq!(face.boundaries.len(), 1);
+        assert_eq!(face.boundaries[0].len(), 4);
+    });
+}
+
+#[test]
+fn too_simple_cylinder_history() {
+    #[derive(
+        Clone,
+        Debug,
+        ParametricCurve,
+        BoundedCurve,
+        ParameterDivision1D,
+        Cut,
+        SearchNearestParameterD1,
+    )]
+    enum Curve {
+        Arc(TrimmedCurve<Processor<UnitCircle<Point3>, Matrix4>>),
+        PCurve(PCurve<Line<Point2>, Surface>),
+    }
+    impl From<PCurve<Line<Point2>, Surface>> for Curve {
+        fn from(value: PCurve<Line<Point2>, Surface>) -> Self { Curve::PCurve(value) }
+    }
+    type Surface = RevolutedCurve<Line<Point3>>;
+
+    let vertices = vec![Point3::new(1.0, 0.0, 0.0), Point3::new(1.0, 0.0, 1.0)];
+
+    let translation = Matrix4::from_translation(Vector3::unit_z());
+    let circle0 = TrimmedCurve::new(Processor::new(UnitCircle::new()), (0.0, 2.0 * PI));
+    let circle1 = TrimmedCurve::new(
+        Processor::new(UnitCircle::new()).transformed(translation),
+        (0.0, 2.0 * PI),
+    );
+    let edges = vec![
+        CompressedEdge {
+            vertices: (0, 0),
+            curve: Curve::Arc(circle0),
+        },
+        CompressedEdge {
+            vertices: (1, 1),
+            curve: Curve::Arc(circle1),
+        },
+    ];
+
+    let surface = RevolutedCurve::by_revolution(
+        Line(vertices[1], vertices[0]),
+        Point3::origin(),
+        Vector3::unit_z(),
+    );
+    let faces = vec![CompressedFace {
+        boundaries: vec![
+            vec![CompressedEdgeIndex {
+                index: 0,
+                orientation: true,
+            }],
+            vec![CompressedEdgeIndex {
+                index: 1,
+                orientation: false,
+            }],
+        ],
+        surface,
+        orientation: true,
+    }];
+
+    let mut shell = CompressedShell {
+        vertices,
+        edges,
+        faces,
+    };
+    let history = shell.split_closed_edges_and_faces_with_history(0.01);
+    assert!(Shell::extract(shell.clone()).is_ok());
+
+    use truck_topology::history::ShapeID;
+    assert_eq!(
+        history.modified(ShapeID::Face(0)),
+        &[ShapeID::Face(0), ShapeID::Face(1)],
+    );
+    assert_eq!(
+        history.modified(ShapeID::Edge(0)),
+        &[ShapeID::Edge(0), ShapeID::Edge(2)],
+    );
+    assert_eq!(
+        history.modified(ShapeID::Edge(1)),
+        &[ShapeID::Edge(1), ShapeID::Edge(3)],
+    );
+    assert_eq!(history.generated(ShapeID::Edge(0)), &[ShapeID::Vertex(2)]);
+    assert_eq!(history.generated(ShapeID::Edge(1)), &[ShapeID::Vertex(3)]);
+    assert_eq!(
+        history.generated(ShapeID::Face(0)),
+        &[ShapeID::Edge(4), ShapeID::Edge(5)],
+    );
+    assert!(history.modified(ShapeID::Vertex(0)).is_empty());
+}
+
 #[test]
 fn double_closed_boundary_cylinder() {
     #[derive(
@@ -1502,6 +1856,266 @@ fn many_closed_boundary_cylinder() {
     });
 }
 
+#[test]
+fn sew_partially_overlapping_faces() {
+    use truck_modeling::{builder, Face, Wire};
+    let rectangle = |points: [(f64, f64); 4]| -> Face {
+        let v: Vec<_> = points
+            .iter()
+            .map(|(x, y)| builder::vertex(Point3::new(*x, *y, 0.0)))
+            .collect();
+        let wire: Wire = (0..4)
+            .map(|i| builder::line(&v[i], &v[(i + 1) % 4]))
+            .collect();
+        builder::try_attach_plane(&[wire]).unwrap()
+    };
+    let faces = vec![
+        rectangle([(0.0, 0.0), (2.0, 0.0), (2.0, 1.0), (0.0, 1.0)]),
+        rectangle([(0.0, -1.0), (1.0, -1.0), (1.0, 0.0), (0.0, 0.0)]),
+        // the orientation is inconsistent with the others
+        rectangle([(1.0, -1.0), (1.0, 0.0), (2.0, 0.0), (2.0, -1.0)]),
+    ];
+    let (shell, report) = sew_with_report(faces, 1.0e-3).unwrap();
+    assert_eq!(report.merged_vertices, 4);
+    assert_eq!(report.split_edges, 1);
+    assert_eq!(report.merged_edges, 3);
+    assert_eq!(report.removed_edges, 0);
+    assert_eq!(report.inverted_faces, 1);
+    assert_eq!(report.free_edges.len(), 7);
+    assert_eq!(shell.len(), 3);
+    assert_eq!(
+        shell.shell_condition(),
+        truck_topology::shell::ShellCondition::Oriented
+    );
+}
+
+#[test]
+fn collapse_short_edges_and_reorient_faces() {
+    use truck_modeling::{builder, Face, Wire};
+    let v: Vec<_> = [
+        (0.0, 0.0),
+        (1.0, 0.0),
+        (1.0, 1.0),
+        (5.0e-4, 1.0),
+        (0.0, 1.0),
+        (2.0, 0.0),
+        (2.0, 1.0),
+    ]
+    .iter()
+    .map(|(x, y)| builder::vertex(Point3::new(*x, *y, 0.0)))
+    .collect();
+    let line = |i: usize, j: usize| builder::line(&v[i], &v[j]);
+    let wire0: Wire = vec![line(0, 1), line(1, 2), line(2, 3), line(3, 4), line(4, 0)].into();
+    // the orientation is inconsistent with the first face
+    let wire1: Wire = vec![wire0[1].clone(), line(2, 6), line(6, 5), line(5, 1)].into();
+    let faces: Vec<Face> = vec![
+        builder::try_attach_plane(&[wire0]).unwrap(),
+        builder::try_attach_plane(&[wire1]).unwrap(),
+    ];
+    let mut shell = truck_modeling::Shell::from(faces).compress();
+
+    let report = collapse_short_edges(&mut shell, 1.0e-3);
+    assert_eq!(report.collapsed_edges.len(), 1);
+    assert_eq!(report.merged_vertices.len(), 1);
+    assert_eq!(report.refitted_edges.len(), 2);
+    let (_, root) = report.merged_vertices[0];
+    assert_near!(shell.vertices[root], Point3::new(2.5e-4, 1.0, 0.0));
+    assert_eq!(shell.faces[0].boundaries[0].len(), 4);
+
+    let report = reorient_faces(&mut shell);
+    assert_eq!(report.inverted_faces, vec![1]);
+    let shell = Shell::extract(shell).unwrap();
+    assert_eq!(
+        shell.shell_condition(),
+        truck_topology::shell::ShellCondition::Oriented
+    );
+}
+
+#[test]
+fn close_gaps_between_edges() {
+    use truck_modeling::{builder, Solid};
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::unit_y());
+    let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+    let mut shell = cube.into_boundaries().pop().unwrap().compress();
+
+    // an edge is detached from the vertex 0 by a gap
+    let p = shell.vertices[0];
+    shell.vertices.push(p + Vector3::new(5.0e-4, 0.0, 0.0));
+    let adjacent: Vec<usize> = (0..shell.edges.len())
+        .filter(|i| shell.edges[*i].vertices.0 == 0 || shell.edges[*i].vertices.1 == 0)
+        .collect();
+    assert_eq!(adjacent.len(), 3);
+    let edge = &mut shell.edges[adjacent[0]];
+    match edge.vertices.0 == 0 {
+        true => edge.vertices.0 = 8,
+        false => edge.vertices.1 = 8,
+    }
+    assert!(Shell::extract(shell.clone()).is_err());
+
+    let report = close_gaps(&mut shell, 1.0e-3);
+    assert_eq!(report.merged_vertices, vec![(8, 0)]);
+    assert_near!(shell.vertices[0], p + Vector3::new(2.5e-4, 0.0, 0.0));
+    assert_eq!(report.refitted_edges, adjacent);
+    let shell = Shell::extract(shell).unwrap();
+    assert_eq!(
+        shell.shell_condition(),
+        truck_topology::shell::ShellCondition::Closed
+    );
+    assert!(with_tolerance(1.0e-3, || shell.is_geometric_consistent()));
+}
+
+#[test]
+fn remove_sliver_faces_of_thin_box() {
+    use truck_modeling::{builder, Solid};
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::unit_y());
+    let thin: Solid = builder::tsweep(&f, Vector3::new(0.0, 0.0, 1.0e-3));
+    let mut shell = thin.into_boundaries().pop().unwrap().compress();
+
+    let report = remove_sliver_faces(&mut shell, 1.0e-2);
+    assert_eq!(report.removed_faces.len(), 4);
+    assert_eq!(report.absorbed_edges.len(), 4);
+    // the vertical edges
+    assert_eq!(report.collapsed_edges.len(), 4);
+    assert_eq!(report.merged_vertices.len(), 4);
+    assert_eq!(report.refitted_edges.len(), 4);
+    assert_eq!(shell.faces.len(), 2);
+    shell.faces.iter().for_each(|face| {
+        assert_eq!(face.boundaries.len(), 1);
+        assert_eq!(face.boundaries[0].len(), 4);
+    });
+    let shell = Shell::extract(shell).unwrap();
+    assert_eq!(
+        shell.shell_condition(),
+        truck_topology::shell::ShellCondition::Closed
+    );
+    assert!(with_tolerance(1.0e-2, || shell.is_geometric_consistent()));
+}
+
+#[test]
+fn remove_sliver_face_with_spike() {
+    use truck_modeling::{builder, Face, Wire};
+    let v: Vec<_> = [
+        (0.0, 0.0),
+        (2.0, 0.0),
+        (1.0, 1.0e-3),
+        (0.0, -1.0),
+        (3.0, -1.0),
+        (3.0, 1.0),
+    ]
+    .iter()
+    .map(|(x, y)| builder::vertex(Point3::new(*x, *y, 0.0)))
+    .collect();
+    let line = |i: usize, j: usize| builder::line(&v[i], &v[j]);
+    let (edge10, edge21) = (line(1, 0), line(2, 1));
+    let wire0: Wire = vec![
+        line(0, 3),
+        line(3, 4),
+        line(4, 5),
+        line(5, 2),
+        edge21.clone(),
+        edge10.clone(),
+    ]
+    .into();
+    // the sliver between the edges from the vertex 2 to the vertex 0 through the vertex 1
+    let wire1: Wire = vec![edge10.inverse(), edge21.inverse(), line(2, 0)].into();
+    let faces: Vec<Face> = vec![
+        builder::try_attach_plane(&[wire0]).unwrap(),
+        builder::try_attach_plane(&[wire1]).unwrap(),
+    ];
+    let mut shell = truck_modeling::Shell::from(faces).compress();
+
+    let report = remove_sliver_faces(&mut shell, 1.0e-2);
+    assert_eq!(report.removed_faces, vec![1]);
+    assert_eq!(report.absorbed_edges, vec![5]);
+    assert!(report.merged_vertices.is_empty());
+    assert_eq!(shell.faces.len(), 1);
+    // the spike from the vertex 2 to the vertex 1 is removed
+    let wire = &shell.faces[0].boundaries[0];
+    assert_eq!(wire.len(), 5);
+    assert!(wire.iter().all(|edge| edge.index != 4));
+    let shell = Shell::extract(shell).unwrap();
+    assert!(shell.is_geometric_consistent());
+}
+
+#[test]
+fn sew_cylinder_halves_sharing_circle() {
+    use truck_modeling::{Curve, Edge, Face, Surface, Vertex, Wire};
+    let circle = |z: f64| {
+        let w = f64::sqrt(0.5);
+        let control_points = [
+            (1.0, 0.0, 1.0),
+            (1.0, 1.0, w),
+            (0.0, 1.0, 1.0),
+            (-1.0, 1.0, w),
+            (-1.0, 0.0, 1.0),
+            (-1.0, -1.0, w),
+            (0.0, -1.0, 1.0),
+            (1.0, -1.0, w),
+            (1.0, 0.0, 1.0),
+        ]
+        .iter()
+        .map(|(x, y, w)| Vector4::new(x * w, y * w, z * w, *w))
+        .collect::<Vec<_>>();
+        let knot_vec = KnotVec::from(vec![
+            0.0, 0.0, 0.0, 1.0, 1.0, 2.0, 2.0, 3.0, 3.0, 4.0, 4.0, 4.0,
+        ]);
+        Curve::NurbsCurve(NurbsCurve::new(BSplineCurve::new(knot_vec, control_points)))
+    };
+    let cylinder = |z0: f64, z1: f64| {
+        let line = Line(Point3::new(1.0, 0.0, z0), Point3::new(1.0, 0.0, z1));
+        let revoluted =
+            RevolutedCurve::by_revolution(Curve::Line(line), Point3::origin(), Vector3::unit_z());
+        Surface::RevolutedCurve(Processor::new(revoluted))
+    };
+    let closed_edge = |z: f64, curve: Curve| {
+        let v = Vertex::new(Point3::new(1.0, 0.0, z));
+        Edge::new_unchecked(&v, &v, curve)
+    };
+
+    let bottom = closed_edge(0.0, circle(0.0));
+    let middle0 = closed_edge(1.0, circle(1.0));
+    let lower = Face::new(
+        vec![
+            Wire::from(vec![bottom]),
+            Wire::from(vec![middle0.inverse()]),
+        ],
+        cylinder(0.0, 1.0),
+    );
+    // the same circle with the opposite parametrization
+    let middle1 = closed_edge(1.0, circle(1.0).inverse());
+    let top = closed_edge(2.0, circle(2.0));
+    let upper = Face::new(
+        vec![
+            Wire::from(vec![middle1.inverse()]),
+            Wire::from(vec![top.inverse()]),
+        ],
+        cylinder(1.0, 2.0),
+    );
+
+    let (shell, report) = sew_with_report(vec![lower, upper], 1.0e-3).unwrap();
+    assert_eq!(report.merged_vertices, 1);
+    assert_eq!(report.merged_edges, 1);
+    assert_eq!(report.inverted_faces, 0);
+    // the closed edges are split into two edges
+    assert_eq!(report.free_edges.len(), 4);
+    assert_eq!(shell.len(), 2);
+    assert_eq!(
+        shell
+            .edge_iter()
+            .filter(|edge| edge.front() == edge.back())
+            .count(),
+        0
+    );
+    assert_eq!(
+        shell.shell_condition(),
+        truck_topology::shell::ShellCondition::Oriented
+    );
+}
+
 fn sp<S>(surface: &S, p: Point3, hint: Option<(f64, f64)>) -> Option<(f64, f64)>
 where S: SearchParameter<D2, Point = Point3> {
     surface.search_parameter(p, hint, 10)
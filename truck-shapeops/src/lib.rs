@@ -14,7 +14,14 @@
 )]
 
 mod healing;
-pub use healing::{RobustSplitClosedEdgesAndFaces, SplitClosedEdgesAndFaces};
+pub use healing::{
+    close_gaps, collapse_short_edges, remove_sliver_faces, reorient_faces, sew, sew_with_report,
+    GapReport, OrientationReport, RobustSplitClosedEdgesAndFaces, SewingReport, ShortEdgeReport,
+    SliverReport, SplitClosedEdgesAndFaces,
+};
 mod transversal;
-pub use transversal::{and, or, ShapeOpsCurve, ShapeOpsSurface};
+pub use transversal::{
+    and, and_with_attributes, and_with_history, or, or_with_attributes, or_with_history,
+    ShapeOpsCurve, ShapeOpsSurface,
+};
 mod alternative;
@@ -154,11 +154,12 @@ where
                 .iter()
                 .all(|wire| wire.status() == ShapesOpStatus::Unknown)
             {
-                res.push(face.clone(), ShapesOpStatus::Unknown);
+                res.push_with_origin(face.clone(), ShapesOpStatus::Unknown, face.id());
             } else {
                 let vec = divide_one_face(face, loops, tol)?;
-                vec.into_iter()
-                    .for_each(|(face, status)| res.push(face, status));
+                vec.into_iter().for_each(|(new_face, status)| {
+                    res.push_with_origin(new_face, status, face.id())
+                });
             }
             Some(())
         })?;
@@ -6,6 +6,7 @@ use truck_topology::*;
 pub struct FacesClassification<P, C, S> {
     shell: Shell<P, C, S>,
     status: HashMap<FaceID<S>, ShapesOpStatus>,
+    origins: HashMap<FaceID<S>, FaceID<S>>,
 }
 
 impl<P, C, S> Default for FacesClassification<P, C, S> {
@@ -13,6 +14,7 @@ impl<P, C, S> Default for FacesClassification<P, C, S> {
         Self {
             shell: Default::default(),
             status: HashMap::default(),
+            origins: HashMap::default(),
         }
     }
 }
@@ -23,6 +25,18 @@ impl<P, C, S> FacesClassification<P, C, S> {
         self.shell.push(face);
     }
 
+    pub fn push_with_origin(
+        &mut self,
+        face: Face<P, C, S>,
+        status: ShapesOpStatus,
+        origin: FaceID<S>,
+    ) {
+        self.origins.insert(face.id(), origin);
+        self.push(face, status);
+    }
+
+    pub fn origins(&self) -> &HashMap<FaceID<S>, FaceID<S>> { &self.origins }
+
     pub fn and_or_unknown(&self) -> [Shell<P, C, S>; 3] {
         let [mut and, mut or, mut unknown] = <[Shell<P, C, S>; 3]>::default();
         for face in &self.shell {
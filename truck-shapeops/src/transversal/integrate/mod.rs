@@ -1,7 +1,8 @@
 use super::*;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 use truck_geometry::prelude::IntersectionCurve;
 use truck_meshalgo::prelude::*;
-use truck_topology::*;
+use truck_topology::{attributes::AttributeStore, history::*, *};
 
 /// Only solids consisting of faces whose surface is implemented this trait can be used for set operations.
 pub trait ShapeOpsSurface:
@@ -47,11 +48,14 @@ impl<C, S: ShapeOpsSurface> ShapeOpsCurve<S> for C where C: ParametricCurve3D
 {
 }
 
+/// The map from the ids of the result faces to the ids of the original faces.
+type FaceOrigins<S> = HashMap<FaceID<S>, FaceID<S>>;
+
 fn process_one_pair_of_shells<C: ShapeOpsCurve<S>, S: ShapeOpsSurface>(
     shell0: &Shell<Point3, C, S>,
     shell1: &Shell<Point3, C, S>,
     tol: f64,
-) -> Option<[Shell<Point3, C, S>; 2]> {
+) -> Option<([Shell<Point3, C, S>; 2], FaceOrigins<S>)> {
     nonpositive_tolerance!(tol);
     let poly_shell0 = shell0.triangulation(tol);
     let poly_shell1 = shell1.triangulation(tol);
@@ -64,6 +68,8 @@ fn process_one_pair_of_shells<C: ShapeOpsCurve<S>, S: ShapeOpsSurface>(
     cls0.integrate_by_component();
     let mut cls1 = divide_face::divide_faces(shell1, &loops_store1, tol)?;
     cls1.integrate_by_component();
+    let mut origins = cls0.origins().clone();
+    origins.extend(cls1.origins().iter().map(|(k, v)| (*k, *v)));
     let [mut and0, mut or0, unknown0] = cls0.and_or_unknown();
     unknown0.into_iter().try_for_each(|face| {
         let pt = face.boundaries()[0].vertex_iter().next().unwrap().point();
@@ -96,30 +102,186 @@ fn process_one_pair_of_shells<C: ShapeOpsCurve<S>, S: ShapeOpsSurface>(
     })?;
     and0.append(&mut and1);
     or0.append(&mut or1);
-    Some([and0, or0])
+    Some(([and0, or0], origins))
 }
 
-/// AND operation between two solids.
-pub fn and<C: ShapeOpsCurve<S>, S: ShapeOpsSurface>(
+fn and_or_with_origins<C: ShapeOpsCurve<S>, S: ShapeOpsSurface>(
     solid0: &Solid<Point3, C, S>,
     solid1: &Solid<Point3, C, S>,
     tol: f64,
-) -> Option<Solid<Point3, C, S>> {
+    is_and: bool,
+) -> Option<(Solid<Point3, C, S>, FaceOrigins<S>)> {
+    let select = |[and, or]: [Shell<Point3, C, S>; 2]| match is_and {
+        true => and,
+        false => or,
+    };
     let mut iter0 = solid0.boundaries().iter();
     let mut iter1 = solid1.boundaries().iter();
     let shell0 = iter0.next().unwrap();
     let shell1 = iter1.next().unwrap();
-    let [mut and_shell, _] = process_one_pair_of_shells(shell0, shell1, tol)?;
-    for shell in iter0 {
-        let [res, _] = process_one_pair_of_shells(&and_shell, shell, tol)?;
-        and_shell = res;
+    let (shells, mut origins) = process_one_pair_of_shells(shell0, shell1, tol)?;
+    let mut res_shell = select(shells);
+    for shell in iter0.chain(iter1) {
+        let (shells, new_origins) = process_one_pair_of_shells(&res_shell, shell, tol)?;
+        // compose before the previous result is dropped, since the ids are addresses.
+        origins = new_origins
+            .into_iter()
+            .map(|(id, mid)| (id, origins.get(&mid).copied().unwrap_or(mid)))
+            .collect();
+        res_shell = select(shells);
     }
-    for shell in iter1 {
-        let [res, _] = process_one_pair_of_shells(&and_shell, shell, tol)?;
-        and_shell = res;
-    }
-    let boundaries = and_shell.connected_components();
-    Some(Solid::new(boundaries))
+    let boundaries = res_shell.connected_components();
+    Some((Solid::new(boundaries), origins))
+}
+
+fn carry_attributes<C, S, A: Clone>(
+    solid: &Solid<Point3, C, S>,
+    origins: &FaceOrigins<S>,
+    attributes: &AttributeStore<Point3, C, S, A>,
+) -> AttributeStore<Point3, C, S, A> {
+    let mut res = AttributeStore::new();
+    solid.face_iter().for_each(|face| {
+        let id = origins.get(&face.id()).copied().unwrap_or(face.id());
+        if let Some(attribute) = attributes.face(id) {
+            res.insert_face(face, attribute.clone());
+        }
+    });
+    solid.edge_iter().for_each(|edge| {
+        if let Some(attribute) = attributes.edge(edge.id()) {
+            res.insert_edge(&edge, attribute.clone());
+        }
+    });
+    solid.vertex_iter().for_each(|vertex| {
+        if let Some(attribute) = attributes.vertex(vertex.id()) {
+            res.insert_vertex(&vertex, attribute.clone());
+        }
+    });
+    res
+}
+
+type Key<C, S> = ShapeID<VertexID<Point3>, EdgeID<C>, FaceID<S>>;
+
+fn boolean_history<C: ShapeOpsCurve<S>, S: ShapeOpsSurface>(
+    solid0: &Solid<Point3, C, S>,
+    solid1: &Solid<Point3, C, S>,
+    solid: &Solid<Point3, C, S>,
+    origins: &FaceOrigins<S>,
+    tol: f64,
+) -> TopologyHistory<Point3, C, S> {
+    let mut history = TopologyHistory::new();
+    let inputs = [solid0.elements(), solid1.elements()];
+    let input_ids: HashSet<_> = inputs.iter().flat_map(Elements::ids).collect();
+    let input_faces: HashMap<_, _> = inputs
+        .iter()
+        .enumerate()
+        .flat_map(|(i, elements)| {
+            elements
+                .faces
+                .iter()
+                .map(move |face| (face.id(), (i, face)))
+        })
+        .collect();
+    let output = solid.elements();
+    let output_ids = output.ids();
+    let origin = |face: &Face<Point3, C, S>| origins.get(&face.id()).copied().unwrap_or(face.id());
+
+    let mut edge_origins = HashMap::<EdgeID<C>, Vec<FaceID<S>>>::default();
+    let mut vertex_origins = HashMap::<VertexID<Point3>, Vec<FaceID<S>>>::default();
+    output.faces.iter().for_each(|face| {
+        let id = origin(face);
+        if id != face.id() {
+            history.add_modified(Key::Face(id), Key::Face(face.id()));
+        }
+        face.edge_iter().for_each(|edge| {
+            let vec = edge_origins.entry(edge.id()).or_default();
+            if !vec.contains(&id) {
+                vec.push(id);
+            }
+        });
+        face.vertex_iter().for_each(|vertex| {
+            let vec = vertex_origins.entry(vertex.id()).or_default();
+            if !vec.contains(&id) {
+                vec.push(id);
+            }
+        });
+    });
+
+    // the input edges divided by the operation, on which `point` lies
+    let divided_edges = |faces: &[FaceID<S>], point: Point3| {
+        let on_curve = move |edge: &Edge<Point3, C>| {
+            let curve = edge.curve();
+            let t = curve.search_nearest_parameter(point, None, 100);
+            t.map_or(false, |t| curve.subs(t).distance(point) < tol)
+        };
+        let edges = faces.iter().filter_map(|id| input_faces.get(id));
+        let edges = edges.flat_map(|(_, face)| face.edge_iter());
+        let mut ids = HashSet::default();
+        edges
+            .filter(|edge| !output_ids.contains(&Key::Edge(edge.id())))
+            .filter(|edge| ids.insert(edge.id()) && on_curve(edge))
+            .collect::<Vec<_>>()
+    };
+    output
+        .edges
+        .iter()
+        .filter(|edge| !input_ids.contains(&Key::Edge(edge.id())))
+        .for_each(|edge| {
+            let faces = &edge_origins[&edge.id()];
+            let solids: HashSet<_> = faces
+                .iter()
+                .filter_map(|id| input_faces.get(id))
+                .map(|(i, _)| *i)
+                .collect();
+            let original = match solids.len() {
+                1 => {
+                    let edges0 = divided_edges(faces, edge.front().point());
+                    let edges1 = divided_edges(faces, edge.back().point());
+                    edges0
+                        .into_iter()
+                        .find(|e| edges1.iter().any(|e1| e1.id() == e.id()))
+                }
+                _ => None,
+            };
+            match original {
+                Some(original) => {
+                    history.add_modified(Key::Edge(original.id()), Key::Edge(edge.id()))
+                }
+                None => faces
+                    .iter()
+                    .for_each(|id| history.add_generated(Key::Face(*id), Key::Edge(edge.id()))),
+            }
+        });
+    output
+        .vertices
+        .iter()
+        .filter(|vertex| !input_ids.contains(&Key::Vertex(vertex.id())))
+        .for_each(|vertex| {
+            let faces = &vertex_origins[&vertex.id()];
+            divided_edges(faces, vertex.point())
+                .into_iter()
+                .for_each(|edge| {
+                    history.add_generated(Key::Edge(edge.id()), Key::Vertex(vertex.id()))
+                });
+            faces.iter().for_each(|id| {
+                history.add_generated(Key::Face(*id), Key::Vertex(vertex.id()));
+            });
+        });
+
+    let deleted: Vec<_> = input_ids
+        .into_iter()
+        .filter(|id| !output_ids.contains(id) && history.modified(*id).is_empty())
+        .collect();
+    deleted.into_iter().for_each(|id| history.add_deleted(id));
+    history
+}
+
+/// AND operation between two solids.
+pub fn and<C: ShapeOpsCurve<S>, S: ShapeOpsSurface>(
+    solid0: &Solid<Point3, C, S>,
+    solid1: &Solid<Point3, C, S>,
+    tol: f64,
+) -> Option<Solid<Point3, C, S>> {
+    and_or_with_origins(solid0, solid1, tol, true).map(|(solid, _)| solid)
 }
 
 /// OR operation between two solids.
@@ -128,21 +290,66 @@ pub fn or<C: ShapeOpsCurve<S>, S: ShapeOpsSurface>(
     solid1: &Solid<Point3, C, S>,
     tol: f64,
 ) -> Option<Solid<Point3, C, S>> {
-    let mut iter0 = solid0.boundaries().iter();
-    let mut iter1 = solid1.boundaries().iter();
-    let shell0 = iter0.next().unwrap();
-    let shell1 = iter1.next().unwrap();
-    let [_, mut or_shell] = process_one_pair_of_shells(shell0, shell1, tol)?;
-    for shell in iter0 {
-        let [_, res] = process_one_pair_of_shells(&or_shell, shell, tol)?;
-        or_shell = res;
-    }
-    for shell in iter1 {
-        let [_, res] = process_one_pair_of_shells(&or_shell, shell, tol)?;
-        or_shell = res;
-    }
-    let boundaries = or_shell.connected_components();
-    Some(Solid::new(boundaries))
+    and_or_with_origins(solid0, solid1, tol, false).map(|(solid, _)| solid)
+}
+
+/// AND operation between two solids, returning the history.
+///
+/// The faces of the result are modified from the faces from which they are divided.
+/// The edges divided by the intersection are modified from the original edges, and the new
+/// intersection edges and vertices are generated from the faces of `solid0` and `solid1`.
+/// The elements which are not in the result are deleted.
+pub fn and_with_history<C: ShapeOpsCurve<S>, S: ShapeOpsSurface>(
+    solid0: &Solid<Point3, C, S>,
+    solid1: &Solid<Point3, C, S>,
+    tol: f64,
+) -> Option<(Solid<Point3, C, S>, TopologyHistory<Point3, C, S>)> {
+    let (solid, origins) = and_or_with_origins(solid0, solid1, tol, true)?;
+    let history = boolean_history(solid0, solid1, &solid, &origins, tol);
+    Some((solid, history))
+}
+
+/// OR operation between two solids, returning the history.
+///
+/// The history is recorded in the same way as [`and_with_history`].
+pub fn or_with_history<C: ShapeOpsCurve<S>, S: ShapeOpsSurface>(
+    solid0: &Solid<Point3, C, S>,
+    solid1: &Solid<Point3, C, S>,
+    tol: f64,
+) -> Option<(Solid<Point3, C, S>, TopologyHistory<Point3, C, S>)> {
+    let (solid, origins) = and_or_with_origins(solid0, solid1, tol, false)?;
+    let history = boolean_history(solid0, solid1, &solid, &origins, tol);
+    Some((solid, history))
+}
+
+/// AND operation between two solids, carrying the attributes of `solid0` and `solid1`.
+///
+/// Each face of the result inherits the attribute of the face from which it is divided.
+/// The edges and vertices which are not divided by the intersection keep their attributes.
+pub fn and_with_attributes<C: ShapeOpsCurve<S>, S: ShapeOpsSurface, A: Clone>(
+    solid0: &Solid<Point3, C, S>,
+    solid1: &Solid<Point3, C, S>,
+    attributes: &AttributeStore<Point3, C, S, A>,
+    tol: f64,
+) -> Option<(Solid<Point3, C, S>, AttributeStore<Point3, C, S, A>)> {
+    let (solid, origins) = and_or_with_origins(solid0, solid1, tol, true)?;
+    let attributes = carry_attributes(&solid, &origins, attributes);
+    Some((solid, attributes))
+}
+
+/// OR operation between two solids, carrying the attributes of `solid0` and `solid1`.
+///
+/// Each face of the result inherits the attribute of the face from which it is divided.
+/// The edges and vertices which are not divided by the intersection keep their attributes.
+pub fn or_with_attributes<C: ShapeOpsCurve<S>, S: ShapeOpsSurface, A: Clone>(
+    solid0: &Solid<Point3, C, S>,
+    solid1: &Solid<Point3, C, S>,
+    attributes: &AttributeStore<Point3, C, S, A>,
+    tol: f64,
+) -> Option<(Solid<Point3, C, S>, AttributeStore<Point3, C, S, A>)> {
+    let (solid, origins) = and_or_with_origins(solid0, solid1, tol, false)?;
+    let attributes = carry_attributes(&solid, &origins, attributes);
+    Some((solid, attributes))
 }
 
 #[cfg(test)]
@@ -19,3 +19,75 @@ fn punched_cube() {
     let file = std::fs::File::create("punched-cube.obj").unwrap();
     obj::write(&poly, file).unwrap();
 }
+
+#[test]
+fn punched_cube_with_attributes() {
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::unit_y());
+    let cube = builder::tsweep(&f, Vector3::unit_z());
+
+    let v = builder::vertex(Point3::new(0.5, 0.25, -0.5));
+    let w = builder::rsweep(&v, Point3::new(0.5, 0.5, 0.0), Vector3::unit_z(), Rad(7.0));
+    let f = builder::try_attach_plane(&[w]).unwrap();
+    let mut cylinder = builder::tsweep(&f, Vector3::unit_z() * 2.0);
+    cylinder.not();
+
+    let mut attributes = AttributeStore::new();
+    cube.face_iter().for_each(|face| {
+        attributes.insert_face(face, "cube");
+    });
+    cylinder.face_iter().for_each(|face| {
+        attributes.insert_face(face, "cylinder");
+    });
+    let (and, attributes) =
+        crate::and_with_attributes(&cube, &cylinder, &attributes, 0.05).unwrap();
+    assert!(and
+        .face_iter()
+        .all(|face| attributes.face(face.id()).is_some()));
+    let count = |name: &str| {
+        and.face_iter()
+            .filter(|face| attributes.face(face.id()) == Some(&name))
+            .count()
+    };
+    assert_eq!(count("cube"), 6);
+    assert!(count("cylinder") > 0);
+}
+
+#[test]
+fn punched_cube_with_history() {
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::unit_y());
+    let cube = builder::tsweep(&f, Vector3::unit_z());
+
+    let v = builder::vertex(Point3::new(0.5, 0.25, -0.5));
+    let w = builder::rsweep(&v, Point3::new(0.5, 0.5, 0.0), Vector3::unit_z(), Rad(7.0));
+    let f = builder::try_attach_plane(&[w]).unwrap();
+    let mut cylinder = builder::tsweep(&f, Vector3::unit_z() * 2.0);
+    cylinder.not();
+
+    let (and, history) = crate::and_with_history(&cube, &cylinder, 0.05).unwrap();
+    let inputs: Vec<_> = cube.face_iter().chain(cylinder.face_iter()).collect();
+    // each face of the result is an input face or a piece of an input face.
+    assert!(and.face_iter().all(|face| {
+        let id = ShapeID::Face(face.id());
+        inputs.iter().any(|input| {
+            let input = ShapeID::Face(input.id());
+            input == id || history.modified(input).contains(&id)
+        })
+    }));
+    // the caps of the cylinder are out of the cube.
+    let deleted = inputs
+        .iter()
+        .filter(|face| history.is_deleted(ShapeID::Face(face.id())))
+        .count();
+    assert_eq!(deleted, 2);
+    // the intersection edges are generated by the faces of both solids.
+    let generates_edge = |face: &Face| {
+        let generated = history.generated(ShapeID::Face(face.id()));
+        generated.iter().any(|id| matches!(id, ShapeID::Edge(_)))
+    };
+    assert!(cube.face_iter().any(generates_edge));
+    assert!(cylinder.face_iter().any(generates_edge));
+}
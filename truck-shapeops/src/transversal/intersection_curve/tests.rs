@@ -171,3 +171,57 @@ fn collide_parabola() {
         assert_near!(pt.distance(Point3::origin()) * 0.5, f64::sqrt(0.5) * 0.5);
     }
 }
+
+#[test]
+fn intersection_curve_through_pole() {
+    const TOL: f64 = 0.05;
+
+    // the octant of the unit sphere whose side u = 0 degenerates to the north pole
+    let meridian = [(0.0, 1.0, 1.0), (1.0, 1.0, f64::sqrt(0.5)), (1.0, 0.0, 1.0)];
+    let ctrl0 = meridian
+        .into_iter()
+        .map(|(r, z, w)| {
+            vec![
+                Vector4::new(r * w, 0.0, z * w, w),
+                Vector4::new(r * w, r * w, z * w, w) * f64::sqrt(0.5),
+                Vector4::new(0.0, r * w, z * w, w),
+            ]
+        })
+        .collect();
+    // the vertical plane through the pole
+    let ctrl1 = vec![
+        vec![
+            Vector4::new(-0.5, -0.5, -0.5, 1.0),
+            Vector4::new(-0.5, -0.5, 1.5, 1.0),
+        ],
+        vec![
+            Vector4::new(1.5, 1.5, -0.5, 1.0),
+            Vector4::new(1.5, 1.5, 1.5, 1.0),
+        ],
+    ];
+    let surface0 = NurbsSurface::new(BSplineSurface::new(
+        (KnotVec::bezier_knot(2), KnotVec::bezier_knot(2)),
+        ctrl0,
+    ));
+    let surface1 = NurbsSurface::new(BSplineSurface::new(
+        (KnotVec::bezier_knot(1), KnotVec::bezier_knot(1)),
+        ctrl1,
+    ));
+
+    let polygon0 = StructuredMesh::from_surface(&surface0, surface0.range_tuple(), TOL).destruct();
+    let polygon1 = StructuredMesh::from_surface(&surface1, surface1.range_tuple(), TOL).destruct();
+    let curves = intersection_curves(surface0.clone(), &polygon0, surface1, &polygon1, TOL);
+    assert_eq!(curves.len(), 1);
+    let curve = curves[0].1.clone().unwrap();
+
+    // the parameters on the sphere are searched along the curve, as in dividing faces.
+    let mut hint = None;
+    for pt in curve.leader().iter() {
+        assert_near!(pt.distance(Point3::origin()), 1.0);
+        assert_near!(pt.x, pt.y);
+        let (u, v) = surface0.search_parameter(*pt, hint, 100).unwrap();
+        assert_near!(surface0.subs(u, v), *pt);
+        hint = Some((u, v));
+    }
+    assert_near!(curve.back(), Point3::new(0.0, 0.0, 1.0));
+}
@@ -2,10 +2,10 @@
 
 use super::*;
 use rustc_hash::FxHashMap as HashMap;
-use truck_base::cgmath64::*;
+use truck_base::{bounding_box::BoundingBox, cgmath64::*};
 use truck_geometry::prelude::IntersectionCurve;
 use truck_meshalgo::prelude::*;
-use truck_topology::{Vertex, *};
+use truck_topology::{bvh::Bvh, Vertex, *};
 
 type PolylineCurve = truck_meshalgo::prelude::PolylineCurve<Point3>;
 
@@ -438,6 +438,50 @@ pub struct LoopsStoreQuadruple<C> {
     pub poly_loops_store1: LoopsStore<Point3, PolylineCurve>,
 }
 
+/// Returns the pairs of the indices of the faces whose polygons may intersect,
+/// by the bounding volume hierarchy of the faces of `poly_shell1`.
+/// The faces without polygons are paired with all the faces of the other shell.
+fn overlapping_face_pairs(
+    poly_shell0: &Shell<Point3, PolylineCurve, Option<PolygonMesh>>,
+    poly_shell1: &Shell<Point3, PolylineCurve, Option<PolygonMesh>>,
+    tol: f64,
+) -> Vec<(usize, usize)> {
+    let polygon_box = |face: &Face<Point3, PolylineCurve, Option<PolygonMesh>>| {
+        face.with_surface(|polygon| polygon.as_ref().map(PolygonMesh::bounding_box))
+    };
+    let mut no_polygons1 = Vec::new();
+    let bvh1: Bvh<usize> = poly_shell1
+        .face_iter()
+        .enumerate()
+        .filter_map(|(j, face)| match polygon_box(face) {
+            Some(bdb) => Some((bdb, j)),
+            None => {
+                no_polygons1.push(j);
+                None
+            }
+        })
+        .collect();
+    let mut pairs = Vec::new();
+    for (i, face) in poly_shell0.face_iter().enumerate() {
+        let Some(bdb) = polygon_box(face) else {
+            pairs.extend((0..poly_shell1.len()).map(|j| (i, j)));
+            continue;
+        };
+        if bdb.is_empty() {
+            continue;
+        }
+        let margin = Vector3::new(tol, tol, tol);
+        let bdb: BoundingBox<Point3> = [bdb.min() - margin, bdb.max() + margin]
+            .into_iter()
+            .collect();
+        let mut candidates: Vec<usize> = bvh1.overlaps(bdb).into_iter().copied().collect();
+        candidates.extend(&no_polygons1);
+        candidates.sort_unstable();
+        pairs.extend(candidates.into_iter().map(|j| (i, j)));
+    }
+    pairs
+}
+
 pub fn create_loops_stores<C, S>(
     geom_shell0: &Shell<Point3, C, S>,
     poly_shell0: &Shell<Point3, PolylineCurve, Option<PolygonMesh>>,
@@ -456,10 +500,9 @@ where
     let mut poly_loops_store0: LoopsStore<_, _> = poly_shell0.face_iter().collect();
     let mut geom_loops_store1: LoopsStore<_, _> = geom_shell1.face_iter().collect();
     let mut poly_loops_store1: LoopsStore<_, _> = poly_shell1.face_iter().collect();
-    let store0_len = geom_loops_store0.len();
-    let store1_len = geom_loops_store1.len();
-    (0..store0_len)
-        .flat_map(move |i| (0..store1_len).map(move |j| (i, j)))
+    let face_pairs = overlapping_face_pairs(poly_shell0, poly_shell1, tol);
+    face_pairs
+        .into_iter()
         .try_for_each(|(face_index0, face_index1)| {
             let ori0 = geom_shell0[face_index0].orientation();
             let ori1 = geom_shell1[face_index1].orientation();
@@ -774,3 +774,38 @@ fn crossing_edges() {
         geom_loops_store0.display(wire_id_format)
     );
 }
+
+#[test]
+fn overlapping_face_pairs_without_polygons() {
+    let triangle = |x: f64, polygon: bool| {
+        let pts = [
+            Point3::new(x, 0.0, 0.0),
+            Point3::new(x + 1.0, 0.0, 0.0),
+            Point3::new(x, 1.0, 0.0),
+        ];
+        let v = Vertex::news(pts);
+        let wire: Wire<_, _> = (0..3)
+            .map(|i| {
+                let curve = PolylineCurve(vec![pts[i], pts[(i + 1) % 3]]);
+                Edge::new(&v[i], &v[(i + 1) % 3], curve)
+            })
+            .collect();
+        let mesh = PolygonMesh::new(
+            StandardAttributes {
+                positions: pts.to_vec(),
+                ..Default::default()
+            },
+            Faces::from_iter(&[&[0, 1, 2]]),
+        );
+        Face::new(vec![wire], polygon.then_some(mesh))
+    };
+    let shell0: Shell<_, _, _> = vec![triangle(0.0, true), triangle(10.0, false)].into();
+    let shell1: Shell<_, _, _> = vec![
+        triangle(0.5, true),
+        triangle(20.0, true),
+        triangle(30.0, false),
+    ]
+    .into();
+    let pairs = overlapping_face_pairs(&shell0, &shell1, TOL);
+    assert_eq!(pairs, vec![(0, 0), (0, 2), (1, 0), (1, 1), (1, 2)]);
+}
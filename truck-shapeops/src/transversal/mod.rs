@@ -4,4 +4,7 @@ mod integrate;
 mod intersection_curve;
 mod loops_store;
 mod polyline_construction;
-pub use integrate::{and, or, ShapeOpsCurve, ShapeOpsSurface};
+pub use integrate::{
+    and, and_with_attributes, and_with_history, or, or_with_attributes, or_with_history,
+    ShapeOpsCurve, ShapeOpsSurface,
+};
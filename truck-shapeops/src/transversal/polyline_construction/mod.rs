@@ -29,7 +29,8 @@ struct PointIndex([i64; 3]);
 impl From<Point3> for PointIndex {
     #[inline(always)]
     fn from(pt: Point3) -> PointIndex {
-        let idx = pt.add_element_wise(TOLERANCE) / (2.0 * TOLERANCE);
+        let tol = tolerance();
+        let idx = pt.add_element_wise(tol) / (2.0 * tol);
         PointIndex(idx.cast::<i64>().unwrap().into())
     }
 }
@@ -1,16 +1,16 @@
 #![allow(missing_docs)]
 
 use ruststep::{
-    ast::{DataSection, EntityInstance, Name, Parameter, SubSuperRecord},
+    ast::{DataSection, EntityInstance, Name, Parameter, Record, SubSuperRecord},
     primitive::Logical,
     tables::{EntityTable, IntoOwned, PlaceHolder},
     Holder,
 };
 use serde::{Deserialize, Serialize};
 use std::result::Result;
-use std::{collections::HashMap, f64::consts::PI};
+use std::{collections::HashMap, f64::consts::PI, sync::Arc};
 use truck_geometry::prelude as truck;
-use truck_topology::compress::*;
+use truck_topology::{assembly::Assembly, compress::*};
 
 /// type alias
 pub mod alias;
@@ -70,6 +70,36 @@ pub struct Table {
     // others
     pub definitional_representation: HashMap<u64, DefinitionalRepresentationHolder>,
 
+    // product structure
+    /// the names of `PRODUCT`s
+    pub product: HashMap<u64, String>,
+    /// the references from `PRODUCT_DEFINITION_FORMATION` to `PRODUCT`
+    pub product_definition_formation: HashMap<u64, u64>,
+    /// the references from `PRODUCT_DEFINITION` to `PRODUCT_DEFINITION_FORMATION`
+    pub product_definition: HashMap<u64, u64>,
+    /// the references from `PRODUCT_DEFINITION_SHAPE` to the definition
+    pub product_definition_shape: HashMap<u64, u64>,
+    /// the references from `SHAPE_DEFINITION_REPRESENTATION` to the definition and the representation
+    pub shape_definition_representation: HashMap<u64, [u64; 2]>,
+    pub shape_representation: HashMap<u64, ShapeRepresentation>,
+    /// the shells of `MANIFOLD_SOLID_BREP` and `BREP_WITH_VOIDS`
+    pub solid_model: HashMap<u64, Vec<u64>>,
+    /// the shells of `SHELL_BASED_SURFACE_MODEL`
+    pub shell_based_surface_model: HashMap<u64, Vec<u64>>,
+    pub next_assembly_usage_occurrence: HashMap<u64, AssemblyUsageOccurrence>,
+    /// the references from `CONTEXT_DEPENDENT_SHAPE_REPRESENTATION` to
+    /// the representation relationship and the product definition shape
+    pub context_dependent_shape_representation: HashMap<u64, [u64; 2]>,
+    pub representation_relationship: HashMap<u64, RepresentationRelationship>,
+    /// the references from `ITEM_DEFINED_TRANSFORMATION` to the two items
+    pub item_defined_transformation: HashMap<u64, [u64; 2]>,
+
+    // presentation
+    pub styled_item: HashMap<u64, StyledItem>,
+    /// references of the entities between `STYLED_ITEM` and `COLOUR_RGB`
+    pub style_reference: HashMap<u64, Vec<u64>>,
+    pub colour_rgb: HashMap<u64, [f64; 3]>,
+
     // dummy
     pub dummy: HashMap<u64, DummyHolder>,
 }
@@ -308,6 +338,148 @@ impl Table {
                         }
                     }
                 }
+                "PRODUCT" => {
+                    if let Parameter::List(params) = &record.parameter {
+                        if let Some(name) = params.get(1) {
+                            self.product.insert(*id, Deserialize::deserialize(name)?);
+                        }
+                    }
+                }
+                "PRODUCT_DEFINITION_FORMATION"
+                | "PRODUCT_DEFINITION_FORMATION_WITH_SPECIFIED_SOURCE" => {
+                    if let Some(product) = entity_ref_at(&record.parameter, 2) {
+                        self.product_definition_formation.insert(*id, product);
+                    }
+                }
+                "PRODUCT_DEFINITION" => {
+                    if let Some(formation) = entity_ref_at(&record.parameter, 2) {
+                        self.product_definition.insert(*id, formation);
+                    }
+                }
+                "PRODUCT_DEFINITION_SHAPE" => {
+                    if let Some(definition) = entity_ref_at(&record.parameter, 2) {
+                        self.product_definition_shape.insert(*id, definition);
+                    }
+                }
+                "SHAPE_DEFINITION_REPRESENTATION" => {
+                    let definition = entity_ref_at(&record.parameter, 0);
+                    let representation = entity_ref_at(&record.parameter, 1);
+                    if let (Some(definition), Some(representation)) = (definition, representation) {
+                        self.shape_definition_representation
+                            .insert(*id, [definition, representation]);
+                    }
+                }
+                "SHAPE_REPRESENTATION"
+                | "ADVANCED_BREP_SHAPE_REPRESENTATION"
+                | "MANIFOLD_SURFACE_SHAPE_REPRESENTATION"
+                | "FACETED_BREP_SHAPE_REPRESENTATION" => {
+                    if let Parameter::List(params) = &record.parameter {
+                        if let (Some(name), Some(items)) = (params.first(), params.get(1)) {
+                            let mut refs = Vec::new();
+                            collect_entity_refs(items, &mut refs);
+                            let representation = ShapeRepresentation {
+                                name: Deserialize::deserialize(name)?,
+                                items: refs,
+                            };
+                            self.shape_representation.insert(*id, representation);
+                        }
+                    }
+                }
+                "MANIFOLD_SOLID_BREP" | "BREP_WITH_VOIDS" => {
+                    if let Parameter::List(params) = &record.parameter {
+                        let mut refs = Vec::new();
+                        params
+                            .iter()
+                            .skip(1)
+                            .for_each(|param| collect_entity_refs(param, &mut refs));
+                        self.solid_model.insert(*id, refs);
+                    }
+                }
+                "SHELL_BASED_SURFACE_MODEL" => {
+                    if let Parameter::List(params) = &record.parameter {
+                        if let Some(shells) = params.get(1) {
+                            let mut refs = Vec::new();
+                            collect_entity_refs(shells, &mut refs);
+                            self.shell_based_surface_model.insert(*id, refs);
+                        }
+                    }
+                }
+                "NEXT_ASSEMBLY_USAGE_OCCURRENCE" => {
+                    if let Parameter::List(params) = &record.parameter {
+                        let relating = entity_ref_at(&record.parameter, 3);
+                        let related = entity_ref_at(&record.parameter, 4);
+                        if let (Some(relating), Some(related)) = (relating, related) {
+                            let occurrence = AssemblyUsageOccurrence {
+                                id: Deserialize::deserialize(&params[0])?,
+                                name: Deserialize::deserialize(&params[1])?,
+                                relating,
+                                related,
+                            };
+                            self.next_assembly_usage_occurrence.insert(*id, occurrence);
+                        }
+                    }
+                }
+                "CONTEXT_DEPENDENT_SHAPE_REPRESENTATION" => {
+                    let relation = entity_ref_at(&record.parameter, 0);
+                    let product_relation = entity_ref_at(&record.parameter, 1);
+                    if let (Some(relation), Some(product_relation)) = (relation, product_relation) {
+                        self.context_dependent_shape_representation
+                            .insert(*id, [relation, product_relation]);
+                    }
+                }
+                "REPRESENTATION_RELATIONSHIP" | "SHAPE_REPRESENTATION_RELATIONSHIP" => {
+                    if let Some(relationship) =
+                        RepresentationRelationship::new(&record.parameter, None)
+                    {
+                        self.representation_relationship.insert(*id, relationship);
+                    }
+                }
+                "ITEM_DEFINED_TRANSFORMATION" => {
+                    let item1 = entity_ref_at(&record.parameter, 2);
+                    let item2 = entity_ref_at(&record.parameter, 3);
+                    if let (Some(item1), Some(item2)) = (item1, item2) {
+                        self.item_defined_transformation.insert(*id, [item1, item2]);
+                    }
+                }
+                "STYLED_ITEM" | "OVER_RIDING_STYLED_ITEM" => {
+                    if let Parameter::List(params) = &record.parameter {
+                        if let (Some(styles), Some(Parameter::Ref(Name::Entity(item)))) =
+                            (params.get(1), params.get(2))
+                        {
+                            let mut refs = Vec::new();
+                            collect_entity_refs(styles, &mut refs);
+                            let styled_item = StyledItem {
+                                styles: refs,
+                                item: *item,
+                            };
+                            self.styled_item.insert(*id, styled_item);
+                        }
+                    }
+                }
+                "PRESENTATION_STYLE_ASSIGNMENT"
+                | "SURFACE_STYLE_USAGE"
+                | "SURFACE_SIDE_STYLE"
+                | "SURFACE_STYLE_FILL_AREA"
+                | "FILL_AREA_STYLE"
+                | "FILL_AREA_STYLE_COLOUR" => {
+                    let mut refs = Vec::new();
+                    collect_entity_refs(&record.parameter, &mut refs);
+                    self.style_reference.insert(*id, refs);
+                }
+                "COLOUR_RGB" => {
+                    if let Parameter::List(params) = &record.parameter {
+                        if params.len() == 4 {
+                            self.colour_rgb.insert(
+                                *id,
+                                [
+                                    Deserialize::deserialize(&params[1])?,
+                                    Deserialize::deserialize(&params[2])?,
+                                    Deserialize::deserialize(&params[3])?,
+                                ],
+                            );
+                        }
+                    }
+                }
                 _ => {
                     self.dummy.insert(
                         *id,
@@ -324,7 +496,9 @@ impl Table {
             } => {
                 use NonRationalBSplineCurveHolder as NRBC;
                 use NonRationalBSplineSurfaceHolder as NRBS;
-                if records.len() == 7 {
+                if let Some(relationship) = RepresentationRelationship::from_records(records) {
+                    self.representation_relationship.insert(*id, relationship);
+                } else if records.len() == 7 {
                     match (
                         records[0].name.as_str(),
                         &records[0].parameter,
@@ -628,6 +802,88 @@ impl<'a> FromIterator<&'a EntityInstance> for Table {
     }
 }
 
+fn collect_entity_refs(parameter: &Parameter, refs: &mut Vec<u64>) {
+    match parameter {
+        Parameter::Ref(Name::Entity(idx)) => refs.push(*idx),
+        Parameter::List(params) => params.iter().for_each(|p| collect_entity_refs(p, refs)),
+        _ => {}
+    }
+}
+
+fn entity_ref_at(parameter: &Parameter, idx: usize) -> Option<u64> {
+    match parameter {
+        Parameter::List(params) => match params.get(idx)? {
+            Parameter::Ref(Name::Entity(idx)) => Some(*idx),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// `SHAPE_REPRESENTATION` and its subtypes, which only hold the references of the items.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ShapeRepresentation {
+    pub name: String,
+    pub items: Vec<u64>,
+}
+
+/// `NEXT_ASSEMBLY_USAGE_OCCURRENCE`, which only holds the names and the product definitions.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AssemblyUsageOccurrence {
+    pub id: String,
+    pub name: String,
+    /// the product definition of the assembly
+    pub relating: u64,
+    /// the product definition of the component
+    pub related: u64,
+}
+
+/// `REPRESENTATION_RELATIONSHIP` and `SHAPE_REPRESENTATION_RELATIONSHIP`,
+/// with the transformation if it is complex with `REPRESENTATION_RELATIONSHIP_WITH_TRANSFORMATION`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RepresentationRelationship {
+    pub rep_1: u64,
+    pub rep_2: u64,
+    pub transformation: Option<u64>,
+}
+
+impl RepresentationRelationship {
+    fn new(parameter: &Parameter, transformation: Option<u64>) -> Option<Self> {
+        Some(Self {
+            rep_1: entity_ref_at(parameter, 2)?,
+            rep_2: entity_ref_at(parameter, 3)?,
+            transformation,
+        })
+    }
+
+    fn from_records(records: &[Record]) -> Option<Self> {
+        let find = |name: &str| records.iter().find(|record| record.name == name);
+        let relationship = find("REPRESENTATION_RELATIONSHIP")?;
+        let transformation = find("REPRESENTATION_RELATIONSHIP_WITH_TRANSFORMATION")
+            .and_then(|record| entity_ref_at(&record.parameter, 0));
+        Self::new(&relationship.parameter, transformation)
+    }
+}
+
+type StepPart = Vec<CompressedShell<Point3, Curve3D, Surface>>;
+
+/// The products and the cache of the parts used in reading an assembly.
+struct AssemblyContext<'a> {
+    /// the shape representations of the product definitions
+    representations: HashMap<u64, u64>,
+    occurrences: Vec<(u64, &'a AssemblyUsageOccurrence)>,
+    parts: HashMap<u64, Option<Arc<StepPart>>>,
+    /// the product definitions from the root to the current node, for avoiding the infinite loop
+    path: Vec<u64>,
+}
+
+/// `STYLED_ITEM`, which only holds the references for the colors of faces.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StyledItem {
+    pub styles: Vec<u64>,
+    pub item: u64,
+}
+
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Holder)]
 #[holder(table = Table)]
 #[holder(field = dummy)]
@@ -1176,14 +1432,68 @@ pub enum BSplineCurveAny {
     RationalBSplineCurve(Box<RationalBSplineCurve>),
 }
 
+impl NonRationalBSplineCurve {
+    fn closed_curve(&self) -> &Logical {
+        use NonRationalBSplineCurve::*;
+        match self {
+            BSplineCurveWithKnots(x) => &x.closed_curve,
+            BezierCurve(x) => &x.closed_curve,
+            QuasiUniformCurve(x) => &x.closed_curve,
+            UniformCurve(x) => &x.closed_curve,
+        }
+    }
+}
+
+/// Closed curves, i.e. the curves with `closed_curve = .T.`, are often written in the unclamped
+/// periodic form, whose valid range is `[knots[degree], knots[n]]`. Such a curve is clamped to
+/// the range so that the both ends are connected smoothly. The other curves are returned as they are.
+fn clamp_periodic_curve<P: ControlPoint<f64> + Tolerance>(
+    curve: BSplineCurve<P>,
+    closed: &Logical,
+) -> BSplineCurve<P> {
+    if !matches!(closed, Logical::True) || curve.is_clamped() {
+        return curve;
+    }
+    PeriodicBSplineCurve::try_from_unclamped(curve.clone())
+        .map(PeriodicBSplineCurve::into_clamped)
+        .unwrap_or(curve)
+}
+
+/// Clamps the surface with respect to the closed directions in the unclamped periodic form.
+/// See also [`clamp_periodic_curve`].
+fn clamp_periodic_surface<P: ControlPoint<f64> + Tolerance>(
+    surface: BSplineSurface<P>,
+    (u_closed, v_closed): (&Logical, &Logical),
+) -> BSplineSurface<P> {
+    let (uknot_vec, vknot_vec) = surface.knot_vecs();
+    let periodic = (
+        matches!(u_closed, Logical::True) && !uknot_vec.is_clamped(surface.udegree()),
+        matches!(v_closed, Logical::True) && !vknot_vec.is_clamped(surface.vdegree()),
+    );
+    if periodic == (false, false) {
+        return surface;
+    }
+    PeriodicBSplineSurface::try_from_unclamped(surface.clone(), periodic)
+        .map(PeriodicBSplineSurface::into_clamped)
+        .unwrap_or(surface)
+}
+
 impl TryFrom<&BSplineCurveAny> for Curve2D {
     type Error = ExpressParseError;
     #[inline(always)]
     fn try_from(value: &BSplineCurveAny) -> Result<Self, Self::Error> {
         use BSplineCurveAny::*;
         Ok(match value {
-            NonRationalBSplineCurve(bsp) => Self::BSplineCurve(bsp.as_ref().try_into()?),
-            RationalBSplineCurve(bsp) => Self::NurbsCurve(bsp.as_ref().try_into()?),
+            NonRationalBSplineCurve(bsp) => {
+                let closed = bsp.closed_curve();
+                Self::BSplineCurve(clamp_periodic_curve(bsp.as_ref().try_into()?, closed))
+            }
+            RationalBSplineCurve(bsp) => {
+                let curve = NurbsCurve::try_from(bsp.as_ref())?;
+                let closed = bsp.non_rational_b_spline_curve.closed_curve();
+                let curve = clamp_periodic_curve(curve.into_non_rationalized(), closed);
+                Self::NurbsCurve(NurbsCurve::new(curve))
+            }
         })
     }
 }
@@ -1194,8 +1504,16 @@ impl TryFrom<&BSplineCurveAny> for Curve3D {
     fn try_from(value: &BSplineCurveAny) -> Result<Self, Self::Error> {
         use BSplineCurveAny::*;
         Ok(match value {
-            NonRationalBSplineCurve(bsp) => Self::BSplineCurve(bsp.as_ref().try_into()?),
-            RationalBSplineCurve(bsp) => Self::NurbsCurve(bsp.as_ref().try_into()?),
+            NonRationalBSplineCurve(bsp) => {
+                let closed = bsp.closed_curve();
+                Self::BSplineCurve(clamp_periodic_curve(bsp.as_ref().try_into()?, closed))
+            }
+            RationalBSplineCurve(bsp) => {
+                let curve = NurbsCurve::try_from(bsp.as_ref())?;
+                let closed = bsp.non_rational_b_spline_curve.closed_curve();
+                let curve = clamp_periodic_curve(curve.into_non_rationalized(), closed);
+                Self::NurbsCurve(NurbsCurve::new(curve))
+            }
         })
     }
 }
@@ -1695,8 +2013,16 @@ impl TryFrom<&BSplineSurfaceAny> for Surface {
     fn try_from(value: &BSplineSurfaceAny) -> Result<Self, Self::Error> {
         use BSplineSurfaceAny::*;
         Ok(match value {
-            NonRationalBSplineSurface(bsp) => Surface::BSplineSurface(Box::new(bsp.try_into()?)),
-            RationalBSplineSurface(bsp) => Surface::NurbsSurface(Box::new(bsp.try_into()?)),
+            NonRationalBSplineSurface(bsp) => {
+                let surface = clamp_periodic_surface(bsp.try_into()?, bsp.closed_flags());
+                Surface::BSplineSurface(Box::new(surface))
+            }
+            RationalBSplineSurface(bsp) => {
+                let surface = NurbsSurface::try_from(bsp)?;
+                let closed = bsp.non_rational_b_spline_surface.closed_flags();
+                let surface = clamp_periodic_surface(surface.into_non_rationalized(), closed);
+                Surface::NurbsSurface(Box::new(NurbsSurface::new(surface)))
+            }
         })
     }
 }
@@ -1879,6 +2205,18 @@ pub enum NonRationalBSplineSurface {
     BezierSurface(Box<BezierSurface>),
 }
 
+impl NonRationalBSplineSurface {
+    fn closed_flags(&self) -> (&Logical, &Logical) {
+        use NonRationalBSplineSurface::*;
+        match self {
+            BSplineSurfaceWithKnots(x) => (&x.u_closed, &x.v_closed),
+            UniformSurface(x) => (&x.u_closed, &x.v_closed),
+            QuasiUniformSurface(x) => (&x.u_closed, &x.v_closed),
+            BezierSurface(x) => (&x.u_closed, &x.v_closed),
+        }
+    }
+}
+
 impl TryFrom<&NonRationalBSplineSurface> for BSplineSurface<Point3> {
     type Error = ExpressParseError;
     #[inline(always)]
@@ -2640,15 +2978,26 @@ impl Table {
         Some(edges)
     }
 
-    fn shell_faces(
+    fn shell_faces_with_indices(
         &self,
         shell: &ShellHolder,
         eidx_map: &HashMap<u64, usize>,
-    ) -> Vec<CompressedFace<Surface>> {
+    ) -> Vec<(Vec<u64>, CompressedFace<Surface>)> {
         shell
-            .cfs_faces_holder(self)
-            .filter_map(|face| self.face_any_to_orientation_and_face(face))
-            .filter_map(|(orientation, face)| {
+            .cfs_faces
+            .iter()
+            .zip(shell.cfs_faces_holder(self))
+            .filter_map(|(place_holder, face)| {
+                let (orientation, face) = self.face_any_to_orientation_and_face(face)?;
+                let mut indices = Vec::new();
+                if let PlaceHolder::Ref(Name::Entity(idx)) = place_holder {
+                    indices.push(*idx);
+                    if let Some(oriented_face) = self.oriented_face.get(idx) {
+                        if let PlaceHolder::Ref(Name::Entity(idx)) = &oriented_face.face_element {
+                            indices.push(*idx);
+                        }
+                    }
+                }
                 let step_surface: SurfaceAny = face
                     .face_geometry
                     .clone()
@@ -2666,15 +3015,259 @@ impl Table {
                     .into_iter()
                     .filter_map(|bound| self.face_bound_to_edges(bound?, eidx_map))
                     .collect();
-                Some(CompressedFace {
+                let face = CompressedFace {
                     surface,
                     boundaries,
                     orientation,
-                })
+                };
+                Some((indices, face))
             })
             .collect()
     }
 
+    fn shell_faces(
+        &self,
+        shell: &ShellHolder,
+        eidx_map: &HashMap<u64, usize>,
+    ) -> Vec<CompressedFace<Surface>> {
+        self.shell_faces_with_indices(shell, eidx_map)
+            .into_iter()
+            .map(|(_, face)| face)
+            .collect()
+    }
+
+    fn style_color(&self, idx: u64, depth: usize) -> Option<[f64; 3]> {
+        if let Some(rgb) = self.colour_rgb.get(&idx) {
+            return Some(*rgb);
+        }
+        // The chain from `STYLED_ITEM` to `COLOUR_RGB` is short. Avoid the infinite loop.
+        if depth > 16 {
+            return None;
+        }
+        let refs = self.style_reference.get(&idx)?;
+        refs.iter()
+            .find_map(|idx| self.style_color(*idx, depth + 1))
+    }
+
+    /// Returns the map from the indices of the styled items to their colors.
+    pub fn item_colors(&self) -> HashMap<u64, [f64; 3]> {
+        self.styled_item
+            .values()
+            .filter_map(|styled_item| {
+                let mut styles = styled_item.styles.iter();
+                let color = styles.find_map(|idx| self.style_color(*idx, 0))?;
+                Some((styled_item.item, color))
+            })
+            .collect()
+    }
+
+    /// Returns the shells of a representation item: a shell, an oriented shell,
+    /// a solid model or a shell based surface model.
+    fn item_shells(&self, idx: u64, shells: &mut Vec<u64>) {
+        if self.shell.contains_key(&idx) {
+            shells.push(idx);
+        } else if let Some(oriented_shell) = self.oriented_shell.get(&idx) {
+            if let PlaceHolder::Ref(Name::Entity(idx)) = &oriented_shell.shell_element {
+                self.item_shells(*idx, shells);
+            }
+        } else if let Some(refs) = self.solid_model.get(&idx) {
+            refs.iter().for_each(|idx| self.item_shells(*idx, shells));
+        } else if let Some(refs) = self.shell_based_surface_model.get(&idx) {
+            refs.iter().for_each(|idx| self.item_shells(*idx, shells));
+        }
+    }
+
+    /// Returns the shells of the representation and the representations related to it without transformations.
+    fn representation_shells(&self, representation: u64) -> Result<StepPart, ExpressParseError> {
+        let mut representations = vec![representation];
+        let mut i = 0;
+        while i < representations.len() {
+            let current = representations[i];
+            self.representation_relationship
+                .values()
+                .filter(|relationship| relationship.transformation.is_none())
+                .for_each(|relationship| {
+                    let other = match (relationship.rep_1 == current, relationship.rep_2 == current)
+                    {
+                        (true, _) => relationship.rep_2,
+                        (_, true) => relationship.rep_1,
+                        _ => return,
+                    };
+                    if !representations.contains(&other) {
+                        representations.push(other);
+                    }
+                });
+            i += 1;
+        }
+        let mut shells = Vec::new();
+        representations
+            .iter()
+            .filter_map(|idx| self.shape_representation.get(idx))
+            .flat_map(|representation| &representation.items)
+            .for_each(|idx| self.item_shells(*idx, &mut shells));
+        shells.sort();
+        shells.dedup();
+        shells
+            .into_iter()
+            .map(|idx| self.to_compressed_shell(&self.shell[&idx]))
+            .collect()
+    }
+
+    fn axis2_placement_3d_matrix(&self, idx: u64) -> Result<Matrix4, ExpressParseError> {
+        let axis = EntityTable::<Axis2Placement3dHolder>::get_owned(self, idx)?;
+        Ok(Matrix4::from(&axis))
+    }
+
+    /// Returns the placement of the component by the occurrence in the coordinate of the assembly.
+    fn occurrence_placement(
+        &self,
+        occurrence: u64,
+        assembly_representation: Option<u64>,
+    ) -> Result<Matrix4, ExpressParseError> {
+        let transformation = || {
+            let [relation, _] = self.context_dependent_shape_representation.values().find(
+                |[_, product_relation]| {
+                    self.product_definition_shape.get(product_relation) == Some(&occurrence)
+                },
+            )?;
+            let relationship = self.representation_relationship.get(relation)?;
+            let items = self
+                .item_defined_transformation
+                .get(&relationship.transformation?)?;
+            Some((relationship.rep_1, *items))
+        };
+        let Some((rep_1, [item1, item2])) = transformation() else {
+            return Ok(Matrix4::identity());
+        };
+        let mat1 = self.axis2_placement_3d_matrix(item1)?;
+        let mat2 = self.axis2_placement_3d_matrix(item2)?;
+        let placement = mat1.invert().and_then(|inv| {
+            let mat = mat2 * inv;
+            match Some(rep_1) == assembly_representation {
+                true => mat.invert(),
+                false => Some(mat),
+            }
+        });
+        Ok(placement.unwrap_or_else(Matrix4::identity))
+    }
+
+    fn product_name(&self, definition: u64) -> String {
+        let name = || {
+            let formation = self.product_definition.get(&definition)?;
+            let product = self.product_definition_formation.get(formation)?;
+            self.product.get(product).cloned()
+        };
+        name().unwrap_or_default()
+    }
+
+    fn assembly_node(
+        &self,
+        definition: u64,
+        name: String,
+        context: &mut AssemblyContext<'_>,
+    ) -> Result<Assembly<StepPart>, ExpressParseError> {
+        let representation = context.representations.get(&definition).copied();
+        let part = match context.parts.get(&definition) {
+            Some(part) => part.clone(),
+            None => {
+                let shells = match representation {
+                    Some(representation) => self.representation_shells(representation)?,
+                    None => Vec::new(),
+                };
+                let part = match shells.is_empty() {
+                    true => None,
+                    false => Some(Arc::new(shells)),
+                };
+                context.parts.insert(definition, part.clone());
+                part
+            }
+        };
+        let mut node = match part {
+            Some(part) => Assembly::with_part(name, part),
+            None => Assembly::new(name),
+        };
+        context.path.push(definition);
+        let children: Vec<_> = context
+            .occurrences
+            .iter()
+            .filter(|(_, occurrence)| {
+                occurrence.relating == definition && !context.path.contains(&occurrence.related)
+            })
+            .copied()
+            .collect();
+        for (idx, occurrence) in children {
+            let name = match (occurrence.name.is_empty(), occurrence.id.is_empty()) {
+                (false, _) => occurrence.name.clone(),
+                (true, false) => occurrence.id.clone(),
+                (true, true) => self.product_name(occurrence.related),
+            };
+            let mut child = self.assembly_node(occurrence.related, name, context)?;
+            child.set_placement(self.occurrence_placement(idx, representation)?);
+            node.push(child);
+        }
+        context.path.pop();
+        Ok(node)
+    }
+
+    /// Reads the assembly of the products related by `NEXT_ASSEMBLY_USAGE_OCCURRENCE`.
+    ///
+    /// The part of each node is the shells of the shape representation of the product,
+    /// which is shared by all the occurrences of the product. If there are some root products,
+    /// they are the children of an unnamed root. Returns `None` if there are no products,
+    /// and the error if the shells or the placements of the products cannot be read.
+    pub fn to_assembly(&self) -> Result<Option<Assembly<StepPart>>, ExpressParseError> {
+        let representations = self
+            .shape_definition_representation
+            .values()
+            .filter_map(|[definition, representation]| {
+                let definition = self.product_definition_shape.get(definition)?;
+                match self.product_definition.contains_key(definition) {
+                    true => Some((*definition, *representation)),
+                    false => None,
+                }
+            })
+            .collect();
+        let mut occurrences: Vec<_> = self
+            .next_assembly_usage_occurrence
+            .iter()
+            .map(|(idx, occurrence)| (*idx, occurrence))
+            .collect();
+        occurrences.sort_by_key(|(idx, _)| *idx);
+        let mut roots: Vec<u64> = self
+            .product_definition
+            .keys()
+            .filter(|definition| {
+                !occurrences
+                    .iter()
+                    .any(|(_, occurrence)| occurrence.related == **definition)
+            })
+            .copied()
+            .collect();
+        roots.sort();
+        let mut context = AssemblyContext {
+            representations,
+            occurrences,
+            parts: HashMap::new(),
+            path: Vec::new(),
+        };
+        let mut nodes = roots
+            .into_iter()
+            .map(|definition| {
+                let name = self.product_name(definition);
+                self.assembly_node(definition, name, &mut context)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(match nodes.len() {
+            0 => None,
+            1 => nodes.pop(),
+            _ => {
+                let mut root = Assembly::new("");
+                nodes.into_iter().for_each(|node| root.push(node));
+                Some(root)
+            }
+        })
+    }
+
     pub fn to_compressed_shell(
         &self,
         shell: &ShellHolder,
@@ -2687,4 +3280,42 @@ impl Table {
             faces: self.shell_faces(shell, &eidx_map),
         })
     }
+
+    /// Converts the shell with the colors of faces given by `STYLED_ITEM`.
+    pub fn to_compressed_shell_with_colors(
+        &self,
+        shell: &ShellHolder,
+    ) -> Result<
+        (
+            CompressedShell<Point3, Curve3D, Surface>,
+            CompressedAttributes<[f64; 3]>,
+        ),
+        ExpressParseError,
+    > {
+        let (vertices, vidx_map) = self.shell_vertices(shell);
+        let (edges, eidx_map) = self.shell_edges(shell, &vidx_map);
+        let item_colors = self.item_colors();
+        let (face_indices, faces): (Vec<_>, Vec<_>) = self
+            .shell_faces_with_indices(shell, &eidx_map)
+            .into_iter()
+            .unzip();
+        let face_colors = face_indices
+            .into_iter()
+            .enumerate()
+            .filter_map(|(i, indices)| {
+                let mut iter = indices.into_iter();
+                Some((i, iter.find_map(|idx| item_colors.get(&idx).copied())?))
+            })
+            .collect();
+        let cshell = CompressedShell {
+            vertices,
+            edges,
+            faces,
+        };
+        let attributes = CompressedAttributes {
+            faces: face_colors,
+            ..Default::default()
+        };
+        Ok((cshell, attributes))
+    }
 }
@@ -0,0 +1,248 @@
+use super::{Result, *};
+use truck_geometry::prelude::*;
+use truck_topology::assembly::Assembly;
+
+/// `AXIS2_PLACEMENT_3D` of the rigid transformation
+#[derive(Clone, Copy, Debug)]
+struct StepPlacement(Matrix4);
+
+impl DisplayByStep for StepPlacement {
+    fn fmt(&self, idx: usize, f: &mut Formatter<'_>) -> Result {
+        let StepPlacement(mat) = self;
+        let location = mat[3].to_point();
+        let axis = VectorAsDirection(mat[2].truncate().normalize());
+        let ref_direction = VectorAsDirection(mat[0].truncate().normalize());
+        f.write_fmt(format_args!(
+            "#{idx} = AXIS2_PLACEMENT_3D('', #{location_idx}, #{axis_idx}, #{ref_direction_idx});\n",
+            location_idx = idx + 1,
+            axis_idx = idx + 2,
+            ref_direction_idx = idx + 3,
+        ))?;
+        DisplayByStep::fmt(&location, idx + 1, f)?;
+        DisplayByStep::fmt(&axis, idx + 2, f)?;
+        DisplayByStep::fmt(&ref_direction, idx + 3, f)
+    }
+}
+impl_const_step_length!(StepPlacement, 4);
+
+/// the number of lines of a product: from `PRODUCT` to the origin of the shape representation.
+const PRODUCT_LENGTH: usize = 10;
+/// the number of lines of an occurrence: from `NEXT_ASSEMBLY_USAGE_OCCURRENCE` to the placement.
+const OCCURRENCE_LENGTH: usize = 5 + StepPlacement::LENGTH;
+
+#[derive(Clone, Debug)]
+struct StepOccurrence<'a> {
+    name: &'a str,
+    /// the index of the child product in `StepAssembly::products`
+    child: usize,
+    placement: Matrix4,
+}
+
+#[derive(Clone, Debug)]
+enum StepProductKind<'a, P, C, S> {
+    Part(PreStepModel<'a, P, C, S>),
+    Assembly(Vec<StepOccurrence<'a>>),
+}
+
+#[derive(Clone, Debug)]
+struct StepProduct<'a, P, C, S> {
+    idx: usize,
+    name: &'a str,
+    kind: StepProductKind<'a, P, C, S>,
+}
+
+impl<'a, P, C, S> StepProduct<'a, P, C, S> {
+    fn product_definition(&self) -> usize { self.idx + 2 }
+    fn shape_representation(&self) -> usize { self.idx + 5 }
+    fn origin(&self) -> usize { self.idx + 6 }
+    fn occurrence_indices(&self) -> impl Iterator<Item = usize> {
+        let len = match &self.kind {
+            StepProductKind::Part(_) => 0,
+            StepProductKind::Assembly(occurrences) => occurrences.len(),
+        };
+        let ep_occurrences = self.idx + PRODUCT_LENGTH;
+        (0..len).map(move |i| ep_occurrences + OCCURRENCE_LENGTH * i)
+    }
+}
+
+impl<'a, P, C, S> StepLength for StepProduct<'a, P, C, S> {
+    fn step_length(&self) -> usize {
+        PRODUCT_LENGTH
+            + match &self.kind {
+                StepProductKind::Part(model) => model.step_length(),
+                StepProductKind::Assembly(occurrences) => OCCURRENCE_LENGTH * occurrences.len(),
+            }
+    }
+}
+
+/// Display an assembly as products related by `NEXT_ASSEMBLY_USAGE_OCCURRENCE`.
+///
+/// Each shared part is output only once, as a product instanced by the occurrences.
+/// A node with children is output as an assembly product. If the node also has a part,
+/// the part is placed in the assembly by the identity.
+///
+/// # Remarks
+/// The placements are assumed to be rigid transformations.
+#[derive(Clone, Debug)]
+pub struct StepAssembly<'a, P, C, S> {
+    products: Vec<StepProduct<'a, P, C, S>>,
+}
+
+struct ProductCollector<'a, T, P, C, S> {
+    parts: Vec<(*const T, usize)>,
+    products: Vec<StepProduct<'a, P, C, S>>,
+}
+
+impl<'a, T, P, C, S> ProductCollector<'a, T, P, C, S>
+where &'a T: Into<StepModel<'a, P, C, S>>
+{
+    fn part_product(&mut self, name: &'a str, part: &'a std::sync::Arc<T>) -> usize {
+        let ptr = std::sync::Arc::as_ptr(part);
+        if let Some((_, i)) = self.parts.iter().find(|(p, _)| *p == ptr) {
+            return *i;
+        }
+        let part: &'a T = part;
+        let StepModel(model) = part.into();
+        self.products.push(StepProduct {
+            idx: 0,
+            name,
+            kind: StepProductKind::Part(model),
+        });
+        self.parts.push((ptr, self.products.len() - 1));
+        self.products.len() - 1
+    }
+
+    fn product(&mut self, node: &'a Assembly<T>) -> usize {
+        if let (Some(part), true) = (node.part(), node.children().is_empty()) {
+            return self.part_product(node.name(), part);
+        }
+        let res = self.products.len();
+        self.products.push(StepProduct {
+            idx: 0,
+            name: node.name(),
+            kind: StepProductKind::Assembly(Vec::new()),
+        });
+        let part_occurrence = node.part().map(|part| StepOccurrence {
+            name: node.name(),
+            child: self.part_product(node.name(), part),
+            placement: Matrix4::identity(),
+        });
+        let child_occurrences = node.children().iter().map(|child| StepOccurrence {
+            name: child.name(),
+            child: self.product(child),
+            placement: child.placement(),
+        });
+        let occurrences = part_occurrence
+            .into_iter()
+            .chain(child_occurrences)
+            .collect();
+        self.products[res].kind = StepProductKind::Assembly(occurrences);
+        res
+    }
+}
+
+impl<'a, T, P, C, S> From<&'a Assembly<T>> for StepAssembly<'a, P, C, S>
+where &'a T: Into<StepModel<'a, P, C, S>>
+{
+    /// Creates the products of the assembly. The placement of the root is ignored.
+    fn from(assembly: &'a Assembly<T>) -> Self {
+        let mut collector = ProductCollector {
+            parts: Vec::new(),
+            products: Vec::new(),
+        };
+        collector.product(assembly);
+        let mut products = collector.products;
+        let mut cursor = 16;
+        products.iter_mut().for_each(|product| {
+            product.idx = cursor;
+            if let StepProductKind::Part(model) = &mut product.kind {
+                model.set_first_index(cursor + PRODUCT_LENGTH);
+            }
+            cursor += product.step_length();
+        });
+        StepAssembly { products }
+    }
+}
+
+/// escapes the apostrophes in the STEP string
+fn escape(name: &str) -> String { name.replace('\'', "''") }
+
+impl<'a, P, C, S> Display for StepAssembly<'a, P, C, S>
+where
+    P: DisplayByStep + Copy,
+    C: DisplayByStep + StepLength + StepCurve,
+    S: DisplayByStep + StepLength + StepSurface,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.pad(APPLICATION_CONTEXT)?;
+        f.pad(
+            "#8 = PRODUCT_CONTEXT('', #2, 'mechanical');
+#9 = PRODUCT_DEFINITION_CONTEXT('part definition', #2, 'design');\n",
+        )?;
+        f.pad(REPRESENTATION_CONTEXT)?;
+        self.products.iter().try_for_each(|product| {
+            let idx = product.idx;
+            let name = escape(product.name);
+            let (representation, items) = match &product.kind {
+                StepProductKind::Part(model) => ("ADVANCED_BREP_SHAPE_REPRESENTATION", model.items()),
+                StepProductKind::Assembly(_) => (
+                    "SHAPE_REPRESENTATION",
+                    product.occurrence_indices().map(|i| i + 5).collect(),
+                ),
+            };
+            f.write_fmt(format_args!(
+                "#{idx} = PRODUCT('{name}', '{name}', '', (#8));
+#{pdf} = PRODUCT_DEFINITION_FORMATION('', '', #{idx});
+#{pd} = PRODUCT_DEFINITION('design', '', #{pdf}, #9);
+#{pds} = PRODUCT_DEFINITION_SHAPE('', '', #{pd});
+#{sdr} = SHAPE_DEFINITION_REPRESENTATION(#{pds}, #{sr});
+#{sr} = {representation}('{name}', {items}, #11);\n",
+                pdf = idx + 1,
+                pd = product.product_definition(),
+                pds = idx + 3,
+                sdr = idx + 4,
+                sr = product.shape_representation(),
+                items = IndexSliceDisplay(std::iter::once(product.origin()).chain(items)),
+            ))?;
+            Display::fmt(
+                &StepDisplay::new(StepPlacement(Matrix4::identity()), product.origin()),
+                f,
+            )?;
+            match &product.kind {
+                StepProductKind::Part(model) => Display::fmt(model, f),
+                StepProductKind::Assembly(occurrences) => occurrences
+                    .iter()
+                    .zip(product.occurrence_indices())
+                    .try_for_each(|(occurrence, nauo)| {
+                        let child = &self.products[occurrence.child];
+                        f.write_fmt(format_args!(
+                            "#{nauo} = NEXT_ASSEMBLY_USAGE_OCCURRENCE('{name}', '{name}', '', #{parent_pd}, #{child_pd}, $);
+#{pds} = PRODUCT_DEFINITION_SHAPE('', '', #{nauo});
+#{cdsr} = CONTEXT_DEPENDENT_SHAPE_REPRESENTATION(#{rr}, #{pds});
+#{rr} = (
+    REPRESENTATION_RELATIONSHIP('', '', #{child_sr}, #{parent_sr})
+    REPRESENTATION_RELATIONSHIP_WITH_TRANSFORMATION(#{idt})
+    SHAPE_REPRESENTATION_RELATIONSHIP()
+);
+#{idt} = ITEM_DEFINED_TRANSFORMATION('', '', #{child_origin}, #{placement});\n",
+                            name = escape(occurrence.name),
+                            parent_pd = product.product_definition(),
+                            child_pd = child.product_definition(),
+                            pds = nauo + 1,
+                            cdsr = nauo + 2,
+                            rr = nauo + 3,
+                            child_sr = child.shape_representation(),
+                            parent_sr = product.shape_representation(),
+                            idt = nauo + 4,
+                            child_origin = child.origin(),
+                            placement = nauo + 5,
+                        ))?;
+                        Display::fmt(
+                            &StepDisplay::new(StepPlacement(occurrence.placement), nauo + 5),
+                            f,
+                        )
+                    }),
+            }
+        })
+    }
+}
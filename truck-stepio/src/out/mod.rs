@@ -6,6 +6,23 @@ use self::topology::PreStepModel;
 
 const ERR: Result = Err(std::fmt::Error);
 
+/// #1 and #2, the application context
+const APPLICATION_CONTEXT: &str =
+    "#1 = APPLICATION_PROTOCOL_DEFINITION('international standard', 'automotive_design', 2000, #2);
+#2 = APPLICATION_CONTEXT('core data for automotive mechanical design processes');\n";
+
+/// #11 - #15, the geometric representation context with the units
+const REPRESENTATION_CONTEXT: &str = "#11 = (
+    GEOMETRIC_REPRESENTATION_CONTEXT(3) 
+    GLOBAL_UNCERTAINTY_ASSIGNED_CONTEXT((#15))
+    GLOBAL_UNIT_ASSIGNED_CONTEXT((#12, #13, #14))
+    REPRESENTATION_CONTEXT('Context #1', '3D Context with UNIT and UNCERTAINTY')
+);
+#12 = ( LENGTH_UNIT() NAMED_UNIT(*) SI_UNIT(.MILLI.,.METRE.) );
+#13 = ( NAMED_UNIT(*) PLANE_ANGLE_UNIT() SI_UNIT($,.RADIAN.) );
+#14 = ( NAMED_UNIT(*) SI_UNIT($,.STERADIAN.) SOLID_ANGLE_UNIT() );
+#15 = UNCERTAINTY_MEASURE_WITH_UNIT(1.0E-6, #12, 'distance_accuracy_value','confusion accuracy');\n";
+
 #[cfg(feature = "derive")]
 pub use truck_derivers::{DisplayByStep, StepLength};
 
@@ -268,6 +285,22 @@ impl<T: StepSurface> StepSurface for Box<T> {
     fn same_sense(&self) -> bool { self.as_ref().same_sense() }
 }
 
+/// Attributes which are output as the colors of faces by `STYLED_ITEM`.
+pub trait StepColor {
+    /// the RGB color, each component of which is in `[0, 1]`. `None` if the face is not colored.
+    fn step_color(&self) -> Option<[f64; 3]>;
+}
+
+impl StepColor for [f64; 3] {
+    #[inline(always)]
+    fn step_color(&self) -> Option<[f64; 3]> { Some(*self) }
+}
+
+impl<T: StepColor> StepColor for Option<T> {
+    #[inline(always)]
+    fn step_color(&self) -> Option<[f64; 3]> { self.as_ref()?.step_color() }
+}
+
 /// Describe STEP file header
 #[derive(Clone, Debug)]
 pub struct StepHeaderDescriptor {
@@ -383,6 +416,8 @@ impl<T> CompleteStepDisplay<T> {
     }
 }
 
+mod assembly;
 mod geometry;
 mod topology;
+pub use assembly::StepAssembly;
 pub use geometry::VectorAsDirection;
@@ -1,8 +1,11 @@
 use super::{Result, *};
 
+/// The faces, edges and vertices output in a row, with their geometries.
 #[derive(Clone, Debug)]
-pub(super) struct StepShell<'a, P, C, S> {
-    entity: &'a CompressedShell<P, C, S>,
+pub(super) struct StepElements<'a, P, C, S> {
+    vertices: &'a [P],
+    edges: &'a [CompressedEdge<C>],
+    faces: &'a [CompressedFace<S>],
     idx: usize,
     face_indices: Vec<usize>,
     ep_edges: usize,
@@ -10,20 +13,23 @@ pub(super) struct StepShell<'a, P, C, S> {
     surface_indices: Vec<usize>,
     curve_indices: Vec<usize>,
     ep_points: usize,
-    is_open: bool,
+    face_colors: Vec<(usize, [f64; 3])>,
 }
 
-impl<'a, P, C, S> StepShell<'a, P, C, S>
+impl<'a, P, C, S> StepElements<'a, P, C, S>
 where
     P: Copy,
     C: StepLength,
     S: StepLength,
 {
-    fn new(shell: &'a CompressedShell<P, C, S>, idx: usize, is_open: bool) -> Self {
-        let faces = &shell.faces;
-        let edges = &shell.edges;
-        let vertices = &shell.vertices;
-        let mut cursor = idx + 1;
+    fn new(
+        vertices: &'a [P],
+        edges: &'a [CompressedEdge<C>],
+        faces: &'a [CompressedFace<S>],
+        idx: usize,
+        face_colors: Vec<(usize, [f64; 3])>,
+    ) -> Self {
+        let mut cursor = idx;
         let face_indices = faces
             .iter()
             .map(|f| {
@@ -55,8 +61,10 @@ where
             })
             .collect::<Vec<_>>();
         let ep_points = cursor;
-        StepShell {
-            entity: shell,
+        StepElements {
+            vertices,
+            edges,
+            faces,
             idx,
             face_indices,
             ep_edges,
@@ -64,40 +72,45 @@ where
             surface_indices,
             curve_indices,
             ep_points,
-            is_open,
+            face_colors,
         }
     }
 }
 
-impl<'a, P, C, S> Display for StepShell<'a, P, C, S>
+impl<'a, P, C, S> StepElements<'a, P, C, S> {
+    fn shift_indices(&mut self, delta: usize) {
+        self.idx += delta;
+        self.face_indices.iter_mut().for_each(|idx| *idx += delta);
+        self.ep_edges += delta;
+        self.ep_vertices += delta;
+        self.surface_indices
+            .iter_mut()
+            .for_each(|idx| *idx += delta);
+        self.curve_indices.iter_mut().for_each(|idx| *idx += delta);
+        self.ep_points += delta;
+    }
+}
+
+impl<'a, P, C, S> Display for StepElements<'a, P, C, S>
 where
     P: DisplayByStep + Copy,
     C: DisplayByStep + StepCurve,
     S: DisplayByStep + StepSurface,
 {
     fn fmt(&self, formatter: &mut Formatter<'_>) -> Result {
-        let StepShell {
-            entity,
-            idx,
+        let StepElements {
+            vertices,
+            edges,
+            faces,
             face_indices,
             ep_edges,
             ep_vertices,
             surface_indices,
             curve_indices,
             ep_points,
-            is_open,
+            face_colors,
+            ..
         } = self;
-        let faces = &entity.faces;
-        let edges = &entity.edges;
-        let vertices = &entity.vertices;
-        let shell_kind = match is_open {
-            true => "OPEN_SHELL",
-            false => "CLOSED_SHELL",
-        };
-        formatter.write_fmt(format_args!(
-            "#{idx} = {shell_kind}('', {face_indices});\n",
-            face_indices = IndexSliceDisplay(self.face_indices.clone()),
-        ))?;
         faces.iter().enumerate().try_for_each(|(i, f)| {
             let idx = face_indices[i];
             let mut cursor = idx + 1;
@@ -180,16 +193,120 @@ where
         vertices
             .iter()
             .enumerate()
-            .try_for_each(|(i, v)| Display::fmt(&StepDisplay::new(*v, ep_points + i), formatter))
+            .try_for_each(|(i, v)| Display::fmt(&StepDisplay::new(*v, ep_points + i), formatter))?;
+        if face_colors.is_empty() {
+            return Ok(());
+        }
+        let ep_styles = ep_points + vertices.len();
+        formatter.write_fmt(format_args!(
+            "#{ep_styles} = MECHANICAL_DESIGN_GEOMETRIC_PRESENTATION_REPRESENTATION('', {styled_items}, #11);\n",
+            styled_items = IndexSliceDisplay((0..face_colors.len()).map(|j| ep_styles + 1 + 8 * j)),
+        ))?;
+        face_colors
+            .iter()
+            .enumerate()
+            .try_for_each(|(j, (i, [r, g, b]))| {
+                let idx = ep_styles + 1 + 8 * j;
+                formatter.write_fmt(format_args!(
+                    "#{idx} = STYLED_ITEM('color', (#{psa}), #{face});
+#{psa} = PRESENTATION_STYLE_ASSIGNMENT((#{ssu}));
+#{ssu} = SURFACE_STYLE_USAGE(.BOTH., #{sss});
+#{sss} = SURFACE_SIDE_STYLE('', (#{ssfa}));
+#{ssfa} = SURFACE_STYLE_FILL_AREA(#{fas});
+#{fas} = FILL_AREA_STYLE('', (#{fasc}));
+#{fasc} = FILL_AREA_STYLE_COLOUR('', #{colour});
+#{colour} = COLOUR_RGB('', {r}, {g}, {b});\n",
+                    face = face_indices[*i],
+                    psa = idx + 1,
+                    ssu = idx + 2,
+                    sss = idx + 3,
+                    ssfa = idx + 4,
+                    fas = idx + 5,
+                    fasc = idx + 6,
+                    colour = idx + 7,
+                    r = FloatDisplay(*r),
+                    g = FloatDisplay(*g),
+                    b = FloatDisplay(*b),
+                ))
+            })
     }
 }
 
-impl<'a, P, C, S> StepLength for StepShell<'a, P, C, S> {
+impl<'a, P, C, S> StepLength for StepElements<'a, P, C, S> {
     fn step_length(&self) -> usize {
-        1 + self.ep_points + self.entity.vertices.len() - self.face_indices[0]
+        let styles = match self.face_colors.is_empty() {
+            true => 0,
+            false => 1 + 8 * self.face_colors.len(),
+        };
+        self.ep_points + self.vertices.len() - self.idx + styles
     }
 }
 
+#[derive(Clone, Debug)]
+pub(super) struct StepShell<'a, P, C, S> {
+    idx: usize,
+    is_open: bool,
+    elements: StepElements<'a, P, C, S>,
+}
+
+impl<'a, P, C, S> StepShell<'a, P, C, S>
+where
+    P: Copy,
+    C: StepLength,
+    S: StepLength,
+{
+    fn new(
+        shell: &'a CompressedShell<P, C, S>,
+        idx: usize,
+        is_open: bool,
+        face_colors: Vec<(usize, [f64; 3])>,
+    ) -> Self {
+        let elements = StepElements::new(
+            &shell.vertices,
+            &shell.edges,
+            &shell.faces,
+            idx + 1,
+            face_colors,
+        );
+        StepShell {
+            idx,
+            is_open,
+            elements,
+        }
+    }
+}
+
+impl<'a, P, C, S> StepShell<'a, P, C, S> {
+    fn shift_indices(&mut self, delta: usize) {
+        self.idx += delta;
+        self.elements.shift_indices(delta);
+    }
+}
+
+impl<'a, P, C, S> Display for StepShell<'a, P, C, S>
+where
+    P: DisplayByStep + Copy,
+    C: DisplayByStep + StepCurve,
+    S: DisplayByStep + StepSurface,
+{
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> Result {
+        let shell_kind = match self.is_open {
+            true => "OPEN_SHELL",
+            false => "CLOSED_SHELL",
+        };
+        formatter.write_fmt(format_args!(
+            "#{idx} = {shell_kind}('', {face_indices});\n",
+            idx = self.idx,
+            face_indices = IndexSliceDisplay(self.elements.face_indices.clone()),
+        ))?;
+        Display::fmt(&self.elements, formatter)
+    }
+}
+
+impl<'a, P, C, S> StepLength for StepShell<'a, P, C, S> {
+    fn step_length(&self) -> usize { 1 + self.elements.step_length() }
+}
+
 #[derive(Clone, Debug)]
 pub(super) struct StepSolid<'a, P, C, S> {
     idx: usize,
@@ -202,13 +319,19 @@ where
     C: StepLength,
     S: StepLength,
 {
-    fn new(solid: &'a CompressedSolid<P, C, S>, idx: usize) -> Self {
+    fn new(
+        solid: &'a CompressedSolid<P, C, S>,
+        idx: usize,
+        face_colors: Vec<Vec<(usize, [f64; 3])>>,
+    ) -> Self {
         let mut cursor = idx + 1;
+        let mut face_colors = face_colors.into_iter();
         let boundaries = solid
             .boundaries
             .iter()
             .map(|shell| {
-                let res = StepShell::new(shell, cursor, false);
+                let colors = face_colors.next().unwrap_or_default();
+                let res = StepShell::new(shell, cursor, false, colors);
                 cursor += 1 + res.step_length();
                 res
             })
@@ -217,6 +340,15 @@ where
     }
 }
 
+impl<'a, P, C, S> StepSolid<'a, P, C, S> {
+    fn shift_indices(&mut self, delta: usize) {
+        self.idx += delta;
+        self.boundaries
+            .iter_mut()
+            .for_each(|shell| shell.shift_indices(delta));
+    }
+}
+
 impl<'a, P, C, S> Display for StepSolid<'a, P, C, S>
 where
     P: DisplayByStep + Copy,
@@ -239,19 +371,17 @@ where
                 Display::fmt(step_shell, f)
             }
             _ => {
-                let first_shell_idx = boundaries[0].face_indices[0] - 1;
+                let first_shell_idx = boundaries[0].idx;
                 f.write_fmt(format_args!(
                     "#{idx} = BREP_WITH_VOIDS('', #{first_shell_idx}, {other_shells});\n",
                     other_shells = IndexSliceDisplay(
-                        boundaries[1..]
-                            .iter()
-                            .map(|step_shell| step_shell.face_indices[0] - 2)
+                        boundaries[1..].iter().map(|step_shell| step_shell.idx - 1)
                     ),
                 ))?;
                 Display::fmt(&boundaries[0], f)?;
                 boundaries[1..].iter().try_for_each(|step_shell| {
-                    let oriented_shell_idx = step_shell.face_indices[0] - 2;
-                    let shell_idx = step_shell.face_indices[0] - 1;
+                    let oriented_shell_idx = step_shell.idx - 1;
+                    let shell_idx = step_shell.idx;
                     f.write_fmt(format_args!(
                     "#{oriented_shell_idx} = ORIENTED_CLOSED_SHELL('', *, #{shell_idx}, .T.);\n",
                 ))?;
@@ -273,12 +403,267 @@ impl<'a, P, C, S> StepLength for StepSolid<'a, P, C, S> {
     }
 }
 
+#[derive(Clone, Debug)]
+pub(super) struct StepCompound<'a, P, C, S> {
+    idx: usize,
+    solids: Vec<(usize, Vec<Vec<usize>>)>,
+    shells: Vec<(usize, Vec<usize>)>,
+    curve_set: Option<(usize, Vec<usize>)>,
+    isolated_vertices: &'a [usize],
+    oriented_faces: Vec<(usize, usize)>,
+    elements: StepElements<'a, P, C, S>,
+}
+
+impl<'a, P, C, S> StepCompound<'a, P, C, S>
+where
+    P: Copy,
+    C: StepLength,
+    S: StepLength,
+{
+    fn new(compound: &'a CompressedCompound<P, C, S>, idx: usize) -> Self {
+        let mut cursor = idx;
+        let solid_indices = compound
+            .solids
+            .iter()
+            .map(|boundaries| {
+                let res = cursor;
+                cursor += 2 * boundaries.len();
+                res
+            })
+            .collect::<Vec<_>>();
+        let shell_indices = compound
+            .shells
+            .iter()
+            .map(|_| {
+                let res = cursor;
+                cursor += 2;
+                res
+            })
+            .collect::<Vec<_>>();
+        let mut wire_edges = compound
+            .wires
+            .iter()
+            .flatten()
+            .map(|edge_index| edge_index.index)
+            .collect::<Vec<_>>();
+        wire_edges.sort();
+        wire_edges.dedup();
+        let curve_set = match wire_edges.is_empty() && compound.isolated_vertices.is_empty() {
+            true => None,
+            false => {
+                let res = cursor;
+                cursor += 1 + wire_edges.len();
+                Some((res, wire_edges))
+            }
+        };
+        let mut inverted_faces = vec![None; compound.faces.len()];
+        compound
+            .solids
+            .iter()
+            .flatten()
+            .chain(&compound.shells)
+            .flatten()
+            .filter(|face_index| !face_index.orientation)
+            .for_each(|face_index| {
+                if inverted_faces[face_index.index].is_none() {
+                    inverted_faces[face_index.index] = Some(cursor);
+                    cursor += 1;
+                }
+            });
+        let elements = StepElements::new(
+            &compound.vertices,
+            &compound.edges,
+            &compound.faces,
+            cursor,
+            Vec::new(),
+        );
+        let face_indices = |shell: &Vec<CompressedFaceIndex>| {
+            shell
+                .iter()
+                .map(|face_index| {
+                    match (face_index.orientation, inverted_faces[face_index.index]) {
+                        (false, Some(idx)) => idx,
+                        _ => elements.face_indices[face_index.index],
+                    }
+                })
+                .collect::<Vec<_>>()
+        };
+        let solids = solid_indices
+            .into_iter()
+            .zip(&compound.solids)
+            .map(|(idx, boundaries)| (idx, boundaries.iter().map(&face_indices).collect()))
+            .collect();
+        let shells = shell_indices
+            .into_iter()
+            .zip(&compound.shells)
+            .map(|(idx, shell)| (idx, face_indices(shell)))
+            .collect();
+        let oriented_faces = inverted_faces
+            .into_iter()
+            .enumerate()
+            .filter_map(|(i, idx)| Some((idx?, elements.face_indices[i])))
+            .collect();
+        StepCompound {
+            idx,
+            solids,
+            shells,
+            curve_set,
+            isolated_vertices: &compound.isolated_vertices,
+            oriented_faces,
+            elements,
+        }
+    }
+
+    fn items(&self) -> Vec<usize> {
+        let solids = self.solids.iter().map(|(idx, _)| *idx);
+        let shells = self.shells.iter().map(|(idx, _)| *idx);
+        let curve_set = self.curve_set.iter().map(|(idx, _)| *idx);
+        solids.chain(shells).chain(curve_set).collect()
+    }
+}
+
+impl<'a, P, C, S> StepCompound<'a, P, C, S> {
+    fn shift_indices(&mut self, delta: usize) {
+        self.idx += delta;
+        self.solids.iter_mut().for_each(|(idx, boundaries)| {
+            *idx += delta;
+            boundaries
+                .iter_mut()
+                .flatten()
+                .for_each(|idx| *idx += delta);
+        });
+        self.shells.iter_mut().for_each(|(idx, face_indices)| {
+            *idx += delta;
+            face_indices.iter_mut().for_each(|idx| *idx += delta);
+        });
+        if let Some((idx, _)) = &mut self.curve_set {
+            *idx += delta;
+        }
+        self.oriented_faces.iter_mut().for_each(|(idx, face_idx)| {
+            *idx += delta;
+            *face_idx += delta;
+        });
+        self.elements.shift_indices(delta);
+    }
+}
+
+impl<'a, P, C, S> Display for StepCompound<'a, P, C, S>
+where
+    P: DisplayByStep + Copy,
+    C: DisplayByStep + StepCurve,
+    S: DisplayByStep + StepSurface,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        self.solids.iter().try_for_each(|(idx, boundaries)| {
+            match boundaries.len() {
+                0 => {
+                    f.pad("empty solid!")?;
+                    return Err(std::fmt::Error);
+                }
+                1 => f.write_fmt(format_args!(
+                    "#{idx} = MANIFOLD_SOLID_BREP('', #{shell_idx});\n",
+                    shell_idx = idx + 1,
+                ))?,
+                n => f.write_fmt(format_args!(
+                    "#{idx} = BREP_WITH_VOIDS('', #{shell_idx}, {other_shells});\n",
+                    shell_idx = idx + 1,
+                    other_shells = IndexSliceDisplay((1..n).map(|k| idx + 2 * k)),
+                ))?,
+            }
+            boundaries.iter().enumerate().try_for_each(|(k, face_indices)| {
+                let shell_idx = idx + 2 * k + 1;
+                if k > 0 {
+                    f.write_fmt(format_args!(
+                        "#{oriented_shell_idx} = ORIENTED_CLOSED_SHELL('', *, #{shell_idx}, .T.);\n",
+                        oriented_shell_idx = shell_idx - 1,
+                    ))?;
+                }
+                f.write_fmt(format_args!(
+                    "#{shell_idx} = CLOSED_SHELL('', {face_indices});\n",
+                    face_indices = IndexSliceDisplay(face_indices.clone()),
+                ))
+            })
+        })?;
+        self.shells.iter().try_for_each(|(idx, face_indices)| {
+            f.write_fmt(format_args!(
+                "#{idx} = SHELL_BASED_SURFACE_MODEL('', (#{shell_idx}));
+#{shell_idx} = OPEN_SHELL('', {face_indices});\n",
+                shell_idx = idx + 1,
+                face_indices = IndexSliceDisplay(face_indices.clone()),
+            ))
+        })?;
+        if let Some((idx, wire_edges)) = &self.curve_set {
+            let elements = &self.elements;
+            let trimmed_curves = (0..wire_edges.len()).map(|j| idx + 1 + j);
+            let points = self
+                .isolated_vertices
+                .iter()
+                .map(|i| elements.ep_points + i);
+            f.write_fmt(format_args!(
+                "#{idx} = GEOMETRIC_CURVE_SET('', {items});\n",
+                items = IndexSliceDisplay(trimmed_curves.chain(points)),
+            ))?;
+            wire_edges.iter().enumerate().try_for_each(|(j, i)| {
+                let edge = &elements.edges[*i];
+                f.write_fmt(format_args!(
+                    "#{trimmed_idx} = TRIMMED_CURVE('', #{curve_idx}, (#{start}), (#{end}), {same_sense}, .CARTESIAN.);\n",
+                    trimmed_idx = idx + 1 + j,
+                    curve_idx = elements.curve_indices[*i],
+                    start = elements.ep_points + edge.vertices.0,
+                    end = elements.ep_points + edge.vertices.1,
+                    same_sense = BooleanDisplay(edge.curve.same_sense()),
+                ))
+            })?;
+        }
+        self.oriented_faces.iter().try_for_each(|(idx, face_idx)| {
+            f.write_fmt(format_args!(
+                "#{idx} = ORIENTED_FACE('', *, #{face_idx}, .F.);\n"
+            ))
+        })?;
+        Display::fmt(&self.elements, f)
+    }
+}
+
+impl<'a, P, C, S> StepLength for StepCompound<'a, P, C, S> {
+    fn step_length(&self) -> usize { self.elements.idx - self.idx + self.elements.step_length() }
+}
+
 #[derive(Clone, Debug)]
 pub(super) enum PreStepModel<'a, P, C, S> {
     /// shell based surface model
     Shell(StepShell<'a, P, C, S>),
     /// solid model
     Solid(StepSolid<'a, P, C, S>),
+    /// compound of solids, shell based surface models and a geometric curve set
+    Compound(StepCompound<'a, P, C, S>),
+}
+
+impl<'a, P, C, S> PreStepModel<'a, P, C, S> {
+    /// the first index of the model
+    fn first_index(&self) -> usize {
+        match self {
+            Self::Shell(x) => x.idx - 1,
+            Self::Solid(x) => x.idx,
+            Self::Compound(x) => x.idx,
+        }
+    }
+    /// moves the model backward so that it is output from `idx`
+    pub(super) fn set_first_index(&mut self, idx: usize) {
+        let delta = idx - self.first_index();
+        match self {
+            Self::Shell(x) => x.shift_indices(delta),
+            Self::Solid(x) => x.shift_indices(delta),
+            Self::Compound(x) => x.shift_indices(delta),
+        }
+    }
+    /// the indices of the representation items
+    pub(super) fn items(&self) -> Vec<usize> {
+        match self {
+            Self::Shell(x) => vec![x.idx - 1],
+            Self::Solid(x) => vec![x.idx],
+            Self::Compound(x) => x.items(),
+        }
+    }
 }
 
 impl<'a, P, C, S> From<&'a CompressedShell<P, C, S>> for PreStepModel<'a, P, C, S>
@@ -288,7 +673,7 @@ where
     S: StepLength,
 {
     fn from(shell: &'a CompressedShell<P, C, S>) -> Self {
-        Self::Shell(StepShell::new(shell, 17, true))
+        Self::Shell(StepShell::new(shell, 17, true, Vec::new()))
     }
 }
 
@@ -298,7 +683,20 @@ where
     C: StepLength,
     S: StepLength,
 {
-    fn from(solid: &'a CompressedSolid<P, C, S>) -> Self { Self::Solid(StepSolid::new(solid, 16)) }
+    fn from(solid: &'a CompressedSolid<P, C, S>) -> Self {
+        Self::Solid(StepSolid::new(solid, 16, Vec::new()))
+    }
+}
+
+impl<'a, P, C, S> From<&'a CompressedCompound<P, C, S>> for PreStepModel<'a, P, C, S>
+where
+    P: Copy,
+    C: StepLength,
+    S: StepLength,
+{
+    fn from(compound: &'a CompressedCompound<P, C, S>) -> Self {
+        Self::Compound(StepCompound::new(compound, 16))
+    }
 }
 
 impl<'a, P, C, S> Display for PreStepModel<'a, P, C, S>
@@ -318,6 +716,7 @@ where
                 Display::fmt(&x, f)
             }
             Self::Solid(x) => Display::fmt(x, f),
+            Self::Compound(x) => Display::fmt(x, f),
         }
     }
 }
@@ -327,6 +726,7 @@ impl<'a, P, C, S> StepLength for PreStepModel<'a, P, C, S> {
         match self {
             Self::Shell(x) => 1 + x.step_length(),
             Self::Solid(x) => x.step_length(),
+            Self::Compound(x) => x.step_length(),
         }
     }
 }
@@ -349,6 +749,47 @@ where
     fn from(solid: &'a CompressedSolid<P, C, S>) -> Self { Self(solid.into()) }
 }
 
+impl<'a, P, C, S> From<&'a CompressedCompound<P, C, S>> for StepModel<'a, P, C, S>
+where
+    P: Copy,
+    C: StepLength,
+    S: StepLength,
+{
+    fn from(compound: &'a CompressedCompound<P, C, S>) -> Self { Self(compound.into()) }
+}
+
+fn face_colors<A: StepColor>(attributes: &CompressedAttributes<A>) -> Vec<(usize, [f64; 3])> {
+    attributes
+        .faces
+        .iter()
+        .filter_map(|(i, a)| Some((*i, a.step_color()?)))
+        .collect()
+}
+
+impl<'a, P, C, S> StepModel<'a, P, C, S>
+where
+    P: Copy,
+    C: StepLength,
+    S: StepLength,
+{
+    /// Creates the model of the shell, whose faces are colored by the attributes.
+    pub fn shell_with_colors<A: StepColor>(
+        shell: &'a CompressedShell<P, C, S>,
+        attributes: &CompressedAttributes<A>,
+    ) -> Self {
+        let step_shell = StepShell::new(shell, 17, true, face_colors(attributes));
+        Self(PreStepModel::Shell(step_shell))
+    }
+    /// Creates the model of the solid, whose faces are colored by the attributes of each boundary shell.
+    pub fn solid_with_colors<A: StepColor>(
+        solid: &'a CompressedSolid<P, C, S>,
+        attributes: &[CompressedAttributes<A>],
+    ) -> Self {
+        let colors = attributes.iter().map(face_colors).collect();
+        Self(PreStepModel::Solid(StepSolid::new(solid, 16, colors)))
+    }
+}
+
 impl<'a, P, C, S> Display for StepModel<'a, P, C, S>
 where
     P: DisplayByStep + Copy,
@@ -356,28 +797,21 @@ where
     S: DisplayByStep + StepLength + StepSurface,
 {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.pad(APPLICATION_CONTEXT)?;
         f.pad(
-"#1 = APPLICATION_PROTOCOL_DEFINITION('international standard', 'automotive_design', 2000, #2);
-#2 = APPLICATION_CONTEXT('core data for automotive mechanical design processes');
-#3 = SHAPE_DEFINITION_REPRESENTATION(#4, #10);
+            "#3 = SHAPE_DEFINITION_REPRESENTATION(#4, #10);
 #4 = PRODUCT_DEFINITION_SHAPE('','', #5);
 #5 = PRODUCT_DEFINITION('design','', #6, #9);
 #6 = PRODUCT_DEFINITION_FORMATION('','', #7);
 #7 = PRODUCT('','','', (#8));
 #8 = PRODUCT_CONTEXT('', #2, 'mechanical');
-#9 = PRODUCT_DEFINITION_CONTEXT('part definition', #2, 'design');
-#10 = ADVANCED_BREP_SHAPE_REPRESENTATION('', (#16), #11);
-#11 = (
-    GEOMETRIC_REPRESENTATION_CONTEXT(3) 
-    GLOBAL_UNCERTAINTY_ASSIGNED_CONTEXT((#15))
-    GLOBAL_UNIT_ASSIGNED_CONTEXT((#12, #13, #14))
-    REPRESENTATION_CONTEXT('Context #1', '3D Context with UNIT and UNCERTAINTY')
-);
-#12 = ( LENGTH_UNIT() NAMED_UNIT(*) SI_UNIT(.MILLI.,.METRE.) );
-#13 = ( NAMED_UNIT(*) PLANE_ANGLE_UNIT() SI_UNIT($,.RADIAN.) );
-#14 = ( NAMED_UNIT(*) SI_UNIT($,.STERADIAN.) SOLID_ANGLE_UNIT() );
-#15 = UNCERTAINTY_MEASURE_WITH_UNIT(1.0E-6, #12, 'distance_accuracy_value','confusion accuracy');\n"
+#9 = PRODUCT_DEFINITION_CONTEXT('part definition', #2, 'design');\n",
         )?;
+        f.write_fmt(format_args!(
+            "#10 = ADVANCED_BREP_SHAPE_REPRESENTATION('', {items}, #11);\n",
+            items = IndexSliceDisplay(self.0.items()),
+        ))?;
+        f.pad(REPRESENTATION_CONTEXT)?;
         Display::fmt(&self.0, f)
     }
 }
@@ -399,13 +833,41 @@ where
 {
     /// push a shell to step models
     pub fn push_shell(&mut self, shell: &'a CompressedShell<P, C, S>) {
-        let model = PreStepModel::Shell(StepShell::new(shell, self.next_idx + 1, true));
+        let model = PreStepModel::Shell(StepShell::new(shell, self.next_idx + 1, true, Vec::new()));
         self.next_idx += model.step_length();
         self.models.push(model)
     }
     /// push a solid to step models
     pub fn push_solid(&mut self, solid: &'a CompressedSolid<P, C, S>) {
-        let model = PreStepModel::Solid(StepSolid::new(solid, self.next_idx));
+        let model = PreStepModel::Solid(StepSolid::new(solid, self.next_idx, Vec::new()));
+        self.next_idx += model.step_length();
+        self.models.push(model)
+    }
+    /// push a compound to step models
+    pub fn push_compound(&mut self, compound: &'a CompressedCompound<P, C, S>) {
+        let model = PreStepModel::Compound(StepCompound::new(compound, self.next_idx));
+        self.next_idx += model.step_length();
+        self.models.push(model)
+    }
+    /// push a shell, whose faces are colored by the attributes, to step models
+    pub fn push_shell_with_colors<A: StepColor>(
+        &mut self,
+        shell: &'a CompressedShell<P, C, S>,
+        attributes: &CompressedAttributes<A>,
+    ) {
+        let colors = face_colors(attributes);
+        let model = PreStepModel::Shell(StepShell::new(shell, self.next_idx + 1, true, colors));
+        self.next_idx += model.step_length();
+        self.models.push(model)
+    }
+    /// push a solid, whose faces are colored by the attributes, to step models
+    pub fn push_solid_with_colors<A: StepColor>(
+        &mut self,
+        solid: &'a CompressedSolid<P, C, S>,
+        attributes: &[CompressedAttributes<A>],
+    ) {
+        let colors = attributes.iter().map(face_colors).collect();
+        let model = PreStepModel::Solid(StepSolid::new(solid, self.next_idx, colors));
         self.next_idx += model.step_length();
         self.models.push(model)
     }
@@ -422,7 +884,8 @@ where
         let models = iter
             .into_iter()
             .map(|shell| {
-                let model = PreStepModel::Shell(StepShell::new(shell, next_idx + 1, true));
+                let model =
+                    PreStepModel::Shell(StepShell::new(shell, next_idx + 1, true, Vec::new()));
                 next_idx += model.step_length();
                 model
             })
@@ -442,7 +905,7 @@ where
         let models = iter
             .into_iter()
             .map(|solid| {
-                let model = PreStepModel::Solid(StepSolid::new(solid, next_idx));
+                let model = PreStepModel::Solid(StepSolid::new(solid, next_idx, Vec::new()));
                 next_idx += model.step_length();
                 model
             })
@@ -458,34 +921,21 @@ where
     S: DisplayByStep + StepLength + StepSurface,
 {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.pad(APPLICATION_CONTEXT)?;
         f.pad(
-"#1 = APPLICATION_PROTOCOL_DEFINITION('international standard', 'automotive_design', 2000, #2);
-#2 = APPLICATION_CONTEXT('core data for automotive mechanical design processes');
-#3 = SHAPE_DEFINITION_REPRESENTATION(#4, #10);
+            "#3 = SHAPE_DEFINITION_REPRESENTATION(#4, #10);
 #4 = PRODUCT_DEFINITION_SHAPE('','', #5);
 #5 = PRODUCT_DEFINITION('design','', #6, #9);
 #6 = PRODUCT_DEFINITION_FORMATION('','', #7);
 #7 = PRODUCT('','','', (#8));
 #8 = PRODUCT_CONTEXT('', #2, 'mechanical');
-#9 = PRODUCT_DEFINITION_CONTEXT('part definition', #2, 'design');\n")?;
-        let models_slice = IndexSliceDisplay(self.models.iter().map(|model| match model {
-            PreStepModel::Shell(x) => x.idx - 1,
-            PreStepModel::Solid(x) => x.idx,
-        }));
+#9 = PRODUCT_DEFINITION_CONTEXT('part definition', #2, 'design');\n",
+        )?;
+        let models_slice = IndexSliceDisplay(self.models.iter().flat_map(PreStepModel::items));
         f.write_fmt(format_args!(
             "#10 = ADVANCED_BREP_SHAPE_REPRESENTATION('', {models_slice}, #11);\n"
         ))?;
-        f.pad("#11 = (
-    GEOMETRIC_REPRESENTATION_CONTEXT(3) 
-    GLOBAL_UNCERTAINTY_ASSIGNED_CONTEXT((#15))
-    GLOBAL_UNIT_ASSIGNED_CONTEXT((#12, #13, #14))
-    REPRESENTATION_CONTEXT('Context #1', '3D Context with UNIT and UNCERTAINTY')
-);
-#12 = ( LENGTH_UNIT() NAMED_UNIT(*) SI_UNIT(.MILLI.,.METRE.) );
-#13 = ( NAMED_UNIT(*) PLANE_ANGLE_UNIT() SI_UNIT($,.RADIAN.) );
-#14 = ( NAMED_UNIT(*) SI_UNIT($,.STERADIAN.) SOLID_ANGLE_UNIT() );
-#15 = UNCERTAINTY_MEASURE_WITH_UNIT(1.0E-6, #12, 'distance_accuracy_value','confusion accuracy');\n"
-        )?;
+        f.pad(REPRESENTATION_CONTEXT)?;
         self.models
             .iter()
             .try_for_each(|model| Display::fmt(model, f))
@@ -1751,3 +1751,86 @@ proptest! {
         exec_surface_of_revolution(point0_coord, point1_coord, org_coord, axis_array)
     }
 }
+
+#[test]
+fn closed_unclamped_b_spline_curve() {
+    let ctrl_pts = vec![
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(0.0, 1.0, 0.5),
+        Point3::new(-1.0, 0.0, 1.0),
+        Point3::new(0.0, -1.0, 0.5),
+    ];
+    let periodic = PeriodicBSplineCurve::new(3, vec![0.0, 1.0, 2.5, 3.0, 4.0], ctrl_pts);
+    let unclamped = periodic.to_unclamped();
+    let (t0, t1) = periodic.range_tuple();
+    [(".T.", true), (".F.", false), (".U.", false)]
+        .into_iter()
+        .for_each(|(closed, clamped)| {
+            let step_str = format!("DATA;{}ENDSEC;", StepDisplay::new(&unclamped, 1)).replacen(
+                ".UNSPECIFIED., .U., .U.,",
+                &format!(".UNSPECIFIED., {closed}, .U.,"),
+                1,
+            );
+            let step_curve = step_to_entity::<BSplineCurveWithKnotsHolder>(&step_str);
+            let step_curve = BSplineCurveAny::NonRationalBSplineCurve(Box::new(
+                NonRationalBSplineCurve::BSplineCurveWithKnots(step_curve),
+            ));
+            let curve = match Curve3D::try_from(&step_curve).unwrap() {
+                Curve3D::BSplineCurve(curve) => curve,
+                _ => panic!("the curve is not a B-spline curve."),
+            };
+            assert_eq!(curve.is_clamped(), clamped, "{closed}");
+            match clamped {
+                true => assert_near!(Point2::from(curve.range_tuple()), Point2::new(t0, t1)),
+                false => assert_eq!(curve, unclamped),
+            }
+            (0..=20).for_each(|i| {
+                let t = t0 + (t1 - t0) * i as f64 / 20.0;
+                assert_near!(curve.subs(t), unclamped.subs(t));
+            });
+        });
+}
+
+#[test]
+fn closed_unclamped_b_spline_surface() {
+    let ctrl_pts = vec![
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+        Point3::new(-1.0, 0.0, 0.0),
+        Point3::new(0.0, -1.0, 0.0),
+        Point3::new(0.5, -0.5, 0.0),
+    ];
+    let periodic = PeriodicBSplineCurve::new(2, vec![0.0, 1.0, 2.0, 3.5, 4.0, 5.0], ctrl_pts);
+    let unclamped = periodic.to_unclamped();
+    let rows = unclamped
+        .control_points()
+        .iter()
+        .map(|p| vec![*p, *p + Vector3::new(0.1, 0.2, 1.0)])
+        .collect();
+    let surface = BSplineSurface::new(
+        (unclamped.knot_vec().clone(), KnotVec::bezier_knot(1)),
+        rows,
+    );
+    let (u0, u1) = periodic.range_tuple();
+    let step_str = format!("DATA;{}ENDSEC;", StepDisplay::new(&surface, 1)).replacen(
+        ".UNSPECIFIED., .U., .U., .U.,",
+        ".UNSPECIFIED., .T., .F., .U.,",
+        1,
+    );
+    let step_surface = step_to_entity::<BSplineSurfaceWithKnotsHolder>(&step_str);
+    let step_surface = BSplineSurfaceAny::NonRationalBSplineSurface(
+        NonRationalBSplineSurface::BSplineSurfaceWithKnots(Box::new(step_surface)),
+    );
+    let res = match Surface::try_from(&step_surface).unwrap() {
+        Surface::BSplineSurface(res) => res,
+        _ => panic!("the surface is not a B-spline surface."),
+    };
+    assert!(res.is_clamped());
+    assert_near!(res.uknot_vec().range_length(), u1 - u0);
+    (0..=10)
+        .flat_map(|i| (0..=10).map(move |j| (i, j)))
+        .for_each(|(i, j)| {
+            let (u, v) = (u0 + (u1 - u0) * i as f64 / 10.0, j as f64 / 10.0);
+            assert_near!(res.subs(u, v), surface.subs(u, v));
+        });
+}
@@ -0,0 +1,63 @@
+use std::sync::Arc;
+use truck_modeling::*;
+use truck_stepio::{
+    out::{CompleteStepDisplay, StepAssembly},
+    r#in::Table,
+};
+
+#[test]
+fn assembly_oi() {
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::unit_y());
+    let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+    let part = Arc::new(cube.compress());
+
+    // two cubes in the sub-assembly, and the sub-assembly and a cube in the root.
+    let mut sub = Assembly::new("pair");
+    (0..2).for_each(|i| {
+        let mut node = Assembly::with_part(format!("cube{i}"), Arc::clone(&part));
+        node.set_placement(Matrix4::from_translation(Vector3::new(
+            0.0,
+            2.0 * i as f64,
+            0.0,
+        )));
+        sub.push(node);
+    });
+    sub.set_placement(
+        Matrix4::from_translation(Vector3::new(3.0, 0.0, 0.0)) * Matrix4::from_angle_z(Rad(1.0)),
+    );
+    let mut root = Assembly::new("root");
+    root.push(sub);
+    root.push(Assembly::with_part("cube2", Arc::clone(&part)));
+
+    let step_string =
+        CompleteStepDisplay::new(StepAssembly::from(&root), Default::default()).to_string();
+    ruststep::parser::parse(&step_string).unwrap();
+    assert_eq!(step_string.matches("MANIFOLD_SOLID_BREP").count(), 1);
+    assert_eq!(
+        step_string
+            .matches("NEXT_ASSEMBLY_USAGE_OCCURRENCE")
+            .count(),
+        4
+    );
+
+    let table = Table::from_step(&step_string).unwrap();
+    let assembly = table.to_assembly().unwrap().unwrap();
+    assert_eq!(assembly.name(), "root");
+    assert_eq!(assembly.children().len(), 2);
+    assert_eq!(assembly.children()[0].name(), "pair");
+    assert_eq!(assembly.parts().len(), 1);
+    let instances = assembly.instances();
+    let expected = root.instances();
+    assert_eq!(instances.len(), 3);
+    instances
+        .iter()
+        .zip(&expected)
+        .for_each(|(instance, expected)| {
+            assert_eq!(instance.name, expected.name);
+            assert_near!(instance.placement, expected.placement);
+            assert_eq!(instance.part.len(), 1);
+            assert_eq!(instance.part[0].faces.len(), 6);
+        });
+}
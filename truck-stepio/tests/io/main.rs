@@ -1,2 +1,4 @@
+mod assembly;
 mod ioi;
 mod oi;
+mod styled_item;
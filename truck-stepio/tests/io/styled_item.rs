@@ -0,0 +1,40 @@
+use truck_modeling::*;
+use truck_stepio::{
+    out::{CompleteStepDisplay, StepModel},
+    r#in::Table,
+};
+
+#[test]
+fn face_colors_oi() {
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::unit_y());
+    let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+
+    // the third face is not colored.
+    let mut attributes = AttributeStore::new();
+    cube.face_iter().enumerate().for_each(|(i, face)| {
+        if i != 2 {
+            attributes.insert_face(face, [i as f64 / 6.0, 0.5, 1.0]);
+        }
+    });
+    let (csolid, cattributes) = cube.compress_with_attributes(&attributes);
+    let step_string = CompleteStepDisplay::new(
+        StepModel::solid_with_colors(&csolid, &cattributes),
+        Default::default(),
+    )
+    .to_string();
+    ruststep::parser::parse(&step_string).unwrap();
+
+    let table = Table::from_step(&step_string).unwrap();
+    assert_eq!(table.styled_item.len(), 5);
+    let step_shell = table.shell.values().next().unwrap();
+    let (cshell, colors) = table.to_compressed_shell_with_colors(step_shell).unwrap();
+    assert_eq!(cshell.faces.len(), 6);
+    assert_eq!(colors.faces, cattributes[0].faces);
+
+    let (shell, attributes) =
+        truck_topology::Shell::extract_with_attributes(cshell, colors).unwrap();
+    assert_eq!(attributes.face(shell[0].id()), Some(&[0.0, 0.5, 1.0]));
+    assert_eq!(attributes.face(shell[2].id()), None);
+}
@@ -59,3 +59,34 @@ fn parse_solids() {
         panic!("failed to parse step\n[Error Message]\n{e}[STEP file]\n{step_string}")
     });
 }
+
+#[test]
+fn parse_compound() {
+    let json = std::fs::read(SOLID_JSONS[1]).unwrap();
+    let csolid: CompressedSolid<Point3, Curve, Surface> =
+        serde_json::from_reader(json.as_slice()).unwrap();
+    let solid = Solid::extract(csolid).unwrap();
+    let face = solid.boundaries()[0][0].clone();
+    let wire: Wire = face.boundaries()[0].iter().take(2).cloned().collect();
+    let compound = Compound::from_components(
+        vec![solid],
+        vec![vec![face.inverse()].into()],
+        vec![wire],
+        vec![builder::vertex(Point3::new(10.0, 10.0, 10.0))],
+    );
+    let ccompound = compound.compress();
+    let step_string =
+        CompleteStepDisplay::new(StepModel::from(&ccompound), Default::default()).to_string();
+    ruststep::parser::parse(&step_string).unwrap_or_else(|e| {
+        panic!("failed to parse step\n[Error Message]\n{e}[STEP file]\n{step_string}")
+    });
+    assert_eq!(step_string.matches("MANIFOLD_SOLID_BREP").count(), 1);
+    assert_eq!(step_string.matches("SHELL_BASED_SURFACE_MODEL").count(), 1);
+    assert_eq!(step_string.matches("GEOMETRIC_CURVE_SET").count(), 1);
+    assert_eq!(step_string.matches("TRIMMED_CURVE").count(), 2);
+    assert_eq!(step_string.matches("ORIENTED_FACE").count(), 1);
+    assert_eq!(
+        step_string.matches("FACE_SURFACE").count(),
+        ccompound.faces.len()
+    );
+}
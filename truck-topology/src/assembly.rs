@@ -0,0 +1,182 @@
+use rustc_hash::FxHashMap as HashMap;
+use std::sync::Arc;
+use truck_base::cgmath64::{Matrix4, SquareMatrix};
+
+/// A node of an assembly tree, which has a name, a placement in the coordinate of the parent node,
+/// an optional part and sub-assemblies.
+///
+/// The parts are shared by [`Arc`], so a part instanced many times is stored only once.
+/// # Examples
+/// ```
+/// use std::sync::Arc;
+/// use truck_base::cgmath64::*;
+/// use truck_topology::assembly::Assembly;
+///
+/// // any shape, e.g. `Solid`
+/// let bolt = Arc::new("bolt");
+///
+/// let mut root = Assembly::new("root");
+/// (0..3).for_each(|i| {
+///     let mut node = Assembly::with_part(format!("bolt{i}"), Arc::clone(&bolt));
+///     node.set_placement(Matrix4::from_translation(Vector3::new(i as f64, 0.0, 0.0)));
+///     root.push(node);
+/// });
+/// let instances = root.instances();
+/// assert_eq!(instances.len(), 3);
+/// assert_eq!(instances[2].name, "bolt2");
+/// assert_eq!(instances[2].placement[3], Vector4::new(2.0, 0.0, 0.0, 1.0));
+/// // the bolt is not copied.
+/// assert_eq!(root.parts().len(), 1);
+/// ```
+#[derive(Debug)]
+pub struct Assembly<T> {
+    name: String,
+    placement: Matrix4,
+    part: Option<Arc<T>>,
+    children: Vec<Assembly<T>>,
+}
+
+impl<T> Clone for Assembly<T> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        Self {
+            name: self.name.clone(),
+            placement: self.placement,
+            part: self.part.clone(),
+            children: self.children.clone(),
+        }
+    }
+}
+
+/// An instance of a part in an assembly.
+#[derive(Debug)]
+pub struct AssemblyInstance<'a, T> {
+    /// the name of the node holding the part
+    pub name: &'a str,
+    /// the placement of the part in the coordinate of the root
+    pub placement: Matrix4,
+    /// the shared part
+    pub part: &'a Arc<T>,
+}
+
+impl<'a, T> Clone for AssemblyInstance<'a, T> {
+    #[inline(always)]
+    fn clone(&self) -> Self { *self }
+}
+
+impl<'a, T> Copy for AssemblyInstance<'a, T> {}
+
+impl<T> Assembly<T> {
+    /// Creates an empty assembly node placed by the identity.
+    #[inline(always)]
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            placement: Matrix4::identity(),
+            part: None,
+            children: Vec::new(),
+        }
+    }
+    /// Creates an assembly node with a part placed by the identity.
+    #[inline(always)]
+    pub fn with_part(name: impl Into<String>, part: Arc<T>) -> Self {
+        Self {
+            part: Some(part),
+            ..Self::new(name)
+        }
+    }
+
+    /// Returns the name of the node.
+    #[inline(always)]
+    pub fn name(&self) -> &str { &self.name }
+    /// Sets the name of the node.
+    #[inline(always)]
+    pub fn set_name(&mut self, name: impl Into<String>) { self.name = name.into() }
+    /// Returns the placement in the coordinate of the parent node.
+    #[inline(always)]
+    pub const fn placement(&self) -> Matrix4 { self.placement }
+    /// Sets the placement in the coordinate of the parent node.
+    #[inline(always)]
+    pub fn set_placement(&mut self, placement: Matrix4) { self.placement = placement }
+    /// Returns the part of the node.
+    #[inline(always)]
+    pub const fn part(&self) -> Option<&Arc<T>> { self.part.as_ref() }
+    /// Sets the part of the node.
+    #[inline(always)]
+    pub fn set_part(&mut self, part: Option<Arc<T>>) { self.part = part }
+    /// Returns the sub-assemblies.
+    #[inline(always)]
+    pub fn children(&self) -> &[Assembly<T>] { &self.children }
+    /// Returns the mutable sub-assemblies.
+    #[inline(always)]
+    pub fn children_mut(&mut self) -> &mut Vec<Assembly<T>> { &mut self.children }
+    /// Adds a sub-assembly.
+    #[inline(always)]
+    pub fn push(&mut self, child: Assembly<T>) { self.children.push(child) }
+
+    /// Returns all instances of the parts in the tree, with the placements in the coordinate of `self`.
+    /// # Remarks
+    /// The placement of `self` is not applied.
+    pub fn instances(&self) -> Vec<AssemblyInstance<'_, T>> {
+        fn sub_instances<'a, T>(
+            node: &'a Assembly<T>,
+            placement: Matrix4,
+            instances: &mut Vec<AssemblyInstance<'a, T>>,
+        ) {
+            if let Some(part) = &node.part {
+                instances.push(AssemblyInstance {
+                    name: &node.name,
+                    placement,
+                    part,
+                });
+            }
+            node.children.iter().for_each(|child| {
+                sub_instances(child, placement * child.placement, instances);
+            });
+        }
+        let mut instances = Vec::new();
+        sub_instances(self, Matrix4::identity(), &mut instances);
+        instances
+    }
+
+    /// Returns the distinct parts in the tree, in the order of appearance.
+    pub fn parts(&self) -> Vec<&Arc<T>> {
+        let mut parts = Vec::<&Arc<T>>::new();
+        self.instances().into_iter().for_each(|instance| {
+            if !parts.iter().any(|part| Arc::ptr_eq(part, instance.part)) {
+                parts.push(instance.part);
+            }
+        });
+        parts
+    }
+
+    /// Returns the assembly whose parts are mapped by `part_mapping`.
+    ///
+    /// Each shared part is mapped only once, and the mapped part is shared again.
+    /// Hence, e.g. tessellating the parts makes the meshes instanced, not copied.
+    pub fn map_parts<U>(&self, mut part_mapping: impl FnMut(&T) -> U) -> Assembly<U> {
+        fn sub_map<T, U>(
+            node: &Assembly<T>,
+            part_mapping: &mut impl FnMut(&T) -> U,
+            cache: &mut HashMap<*const T, Arc<U>>,
+        ) -> Assembly<U> {
+            let part = node.part.as_ref().map(|part| {
+                let mapped = cache
+                    .entry(Arc::as_ptr(part))
+                    .or_insert_with(|| Arc::new(part_mapping(part)));
+                Arc::clone(mapped)
+            });
+            Assembly {
+                name: node.name.clone(),
+                placement: node.placement,
+                part,
+                children: node
+                    .children
+                    .iter()
+                    .map(|child| sub_map(child, part_mapping, cache))
+                    .collect(),
+            }
+        }
+        sub_map(self, &mut part_mapping, &mut HashMap::default())
+    }
+}
@@ -0,0 +1,275 @@
+//! User attributes attached to vertices, edges and faces
+//!
+//! An [`AttributeStore`] holds typed attributes, e.g. names, colors, material ids or feature ids,
+//! keyed by [`VertexID`], [`EdgeID`] and [`FaceID`]. The topological elements do not hold the
+//! attributes by themselves, so the store is carried through the modeling operations as follows.
+//!
+//! - [`AttributeStore::transfer`] carries the attributes to the shape with the same structure,
+//!   e.g. the shape created by `mapped`, or by `compress` and `extract`.
+//! - [`AttributeStore::inherit_face`] and its siblings copy an attribute to the elements derived
+//!   from an element, e.g. the faces created by [`Face::cut_by_edge`].
+//! - [`Shell::compress_with_attributes`] and [`Shell::extract_with_attributes`] serialize the
+//!   attributes together with the shape as [`CompressedAttributes`].
+//!
+//! [`CompressedAttributes`]: crate::compress::CompressedAttributes
+
+use crate::*;
+use rustc_hash::FxHashMap as HashMap;
+
+/// Typed attributes attached to vertices, edges and faces.
+///
+/// Each entry keeps a clone of its element, so that the id of the element is never reused by
+/// another element while the attribute is stored.
+/// # Examples
+/// ```
+/// use truck_topology::{attributes::AttributeStore, *};
+/// let v = Vertex::news(&[(); 4]);
+/// let wire = Wire::from(vec![
+///     Edge::new(&v[0], &v[1], ()),
+///     Edge::new(&v[1], &v[2], ()),
+///     Edge::new(&v[2], &v[3], ()),
+///     Edge::new(&v[3], &v[0], ()),
+/// ]);
+/// let face = Face::new(vec![wire], ());
+///
+/// let mut attributes = AttributeStore::new();
+/// attributes.insert_face(&face, "red");
+/// attributes.insert_vertex(&v[0], "origin");
+/// assert_eq!(attributes.face(face.id()), Some(&"red"));
+///
+/// // The divided faces inherit the attribute of the original face.
+/// let (face0, face1) = face.cut_by_edge(Edge::new(&v[1], &v[3], ())).unwrap();
+/// assert!(attributes.inherit_face(face.id(), &face0));
+/// assert!(attributes.inherit_face(face.id(), &face1));
+/// assert_eq!(attributes.face(face0.id()), Some(&"red"));
+/// assert_eq!(attributes.face(face1.id()), Some(&"red"));
+///
+/// // The vertices are not changed by the cutting.
+/// assert_eq!(attributes.vertex(v[0].id()), Some(&"origin"));
+/// ```
+#[derive(Clone, Debug)]
+pub struct AttributeStore<P, C, S, A> {
+    vertices: HashMap<VertexID<P>, (Vertex<P>, A)>,
+    edges: HashMap<EdgeID<C>, (Edge<P, C>, A)>,
+    faces: HashMap<FaceID<S>, (Face<P, C, S>, A)>,
+}
+
+impl<P, C, S, A> Default for AttributeStore<P, C, S, A> {
+    fn default() -> Self {
+        Self {
+            vertices: HashMap::default(),
+            edges: HashMap::default(),
+            faces: HashMap::default(),
+        }
+    }
+}
+
+impl<P, C, S, A> AttributeStore<P, C, S, A> {
+    /// Creates an empty store.
+    #[inline(always)]
+    pub fn new() -> Self { Self::default() }
+
+    /// Returns the number of the stored attributes.
+    #[inline(always)]
+    pub fn len(&self) -> usize { self.vertices.len() + self.edges.len() + self.faces.len() }
+
+    /// Returns `true` if no attribute is stored.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool { self.len() == 0 }
+
+    /// Returns the attribute of the vertex.
+    #[inline(always)]
+    pub fn vertex(&self, id: VertexID<P>) -> Option<&A> { self.vertices.get(&id).map(|x| &x.1) }
+
+    /// Returns the attribute of the edge.
+    #[inline(always)]
+    pub fn edge(&self, id: EdgeID<C>) -> Option<&A> { self.edges.get(&id).map(|x| &x.1) }
+
+    /// Returns the attribute of the face.
+    #[inline(always)]
+    pub fn face(&self, id: FaceID<S>) -> Option<&A> { self.faces.get(&id).map(|x| &x.1) }
+
+    /// Returns the mutable reference to the attribute of the vertex.
+    #[inline(always)]
+    pub fn vertex_mut(&mut self, id: VertexID<P>) -> Option<&mut A> {
+        self.vertices.get_mut(&id).map(|x| &mut x.1)
+    }
+
+    /// Returns the mutable reference to the attribute of the edge.
+    #[inline(always)]
+    pub fn edge_mut(&mut self, id: EdgeID<C>) -> Option<&mut A> {
+        self.edges.get_mut(&id).map(|x| &mut x.1)
+    }
+
+    /// Returns the mutable reference to the attribute of the face.
+    #[inline(always)]
+    pub fn face_mut(&mut self, id: FaceID<S>) -> Option<&mut A> {
+        self.faces.get_mut(&id).map(|x| &mut x.1)
+    }
+
+    /// Attaches the attribute to the vertex, and returns the previous one.
+    #[inline(always)]
+    pub fn insert_vertex(&mut self, vertex: &Vertex<P>, attribute: A) -> Option<A> {
+        let entry = (vertex.clone(), attribute);
+        self.vertices.insert(vertex.id(), entry).map(|x| x.1)
+    }
+
+    /// Attaches the attribute to the edge, and returns the previous one.
+    /// The attribute does not depend on the orientation of the edge.
+    #[inline(always)]
+    pub fn insert_edge(&mut self, edge: &Edge<P, C>, attribute: A) -> Option<A> {
+        let entry = (edge.clone(), attribute);
+        self.edges.insert(edge.id(), entry).map(|x| x.1)
+    }
+
+    /// Attaches the attribute to the face, and returns the previous one.
+    /// The attribute does not depend on the orientation of the face.
+    #[inline(always)]
+    pub fn insert_face(&mut self, face: &Face<P, C, S>, attribute: A) -> Option<A> {
+        let entry = (face.clone(), attribute);
+        self.faces.insert(face.id(), entry).map(|x| x.1)
+    }
+
+    /// Removes the attribute of the vertex.
+    #[inline(always)]
+    pub fn remove_vertex(&mut self, id: VertexID<P>) -> Option<A> {
+        self.vertices.remove(&id).map(|x| x.1)
+    }
+
+    /// Removes the attribute of the edge.
+    #[inline(always)]
+    pub fn remove_edge(&mut self, id: EdgeID<C>) -> Option<A> {
+        self.edges.remove(&id).map(|x| x.1)
+    }
+
+    /// Removes the attribute of the face.
+    #[inline(always)]
+    pub fn remove_face(&mut self, id: FaceID<S>) -> Option<A> {
+        self.faces.remove(&id).map(|x| x.1)
+    }
+
+    /// Returns the iterator over the vertices with attributes.
+    #[inline(always)]
+    pub fn vertices(&self) -> impl Iterator<Item = (&Vertex<P>, &A)> {
+        self.vertices.values().map(|(v, a)| (v, a))
+    }
+
+    /// Returns the iterator over the edges with attributes.
+    #[inline(always)]
+    pub fn edges(&self) -> impl Iterator<Item = (&Edge<P, C>, &A)> {
+        self.edges.values().map(|(e, a)| (e, a))
+    }
+
+    /// Returns the iterator over the faces with attributes.
+    #[inline(always)]
+    pub fn faces(&self) -> impl Iterator<Item = (&Face<P, C, S>, &A)> {
+        self.faces.values().map(|(f, a)| (f, a))
+    }
+
+    /// Moves all attributes of `other` into `self`.
+    /// The attributes of `other` take priority.
+    pub fn merge(&mut self, other: Self) {
+        self.vertices.extend(other.vertices);
+        self.edges.extend(other.edges);
+        self.faces.extend(other.faces);
+    }
+}
+
+impl<P, C, S, A: Clone> AttributeStore<P, C, S, A> {
+    /// Copies the attribute of the vertex `original` to `derived`.
+    /// Returns `false` if `original` has no attribute.
+    pub fn inherit_vertex(&mut self, original: VertexID<P>, derived: &Vertex<P>) -> bool {
+        match self.vertex(original).cloned() {
+            Some(attribute) => {
+                self.insert_vertex(derived, attribute);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Copies the attribute of the edge `original` to `derived`,
+    /// e.g. the edges created by [`Edge::cut`] or [`Shell::cut_edge`].
+    /// Returns `false` if `original` has no attribute.
+    pub fn inherit_edge(&mut self, original: EdgeID<C>, derived: &Edge<P, C>) -> bool {
+        match self.edge(original).cloned() {
+            Some(attribute) => {
+                self.insert_edge(derived, attribute);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Copies the attribute of the face `original` to `derived`,
+    /// e.g. the faces created by [`Face::cut_by_edge`] or [`Face::imprint`].
+    /// Returns `false` if `original` has no attribute.
+    pub fn inherit_face(&mut self, original: FaceID<S>, derived: &Face<P, C, S>) -> bool {
+        match self.face(original).cloned() {
+            Some(attribute) => {
+                self.insert_face(derived, attribute);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Creates the attributes of the faces `to`, which have the same structure as `from`,
+    /// e.g. the shape created by `mapped`, or by `compress` and `extract`.
+    ///
+    /// The faces, the edges of the boundaries and their vertices are matched in the order of the
+    /// iterators, and the attributes of the elements of `from` are copied to the matched elements.
+    /// # Examples
+    /// ```
+    /// use truck_topology::{attributes::AttributeStore, *};
+    /// let v = Vertex::news(&[0, 1, 2]);
+    /// let wire = Wire::from(vec![
+    ///     Edge::new(&v[0], &v[1], ()),
+    ///     Edge::new(&v[1], &v[2], ()),
+    ///     Edge::new(&v[2], &v[0], ()),
+    /// ]);
+    /// let shell: Shell<_, _, _> = vec![Face::new(vec![wire], ())].into();
+    ///
+    /// let mut attributes = AttributeStore::new();
+    /// attributes.insert_face(&shell[0], "triangle");
+    /// attributes.insert_vertex(&v[2], "apex");
+    ///
+    /// let mapped = shell.mapped(|i| *i as f64, Clone::clone, Clone::clone);
+    /// let mapped_attributes = attributes.transfer(&shell, &mapped);
+    /// assert_eq!(mapped_attributes.face(mapped[0].id()), Some(&"triangle"));
+    /// let apex = mapped.vertex_iter().find(|v| v.point() == 2.0).unwrap();
+    /// assert_eq!(mapped_attributes.vertex(apex.id()), Some(&"apex"));
+    /// ```
+    pub fn transfer<'a, 'b, Q, D, T>(
+        &self,
+        from: impl IntoIterator<Item = &'a Face<P, C, S>>,
+        to: impl IntoIterator<Item = &'b Face<Q, D, T>>,
+    ) -> AttributeStore<Q, D, T, A>
+    where
+        P: 'a,
+        C: 'a,
+        S: 'a,
+        Q: 'b,
+        D: 'b,
+        T: 'b,
+    {
+        let mut res = AttributeStore::new();
+        from.into_iter().zip(to).for_each(|(face0, face1)| {
+            if let Some(attribute) = self.face(face0.id()) {
+                res.insert_face(face1, attribute.clone());
+            }
+            face0
+                .edge_iter()
+                .zip(face1.edge_iter())
+                .for_each(|(edge0, edge1)| {
+                    if let Some(attribute) = self.edge(edge0.id()) {
+                        res.insert_edge(&edge1, attribute.clone());
+                    }
+                    if let Some(attribute) = self.vertex(edge0.front().id()) {
+                        res.insert_vertex(edge1.front(), attribute.clone());
+                    }
+                });
+        });
+        res
+    }
+}
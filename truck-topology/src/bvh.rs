@@ -0,0 +1,384 @@
+//! Bounding volume hierarchies over the faces and edges of shells and solids
+//!
+//! A [`Bvh`] is a binary tree of axis aligned bounding boxes, which answers the spatial queries,
+//! box overlap, ray casting and nearest search, without iterating all elements.
+//! A [`ShapeBvh`] bundles the hierarchies of the faces and the edges of a shape.
+//! Since the bounding boxes are cached by the ids of the elements, the hierarchies can be rebuilt
+//! cheaply after the topological operations, e.g. [`Shell::cut_edge`] or
+//! [`Shell::remove_vertex_by_concat_edges`].
+
+use crate::*;
+use rustc_hash::FxHashMap as HashMap;
+use truck_base::cgmath64::{MetricSpace, Vector3};
+
+/// The maximum number of the elements in a leaf node.
+const LEAF_SIZE: usize = 4;
+
+/// Bounding volume hierarchy, a binary tree of axis aligned bounding boxes.
+/// # Examples
+/// ```
+/// use truck_topology::bvh::Bvh;
+/// use truck_base::{bounding_box::BoundingBox, cgmath64::*};
+///
+/// // unit cubes on the x-axis
+/// let bvh: Bvh<usize> = (0..10)
+///     .map(|i| {
+///         let p = Point3::new(i as f64 * 2.0, 0.0, 0.0);
+///         let bdb: BoundingBox<Point3> = [p, p + Vector3::new(1.0, 1.0, 1.0)].into_iter().collect();
+///         (bdb, i)
+///     })
+///     .collect();
+///
+/// // box overlap
+/// let query: BoundingBox<Point3> =
+///     [Point3::new(3.5, 0.5, 0.5), Point3::new(6.5, 0.5, 0.5)].into_iter().collect();
+/// let mut overlaps: Vec<usize> = bvh.overlaps(query).into_iter().copied().collect();
+/// overlaps.sort();
+/// assert_eq!(overlaps, vec![2, 3]);
+///
+/// // ray casting
+/// let hits = bvh.ray_cast(Point3::new(-1.0, 0.5, 0.5), Vector3::unit_x());
+/// assert_eq!(hits.len(), 10);
+/// assert_eq!(*hits[0].1, 0);
+///
+/// // nearest search with the exact distance
+/// let point = Point3::new(9.0, 3.0, 0.5);
+/// let center = |i: &usize| Point3::new(*i as f64 * 2.0 + 0.5, 0.5, 0.5);
+/// let (_, nearest) = bvh.nearest(point, |i| center(i).distance(point)).unwrap();
+/// assert_eq!(*nearest, 4);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Bvh<T> {
+    items: Vec<(BoundingBox<Point3>, T)>,
+    nodes: Vec<BvhNode>,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct BvhNode {
+    bdb: BoundingBox<Point3>,
+    kind: NodeKind,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum NodeKind {
+    /// the range of the indices of the items
+    Leaf(usize, usize),
+    /// the indices of the children
+    Branch(usize, usize),
+}
+
+impl<T> Bvh<T> {
+    /// Constructs the hierarchy of the elements with their bounding boxes.
+    pub fn new(items: impl IntoIterator<Item = (BoundingBox<Point3>, T)>) -> Self {
+        let mut items: Vec<_> = items.into_iter().collect();
+        let mut nodes = Vec::new();
+        if !items.is_empty() {
+            build(&mut items, 0, &mut nodes);
+        }
+        Self { items, nodes }
+    }
+
+    /// Returns the number of the elements.
+    #[inline(always)]
+    pub fn len(&self) -> usize { self.items.len() }
+
+    /// Returns `true` if the hierarchy has no elements.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool { self.items.is_empty() }
+
+    /// Returns the iterator over the elements and their bounding boxes.
+    #[inline(always)]
+    pub fn iter(&self) -> impl Iterator<Item = (&BoundingBox<Point3>, &T)> {
+        self.items.iter().map(|(bdb, item)| (bdb, item))
+    }
+
+    /// Returns the bounding box of all elements.
+    #[inline(always)]
+    pub fn bounding_box(&self) -> BoundingBox<Point3> {
+        match self.nodes.first() {
+            Some(root) => root.bdb,
+            None => BoundingBox::new(),
+        }
+    }
+
+    /// Returns the elements whose bounding boxes overlap `bdb`.
+    pub fn overlaps(&self, bdb: BoundingBox<Point3>) -> Vec<&T> {
+        let mut res = Vec::new();
+        self.traverse(
+            |node_bdb| overlap(node_bdb, bdb),
+            |i| {
+                if overlap(self.items[i].0, bdb) {
+                    res.push(&self.items[i].1);
+                }
+            },
+        );
+        res
+    }
+
+    /// Returns the elements whose bounding boxes are hit by the ray from `origin` toward `dir`,
+    /// with the parameters at which the ray enters the boxes.
+    /// The result is sorted by the parameters.
+    pub fn ray_cast(&self, origin: Point3, dir: Vector3) -> Vec<(f64, &T)> {
+        let mut res = Vec::new();
+        self.traverse(
+            |node_bdb| ray_entrance(node_bdb, origin, dir).is_some(),
+            |i| {
+                if let Some(t) = ray_entrance(self.items[i].0, origin, dir) {
+                    res.push((t, &self.items[i].1));
+                }
+            },
+        );
+        res.sort_by(|x, y| x.0.total_cmp(&y.0));
+        res
+    }
+
+    /// Returns the nearest element from `point` and its distance.
+    ///
+    /// `distance` is the exact distance between `point` and an element, which must be
+    /// at least the distance between `point` and the bounding box of the element.
+    /// Returns `None` if the hierarchy is empty.
+    pub fn nearest(&self, point: Point3, distance: impl Fn(&T) -> f64) -> Option<(f64, &T)> {
+        let mut nearest: Option<(f64, &T)> = None;
+        let mut stack = match self.nodes.is_empty() {
+            true => Vec::new(),
+            false => vec![(box_distance(self.nodes[0].bdb, point), 0)],
+        };
+        while let Some((dist, idx)) = stack.pop() {
+            if matches!(nearest, Some((best, _)) if best <= dist) {
+                continue;
+            }
+            match self.nodes[idx].kind {
+                NodeKind::Leaf(start, end) => {
+                    self.items[start..end].iter().for_each(|(_, item)| {
+                        let dist = distance(item);
+                        if !matches!(nearest, Some((best, _)) if best <= dist) {
+                            nearest = Some((dist, item));
+                        }
+                    })
+                }
+                NodeKind::Branch(left, right) => {
+                    let dist0 = box_distance(self.nodes[left].bdb, point);
+                    let dist1 = box_distance(self.nodes[right].bdb, point);
+                    // the nearer child is popped first.
+                    match dist0 < dist1 {
+                        true => stack.extend([(dist1, right), (dist0, left)]),
+                        false => stack.extend([(dist0, left), (dist1, right)]),
+                    }
+                }
+            }
+        }
+        nearest
+    }
+
+    fn traverse(
+        &self,
+        node_filter: impl Fn(BoundingBox<Point3>) -> bool,
+        mut leaf: impl FnMut(usize),
+    ) {
+        let mut stack = match self.nodes.is_empty() {
+            true => Vec::new(),
+            false => vec![0],
+        };
+        while let Some(idx) = stack.pop() {
+            let node = self.nodes[idx];
+            if !node_filter(node.bdb) {
+                continue;
+            }
+            match node.kind {
+                NodeKind::Leaf(start, end) => (start..end).for_each(&mut leaf),
+                NodeKind::Branch(left, right) => stack.extend([left, right]),
+            }
+        }
+    }
+}
+
+impl<T> FromIterator<(BoundingBox<Point3>, T)> for Bvh<T> {
+    #[inline(always)]
+    fn from_iter<I: IntoIterator<Item = (BoundingBox<Point3>, T)>>(iter: I) -> Self {
+        Self::new(iter)
+    }
+}
+
+/// Builds the subtree of `items` and returns the index of the root.
+/// `offset` is the index of `items[0]` in the whole items.
+fn build<T>(
+    items: &mut [(BoundingBox<Point3>, T)],
+    offset: usize,
+    nodes: &mut Vec<BvhNode>,
+) -> usize {
+    let bdb = items
+        .iter()
+        .fold(BoundingBox::new(), |bdb, (item_bdb, _)| bdb + item_bdb);
+    let idx = nodes.len();
+    if items.len() <= LEAF_SIZE {
+        let kind = NodeKind::Leaf(offset, offset + items.len());
+        nodes.push(BvhNode { bdb, kind });
+        return idx;
+    }
+    // splits at the median of the centers along the longest axis
+    let centers: BoundingBox<Point3> = items.iter().map(|(bdb, _)| bdb.center()).collect();
+    let diag = centers.diagonal();
+    let axis = (1..3).fold(0, |axis, k| match diag[axis] < diag[k] {
+        true => k,
+        false => axis,
+    });
+    let mid = items.len() / 2;
+    items.select_nth_unstable_by(mid, |x, y| {
+        x.0.center()[axis].total_cmp(&y.0.center()[axis])
+    });
+    nodes.push(BvhNode {
+        bdb,
+        kind: NodeKind::Leaf(offset, offset),
+    });
+    let (items0, items1) = items.split_at_mut(mid);
+    let left = build(items0, offset, nodes);
+    let right = build(items1, offset + mid, nodes);
+    nodes[idx].kind = NodeKind::Branch(left, right);
+    idx
+}
+
+fn overlap(bdb0: BoundingBox<Point3>, bdb1: BoundingBox<Point3>) -> bool {
+    let (min0, max0, min1, max1) = (bdb0.min(), bdb0.max(), bdb1.min(), bdb1.max());
+    (0..3).all(|k| min0[k] <= max1[k] && min1[k] <= max0[k])
+}
+
+/// Returns the parameter at which the ray enters the box by the slab method.
+fn ray_entrance(bdb: BoundingBox<Point3>, origin: Point3, dir: Vector3) -> Option<f64> {
+    if bdb.is_empty() {
+        return None;
+    }
+    let (min, max) = (bdb.min(), bdb.max());
+    let (t0, t1) = (0..3).fold((0.0, f64::INFINITY), |(t0, t1), k| {
+        if dir[k] == 0.0 {
+            match min[k] <= origin[k] && origin[k] <= max[k] {
+                true => (t0, t1),
+                false => (t0, f64::NEG_INFINITY),
+            }
+        } else {
+            let (a, b) = ((min[k] - origin[k]) / dir[k], (max[k] - origin[k]) / dir[k]);
+            (f64::max(t0, f64::min(a, b)), f64::min(t1, f64::max(a, b)))
+        }
+    });
+    match t0 <= t1 {
+        true => Some(t0),
+        false => None,
+    }
+}
+
+fn box_distance(bdb: BoundingBox<Point3>, point: Point3) -> f64 {
+    if bdb.is_empty() {
+        return f64::INFINITY;
+    }
+    let (min, max) = (bdb.min(), bdb.max());
+    let nearest = Point3::new(
+        point.x.clamp(min.x, max.x),
+        point.y.clamp(min.y, max.y),
+        point.z.clamp(min.z, max.z),
+    );
+    nearest.distance(point)
+}
+
+/// Bounding volume hierarchies of the faces and the edges of a shell or a solid.
+///
+/// The bounding boxes are tight ones by [`Face::bounding_box`] and [`Edge::bounding_box`],
+/// and cached by the ids of the elements. [`ShapeBvh::rebuild`] computes the boxes only for the new
+/// elements, so it is cheap to rebuild after the topological operations which do not change
+/// the geometry of the remaining elements, e.g. [`Shell::cut_edge`].
+#[derive(Clone, Debug)]
+pub struct ShapeBvh<C, S> {
+    faces: Bvh<Face<Point3, C, S>>,
+    edges: Bvh<Edge<Point3, C>>,
+    face_boxes: HashMap<FaceID<S>, BoundingBox<Point3>>,
+    edge_boxes: HashMap<EdgeID<C>, BoundingBox<Point3>>,
+}
+
+impl<C, S> ShapeBvh<C, S> {
+    /// Returns the hierarchy of the faces.
+    #[inline(always)]
+    pub fn faces(&self) -> &Bvh<Face<Point3, C, S>> { &self.faces }
+
+    /// Returns the hierarchy of the edges. Each edge appears once, in the orientation
+    /// in which it is found first.
+    #[inline(always)]
+    pub fn edges(&self) -> &Bvh<Edge<Point3, C>> { &self.edges }
+}
+
+impl<C, S> ShapeBvh<C, S>
+where
+    C: ParametricCurve3D + BoundedCurve + BoundingBoxed<Point = Point3>,
+    S: ParametricSurface3D + SearchParameter<D2, Point = Point3>,
+{
+    /// Constructs the hierarchies of `faces` and their edges.
+    pub fn new<'a>(faces: impl IntoIterator<Item = &'a Face<Point3, C, S>>) -> Self
+    where
+        C: 'a,
+        S: 'a, {
+        let mut res = Self {
+            faces: Bvh::new(Vec::new()),
+            edges: Bvh::new(Vec::new()),
+            face_boxes: HashMap::default(),
+            edge_boxes: HashMap::default(),
+        };
+        res.rebuild(faces);
+        res
+    }
+
+    /// Rebuilds the hierarchies of `faces` and their edges.
+    ///
+    /// The bounding boxes of the faces and edges which are already in the hierarchies are reused.
+    /// If the geometry of an element is modified, e.g. by [`Face::set_surface`],
+    /// use [`ShapeBvh::new`] instead.
+    pub fn rebuild<'a>(&mut self, faces: impl IntoIterator<Item = &'a Face<Point3, C, S>>)
+    where
+        C: 'a,
+        S: 'a, {
+        let faces: Vec<&Face<Point3, C, S>> = faces.into_iter().collect();
+        let mut face_boxes = HashMap::default();
+        let mut edge_boxes = HashMap::default();
+        let mut edges = Vec::new();
+        faces.iter().for_each(|face| {
+            let face_bdb = match self.face_boxes.get(&face.id()) {
+                Some(bdb) => *bdb,
+                None => face.bounding_box(),
+            };
+            face_boxes.insert(face.id(), face_bdb);
+            face.edge_iter().for_each(|edge| {
+                if !edge_boxes.contains_key(&edge.id()) {
+                    let edge_bdb = match self.edge_boxes.get(&edge.id()) {
+                        Some(bdb) => *bdb,
+                        None => edge.bounding_box(),
+                    };
+                    edge_boxes.insert(edge.id(), edge_bdb);
+                    edges.push((edge_bdb, edge));
+                }
+            });
+        });
+        self.faces = faces
+            .into_iter()
+            .map(|face| (face_boxes[&face.id()], face.clone()))
+            .collect();
+        self.edges = edges.into_iter().collect();
+        self.face_boxes = face_boxes;
+        self.edge_boxes = edge_boxes;
+    }
+}
+
+impl<C, S> Shell<Point3, C, S>
+where
+    C: ParametricCurve3D + BoundedCurve + BoundingBoxed<Point = Point3>,
+    S: ParametricSurface3D + SearchParameter<D2, Point = Point3>,
+{
+    /// Returns the bounding volume hierarchies of the faces and the edges.
+    #[inline(always)]
+    pub fn bvh(&self) -> ShapeBvh<C, S> { ShapeBvh::new(self.face_iter()) }
+}
+
+impl<C, S> Solid<Point3, C, S>
+where
+    C: ParametricCurve3D + BoundedCurve + BoundingBoxed<Point = Point3>,
+    S: ParametricSurface3D + SearchParameter<D2, Point = Point3>,
+{
+    /// Returns the bounding volume hierarchies of the faces and the edges of all boundary shells.
+    #[inline(always)]
+    pub fn bvh(&self) -> ShapeBvh<C, S> { ShapeBvh::new(self.face_iter()) }
+}
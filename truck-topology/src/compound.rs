@@ -0,0 +1,224 @@
+use crate::*;
+use rustc_hash::FxHashMap as HashMap;
+
+impl<P, C, S> Compound<P, C, S> {
+    /// Creates an empty compound.
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Compound {
+            solids: Vec::new(),
+            shells: Vec::new(),
+            wires: Vec::new(),
+            vertices: Vec::new(),
+        }
+    }
+
+    /// Creates the compound of solids, shells, wires and vertices.
+    /// # Examples
+    /// ```
+    /// use truck_topology::*;
+    /// let v = Vertex::news(&[(); 3]);
+    /// let wire = Wire::from(vec![
+    ///     Edge::new(&v[0], &v[1], ()),
+    ///     Edge::new(&v[1], &v[2], ()),
+    /// ]);
+    /// let compound = Compound::<_, _, ()>::from_components(
+    ///     Vec::new(),
+    ///     Vec::new(),
+    ///     vec![wire],
+    ///     vec![Vertex::new(())],
+    /// );
+    /// assert_eq!(compound.edge_iter().count(), 2);
+    /// assert_eq!(compound.vertex_iter().count(), 4);
+    /// ```
+    #[inline(always)]
+    pub const fn from_components(
+        solids: Vec<Solid<P, C, S>>,
+        shells: Vec<Shell<P, C, S>>,
+        wires: Vec<Wire<P, C>>,
+        vertices: Vec<Vertex<P>>,
+    ) -> Self {
+        Compound {
+            solids,
+            shells,
+            wires,
+            vertices,
+        }
+    }
+
+    /// Returns the reference of the solids
+    #[inline(always)]
+    pub const fn solids(&self) -> &Vec<Solid<P, C, S>> { &self.solids }
+    /// Returns the reference of the shells
+    #[inline(always)]
+    pub const fn shells(&self) -> &Vec<Shell<P, C, S>> { &self.shells }
+    /// Returns the reference of the wires
+    #[inline(always)]
+    pub const fn wires(&self) -> &Vec<Wire<P, C>> { &self.wires }
+    /// Returns the reference of the isolated vertices
+    #[inline(always)]
+    pub const fn vertices(&self) -> &Vec<Vertex<P>> { &self.vertices }
+    /// Returns the solids, shells, wires and isolated vertices
+    #[inline(always)]
+    #[allow(clippy::type_complexity)]
+    pub fn into_components(
+        self,
+    ) -> (
+        Vec<Solid<P, C, S>>,
+        Vec<Shell<P, C, S>>,
+        Vec<Wire<P, C>>,
+        Vec<Vertex<P>>,
+    ) {
+        (self.solids, self.shells, self.wires, self.vertices)
+    }
+
+    /// Adds a solid to the compound.
+    #[inline(always)]
+    pub fn push_solid(&mut self, solid: Solid<P, C, S>) { self.solids.push(solid) }
+    /// Adds a shell to the compound.
+    #[inline(always)]
+    pub fn push_shell(&mut self, shell: Shell<P, C, S>) { self.shells.push(shell) }
+    /// Adds a wire to the compound.
+    #[inline(always)]
+    pub fn push_wire(&mut self, wire: Wire<P, C>) { self.wires.push(wire) }
+    /// Adds an isolated vertex to the compound.
+    #[inline(always)]
+    pub fn push_vertex(&mut self, vertex: Vertex<P>) { self.vertices.push(vertex) }
+
+    /// Returns whether the compound has no elements.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.solids.is_empty()
+            && self.shells.is_empty()
+            && self.wires.is_empty()
+            && self.vertices.is_empty()
+    }
+
+    /// Returns an iterator over the faces of the solids and the shells.
+    /// # Remarks
+    /// The faces shared by some solids or shells are returned several times.
+    #[inline(always)]
+    pub fn face_iter(&self) -> impl Iterator<Item = &Face<P, C, S>> {
+        let solid_faces = self.solids.iter().flat_map(Solid::face_iter);
+        solid_faces.chain(self.shells.iter().flatten())
+    }
+
+    /// Returns an iterator over the edges of the faces and the wires.
+    #[inline(always)]
+    pub fn edge_iter(&self) -> impl Iterator<Item = Edge<P, C>> + '_ {
+        let face_edges = self.face_iter().flat_map(Face::edge_iter);
+        face_edges.chain(self.wires.iter().flatten().cloned())
+    }
+
+    /// Returns an iterator over the vertices of the faces, the wires and the isolated vertices.
+    #[inline(always)]
+    pub fn vertex_iter(&self) -> impl Iterator<Item = Vertex<P>> + '_ {
+        let face_vertices = self.face_iter().flat_map(Face::vertex_iter);
+        let wire_vertices = self.wires.iter().flat_map(Wire::vertex_iter);
+        face_vertices
+            .chain(wire_vertices)
+            .chain(self.vertices.iter().cloned())
+    }
+
+    /// Returns the faces shared by two or more solids, i.e. the interfaces of the cells.
+    /// # Examples
+    /// ```
+    /// use truck_topology::*;
+    /// let v = Vertex::news(&[(); 4]);
+    /// let edge = [
+    ///     Edge::new(&v[0], &v[1], ()),
+    ///     Edge::new(&v[0], &v[2], ()),
+    ///     Edge::new(&v[0], &v[3], ()),
+    ///     Edge::new(&v[1], &v[2], ()),
+    ///     Edge::new(&v[1], &v[3], ()),
+    ///     Edge::new(&v[2], &v[3], ()),
+    /// ];
+    /// let wire = vec![
+    ///     Wire::from_iter(vec![&edge[0], &edge[3], &edge[1].inverse()]),
+    ///     Wire::from_iter(vec![&edge[1], &edge[5], &edge[2].inverse()]),
+    ///     Wire::from_iter(vec![&edge[2], &edge[4].inverse(), &edge[0].inverse()]),
+    ///     Wire::from_iter(vec![&edge[3], &edge[5], &edge[4].inverse()]),
+    /// ];
+    /// let mut face: Vec<Face<_, _, _>> = wire.into_iter().map(|wire| Face::new(vec![wire], ())).collect();
+    /// face[3].invert();
+    /// let solid0 = Solid::new(vec![face.clone().into()]);
+    ///
+    /// // another tetrahedron sharing the face `face[0]`
+    /// let w = Vertex::new(());
+    /// let new_edge = [
+    ///     Edge::new(&v[0], &w, ()),
+    ///     Edge::new(&v[1], &w, ()),
+    ///     Edge::new(&v[2], &w, ()),
+    /// ];
+    /// let wire = vec![
+    ///     Wire::from_iter(vec![&edge[0], &new_edge[1], &new_edge[0].inverse()]),
+    ///     Wire::from_iter(vec![&edge[3], &new_edge[2], &new_edge[1].inverse()]),
+    ///     Wire::from_iter(vec![&edge[1].inverse(), &new_edge[0], &new_edge[2].inverse()]),
+    /// ];
+    /// let mut shell: Shell<_, _, _> = wire.into_iter().map(|wire| Face::new(vec![wire], ())).collect();
+    /// shell.push(face[0].inverse());
+    /// let solid1 = Solid::new(vec![shell]);
+    ///
+    /// let compound = Compound::from_components(vec![solid0, solid1], Vec::new(), Vec::new(), Vec::new());
+    /// let shared = compound.shared_faces();
+    /// assert_eq!(shared.len(), 1);
+    /// assert_eq!(shared[0].id(), face[0].id());
+    /// ```
+    pub fn shared_faces(&self) -> Vec<Face<P, C, S>> {
+        let mut counter = HashMap::<FaceID<S>, (usize, usize)>::default();
+        self.solids.iter().enumerate().for_each(|(i, solid)| {
+            solid.face_iter().for_each(|face| {
+                let (last, count) = counter.entry(face.id()).or_insert((usize::MAX, 0));
+                if *last != i {
+                    *last = i;
+                    *count += 1;
+                }
+            })
+        });
+        let mut faces = Vec::new();
+        self.solids
+            .iter()
+            .flat_map(Solid::face_iter)
+            .for_each(|face| {
+                if let Some((_, count)) = counter.remove(&face.id()) {
+                    if count > 1 {
+                        faces.push(face.clone());
+                    }
+                }
+            });
+        faces
+    }
+}
+
+impl<P, C, S> Default for Compound<P, C, S> {
+    #[inline(always)]
+    fn default() -> Self { Self::new() }
+}
+
+impl<P, C, S> From<Solid<P, C, S>> for Compound<P, C, S> {
+    #[inline(always)]
+    fn from(solid: Solid<P, C, S>) -> Self {
+        Self::from_components(vec![solid], Vec::new(), Vec::new(), Vec::new())
+    }
+}
+
+impl<P, C, S> From<Vec<Solid<P, C, S>>> for Compound<P, C, S> {
+    #[inline(always)]
+    fn from(solids: Vec<Solid<P, C, S>>) -> Self {
+        Self::from_components(solids, Vec::new(), Vec::new(), Vec::new())
+    }
+}
+
+impl<P, C, S> From<Shell<P, C, S>> for Compound<P, C, S> {
+    #[inline(always)]
+    fn from(shell: Shell<P, C, S>) -> Self {
+        Self::from_components(Vec::new(), vec![shell], Vec::new(), Vec::new())
+    }
+}
+
+impl<P, C, S> From<Wire<P, C>> for Compound<P, C, S> {
+    #[inline(always)]
+    fn from(wire: Wire<P, C>) -> Self {
+        Self::from_components(Vec::new(), Vec::new(), vec![wire], Vec::new())
+    }
+}
@@ -8,7 +8,7 @@
 //! They directly reflect the results of parsing data from json or STEP, and all member variables are public.
 //! Boundary connectivity and closure are checked when converting to proprietary data structures, `Vertex`, `Edge`, and so on.
 
-use crate::*;
+use crate::{attributes::AttributeStore, *};
 use rustc_hash::FxHashMap as HashMap;
 use serde::{Deserialize, Serialize};
 
@@ -43,6 +43,19 @@ impl From<(usize, bool)> for CompressedEdgeIndex {
     fn from((index, orientation): (usize, bool)) -> Self { Self { index, orientation } }
 }
 
+/// The index of a face in `CompressedCompound`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct CompressedFaceIndex {
+    /// the index of the face
+    pub index: usize,
+    /// `false` if the face is inverted from the compressed face
+    pub orientation: bool,
+}
+
+impl From<(usize, bool)> for CompressedFaceIndex {
+    fn from((index, orientation): (usize, bool)) -> Self { Self { index, orientation } }
+}
+
 /// Serialized compressed face
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CompressedFace<S> {
@@ -96,6 +109,50 @@ pub struct CompressedSolid<P, C, S> {
     pub boundaries: Vec<CompressedShell<P, C, S>>,
 }
 
+/// Serialized compressed compound
+///
+/// The vertices, edges and faces are shared by the solids, shells, wires and isolated vertices.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CompressedCompound<P, C, S> {
+    /// all geometries of vertices
+    pub vertices: Vec<P>,
+    /// all geometries and end vertices of edges
+    pub edges: Vec<CompressedEdge<C>>,
+    /// all geometries and boundaries of faces
+    pub faces: Vec<CompressedFace<S>>,
+    /// boundary shells of solids
+    pub solids: Vec<Vec<Vec<CompressedFaceIndex>>>,
+    /// shells, which need not be closed
+    pub shells: Vec<Vec<CompressedFaceIndex>>,
+    /// wires, which need not be closed
+    pub wires: Vec<Vec<CompressedEdgeIndex>>,
+    /// isolated vertices
+    pub isolated_vertices: Vec<usize>,
+}
+
+/// Serialized attributes of a compressed shell
+///
+/// The indices refer to the vertices, edges and faces of the corresponding [`CompressedShell`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CompressedAttributes<A> {
+    /// attributes of vertices
+    pub vertices: Vec<(usize, A)>,
+    /// attributes of edges
+    pub edges: Vec<(usize, A)>,
+    /// attributes of faces
+    pub faces: Vec<(usize, A)>,
+}
+
+impl<A> Default for CompressedAttributes<A> {
+    fn default() -> Self {
+        Self {
+            vertices: Vec::new(),
+            edges: Vec::new(),
+            faces: Vec::new(),
+        }
+    }
+}
+
 struct CompressDirector<P, C> {
     vmap: HashMap<VertexID<P>, (usize, P)>,
     emap: HashMap<EdgeID<C>, (usize, CompressedEdge<C>)>,
@@ -155,6 +212,33 @@ impl<P: Clone, C: Clone> CompressDirector<P, C> {
         }
     }
 
+    fn create_attributes<S, A: Clone>(
+        &self,
+        shell: &Shell<P, C, S>,
+        attributes: &AttributeStore<P, C, S, A>,
+    ) -> CompressedAttributes<A> {
+        let mut vertices: Vec<_> = attributes
+            .vertices()
+            .filter_map(|(v, a)| Some((self.vmap.get(&v.id())?.0, a.clone())))
+            .collect();
+        vertices.sort_by(|x, y| x.0.cmp(&y.0));
+        let mut edges: Vec<_> = attributes
+            .edges()
+            .filter_map(|(e, a)| Some((self.emap.get(&e.id())?.0, a.clone())))
+            .collect();
+        edges.sort_by(|x, y| x.0.cmp(&y.0));
+        let faces = shell
+            .iter()
+            .enumerate()
+            .filter_map(|(i, face)| Some((i, attributes.face(face.id())?.clone())))
+            .collect();
+        CompressedAttributes {
+            vertices,
+            edges,
+            faces,
+        }
+    }
+
     #[inline(always)]
     fn map2vec<K, T>(map: HashMap<K, (usize, T)>) -> Vec<T> {
         let mut vec: Vec<_> = map.into_iter().map(|entry| entry.1).collect();
@@ -182,8 +266,28 @@ impl<P: Clone, C: Clone, S: Clone> Shell<P, C, S> {
         }
     }
 
-    /// Extracts the serialized compressed shell into the shell.
-    pub fn extract(cshell: CompressedShell<P, C, S>) -> Result<Self> {
+    /// Compresses the shell and its attributes.
+    /// The attributes of the elements which are not included in the shell are ignored.
+    pub fn compress_with_attributes<A: Clone>(
+        &self,
+        attributes: &AttributeStore<P, C, S, A>,
+    ) -> (CompressedShell<P, C, S>, CompressedAttributes<A>) {
+        let mut director = CompressDirector::new();
+        let mut face_closure = |face: &Face<P, C, S>| director.create_cface(face);
+        let faces = self.iter().map(&mut face_closure).collect();
+        let cattributes = director.create_attributes(self, attributes);
+        let (vertices, edges) = director.vertices_edges();
+        let cshell = CompressedShell {
+            vertices,
+            edges,
+            faces,
+        };
+        (cshell, cattributes)
+    }
+
+    fn extract_elements(
+        cshell: CompressedShell<P, C, S>,
+    ) -> Result<(Vec<Vertex<P>>, Vec<Edge<P, C>>, Self)> {
         let CompressedShell {
             vertices,
             edges,
@@ -192,12 +296,65 @@ impl<P: Clone, C: Clone, S: Clone> Shell<P, C, S> {
         let vertices: Vec<_> = vertices.into_iter().map(Vertex::new).collect();
         let edges = edges
             .into_iter()
-            .map(move |edge| edge.create_edge(&vertices))
+            .map(|edge| edge.create_edge(&vertices))
             .collect::<Result<Vec<_>>>()?;
-        faces
+        let shell = faces
             .into_iter()
-            .map(move |face| face.create_face(&edges))
-            .collect()
+            .map(|face| face.create_face(&edges))
+            .collect::<Result<Self>>()?;
+        Ok((vertices, edges, shell))
+    }
+
+    /// Extracts the serialized compressed shell into the shell.
+    pub fn extract(cshell: CompressedShell<P, C, S>) -> Result<Self> {
+        Self::extract_elements(cshell).map(|(_, _, shell)| shell)
+    }
+
+    /// Extracts the serialized compressed shell and its attributes.
+    /// # Examples
+    /// ```
+    /// use truck_topology::{attributes::AttributeStore, *};
+    /// let v = Vertex::news(&[(); 3]);
+    /// let wire = Wire::from(vec![
+    ///     Edge::new(&v[0], &v[1], ()),
+    ///     Edge::new(&v[1], &v[2], ()),
+    ///     Edge::new(&v[2], &v[0], ()),
+    /// ]);
+    /// let shell: Shell<_, _, _> = vec![Face::new(vec![wire], ())].into();
+    /// let mut attributes = AttributeStore::new();
+    /// attributes.insert_face(&shell[0], [1.0, 0.0, 0.0]);
+    /// attributes.insert_edge(&shell[0].boundaries()[0][1], [0.0, 1.0, 0.0]);
+    ///
+    /// let (cshell, cattributes) = shell.compress_with_attributes(&attributes);
+    /// assert_eq!(cattributes.faces, vec![(0, [1.0, 0.0, 0.0])]);
+    /// assert_eq!(cattributes.edges, vec![(1, [0.0, 1.0, 0.0])]);
+    ///
+    /// let (shell, attributes) = Shell::extract_with_attributes(cshell, cattributes).unwrap();
+    /// assert_eq!(attributes.face(shell[0].id()), Some(&[1.0, 0.0, 0.0]));
+    /// let edge = &shell[0].boundaries()[0][1];
+    /// assert_eq!(attributes.edge(edge.id()), Some(&[0.0, 1.0, 0.0]));
+    /// ```
+    pub fn extract_with_attributes<A>(
+        cshell: CompressedShell<P, C, S>,
+        cattributes: CompressedAttributes<A>,
+    ) -> Result<(Self, AttributeStore<P, C, S, A>)> {
+        let (vertices, edges, shell) = Self::extract_elements(cshell)?;
+        let mut attributes = AttributeStore::new();
+        let CompressedAttributes {
+            vertices: vattrs,
+            edges: eattrs,
+            faces: fattrs,
+        } = cattributes;
+        vattrs.into_iter().for_each(|(i, a)| {
+            attributes.insert_vertex(&vertices[i], a);
+        });
+        eattrs.into_iter().for_each(|(i, a)| {
+            attributes.insert_edge(&edges[i], a);
+        });
+        fattrs.into_iter().for_each(|(i, a)| {
+            attributes.insert_face(&shell[i], a);
+        });
+        Ok((shell, attributes))
     }
 }
 
@@ -219,6 +376,156 @@ impl<P: Clone, C: Clone, S: Clone> Solid<P, C, S> {
             csolid.boundaries.into_iter().map(Shell::extract).collect();
         Solid::try_new(shells?)
     }
+
+    /// Compresses the solid and its attributes.
+    /// The attributes are compressed for each boundary shell.
+    pub fn compress_with_attributes<A: Clone>(
+        &self,
+        attributes: &AttributeStore<P, C, S, A>,
+    ) -> (CompressedSolid<P, C, S>, Vec<CompressedAttributes<A>>) {
+        let (boundaries, cattributes) = self
+            .boundaries()
+            .iter()
+            .map(|shell| shell.compress_with_attributes(attributes))
+            .unzip();
+        (CompressedSolid { boundaries }, cattributes)
+    }
+
+    /// Extracts the serialized compressed solid and its attributes.
+    /// The shells without corresponding attributes have no attributes.
+    pub fn extract_with_attributes<A>(
+        csolid: CompressedSolid<P, C, S>,
+        cattributes: Vec<CompressedAttributes<A>>,
+    ) -> Result<(Self, AttributeStore<P, C, S, A>)> {
+        let mut attributes = AttributeStore::new();
+        let mut cattributes = cattributes.into_iter();
+        let shells = csolid
+            .boundaries
+            .into_iter()
+            .map(|cshell| {
+                let cattrs = cattributes.next().unwrap_or_default();
+                let (shell, attrs) = Shell::extract_with_attributes(cshell, cattrs)?;
+                attributes.merge(attrs);
+                Ok(shell)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok((Solid::try_new(shells)?, attributes))
+    }
+}
+
+impl<P: Clone, C: Clone, S: Clone> Compound<P, C, S> {
+    /// Compresses the compound into the serialized compressed compound.
+    /// The vertices, edges and faces shared by some elements are compressed only once.
+    pub fn compress(&self) -> CompressedCompound<P, C, S> {
+        let mut director = CompressDirector::new();
+        let mut fmap = HashMap::<FaceID<S>, (usize, CompressedFace<S>)>::default();
+        let mut shell_closure = |shell: &Shell<P, C, S>| -> Vec<CompressedFaceIndex> {
+            let face_closure = |face: &Face<P, C, S>| -> CompressedFaceIndex {
+                match fmap.get(&face.id()) {
+                    Some((index, cface)) => {
+                        (*index, cface.orientation == face.orientation()).into()
+                    }
+                    None => {
+                        let index = fmap.len();
+                        let cface = director.create_cface(face);
+                        fmap.insert(face.id(), (index, cface));
+                        (index, true).into()
+                    }
+                }
+            };
+            shell.iter().map(face_closure).collect()
+        };
+        let solids = self
+            .solids()
+            .iter()
+            .map(|solid| solid.boundaries().iter().map(&mut shell_closure).collect())
+            .collect();
+        let shells = self.shells().iter().map(&mut shell_closure).collect();
+        let wires = self
+            .wires()
+            .iter()
+            .map(|wire| director.create_boundary(wire))
+            .collect();
+        let isolated_vertices = self
+            .vertices()
+            .iter()
+            .map(|vertex| director.get_vid(vertex))
+            .collect();
+        let faces = CompressDirector::<P, C>::map2vec(fmap);
+        let (vertices, edges) = director.vertices_edges();
+        CompressedCompound {
+            vertices,
+            edges,
+            faces,
+            solids,
+            shells,
+            wires,
+            isolated_vertices,
+        }
+    }
+
+    /// Extracts the serialized compressed compound into the compound.
+    /// # Failure
+    /// The boundaries of each solid must be non-empty, connected, and closed manifold.
+    pub fn extract(ccompound: CompressedCompound<P, C, S>) -> Result<Self> {
+        let CompressedCompound {
+            vertices,
+            edges,
+            faces,
+            solids,
+            shells,
+            wires,
+            isolated_vertices,
+        } = ccompound;
+        let vertices: Vec<_> = vertices.into_iter().map(Vertex::new).collect();
+        let edges = edges
+            .into_iter()
+            .map(|edge| edge.create_edge(&vertices))
+            .collect::<Result<Vec<_>>>()?;
+        let faces = faces
+            .into_iter()
+            .map(|face| face.create_face(&edges))
+            .collect::<Result<Vec<_>>>()?;
+        let create_shell = |shell: Vec<CompressedFaceIndex>| -> Shell<P, C, S> {
+            shell
+                .into_iter()
+                .map(
+                    |CompressedFaceIndex { index, orientation }| match orientation {
+                        true => faces[index].clone(),
+                        false => faces[index].inverse(),
+                    },
+                )
+                .collect()
+        };
+        let solids = solids
+            .into_iter()
+            .map(|boundaries| Solid::try_new(boundaries.into_iter().map(&create_shell).collect()))
+            .collect::<Result<Vec<_>>>()?;
+        let shells = shells.into_iter().map(create_shell).collect();
+        let wires = wires
+            .into_iter()
+            .map(|wire| {
+                wire.into_iter()
+                    .map(
+                        |CompressedEdgeIndex { index, orientation }| match orientation {
+                            true => edges[index].clone(),
+                            false => edges[index].inverse(),
+                        },
+                    )
+                    .collect()
+            })
+            .collect();
+        let isolated_vertices = isolated_vertices
+            .into_iter()
+            .map(|i| vertices[i].clone())
+            .collect();
+        Ok(Compound::from_components(
+            solids,
+            shells,
+            wires,
+            isolated_vertices,
+        ))
+    }
 }
 
 // -------------------------- test -------------------------- //
@@ -231,6 +538,65 @@ fn compress_extract() {
     assert!(same_topology(shell0, &shell1));
 }
 
+#[test]
+fn compress_extract_with_attributes() {
+    let cube = solid::cube();
+    let mut attributes = AttributeStore::new();
+    cube.face_iter().enumerate().for_each(|(i, face)| {
+        attributes.insert_face(face, i);
+    });
+    cube.vertex_iter().enumerate().for_each(|(i, vertex)| {
+        attributes.insert_vertex(&vertex, 100 + i);
+    });
+    let (csolid, cattributes) = cube.compress_with_attributes(&attributes);
+    let (solid, extracted) = Solid::extract_with_attributes(csolid, cattributes).unwrap();
+    assert!(same_topology(&cube.boundaries()[0], &solid.boundaries()[0]));
+    assert_eq!(extracted.len(), attributes.len());
+    let transferred = attributes.transfer(cube.face_iter(), solid.face_iter());
+    solid.face_iter().for_each(|face| {
+        assert_eq!(extracted.face(face.id()), transferred.face(face.id()));
+    });
+    solid.vertex_iter().for_each(|vertex| {
+        assert_eq!(
+            extracted.vertex(vertex.id()),
+            transferred.vertex(vertex.id())
+        );
+    });
+}
+
+#[test]
+fn compress_extract_compound() {
+    let cube = solid::cube();
+    let shell0 = &cube.boundaries()[0];
+    let sheet: Shell<_, _, _> = vec![shell0[0].inverse()].into();
+    let wire: Wire<_, _> = shell0[1].boundaries()[0].iter().take(2).cloned().collect();
+    let vertex = Vertex::new(());
+    let compound =
+        Compound::from_components(vec![cube.clone()], vec![sheet], vec![wire], vec![vertex]);
+    let ccompound = compound.compress();
+    assert_eq!(ccompound.vertices.len(), 9);
+    assert_eq!(ccompound.edges.len(), 12);
+    assert_eq!(ccompound.faces.len(), 6);
+    assert_eq!(ccompound.shells, vec![vec![(0, false).into()]]);
+    assert_eq!(ccompound.isolated_vertices, vec![8]);
+
+    let extracted = Compound::extract(ccompound).unwrap();
+    let solid = &extracted.solids()[0];
+    assert!(same_topology(shell0, &solid.boundaries()[0]));
+    let sheet = &extracted.shells()[0];
+    assert_eq!(sheet[0].id(), solid.boundaries()[0][0].id());
+    assert_eq!(
+        sheet[0].orientation(),
+        !solid.boundaries()[0][0].orientation()
+    );
+    let wire = &extracted.wires()[0];
+    assert_eq!(wire.len(), 2);
+    assert!(wire.is_continuous());
+    let edges = solid.boundaries()[0][1].boundaries()[0].clone();
+    assert_eq!(wire[0], edges[0]);
+    assert_eq!(wire[1], edges[1]);
+}
+
 #[allow(dead_code)]
 fn vmap_subroutin<P, Q>(
     v0: &Vertex<P>,
@@ -352,6 +718,37 @@ where
     }
 }
 
+impl<P, C, S> Serialize for Compound<P, C, S>
+where
+    P: Clone + Serialize,
+    C: Clone + Serialize,
+    S: Clone + Serialize,
+{
+    fn serialize<Serializer>(
+        &self,
+        serializer: Serializer,
+    ) -> std::result::Result<Serializer::Ok, Serializer::Error>
+    where
+        Serializer: serde::Serializer,
+    {
+        self.compress().serialize(serializer)
+    }
+}
+
+impl<'de, P, C, S> Deserialize<'de> for Compound<P, C, S>
+where
+    P: Clone + Deserialize<'de>,
+    C: Clone + Deserialize<'de>,
+    S: Clone + Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where D: serde::Deserializer<'de> {
+        use serde::de::Error;
+        let compressed = CompressedCompound::<P, C, S>::deserialize(deserializer)?;
+        Compound::extract(compressed).map_err(D::Error::custom)
+    }
+}
+
 impl<P, C, S> Serialize for Face<P, C, S>
 where
     P: Clone + Serialize,
@@ -42,7 +42,7 @@ impl<P, C> Edge<P, C> {
         Edge {
             vertices: (front.clone(), back.clone()),
             orientation: true,
-            curve: Arc::new(Mutex::new(curve)),
+            curve: Arc::new(RwLock::new(curve)),
         }
     }
 
@@ -252,9 +252,24 @@ impl<P, C> Edge<P, C> {
     #[inline(always)]
     pub fn curve(&self) -> C
     where C: Clone {
-        self.curve.lock().clone()
+        self.curve.read().clone()
     }
 
+    /// Calls `f` with the reference to the absolute curve, without cloning the curve.
+    ///
+    /// The curve is locked only for reading, so the calls from many threads do not block each other.
+    /// # Remarks
+    /// Setting the curve of the same edge in `f` causes a deadlock.
+    /// # Examples
+    /// ```
+    /// use truck_topology::*;
+    /// let v = Vertex::news(&[0, 1]);
+    /// let edge = Edge::new(&v[0], &v[1], (0, 1)).inverse();
+    /// assert_eq!(edge.with_curve(|curve| curve.1), 1);
+    /// ```
+    #[inline(always)]
+    pub fn with_curve<R>(&self, f: impl FnOnce(&C) -> R) -> R { f(&self.curve.read()) }
+
     /// Set the curve.
     /// # Examples
     /// ```
@@ -275,7 +290,7 @@ impl<P, C> Edge<P, C> {
     /// assert_eq!(edge1.curve(), 1);
     /// ```
     #[inline(always)]
-    pub fn set_curve(&self, curve: C) { *self.curve.lock() = curve; }
+    pub fn set_curve(&self, curve: C) { *self.curve.write() = curve; }
 
     /// Returns the id that does not depend on the direction of the edge.
     /// # Examples
@@ -319,8 +334,8 @@ impl<P, C> Edge<P, C> {
     pub fn oriented_curve(&self) -> C
     where C: Clone + Invertible {
         match self.orientation {
-            true => self.curve.lock().clone(),
-            false => self.curve.lock().inverse(),
+            true => self.curve.read().clone(),
+            false => self.curve.read().inverse(),
         }
     }
 
@@ -338,7 +353,7 @@ impl<P, C> Edge<P, C> {
     ) -> Option<Edge<Q, D>> {
         let v0 = self.absolute_front().try_mapped(&mut point_mapping)?;
         let v1 = self.absolute_back().try_mapped(&mut point_mapping)?;
-        let curve = curve_mapping(&*self.curve.lock())?;
+        let curve = curve_mapping(&*self.curve.read())?;
         let mut edge = Edge::debug_new(&v0, &v1, curve);
         if !self.orientation() {
             edge.invert();
@@ -375,7 +390,7 @@ impl<P, C> Edge<P, C> {
     ) -> Edge<Q, D> {
         let v0 = self.absolute_front().mapped(&mut point_mapping);
         let v1 = self.absolute_back().mapped(&mut point_mapping);
-        let curve = curve_mapping(&*self.curve.lock());
+        let curve = curve_mapping(&*self.curve.read());
         let mut edge = Edge::debug_new(&v0, &v1, curve);
         if edge.orientation() != self.orientation() {
             edge.invert();
@@ -385,19 +400,29 @@ impl<P, C> Edge<P, C> {
 
     /// Returns the consistence of the geometry of end vertices
     /// and the geometry of edge.
+    ///
+    /// The points are compared by the tolerance of the current context,
+    /// cf. [`with_tolerance`].
     #[inline(always)]
     pub fn is_geometric_consistent(&self) -> bool
     where
         P: Tolerance,
         C: BoundedCurve<Point = P>, {
-        let curve = self.curve.lock();
+        let curve = self.curve.read();
         let geom_front = curve.front();
         let geom_back = curve.back();
-        let top_front = self.absolute_front().point.lock();
-        let top_back = self.absolute_back().point.lock();
+        let top_front = self.absolute_front().point.read();
+        let top_back = self.absolute_back().point.read();
         geom_front.near(&*top_front) && geom_back.near(&*top_back)
     }
 
+    /// Returns the tight bounding box of the curve.
+    #[inline(always)]
+    pub fn bounding_box(&self) -> BoundingBox<C::Point>
+    where C: BoundingBoxed {
+        self.curve.read().bounding_box()
+    }
+
     /// Cuts the edge at `vertex`.
     /// # Failures
     /// Returns `None` if:
@@ -410,19 +435,19 @@ impl<P, C> Edge<P, C> {
         let mut curve0 = self.curve();
         let t = curve0.search_parameter(vertex.point(), None, SEARCH_PARAMETER_TRIALS)?;
         let (t0, t1) = curve0.range_tuple();
-        if t < t0 + TOLERANCE || t1 - TOLERANCE < t {
+        if t < t0 + tolerance() || t1 - tolerance() < t {
             return None;
         }
         let curve1 = curve0.cut(t);
         let edge0 = Edge {
             vertices: (self.absolute_front().clone(), vertex.clone()),
             orientation: self.orientation,
-            curve: Arc::new(Mutex::new(curve0)),
+            curve: Arc::new(RwLock::new(curve0)),
         };
         let edge1 = Edge {
             vertices: (vertex.clone(), self.absolute_back().clone()),
             orientation: self.orientation,
-            curve: Arc::new(Mutex::new(curve1)),
+            curve: Arc::new(RwLock::new(curve1)),
         };
         match self.orientation {
             true => Some((edge0, edge1)),
@@ -442,19 +467,19 @@ impl<P, C> Edge<P, C> {
             return None;
         }
         let (t0, t1) = curve0.range_tuple();
-        if t < t0 + TOLERANCE || t1 - TOLERANCE < t {
+        if t < t0 + tolerance() || t1 - tolerance() < t {
             return None;
         }
         let curve1 = curve0.cut(t);
         let edge0 = Edge {
             vertices: (self.absolute_front().clone(), vertex.clone()),
             orientation: self.orientation,
-            curve: Arc::new(Mutex::new(curve0)),
+            curve: Arc::new(RwLock::new(curve0)),
         };
         let edge1 = Edge {
             vertices: (vertex.clone(), self.absolute_back().clone()),
             orientation: self.orientation,
-            curve: Arc::new(Mutex::new(curve1)),
+            curve: Arc::new(RwLock::new(curve1)),
         };
         match self.orientation {
             true => Some((edge0, edge1)),
@@ -591,7 +616,7 @@ impl<'a, P: Debug, C: Debug> Debug for DebugDisplay<'a, Edge<P, C>, EdgeDisplayF
                         self.entity.back().display(vertex_format),
                     ),
                 )
-                .field("entity", &MutexFmt(&self.entity.curve))
+                .field("entity", &RwLockFmt(&self.entity.curve))
                 .finish(),
             EdgeDisplayFormat::VerticesTupleAndID { vertex_format } => f
                 .debug_struct("Edge")
@@ -613,7 +638,7 @@ impl<'a, P: Debug, C: Debug> Debug for DebugDisplay<'a, Edge<P, C>, EdgeDisplayF
                         self.entity.back().display(vertex_format),
                     ),
                 )
-                .field("entity", &MutexFmt(&self.entity.curve))
+                .field("entity", &RwLockFmt(&self.entity.curve))
                 .finish(),
             EdgeDisplayFormat::VerticesTupleStruct { vertex_format } => f
                 .debug_tuple("Edge")
@@ -626,7 +651,7 @@ impl<'a, P: Debug, C: Debug> Debug for DebugDisplay<'a, Edge<P, C>, EdgeDisplayF
                 self.entity.back().display(vertex_format),
             )),
             EdgeDisplayFormat::AsCurve => {
-                f.write_fmt(format_args!("{:?}", &MutexFmt(&self.entity.curve)))
+                f.write_fmt(format_args!("{:?}", &RwLockFmt(&self.entity.curve)))
             }
         }
     }
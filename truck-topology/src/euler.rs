@@ -0,0 +1,599 @@
+//! Euler operators, the invertible low-level edits of shells and solids
+//!
+//! The operators change the numbers of vertices `V`, edges `E`, faces `F`, rings `R`,
+//! i.e. the inner boundaries of faces, shells `S`, and holes `H` of the solid
+//! keeping the Euler–Poincaré formula
+//! ```text
+//! V - E + F - R = 2 (S - H).
+//! ```
+//!
+//! | operator | inverse | change |
+//! |----------|---------|--------|
+//! | [`Shell::mvfs`] | [`Shell::kvfs`] | `V + 1`, `F + 1`, `S + 1` |
+//! | [`Shell::mev`] | [`Shell::kev`] | `V + 1`, `E + 1` |
+//! | [`Shell::mef`] | [`Shell::kef`] | `E + 1`, `F + 1` |
+//! | [`Shell::mekr`] | [`Shell::kemr`] | `E + 1`, `R - 1` |
+//! | [`Shell::kfmrh`] | [`Shell::mfkrh`] | `F - 1`, `R + 1`, `H + 1` (or `S - 1` for [`Solid::kfmrh`]) |
+//!
+//! The intermediate shells of the operators are not always manifolds.
+//! An edge may be bridging in a boundary wire, i.e. the wire passes through the edge twice,
+//! and a boundary wire of the face made by `mvfs` is empty, which represents the only vertex.
+//! The validity as a structure of Euler operators is checked by [`Shell::is_euler_valid`].
+
+use crate::*;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+
+/// The numbers of the elements in the Euler–Poincaré formula.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub struct EulerCounts {
+    /// the number of vertices
+    pub vertices: usize,
+    /// the number of edges
+    pub edges: usize,
+    /// the number of faces
+    pub faces: usize,
+    /// the number of rings, i.e. the boundary wires except one for each face
+    pub rings: usize,
+    /// the number of shells
+    pub shells: usize,
+}
+
+impl EulerCounts {
+    /// Returns the Euler characteristic `V - E + F - R`.
+    #[inline(always)]
+    pub fn characteristic(self) -> isize {
+        self.vertices as isize - self.edges as isize + self.faces as isize - self.rings as isize
+    }
+
+    /// Returns the number of holes `H` determined by the Euler–Poincaré formula.
+    /// Returns `None` if the formula cannot be satisfied by a non-negative integer `H`.
+    #[inline(always)]
+    pub fn holes(self) -> Option<usize> {
+        let twice = 2 * self.shells as isize - self.characteristic();
+        match twice >= 0 && twice % 2 == 0 {
+            true => Some(twice as usize / 2),
+            false => None,
+        }
+    }
+}
+
+impl std::ops::Add for EulerCounts {
+    type Output = Self;
+    #[inline(always)]
+    fn add(self, other: Self) -> Self {
+        Self {
+            vertices: self.vertices + other.vertices,
+            edges: self.edges + other.edges,
+            faces: self.faces + other.faces,
+            rings: self.rings + other.rings,
+            shells: self.shells + other.shells,
+        }
+    }
+}
+
+/// Returns the oriented boundaries, leaving the boundaries of `face` empty.
+fn take_loops<P, C, S>(face: &mut Face<P, C, S>) -> Vec<Wire<P, C>> {
+    let loops = std::mem::take(&mut face.boundaries);
+    match face.orientation {
+        true => loops,
+        false => loops.iter().map(Wire::inverse).collect(),
+    }
+}
+
+/// Sets the oriented boundaries.
+fn put_loops<P, C, S>(face: &mut Face<P, C, S>, loops: Vec<Wire<P, C>>) {
+    face.boundaries = match face.orientation {
+        true => loops,
+        false => loops.iter().map(Wire::inverse).collect(),
+    };
+}
+
+/// Returns the face with the oriented boundaries `loops` and the orientation of `face`.
+fn sibling_face<P, C, S>(
+    face: &Face<P, C, S>,
+    loops: Vec<Wire<P, C>>,
+    surface: S,
+) -> Face<P, C, S> {
+    let mut new_face = Face {
+        boundaries: Vec::new(),
+        orientation: face.orientation,
+        surface: Arc::new(RwLock::new(surface)),
+    };
+    put_loops(&mut new_face, loops);
+    new_face
+}
+
+/// Returns the index of the edge starting from `vertex` in `wire`.
+fn corner<P, C>(wire: &Wire<P, C>, vertex: &Vertex<P>) -> Option<usize> {
+    wire.iter().position(|edge| edge.front() == vertex)
+}
+
+impl<P, C, S> Shell<P, C, S> {
+    fn face_index(&self, face_id: FaceID<S>) -> Option<usize> {
+        self.face_list.iter().position(|face| face.id() == face_id)
+    }
+
+    /// Applies `f` to the oriented boundaries of the `idx`th face.
+    /// The boundaries are restored if `f` returns `None`.
+    fn edit_loops<T>(
+        &mut self,
+        idx: usize,
+        f: impl FnOnce(&mut Vec<Wire<P, C>>) -> Option<T>,
+    ) -> Option<T> {
+        let face = &mut self.face_list[idx];
+        let mut loops = take_loops(face);
+        let backup = loops.clone();
+        let res = f(&mut loops);
+        match res.is_some() {
+            true => put_loops(face, loops),
+            false => put_loops(face, backup),
+        }
+        res
+    }
+
+    /// Returns the numbers of the elements in the Euler–Poincaré formula.
+    /// An empty boundary wire is counted as a vertex.
+    pub fn euler_counts(&self) -> EulerCounts {
+        let mut vertices = HashSet::default();
+        let mut edges = HashSet::default();
+        let mut empty_loops = 0;
+        let mut loops = 0;
+        self.face_iter()
+            .flat_map(|face| &face.boundaries)
+            .for_each(|wire| {
+                loops += 1;
+                if wire.is_empty() {
+                    empty_loops += 1;
+                }
+                wire.iter().for_each(|edge| {
+                    vertices.insert(edge.front().id());
+                    edges.insert(edge.id());
+                });
+            });
+        EulerCounts {
+            vertices: vertices.len() + empty_loops,
+            edges: edges.len(),
+            faces: self.len(),
+            rings: loops - self.len(),
+            shells: usize::from(!self.is_empty()),
+        }
+    }
+
+    /// Returns whether the shell is valid as a structure of Euler operators, i.e.
+    /// - all boundary wires are closed,
+    /// - each edge appears exactly twice in the boundaries with the opposite orientations, and
+    /// - the numbers of elements satisfy the Euler–Poincaré formula with a non-negative number of holes.
+    pub fn is_euler_valid(&self) -> bool {
+        let closed = self
+            .face_iter()
+            .flat_map(|face| &face.boundaries)
+            .all(Wire::is_closed);
+        let mut orientations = HashMap::<EdgeID<C>, Vec<bool>>::default();
+        self.face_iter().for_each(|face| {
+            face.boundary_iters()
+                .into_iter()
+                .flatten()
+                .for_each(|edge| {
+                    let entry = orientations.entry(edge.id()).or_default();
+                    entry.push(edge.orientation);
+                })
+        });
+        let paired = orientations
+            .values()
+            .all(|vec| vec.len() == 2 && vec[0] != vec[1]);
+        closed && paired && self.euler_counts().holes().is_some()
+    }
+
+    /// MVFS: makes a vertex, a face and a shell.
+    ///
+    /// The face has one empty boundary wire, which represents the returned vertex.
+    /// Since the wire has no edges, the vertex is not referenced by the shell until the first
+    /// [`Shell::mev`], and only [`Shell::euler_counts`], [`Shell::is_euler_valid`], [`Shell::mev`]
+    /// and [`Shell::kvfs`] take it into account. The other methods regard the shell as a face
+    /// without vertices and edges, e.g. [`Shell::vertex_iter`] returns no vertices.
+    pub fn mvfs(point: P, surface: S) -> (Self, Vertex<P>) {
+        let vertex = Vertex::new(point);
+        let face = Face::new_unchecked(vec![Wire::new()], surface);
+        (vec![face].into(), vertex)
+    }
+
+    /// KVFS: kills the vertex, the face and the shell made by [`Shell::mvfs`].
+    ///
+    /// Returns `false` and does nothing if the shell does not consist of one face with
+    /// one empty boundary wire.
+    pub fn kvfs(&mut self) -> bool {
+        let minimal =
+            self.len() == 1 && self[0].boundaries.len() == 1 && self[0].boundaries[0].is_empty();
+        if minimal {
+            self.face_list.clear();
+        }
+        minimal
+    }
+
+    /// MEV: makes an edge from `vertex` to a new vertex at `point` in the face `face_id`.
+    ///
+    /// The new edge is inserted at the first corner of `vertex` in the boundaries.
+    /// If `vertex` is not in the boundaries, the new edge is inserted to an empty boundary wire,
+    /// which represents `vertex`.
+    /// Returns the new edge, whose back is the new vertex.
+    pub fn mev(
+        &mut self,
+        face_id: FaceID<S>,
+        vertex: &Vertex<P>,
+        point: P,
+        curve: C,
+    ) -> Option<Edge<P, C>> {
+        let idx = self.face_index(face_id)?;
+        let new_vertex = Vertex::new(point);
+        let edge = Edge::new(vertex, &new_vertex, curve);
+        self.edit_loops(idx, |loops| {
+            let position = loops
+                .iter()
+                .enumerate()
+                .find_map(|(i, wire)| Some((i, corner(wire, vertex)?)));
+            match position {
+                Some((i, j)) => {
+                    loops[i].insert(j, edge.inverse());
+                    loops[i].insert(j, edge.clone());
+                }
+                None => {
+                    let wire = loops.iter_mut().find(|wire| wire.is_empty())?;
+                    *wire = vec![edge.clone(), edge.inverse()].into();
+                }
+            }
+            Some(edge.clone())
+        })
+    }
+
+    /// KEV: kills the edge `edge_id` and its end vertex of degree one.
+    ///
+    /// The edge must be dangling, i.e. one boundary wire passes through the edge
+    /// and returns immediately. Returns the killed vertex.
+    pub fn kev(&mut self, edge_id: EdgeID<C>) -> Option<Vertex<P>> {
+        (0..self.len()).find_map(|idx| {
+            self.edit_loops(idx, |loops| {
+                loops.iter_mut().find_map(|wire| {
+                    let len = wire.len();
+                    let j = (0..len).find(|&j| {
+                        let (e0, e1) = (&wire[j], &wire[(j + 1) % len]);
+                        e0.id() == edge_id && e1.id() == edge_id && e0.orientation != e1.orientation
+                    })?;
+                    let vertex = wire[j].back().clone();
+                    wire.rotate_left(j);
+                    wire.pop_front();
+                    wire.pop_front();
+                    Some(vertex)
+                })
+            })
+        })
+    }
+
+    /// MEF: makes an edge from `vertex0` to `vertex1` and a face splitting the face `face_id`.
+    ///
+    /// Both vertices must be in the same boundary wire. The new face, whose surface is
+    /// a clone of the surface of `face_id`, is bounded by the new edge inverted and the part of
+    /// the wire from `vertex0` to `vertex1`. Returns the new edge and the new face.
+    pub fn mef(
+        &mut self,
+        face_id: FaceID<S>,
+        vertex0: &Vertex<P>,
+        vertex1: &Vertex<P>,
+        curve: C,
+    ) -> Option<(Edge<P, C>, Face<P, C, S>)>
+    where
+        S: Clone,
+    {
+        let idx = self.face_index(face_id)?;
+        let edge = Edge::try_new(vertex0, vertex1, curve).ok()?;
+        let new_loop = self.edit_loops(idx, |loops| {
+            let wire = loops
+                .iter_mut()
+                .find(|wire| corner(wire, vertex0).is_some() && corner(wire, vertex1).is_some())?;
+            let i = corner(wire, vertex0)?;
+            wire.rotate_left(i);
+            let mut new_loop: Wire<P, C> = wire.split_off(corner(wire, vertex1)?).into();
+            std::mem::swap(wire, &mut new_loop);
+            wire.push_back(edge.clone());
+            new_loop.push_back(edge.inverse());
+            Some(new_loop)
+        })?;
+        let face = &self.face_list[idx];
+        let new_face = sibling_face(face, vec![new_loop], face.surface());
+        self.face_list.push(new_face.clone());
+        Some((edge, new_face))
+    }
+
+    /// KEF: kills the edge `edge_id` and the face on the inverse side of the edge.
+    ///
+    /// The edge must be shared by two different faces. The boundaries of the killed face are
+    /// merged to the face in which the edge appears in the forward direction.
+    /// Returns the killed edge.
+    pub fn kef(&mut self, edge_id: EdgeID<C>) -> Option<Edge<P, C>> {
+        let find = |orientation: bool| {
+            self.face_iter().enumerate().find_map(|(i, face)| {
+                let mut iter = face.boundary_iters().into_iter().flatten();
+                iter.find(|edge| edge.id() == edge_id && edge.orientation == orientation)
+                    .map(|edge| (i, edge))
+            })
+        };
+        let ((idx0, edge), (idx1, _)) = (find(true)?, find(false)?);
+        if idx0 == idx1 {
+            return None;
+        }
+        let mut loops1 = self.face_list[idx1].boundaries();
+        let i1 = loops1
+            .iter()
+            .position(|wire| wire.iter().any(|e| e.id() == edge_id))?;
+        let mut wire1 = loops1.remove(i1);
+        self.edit_loops(idx0, |loops0| {
+            let wire0 = loops0
+                .iter_mut()
+                .find(|wire| wire.iter().any(|e| e.id() == edge_id))?;
+            let i = wire0.iter().position(|e| e.id() == edge_id)?;
+            wire0.rotate_left(i);
+            let i = wire1.iter().position(|e| e.id() == edge_id)?;
+            wire1.rotate_left(i);
+            wire0.pop_front();
+            wire1.pop_front();
+            wire0.append(&mut wire1);
+            loops0.append(&mut loops1);
+            Some(())
+        })?;
+        self.face_list.remove(idx1);
+        Some(edge)
+    }
+
+    /// KEMR: kills the bridging edge `edge_id` and makes a ring.
+    ///
+    /// The edge must appear twice in one boundary wire, and both parts of the wire divided by
+    /// the edge must be non-empty. Returns the killed edge.
+    pub fn kemr(&mut self, edge_id: EdgeID<C>) -> Option<Edge<P, C>> {
+        (0..self.len()).find_map(|idx| {
+            self.edit_loops(idx, |loops| {
+                let wire = loops
+                    .iter_mut()
+                    .find(|wire| wire.iter().filter(|e| e.id() == edge_id).count() == 2)?;
+                let i = wire.iter().position(|e| e.id() == edge_id)?;
+                wire.rotate_left(i);
+                let edge = wire.pop_front()?;
+                let k = wire.iter().position(|e| e.id() == edge_id)?;
+                let mut ring: Wire<P, C> = wire.split_off(k).into();
+                ring.pop_front();
+                std::mem::swap(wire, &mut ring);
+                match wire.is_empty() || ring.is_empty() {
+                    true => None,
+                    false => {
+                        loops.push(ring);
+                        Some(edge)
+                    }
+                }
+            })
+        })
+    }
+
+    /// MEKR: makes an edge from `vertex0` to `vertex1` and kills a ring of the face `face_id`.
+    ///
+    /// The vertices must be in different boundary wires of the face.
+    /// The wires are merged into one wire passing through the new edge twice.
+    /// Returns the new edge.
+    pub fn mekr(
+        &mut self,
+        face_id: FaceID<S>,
+        vertex0: &Vertex<P>,
+        vertex1: &Vertex<P>,
+        curve: C,
+    ) -> Option<Edge<P, C>> {
+        let idx = self.face_index(face_id)?;
+        let edge = Edge::try_new(vertex0, vertex1, curve).ok()?;
+        self.edit_loops(idx, |loops| {
+            let i0 = loops
+                .iter()
+                .position(|wire| corner(wire, vertex0).is_some())?;
+            let i1 = loops
+                .iter()
+                .position(|wire| corner(wire, vertex1).is_some())?;
+            if i0 == i1 {
+                return None;
+            }
+            let mut wire1 = loops[i1].clone();
+            let i = corner(&wire1, vertex1)?;
+            wire1.rotate_left(i);
+            let wire0 = &mut loops[i0];
+            let i = corner(wire0, vertex0)?;
+            wire0.rotate_left(i);
+            wire0.push_back(edge.clone());
+            wire0.append(&mut wire1);
+            wire0.push_back(edge.inverse());
+            loops.remove(i1);
+            Some(edge.clone())
+        })
+    }
+
+    /// KFMRH: kills the face `face_id1` and makes its boundary a ring of the face `face_id0`.
+    ///
+    /// The face `face_id1` must have exactly one boundary wire. Since both faces are in this shell,
+    /// the number of holes increases. Returns `false` and does nothing if failed.
+    pub fn kfmrh(&mut self, face_id0: FaceID<S>, face_id1: FaceID<S>) -> bool {
+        // `face_id0` is searched again after removing `face_id1`, since its index may be shifted.
+        let idx1 = match (self.face_index(face_id0), self.face_index(face_id1)) {
+            (Some(idx0), Some(idx1)) if idx0 != idx1 => idx1,
+            _ => return false,
+        };
+        if self[idx1].boundaries.len() != 1 {
+            return false;
+        }
+        let face1 = self.face_list.remove(idx1);
+        let ring = face1.boundaries().pop().unwrap();
+        let idx0 = self.face_index(face_id0).unwrap();
+        self.edit_loops(idx0, |loops| {
+            loops.push(ring);
+            Some(())
+        });
+        true
+    }
+
+    /// MFKRH: makes a face from the `ring_index`th boundary wire of the face `face_id`
+    /// and kills the ring.
+    ///
+    /// The face must have at least two boundary wires. The surface of the new face is a clone
+    /// of the surface of `face_id`. Returns the new face.
+    pub fn mfkrh(&mut self, face_id: FaceID<S>, ring_index: usize) -> Option<Face<P, C, S>>
+    where S: Clone {
+        let idx = self.face_index(face_id)?;
+        let ring = self.edit_loops(idx, |loops| {
+            match loops.len() > 1 && ring_index < loops.len() {
+                true => Some(loops.remove(ring_index)),
+                false => None,
+            }
+        })?;
+        let face = &self.face_list[idx];
+        let new_face = sibling_face(face, vec![ring], face.surface());
+        self.face_list.push(new_face.clone());
+        Some(new_face)
+    }
+}
+
+impl<P, C, S> Solid<P, C, S> {
+    fn shell_of_face(&mut self, face_id: FaceID<S>) -> Option<&mut Shell<P, C, S>> {
+        self.boundaries
+            .iter_mut()
+            .find(|shell| shell.face_index(face_id).is_some())
+    }
+
+    fn shell_of_edge(&mut self, edge_id: EdgeID<C>) -> Option<&mut Shell<P, C, S>> {
+        self.boundaries
+            .iter_mut()
+            .find(|shell| shell.edge_iter().any(|edge| edge.id() == edge_id))
+    }
+
+    /// Returns the numbers of the elements in the Euler–Poincaré formula.
+    pub fn euler_counts(&self) -> EulerCounts {
+        self.boundaries
+            .iter()
+            .fold(EulerCounts::default(), |sum, shell| {
+                sum + shell.euler_counts()
+            })
+    }
+
+    /// Returns whether all boundary shells are valid as structures of Euler operators.
+    /// cf. [`Shell::is_euler_valid`]
+    pub fn is_euler_valid(&self) -> bool {
+        self.boundaries.iter().all(Shell::is_euler_valid) && self.euler_counts().holes().is_some()
+    }
+
+    /// MVFS: makes a vertex, a face and a shell. cf. [`Shell::mvfs`]
+    pub fn mvfs(&mut self, point: P, surface: S) -> Vertex<P> {
+        let (shell, vertex) = Shell::mvfs(point, surface);
+        self.boundaries.push(shell);
+        vertex
+    }
+
+    /// KVFS: kills the `shell_index`th shell made by [`Solid::mvfs`]. cf. [`Shell::kvfs`]
+    pub fn kvfs(&mut self, shell_index: usize) -> bool {
+        let killed = match self.boundaries.get_mut(shell_index) {
+            Some(shell) => shell.kvfs(),
+            None => false,
+        };
+        if killed {
+            self.boundaries.remove(shell_index);
+        }
+        killed
+    }
+
+    /// MEV: makes an edge and a vertex. cf. [`Shell::mev`]
+    pub fn mev(
+        &mut self,
+        face_id: FaceID<S>,
+        vertex: &Vertex<P>,
+        point: P,
+        curve: C,
+    ) -> Option<Edge<P, C>> {
+        self.shell_of_face(face_id)?
+            .mev(face_id, vertex, point, curve)
+    }
+
+    /// KEV: kills an edge and a vertex. cf. [`Shell::kev`]
+    pub fn kev(&mut self, edge_id: EdgeID<C>) -> Option<Vertex<P>> {
+        self.shell_of_edge(edge_id)?.kev(edge_id)
+    }
+
+    /// MEF: makes an edge and a face. cf. [`Shell::mef`]
+    pub fn mef(
+        &mut self,
+        face_id: FaceID<S>,
+        vertex0: &Vertex<P>,
+        vertex1: &Vertex<P>,
+        curve: C,
+    ) -> Option<(Edge<P, C>, Face<P, C, S>)>
+    where
+        S: Clone,
+    {
+        self.shell_of_face(face_id)?
+            .mef(face_id, vertex0, vertex1, curve)
+    }
+
+    /// KEF: kills an edge and a face. cf. [`Shell::kef`]
+    pub fn kef(&mut self, edge_id: EdgeID<C>) -> Option<Edge<P, C>> {
+        self.shell_of_edge(edge_id)?.kef(edge_id)
+    }
+
+    /// KEMR: kills an edge and makes a ring. cf. [`Shell::kemr`]
+    pub fn kemr(&mut self, edge_id: EdgeID<C>) -> Option<Edge<P, C>> {
+        self.shell_of_edge(edge_id)?.kemr(edge_id)
+    }
+
+    /// MEKR: makes an edge and kills a ring. cf. [`Shell::mekr`]
+    pub fn mekr(
+        &mut self,
+        face_id: FaceID<S>,
+        vertex0: &Vertex<P>,
+        vertex1: &Vertex<P>,
+        curve: C,
+    ) -> Option<Edge<P, C>> {
+        self.shell_of_face(face_id)?
+            .mekr(face_id, vertex0, vertex1, curve)
+    }
+
+    /// KFMRH: kills the face `face_id1` and makes its boundary a ring of the face `face_id0`.
+    ///
+    /// If the faces are in the same shell, the number of holes increases.
+    /// Otherwise, the shell of `face_id1` is merged into the shell of `face_id0`,
+    /// and the number of shells decreases. cf. [`Shell::kfmrh`]
+    pub fn kfmrh(&mut self, face_id0: FaceID<S>, face_id1: FaceID<S>) -> bool {
+        let position = |shells: &[Shell<P, C, S>], face_id: FaceID<S>| {
+            shells
+                .iter()
+                .position(|shell| shell.face_index(face_id).is_some())
+        };
+        let (i0, i1) = match (
+            position(&self.boundaries, face_id0),
+            position(&self.boundaries, face_id1),
+        ) {
+            (Some(i0), Some(i1)) => (i0, i1),
+            _ => return false,
+        };
+        if i0 == i1 {
+            return self.boundaries[i0].kfmrh(face_id0, face_id1);
+        }
+        let shell1 = &self.boundaries[i1];
+        if shell1[shell1.face_index(face_id1).unwrap()]
+            .boundaries
+            .len()
+            != 1
+        {
+            return false;
+        }
+        let mut shell1 = self.boundaries.remove(i1);
+        let i0 = position(&self.boundaries, face_id0).unwrap();
+        self.boundaries[i0].append(&mut shell1);
+        self.boundaries[i0].kfmrh(face_id0, face_id1)
+    }
+
+    /// MFKRH: makes a face and kills a ring. cf. [`Shell::mfkrh`]
+    ///
+    /// The new face stays in the shell of `face_id`, even if the shell can be divided.
+    pub fn mfkrh(&mut self, face_id: FaceID<S>, ring_index: usize) -> Option<Face<P, C, S>>
+    where S: Clone {
+        self.shell_of_face(face_id)?.mfkrh(face_id, ring_index)
+    }
+}
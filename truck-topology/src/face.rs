@@ -61,7 +61,7 @@ impl<P, C, S> Face<P, C, S> {
         Face {
             boundaries,
             orientation: true,
-            surface: Arc::new(Mutex::new(surface)),
+            surface: Arc::new(RwLock::new(surface)),
         }
     }
 
@@ -215,7 +215,7 @@ impl<P, C, S> Face<P, C, S> {
     fn renew_pointer(&mut self)
     where S: Clone {
         let surface = self.surface();
-        self.surface = Arc::new(Mutex::new(surface));
+        self.surface = Arc::new(RwLock::new(surface));
     }
 
     /// Returns an iterator over the edges.
@@ -415,7 +415,7 @@ impl<P, C, S> Face<P, C, S> {
             .iter()
             .map(|wire| wire.try_mapped(&mut point_mapping, &mut curve_mapping))
             .collect::<Option<Vec<_>>>()?;
-        let surface = surface_mapping(&*self.surface.lock())?;
+        let surface = surface_mapping(&*self.surface.read())?;
         let mut face = Face::debug_new(wires, surface);
         if !self.orientation() {
             face.invert();
@@ -487,7 +487,7 @@ impl<P, C, S> Face<P, C, S> {
             .iter()
             .map(|wire| wire.mapped(&mut point_mapping, &mut curve_mapping))
             .collect();
-        let surface = surface_mapping(&*self.surface.lock());
+        let surface = surface_mapping(&*self.surface.read());
         let mut face = Face::debug_new(wires, surface);
         if !self.orientation() {
             face.invert();
@@ -506,9 +506,29 @@ impl<P, C, S> Face<P, C, S> {
     #[inline(always)]
     pub fn surface(&self) -> S
     where S: Clone {
-        self.surface.lock().clone()
+        self.surface.read().clone()
     }
 
+    /// Calls `f` with the reference to the absolute surface, without cloning the surface.
+    ///
+    /// The surface is locked only for reading, so the calls from many threads do not block each other.
+    /// # Remarks
+    /// Setting the surface of the same face in `f` causes a deadlock.
+    /// # Examples
+    /// ```
+    /// use truck_topology::*;
+    /// let v = Vertex::news(&[(), (), ()]);
+    /// let wire = Wire::from(vec![
+    ///      Edge::new(&v[0], &v[1], ()),
+    ///      Edge::new(&v[1], &v[2], ()),
+    ///      Edge::new(&v[2], &v[0], ()),
+    /// ]);
+    /// let face = Face::new(vec![wire], vec![1, 2, 3]);
+    /// assert_eq!(face.with_surface(|surface| surface.len()), 3);
+    /// ```
+    #[inline(always)]
+    pub fn with_surface<R>(&self, f: impl FnOnce(&S) -> R) -> R { f(&self.surface.read()) }
+
     /// Sets the surface of face.
     /// # Examples
     /// ```
@@ -534,7 +554,7 @@ impl<P, C, S> Face<P, C, S> {
     /// assert_eq!(face1.surface(), 1);
     /// ```
     #[inline(always)]
-    pub fn set_surface(&self, surface: S) { *self.surface.lock() = surface; }
+    pub fn set_surface(&self, surface: S) { *self.surface.write() = surface; }
 
     /// Inverts the direction of the face.
     /// # Examples
@@ -754,37 +774,117 @@ impl<P, C, S> Face<P, C, S> {
     /// let face = Face::new(vec![wire], ());
     /// assert!(face.cut_by_edge(Edge::new(&v[1], &v[4], ())).is_none());
     pub fn cut_by_edge(&self, edge: Edge<P, C>) -> Option<(Self, Self)>
+    where S: Clone {
+        self.cut_by_wire(&Wire::from(vec![edge]))
+    }
+
+    /// Imprints a wire on the face, and returns the two faces divided by the wire.
+    ///
+    /// - If `wire` is not closed, the face is cut along `wire` in the same way as [`Face::cut_by_edge`].
+    ///   The end vertices of `wire` have to be vertices of the boundary.
+    /// - If `wire` is closed, the first face is `self` with the hole bounded by `wire`, and the second
+    ///   face is the region enclosed by `wire`. The wire has to be in the interior of the face and
+    ///   oriented so that the enclosed region is on the left side with respect to the orientation of
+    ///   the face. Since the topology does not know the geometry, the interior means here that `wire`
+    ///   shares no vertices with the boundaries of the face.
+    /// # Examples
+    /// ```
+    /// use truck_topology::*;
+    /// let v = Vertex::news(&[(); 6]);
+    /// let wire = Wire::from(vec![
+    ///     Edge::new(&v[0], &v[1], ()),
+    ///     Edge::new(&v[1], &v[2], ()),
+    ///     Edge::new(&v[2], &v[3], ()),
+    ///     Edge::new(&v[3], &v[0], ()),
+    /// ]);
+    /// let face = Face::new(vec![wire], ());
+    ///
+    /// // cut by the wire from `v[1]` to `v[3]` via `v[4]`.
+    /// let cutter = Wire::from(vec![Edge::new(&v[1], &v[4], ()), Edge::new(&v[4], &v[3], ())]);
+    /// let (face0, face1) = face.imprint(&cutter).unwrap();
+    /// let v0: Vec<Vertex<()>> = face0.boundaries()[0].vertex_iter().collect();
+    /// assert_eq!(v0, vec![v[3].clone(), v[0].clone(), v[1].clone(), v[4].clone()]);
+    /// let v1: Vec<Vertex<()>> = face1.boundaries()[0].vertex_iter().collect();
+    /// assert_eq!(v1, vec![v[1].clone(), v[2].clone(), v[3].clone(), v[4].clone()]);
+    ///
+    /// // imprint the closed wire in the interior.
+    /// let v = Vertex::news(&[(); 3]);
+    /// let loop_wire = Wire::from(vec![
+    ///     Edge::new(&v[0], &v[1], ()),
+    ///     Edge::new(&v[1], &v[2], ()),
+    ///     Edge::new(&v[2], &v[0], ()),
+    /// ]);
+    /// let (outer, inner) = face0.imprint(&loop_wire).unwrap();
+    /// assert_eq!(outer.boundaries().len(), 2);
+    /// assert_eq!(outer.boundaries()[1], loop_wire.inverse());
+    /// assert_eq!(inner.boundaries(), vec![loop_wire]);
+    /// ```
+    /// # Failures
+    /// Returns `None` if:
+    /// - `wire` is empty or not continuous,
+    /// - `wire` is closed but not simple,
+    /// - `wire` is closed and shares a vertex with the boundaries of `self`,
+    /// - `wire` is not closed and `self` has several boundaries, or
+    /// - `wire` is not closed and `self` does not include the end vertices of `wire`.
+    pub fn imprint(&self, wire: &Wire<P, C>) -> Option<(Self, Self)>
+    where S: Clone {
+        if wire.is_empty() || !wire.is_continuous() {
+            None
+        } else if wire.is_cyclic() {
+            let wire = match self.orientation {
+                true => wire.clone(),
+                false => wire.inverse(),
+            };
+            let mut boundaries = self.boundaries.clone();
+            boundaries.push(wire.inverse());
+            let mut face0 = Face::try_new(boundaries, self.surface()).ok()?;
+            let mut face1 = Face::try_new(vec![wire], self.surface()).ok()?;
+            face0.orientation = self.orientation;
+            face1.orientation = self.orientation;
+            Some((face0, face1))
+        } else {
+            self.cut_by_wire(wire)
+        }
+    }
+
+    fn cut_by_wire(&self, cutter: &Wire<P, C>) -> Option<(Self, Self)>
     where S: Clone {
         if self.boundaries.len() != 1 {
             return None;
         }
+        let (front, back) = cutter.ends_vertices()?;
         let mut face0 = Face {
             boundaries: self.boundaries.clone(),
             orientation: self.orientation,
-            surface: Arc::new(Mutex::new(self.surface())),
+            surface: Arc::new(RwLock::new(self.surface())),
         };
         let wire = &mut face0.boundaries[0];
         let i = wire
             .edge_iter()
             .enumerate()
-            .find(|(_, e)| e.front() == edge.back())
+            .find(|(_, e)| e.front() == back)
             .map(|(i, _)| i)?;
         let j = wire
             .edge_iter()
             .enumerate()
-            .find(|(_, e)| e.back() == edge.front())
+            .find(|(_, e)| e.back() == front)
             .map(|(i, _)| i)?;
         wire.rotate_left(i);
         let j = (j + wire.len() - i) % wire.len();
         let mut new_wire = wire.split_off(j + 1);
-        wire.push_back(edge.clone());
-        new_wire.push_back(edge.inverse());
+        cutter
+            .edge_iter()
+            .for_each(|edge| wire.push_back(edge.clone()));
+        cutter
+            .inverse()
+            .edge_into_iter()
+            .for_each(|edge| new_wire.push_back(edge));
         debug_assert!(Face::try_new(self.boundaries.clone(), ()).is_ok());
         debug_assert!(Face::try_new(vec![new_wire.clone()], ()).is_ok());
         let face1 = Face {
             boundaries: vec![new_wire],
             orientation: self.orientation,
-            surface: Arc::new(Mutex::new(self.surface())),
+            surface: Arc::new(RwLock::new(self.surface())),
         };
         Some((face0, face1))
     }
@@ -885,7 +985,7 @@ impl<P, C, S> Face<P, C, S> {
         Some(Face {
             boundaries,
             orientation: self.orientation(),
-            surface: Arc::new(Mutex::new(surface)),
+            surface: Arc::new(RwLock::new(surface)),
         })
     }
 
@@ -960,8 +1060,8 @@ impl<P, C, S: Clone + Invertible> Face<P, C, S> {
     #[inline(always)]
     pub fn oriented_surface(&self) -> S {
         match self.orientation {
-            true => self.surface.lock().clone(),
-            false => self.surface.lock().inverse(),
+            true => self.surface.read().clone(),
+            false => self.surface.read().inverse(),
         }
     }
 }
@@ -976,16 +1076,148 @@ where
     /// and the geometry of edge.
     #[inline(always)]
     pub fn is_geometric_consistent(&self) -> bool {
-        let surface = &*self.surface.lock();
+        let surface = &*self.surface.read();
         self.boundary_iters().into_iter().flatten().all(|edge| {
             let edge_consist = edge.is_geometric_consistent();
-            let curve = &*edge.curve.lock();
+            let curve = &*edge.curve.read();
             let curve_consist = surface.include(curve);
             edge_consist && curve_consist
         })
     }
 }
 
+impl<C, S> Face<Point3, C, S>
+where
+    C: ParametricCurve3D + BoundedCurve + BoundingBoxed<Point = Point3>,
+    S: ParametricSurface3D + SearchParameter<D2, Point = Point3>,
+{
+    /// Returns the tight bounding box of the trimmed surface.
+    ///
+    /// The box is the union of the bounding boxes of the boundary edges and the extrema of
+    /// the coordinates in the interior of the parameter domain trimmed by the boundaries.
+    /// If a boundary point cannot be projected to the surface, all the extrema in the
+    /// parameter range spanned by the boundaries are regarded as the ones in the domain.
+    pub fn bounding_box(&self) -> BoundingBox<Point3> {
+        let mut bdb = BoundingBox::new();
+        self.edge_iter().for_each(|edge| bdb += edge.bounding_box());
+        let surface = &*self.surface.read();
+        let polygons: Option<Vec<Vec<Point2>>> = self
+            .boundaries
+            .iter()
+            .map(|wire| parameter_polygon(surface, wire))
+            .collect();
+        let (u_period, v_period) = (surface.u_period(), surface.v_period());
+        let range = match &polygons {
+            Some(polygons) => polygons.iter().flatten().collect::<BoundingBox<_>>(),
+            None => self
+                .boundaries
+                .iter()
+                .flat_map(Wire::edge_iter)
+                .flat_map(|edge| sample_points(edge, BOUNDING_BOX_DIVISION))
+                .filter_map(|pt| {
+                    surface.search_parameter(pt, None::<(f64, f64)>, SEARCH_PARAMETER_TRIALS)
+                })
+                .map(|(u, v)| Point2::new(u, v))
+                .collect::<BoundingBox<_>>(),
+        };
+        if range.is_empty() {
+            return bdb;
+        }
+        let range = (
+            (range.min()[0], range.max()[0]),
+            (range.min()[1], range.max()[1]),
+        );
+        let critical_points =
+            algo::surface::coordinate_critical_points(surface, range, BOUNDING_BOX_DIVISION);
+        critical_points
+            .into_iter()
+            .filter(|&(u, v)| {
+                let polygons = match &polygons {
+                    Some(polygons) => polygons,
+                    None => return true,
+                };
+                let shifts = |period: Option<f64>| match period {
+                    Some(p) => vec![-p, 0.0, p],
+                    None => vec![0.0],
+                };
+                shifts(u_period).into_iter().any(|du| {
+                    shifts(v_period).into_iter().any(|dv| {
+                        let uv = (u + du, v + dv);
+                        polygons
+                            .iter()
+                            .map(|poly| winding_number(poly, uv))
+                            .sum::<i32>()
+                            != 0
+                    })
+                })
+            })
+            .for_each(|(u, v)| bdb.push(surface.subs(u, v)));
+        bdb
+    }
+}
+
+/// Returns the points on the curve of `edge`, dividing its parameter range into `division` parts.
+/// The end point is not included.
+pub(crate) fn sample_points<C>(edge: &Edge<Point3, C>, division: usize) -> Vec<Point3>
+where C: ParametricCurve3D + BoundedCurve {
+    let curve = edge.curve.read();
+    let (t0, t1) = curve.range_tuple();
+    let params = (0..division).map(|i| t0 + (t1 - t0) * i as f64 / division as f64);
+    match edge.orientation {
+        true => params.map(|t| curve.subs(t)).collect(),
+        false => params.map(|t| curve.subs(t1 + t0 - t)).collect(),
+    }
+}
+
+/// Returns the polygon in the parameter domain of `surface` approximating `wire`.
+/// The periodic parameters are unwrapped so that the polygon is continuous.
+/// Returns `None` if a point cannot be projected or the polygon is not closed.
+pub(crate) fn parameter_polygon<C, S>(surface: &S, wire: &Wire<Point3, C>) -> Option<Vec<Point2>>
+where
+    C: ParametricCurve3D + BoundedCurve,
+    S: ParametricSurface3D + SearchParameter<D2, Point = Point3>, {
+    let unwrap = |x: f64, prev: f64, period: Option<f64>| match period {
+        Some(p) => x + p * ((prev - x) / p).round(),
+        None => x,
+    };
+    let (u_period, v_period) = (surface.u_period(), surface.v_period());
+    let mut polygon = Vec::<Point2>::new();
+    for pt in wire
+        .edge_iter()
+        .flat_map(|edge| sample_points(edge, BOUNDING_BOX_DIVISION))
+    {
+        let hint = polygon.last().map(|p| (p.x, p.y));
+        let (u, v) = surface.search_parameter(pt, hint, SEARCH_PARAMETER_TRIALS)?;
+        let (u, v) = match polygon.last() {
+            Some(p) => (unwrap(u, p.x, u_period), unwrap(v, p.y, v_period)),
+            None => (u, v),
+        };
+        polygon.push(Point2::new(u, v));
+    }
+    let (first, last) = (polygon.first()?, polygon.last()?);
+    let closed = |x: f64, y: f64, period: Option<f64>| match period {
+        Some(p) => (x - y).abs() < p / 2.0,
+        None => true,
+    };
+    match closed(first.x, last.x, u_period) && closed(first.y, last.y, v_period) {
+        true => Some(polygon),
+        false => None,
+    }
+}
+
+/// Returns the winding number of `polygon` around the point `(u, v)`.
+pub(crate) fn winding_number(polygon: &[Point2], (u, v): (f64, f64)) -> i32 {
+    let edges = polygon.iter().zip(polygon.iter().cycle().skip(1));
+    edges.fold(0, |w, (p, q)| {
+        let cross = (q.x - p.x) * (v - p.y) - (u - p.x) * (q.y - p.y);
+        match (p.y <= v, q.y <= v) {
+            (true, false) if cross > 0.0 => w + 1,
+            (false, true) if cross < 0.0 => w - 1,
+            _ => w,
+        }
+    })
+}
+
 impl<P, C, S> Clone for Face<P, C, S> {
     #[inline(always)]
     fn clone(&self) -> Face<P, C, S> {
@@ -1093,7 +1325,7 @@ impl<'a, P: Debug, C: Debug, S: Debug> Debug
                         .map(|wire| wire.display(wire_format))
                         .collect::<Vec<_>>(),
                 )
-                .field("entity", &MutexFmt(&self.entity.surface))
+                .field("entity", &RwLockFmt(&self.entity.surface))
                 .finish(),
             FaceDisplayFormat::BoundariesAndID { wire_format } => f
                 .debug_struct("Face")
@@ -1119,7 +1351,7 @@ impl<'a, P: Debug, C: Debug, S: Debug> Debug
                         .map(|wire| wire.display(wire_format))
                         .collect::<Vec<_>>(),
                 )
-                .field("entity", &MutexFmt(&self.entity.surface))
+                .field("entity", &RwLockFmt(&self.entity.surface))
                 .finish(),
             FaceDisplayFormat::LoopsListTuple { wire_format } => f
                 .debug_tuple("Face")
@@ -1142,7 +1374,7 @@ impl<'a, P: Debug, C: Debug, S: Debug> Debug
                 )
                 .finish(),
             FaceDisplayFormat::AsSurface => {
-                f.write_fmt(format_args!("{:?}", &MutexFmt(&self.entity.surface)))
+                f.write_fmt(format_args!("{:?}", &RwLockFmt(&self.entity.surface)))
             }
         }
     }
@@ -0,0 +1,363 @@
+//! Geometric fingerprints and differences of shells and solids
+//!
+//! [`Shell::fingerprint`] and [`Solid::fingerprint`] summarize the topology and the geometry of
+//! the shapes in [`ShapeFingerprint`], which does not depend on the order of the faces, the ids of
+//! the elements or the parametrizations of the curves and the surfaces. Two fingerprints are
+//! compared by [`ShapeFingerprint::matches`] with a tolerance.
+//!
+//! The sample points in the fingerprints are the points on the curves and the surfaces nearest
+//! to the probe points determined by the points of the vertices, and the points on the closed
+//! curves farthest from their vertices. Hence, the sample points are not moved by
+//! reparametrizations. The surfaces of the faces without boundaries are probed by the fixed
+//! points [`BOUNDLESS_PROBES`] instead.
+//!
+//! [`Shell::diff`] and [`Solid::diff`] report the added, removed and modified faces between
+//! two versions of a shape in [`ShapeDiff`].
+
+use crate::*;
+use rustc_hash::FxHashSet as HashSet;
+use truck_base::cgmath64::{EuclideanSpace, InnerSpace, MetricSpace, Vector3};
+
+/// The probe points of the surfaces of the faces without boundaries, the vertices of a regular
+/// tetrahedron centered at the origin.
+pub const BOUNDLESS_PROBES: [Point3; 4] = [
+    Point3::new(1.0, 1.0, 1.0),
+    Point3::new(1.0, -1.0, -1.0),
+    Point3::new(-1.0, 1.0, -1.0),
+    Point3::new(-1.0, -1.0, 1.0),
+];
+
+/// The summary of a face, which does not depend on the parametrizations.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FaceFingerprint {
+    /// the number of the boundary wires
+    pub wires: usize,
+    /// the number of the boundary edges
+    pub edges: usize,
+    /// the points of the vertices on the boundaries
+    pub vertices: Vec<Point3>,
+    /// the points on the curves nearest to the midpoints of the ends of the non-closed edges,
+    /// and the points on the curves farthest from the vertices of the closed edges
+    pub edge_points: Vec<Point3>,
+    /// the points on the surface nearest to the vertices, the edge points and their barycenter,
+    /// or to [`BOUNDLESS_PROBES`] if the face has no boundaries, with the unit normals oriented
+    /// by the face
+    pub surface_points: Vec<(Point3, Vector3)>,
+}
+
+/// The summary of a shell or a solid, which does not depend on the order of the faces, the ids
+/// of the elements or the parametrizations.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ShapeFingerprint {
+    /// the number of the shells
+    pub shells: usize,
+    /// the number of the edges
+    pub edges: usize,
+    /// the number of the vertices
+    pub vertices: usize,
+    /// the fingerprints of the faces
+    pub faces: Vec<FaceFingerprint>,
+}
+
+/// The differences of the faces between two versions of a shape, by [`Shell::diff`] or [`Solid::diff`].
+#[derive(Clone, Debug)]
+pub struct ShapeDiff<S> {
+    /// the faces of the new shape which are not in the old shape
+    pub added: Vec<FaceID<S>>,
+    /// the faces of the old shape which are not in the new shape
+    pub removed: Vec<FaceID<S>>,
+    /// the pairs of the faces of the old shape and the new shape which are on the same surface
+    /// but whose boundaries are different
+    pub modified: Vec<(FaceID<S>, FaceID<S>)>,
+}
+
+impl<S> ShapeDiff<S> {
+    /// Returns `true` if the two shapes are geometrically identical.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.modified.is_empty()
+    }
+}
+
+impl FaceFingerprint {
+    /// Returns whether the two faces are geometrically identical in the tolerance `tol`.
+    pub fn matches(&self, other: &Self, tol: f64) -> bool {
+        let near = |p: &Point3, q: &Point3| p.distance(*q) < tol;
+        self.wires == other.wires
+            && self.edges == other.edges
+            && match_all(&self.vertices, &other.vertices, near)
+            && match_all(&self.edge_points, &other.edge_points, near)
+            && match_all(
+                &self.surface_points,
+                &other.surface_points,
+                |(p, n), (q, m)| near(p, q) && same_normal(*n, *m),
+            )
+    }
+}
+
+impl ShapeFingerprint {
+    /// Returns whether the two shapes are geometrically identical in the tolerance `tol`.
+    pub fn matches(&self, other: &Self, tol: f64) -> bool {
+        self.shells == other.shells
+            && self.edges == other.edges
+            && self.vertices == other.vertices
+            && match_all(&self.faces, &other.faces, |x, y| x.matches(y, tol))
+    }
+}
+
+impl<C, S> Face<Point3, C, S>
+where
+    C: ParametricCurve3D + BoundedCurve + SearchNearestParameter<D1, Point = Point3>,
+    S: ParametricSurface3D + SearchNearestParameter<D2, Point = Point3>,
+{
+    /// Returns the fingerprint of the face.
+    pub fn fingerprint(&self) -> FaceFingerprint {
+        let mut vertex_ids = HashSet::default();
+        let vertices: Vec<Point3> = self
+            .vertex_iter()
+            .filter(|vertex| vertex_ids.insert(vertex.id()))
+            .map(|vertex| vertex.point())
+            .collect();
+        let mut edge_ids = HashSet::default();
+        let edge_points: Vec<Point3> = self
+            .edge_iter()
+            .filter(|edge| edge_ids.insert(edge.id()))
+            .filter_map(|edge| {
+                let curve = edge.curve.read();
+                if edge.front().id() == edge.back().id() {
+                    return Some(farthest_point(&*curve, edge.front().point()));
+                }
+                let midpoint = edge.front().point().midpoint(edge.back().point());
+                let t = curve.search_nearest_parameter(
+                    midpoint,
+                    SPHint1D::None,
+                    SEARCH_PARAMETER_TRIALS,
+                )?;
+                Some(curve.subs(t))
+            })
+            .collect();
+        let probes: Vec<Point3> = vertices.iter().chain(&edge_points).copied().collect();
+        let (probes, barycenter) = match probes.is_empty() {
+            true => (BOUNDLESS_PROBES.to_vec(), None),
+            false => {
+                let barycenter = Point3::centroid(&probes);
+                (probes, Some(barycenter))
+            }
+        };
+        let surface = self.surface.read();
+        let surface_points = probes
+            .into_iter()
+            .chain(barycenter)
+            .filter_map(|probe| {
+                let (u, v) = surface.search_nearest_parameter(
+                    probe,
+                    SPHint2D::None,
+                    SEARCH_PARAMETER_TRIALS,
+                )?;
+                let normal = match self.orientation {
+                    true => surface.normal(u, v),
+                    false => -surface.normal(u, v),
+                };
+                Some((surface.subs(u, v), normal))
+            })
+            .collect();
+        FaceFingerprint {
+            wires: self.boundaries.len(),
+            edges: self.edge_iter().count(),
+            vertices,
+            edge_points,
+            surface_points,
+        }
+    }
+}
+
+impl<C, S> Shell<Point3, C, S>
+where
+    C: ParametricCurve3D + BoundedCurve + SearchNearestParameter<D1, Point = Point3>,
+    S: ParametricSurface3D + SearchNearestParameter<D2, Point = Point3>,
+{
+    /// Returns the fingerprint of the shell.
+    pub fn fingerprint(&self) -> ShapeFingerprint { shape_fingerprint(std::slice::from_ref(self)) }
+
+    /// Returns the differences of the faces from `self` to `new`.
+    ///
+    /// The faces are matched by [`FaceFingerprint::matches`] with the tolerance `tol`.
+    /// A face of `self` and a face of `new` which are not matched are reported as modified if the
+    /// sample points of the new face are on the surface of the old face in the same orientation.
+    pub fn diff(&self, new: &Self, tol: f64) -> ShapeDiff<S> {
+        faces_diff(self.face_iter(), new.face_iter(), tol)
+    }
+}
+
+impl<C, S> Solid<Point3, C, S>
+where
+    C: ParametricCurve3D + BoundedCurve + SearchNearestParameter<D1, Point = Point3>,
+    S: ParametricSurface3D + SearchNearestParameter<D2, Point = Point3>,
+{
+    /// Returns the fingerprint of the solid.
+    pub fn fingerprint(&self) -> ShapeFingerprint { shape_fingerprint(self.boundaries()) }
+
+    /// Returns the differences of the faces from `self` to `new`.
+    ///
+    /// See [`Shell::diff`] for details.
+    pub fn diff(&self, new: &Self, tol: f64) -> ShapeDiff<S> {
+        faces_diff(self.face_iter(), new.face_iter(), tol)
+    }
+}
+
+fn shape_fingerprint<C, S>(shells: &[Shell<Point3, C, S>]) -> ShapeFingerprint
+where
+    C: ParametricCurve3D + BoundedCurve + SearchNearestParameter<D1, Point = Point3>,
+    S: ParametricSurface3D + SearchNearestParameter<D2, Point = Point3>, {
+    let edges: HashSet<EdgeID<C>> = shells
+        .iter()
+        .flat_map(Shell::edge_iter)
+        .map(|edge| edge.id())
+        .collect();
+    let vertices: HashSet<VertexID<Point3>> = shells
+        .iter()
+        .flat_map(Shell::vertex_iter)
+        .map(|vertex| vertex.id())
+        .collect();
+    ShapeFingerprint {
+        shells: shells.len(),
+        edges: edges.len(),
+        vertices: vertices.len(),
+        faces: shells
+            .iter()
+            .flat_map(Shell::face_iter)
+            .map(Face::fingerprint)
+            .collect(),
+    }
+}
+
+fn faces_diff<'a, C, S>(
+    old: impl Iterator<Item = &'a Face<Point3, C, S>>,
+    new: impl Iterator<Item = &'a Face<Point3, C, S>>,
+    tol: f64,
+) -> ShapeDiff<S>
+where
+    C: ParametricCurve3D + BoundedCurve + SearchNearestParameter<D1, Point = Point3> + 'a,
+    S: ParametricSurface3D + SearchNearestParameter<D2, Point = Point3> + 'a,
+{
+    let old: Vec<_> = old.map(|face| (face, face.fingerprint())).collect();
+    let new: Vec<_> = new.map(|face| (face, face.fingerprint())).collect();
+    let mut new_matched = vec![false; new.len()];
+    let old_unmatched: Vec<_> = old
+        .iter()
+        .filter(|(_, fingerprint)| {
+            let found = new
+                .iter()
+                .enumerate()
+                .position(|(j, (_, new_fingerprint))| {
+                    !new_matched[j] && fingerprint.matches(new_fingerprint, tol)
+                });
+            match found {
+                Some(j) => {
+                    new_matched[j] = true;
+                    false
+                }
+                None => true,
+            }
+        })
+        .collect();
+    let mut modified = Vec::new();
+    let removed = old_unmatched
+        .into_iter()
+        .filter_map(|(old_face, _)| {
+            let found = new
+                .iter()
+                .enumerate()
+                .position(|(j, (_, new_fingerprint))| {
+                    !new_matched[j] && on_surface(old_face, new_fingerprint, tol)
+                });
+            match found {
+                Some(j) => {
+                    new_matched[j] = true;
+                    modified.push((old_face.id(), new[j].0.id()));
+                    None
+                }
+                None => Some(old_face.id()),
+            }
+        })
+        .collect();
+    let added = new
+        .iter()
+        .zip(new_matched)
+        .filter(|(_, matched)| !matched)
+        .map(|((face, _), _)| face.id())
+        .collect();
+    ShapeDiff {
+        added,
+        removed,
+        modified,
+    }
+}
+
+/// Returns whether the sample points of the fingerprint are on the surface of the face in the same orientation.
+fn on_surface<C, S>(face: &Face<Point3, C, S>, fingerprint: &FaceFingerprint, tol: f64) -> bool
+where S: ParametricSurface3D + SearchNearestParameter<D2, Point = Point3> {
+    let surface = face.surface.read();
+    !fingerprint.surface_points.is_empty()
+        && fingerprint.surface_points.iter().all(|(point, normal)| {
+            let Some((u, v)) =
+                surface.search_nearest_parameter(*point, SPHint2D::None, SEARCH_PARAMETER_TRIALS)
+            else {
+                return false;
+            };
+            let surface_normal = match face.orientation {
+                true => surface.normal(u, v),
+                false => -surface.normal(u, v),
+            };
+            surface.subs(u, v).distance(*point) < tol && same_normal(surface_normal, *normal)
+        })
+}
+
+/// Returns the point on the closed curve farthest from its vertex `vertex`.
+fn farthest_point<C: ParametricCurve3D + BoundedCurve>(curve: &C, vertex: Point3) -> Point3 {
+    const DIVISION: usize = 32;
+    const TRIALS: usize = 64;
+    let (t0, t1) = curve.range_tuple();
+    let dt = (t1 - t0) / DIVISION as f64;
+    let distance2 = |t: f64| curve.subs(t).distance2(vertex);
+    let i = (0..=DIVISION)
+        .map(|i| i as f64)
+        .max_by(|i, j| f64::total_cmp(&distance2(t0 + dt * i), &distance2(t0 + dt * j)))
+        .unwrap_or(0.0);
+    // ternary search around the farthest division point
+    let mut a = f64::max(t0 + dt * (i - 1.0), t0);
+    let mut b = f64::min(t0 + dt * (i + 1.0), t1);
+    (0..TRIALS).for_each(|_| {
+        let (s0, s1) = (a + (b - a) / 3.0, b - (b - a) / 3.0);
+        match distance2(s0) < distance2(s1) {
+            true => a = s0,
+            false => b = s1,
+        }
+    });
+    curve.subs((a + b) / 2.0)
+}
+
+/// Returns whether the two unit normals are the same. The normals at the singular points are ignored.
+fn same_normal(n: Vector3, m: Vector3) -> bool {
+    let singular = |n: Vector3| !n.magnitude2().is_finite() || n.magnitude2().so_small();
+    singular(n) || singular(m) || n.near(&m)
+}
+
+/// Returns whether each element of `a` corresponds to an element of `b` one-to-one by `matches`.
+fn match_all<T>(a: &[T], b: &[T], matches: impl Fn(&T, &T) -> bool) -> bool {
+    let mut used = vec![false; b.len()];
+    a.len() == b.len()
+        && a.iter().all(|x| {
+            let found = b
+                .iter()
+                .enumerate()
+                .position(|(j, y)| !used[j] && matches(x, y));
+            match found {
+                Some(j) => {
+                    used[j] = true;
+                    true
+                }
+                None => false,
+            }
+        })
+}
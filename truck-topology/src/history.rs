@@ -0,0 +1,387 @@
+//! Histories of modeling operations, the basis of persistent naming
+//!
+//! A [`ShapeHistory`] is returned alongside a modeling operation, and records which elements of
+//! the result come from which elements of the input.
+//!
+//! - `generated`: the elements newly created from an input element, e.g. the side face swept by
+//!   an edge, or the intersection edge of two faces.
+//! - `modified`: the elements which replace an input element, e.g. the pieces of a divided face.
+//! - `deleted`: the input elements which have no image in the result.
+//!
+//! The input elements which appear in the result as they are, are recorded in none of them.
+//! The histories of successive operations are composed by [`ShapeHistory::then`].
+//!
+//! The elements are specified by [`ShapeID`]s, whose components are the ids of the topological
+//! elements, or the indices of the elements of compressed shapes. Since the ids of the topological
+//! elements are the addresses of their geometries, the input shapes have to be kept alive while
+//! the history is referred to.
+
+use crate::*;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+
+/// The id of a vertex, an edge or a face recorded in [`ShapeHistory`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ShapeID<V, E, F> {
+    /// vertex
+    Vertex(V),
+    /// edge
+    Edge(E),
+    /// face
+    Face(F),
+}
+
+/// History of topological elements, i.e. of shapes whose elements are [`Vertex`], [`Edge`] and [`Face`].
+pub type TopologyHistory<P, C, S> = ShapeHistory<VertexID<P>, EdgeID<C>, FaceID<S>>;
+
+/// History of compressed shapes, whose elements are specified by their indices.
+pub type CompressedHistory = ShapeHistory<usize, usize, usize>;
+
+/// Generated, modified and deleted elements by a modeling operation.
+/// # Examples
+/// ```
+/// use truck_topology::{history::*, *};
+/// let v = Vertex::news(&[(); 4]);
+/// let wire = Wire::from(vec![
+///     Edge::new(&v[0], &v[1], ()),
+///     Edge::new(&v[1], &v[2], ()),
+///     Edge::new(&v[2], &v[3], ()),
+///     Edge::new(&v[3], &v[0], ()),
+/// ]);
+/// let face = Face::new(vec![wire], ());
+///
+/// // cut the face by the diagonal
+/// let diagonal = Edge::new(&v[1], &v[3], ());
+/// let (face0, face1) = face.cut_by_edge(diagonal.clone()).unwrap();
+/// let mut history = TopologyHistory::new();
+/// history.add_modified(ShapeID::Face(face.id()), ShapeID::Face(face0.id()));
+/// history.add_modified(ShapeID::Face(face.id()), ShapeID::Face(face1.id()));
+/// history.add_generated(ShapeID::Face(face.id()), ShapeID::Edge(diagonal.id()));
+///
+/// assert_eq!(history.modified(ShapeID::Face(face.id())).len(), 2);
+/// assert_eq!(
+///     history.generated(ShapeID::Face(face.id())),
+///     &[ShapeID::Edge(diagonal.id())],
+/// );
+/// // The vertices are not changed.
+/// assert_eq!(
+///     history.images(ShapeID::Vertex(v[0].id())),
+///     vec![ShapeID::Vertex(v[0].id())],
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct ShapeHistory<V, E, F> {
+    generated: HashMap<ShapeID<V, E, F>, Vec<ShapeID<V, E, F>>>,
+    modified: HashMap<ShapeID<V, E, F>, Vec<ShapeID<V, E, F>>>,
+    deleted: HashSet<ShapeID<V, E, F>>,
+}
+
+impl<V, E, F> Default for ShapeHistory<V, E, F> {
+    fn default() -> Self {
+        Self {
+            generated: HashMap::default(),
+            modified: HashMap::default(),
+            deleted: HashSet::default(),
+        }
+    }
+}
+
+impl<V, E, F> ShapeHistory<V, E, F>
+where
+    V: Copy + Eq + Hash,
+    E: Copy + Eq + Hash,
+    F: Copy + Eq + Hash,
+{
+    /// Creates an empty history, i.e. the history of the operation which changes nothing.
+    #[inline(always)]
+    pub fn new() -> Self { Self::default() }
+
+    /// Returns `true` if no element is generated, modified or deleted.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.generated.is_empty() && self.modified.is_empty() && self.deleted.is_empty()
+    }
+
+    /// Records that `output` is newly created from `input`.
+    pub fn add_generated(&mut self, input: ShapeID<V, E, F>, output: ShapeID<V, E, F>) {
+        let vec = self.generated.entry(input).or_default();
+        if !vec.contains(&output) {
+            vec.push(output);
+        }
+    }
+
+    /// Records that `output` replaces `input`, or a part of `input`.
+    ///
+    /// If `input` itself remains in the result but is changed, e.g. its boundary is divided,
+    /// add `input` as its own modification.
+    pub fn add_modified(&mut self, input: ShapeID<V, E, F>, output: ShapeID<V, E, F>) {
+        let vec = self.modified.entry(input).or_default();
+        if !vec.contains(&output) {
+            vec.push(output);
+        }
+    }
+
+    /// Records that `input` has no image in the result.
+    #[inline(always)]
+    pub fn add_deleted(&mut self, input: ShapeID<V, E, F>) { self.deleted.insert(input); }
+
+    /// Returns the elements newly created from `input`.
+    #[inline(always)]
+    pub fn generated(&self, input: ShapeID<V, E, F>) -> &[ShapeID<V, E, F>] {
+        self.generated.get(&input).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    /// Returns the elements which replace `input`.
+    #[inline(always)]
+    pub fn modified(&self, input: ShapeID<V, E, F>) -> &[ShapeID<V, E, F>] {
+        self.modified.get(&input).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    /// Returns `true` if `input` has no image in the result.
+    #[inline(always)]
+    pub fn is_deleted(&self, input: ShapeID<V, E, F>) -> bool { self.deleted.contains(&input) }
+
+    /// Returns the elements corresponding to `input` in the result:
+    /// the empty vector if deleted, the modified elements if modified, and `input` itself otherwise.
+    pub fn images(&self, input: ShapeID<V, E, F>) -> Vec<ShapeID<V, E, F>> {
+        match (self.is_deleted(input), self.modified(input)) {
+            (true, _) => Vec::new(),
+            (false, []) => vec![input],
+            (false, modified) => modified.to_vec(),
+        }
+    }
+
+    /// Returns the iterator over the generated elements, as the pairs of the input and the outputs.
+    #[inline(always)]
+    pub fn generated_iter(&self) -> impl Iterator<Item = (ShapeID<V, E, F>, &[ShapeID<V, E, F>])> {
+        self.generated.iter().map(|(x, vec)| (*x, vec.as_slice()))
+    }
+
+    /// Returns the iterator over the modified elements, as the pairs of the input and the outputs.
+    #[inline(always)]
+    pub fn modified_iter(&self) -> impl Iterator<Item = (ShapeID<V, E, F>, &[ShapeID<V, E, F>])> {
+        self.modified.iter().map(|(x, vec)| (*x, vec.as_slice()))
+    }
+
+    /// Returns the iterator over the deleted elements.
+    #[inline(always)]
+    pub fn deleted_iter(&self) -> impl Iterator<Item = ShapeID<V, E, F>> + '_ {
+        self.deleted.iter().copied()
+    }
+
+    /// Returns the history of the operation `self` followed by the operation `next`.
+    ///
+    /// The elements newly created by `self` and recorded in `next` are regarded as intermediate,
+    /// and do not appear as the inputs of the composed history. The generation is transitive:
+    /// the elements generated from the elements generated by `self` are generated from the input.
+    /// # Examples
+    /// ```
+    /// use truck_topology::history::*;
+    /// let mut first = CompressedHistory::new();
+    /// first.add_modified(ShapeID::Face(0), ShapeID::Face(0));
+    /// first.add_modified(ShapeID::Face(0), ShapeID::Face(1));
+    /// first.add_generated(ShapeID::Face(0), ShapeID::Edge(2));
+    ///
+    /// let mut second = CompressedHistory::new();
+    /// second.add_deleted(ShapeID::Face(1));
+    /// second.add_modified(ShapeID::Edge(2), ShapeID::Edge(3));
+    /// second.add_modified(ShapeID::Edge(2), ShapeID::Edge(4));
+    ///
+    /// let history = first.then(&second);
+    /// assert_eq!(history.modified(ShapeID::Face(0)), &[ShapeID::Face(0)]);
+    /// assert_eq!(
+    ///     history.generated(ShapeID::Face(0)),
+    ///     &[ShapeID::Edge(3), ShapeID::Edge(4)],
+    /// );
+    /// // the intermediate face is not an input
+    /// assert!(!history.is_deleted(ShapeID::Face(1)));
+    /// ```
+    pub fn then(&self, next: &Self) -> Self {
+        let intermediates: HashSet<_> = self
+            .generated
+            .values()
+            .chain(self.modified.values())
+            .flatten()
+            .copied()
+            .collect();
+        let inputs: Vec<_> = self
+            .generated
+            .keys()
+            .chain(self.modified.keys())
+            .chain(&self.deleted)
+            .copied()
+            .chain(
+                next.generated
+                    .keys()
+                    .chain(next.modified.keys())
+                    .chain(&next.deleted)
+                    .copied()
+                    .filter(|x| !intermediates.contains(x)),
+            )
+            .collect();
+        let mut res = Self::new();
+        inputs.into_iter().for_each(|input| {
+            let images = self.images(input);
+            let images1: Vec<_> = images.iter().flat_map(|x| next.images(*x)).collect();
+            let recorded = !self.modified(input).is_empty() || !next.modified(input).is_empty();
+            match images1.as_slice() {
+                [] => res.add_deleted(input),
+                [x] if *x == input && !recorded => {}
+                _ => images1
+                    .into_iter()
+                    .for_each(|output| res.add_modified(input, output)),
+            }
+            let generated = self.generated(input);
+            let generated0 = generated.iter().flat_map(|x| next.images(*x));
+            let generated1 = generated
+                .iter()
+                .flat_map(|x| next.generated(*x).iter().copied());
+            let generated2 = images
+                .iter()
+                .flat_map(|x| next.generated(*x).iter().copied());
+            generated0
+                .chain(generated1)
+                .chain(generated2)
+                .for_each(|output| res.add_generated(input, output));
+        });
+        res
+    }
+}
+
+/// The vertices, edges and faces of a shape, without duplication.
+#[derive(Clone, Debug)]
+pub struct Elements<P, C, S> {
+    /// vertices
+    pub vertices: Vec<Vertex<P>>,
+    /// edges
+    pub edges: Vec<Edge<P, C>>,
+    /// faces
+    pub faces: Vec<Face<P, C, S>>,
+}
+
+impl<P, C, S> Default for Elements<P, C, S> {
+    fn default() -> Self {
+        Self {
+            vertices: Vec::new(),
+            edges: Vec::new(),
+            faces: Vec::new(),
+        }
+    }
+}
+
+impl<P, C, S> Elements<P, C, S> {
+    /// Returns the ids of all elements.
+    pub fn ids(&self) -> HashSet<ShapeID<VertexID<P>, EdgeID<C>, FaceID<S>>> {
+        let vertices = self.vertices.iter().map(|v| ShapeID::Vertex(v.id()));
+        let edges = self.edges.iter().map(|e| ShapeID::Edge(e.id()));
+        let faces = self.faces.iter().map(|f| ShapeID::Face(f.id()));
+        vertices.chain(edges).chain(faces).collect()
+    }
+
+    fn extend(&mut self, other: Self) {
+        self.vertices.extend(other.vertices);
+        self.edges.extend(other.edges);
+        self.faces.extend(other.faces);
+    }
+
+    fn dedup(mut self) -> Self {
+        let mut vertices = HashSet::default();
+        self.vertices.retain(|v| vertices.insert(v.id()));
+        let mut edges = HashSet::default();
+        self.edges.retain(|e| edges.insert(e.id()));
+        let mut faces = HashSet::default();
+        self.faces.retain(|f| faces.insert(f.id()));
+        self
+    }
+
+    fn from_faces<'a>(faces: impl IntoIterator<Item = &'a Face<P, C, S>>) -> Self
+    where
+        P: 'a,
+        C: 'a,
+        S: 'a, {
+        faces
+            .into_iter()
+            .fold(Self::default(), |mut res, face| {
+                res.extend(face.elements());
+                res
+            })
+            .dedup()
+    }
+}
+
+/// Shapes whose elements are recorded in [`ShapeHistory`].
+pub trait TopologicalElements<P, C, S> {
+    /// Returns the vertices, edges and faces of the shape, without duplication.
+    fn elements(&self) -> Elements<P, C, S>;
+}
+
+impl<P, C, S> TopologicalElements<P, C, S> for Vertex<P> {
+    fn elements(&self) -> Elements<P, C, S> {
+        Elements {
+            vertices: vec![self.clone()],
+            ..Default::default()
+        }
+    }
+}
+
+impl<P, C, S> TopologicalElements<P, C, S> for Edge<P, C> {
+    fn elements(&self) -> Elements<P, C, S> {
+        Elements {
+            vertices: vec![self.front().clone(), self.back().clone()],
+            edges: vec![self.clone()],
+            ..Default::default()
+        }
+        .dedup()
+    }
+}
+
+impl<P, C, S> TopologicalElements<P, C, S> for Wire<P, C> {
+    fn elements(&self) -> Elements<P, C, S> {
+        Elements {
+            vertices: self.vertex_iter().collect(),
+            edges: self.edge_iter().cloned().collect(),
+            ..Default::default()
+        }
+        .dedup()
+    }
+}
+
+impl<P, C, S> TopologicalElements<P, C, S> for Face<P, C, S> {
+    fn elements(&self) -> Elements<P, C, S> {
+        Elements {
+            vertices: self.vertex_iter().collect(),
+            edges: self.edge_iter().collect(),
+            faces: vec![self.clone()],
+        }
+        .dedup()
+    }
+}
+
+impl<P, C, S> TopologicalElements<P, C, S> for Shell<P, C, S> {
+    fn elements(&self) -> Elements<P, C, S> { Elements::from_faces(self.face_iter()) }
+}
+
+impl<P, C, S> TopologicalElements<P, C, S> for Solid<P, C, S> {
+    fn elements(&self) -> Elements<P, C, S> { Elements::from_faces(self.face_iter()) }
+}
+
+impl<P, C, S, T: TopologicalElements<P, C, S>> TopologicalElements<P, C, S> for Vec<T> {
+    fn elements(&self) -> Elements<P, C, S> {
+        self.iter()
+            .fold(Elements::default(), |mut res, shape| {
+                res.extend(shape.elements());
+                res
+            })
+            .dedup()
+    }
+}
+
+impl<P, C, S, T, E> TopologicalElements<P, C, S> for std::result::Result<T, E>
+where T: TopologicalElements<P, C, S>
+{
+    fn elements(&self) -> Elements<P, C, S> {
+        match self {
+            Ok(shape) => shape.elements(),
+            Err(_) => Elements::default(),
+        }
+    }
+}
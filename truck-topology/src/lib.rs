@@ -57,11 +57,14 @@
 //!
 //! * [`Wire`](./struct.Wire.html)
 //! * [`Shell`](./struct.Shell.html)
+//! * [`Compound`](./struct.Compound.html)
 //!
 //! The entities of `Wire` and `Shell` are `std::collections::VecDeque<Edge>` and `std::vec::Vec<Face>`,
 //! respectively, and many methods inherited by `Deref` and `DerefMut`.
 //! These containers are used for creating higher-dimentional topological elements and checked the
 //! regularity (e.g. connectivity, closedness, and so on) before creating these elements.
+//! `Compound` is a collection of solids, open shells, wires and vertices, which may share their
+//! boundaries, e.g. a face shared by two solids in a cellular model.
 //! ## Features
 //! * `nightly` – Use features available only in a `nightly` toolchain.
 //! * `rclite` – Use of `rclite::Arc` instead of `std::syn::Arc`. The latter
@@ -80,11 +83,16 @@
     unused_qualifications
 )]
 
-use parking_lot::Mutex;
+use parking_lot::RwLock;
 use std::collections::VecDeque;
 use std::fmt::{Debug, Formatter};
 use std::hash::{Hash, Hasher};
-use truck_base::{id::ID, tolerance::*};
+use truck_base::{
+    bounding_box::BoundingBox,
+    cgmath64::{Point2, Point3},
+    id::ID,
+    tolerance::*,
+};
 use truck_geotrait::*;
 
 #[cfg(feature = "rclite")]
@@ -93,6 +101,7 @@ use rclite::Arc;
 use std::sync::Arc;
 
 const SEARCH_PARAMETER_TRIALS: usize = 100;
+const BOUNDING_BOX_DIVISION: usize = 50;
 
 /// Vertex, the minimum topological unit.
 ///
@@ -106,7 +115,7 @@ const SEARCH_PARAMETER_TRIALS: usize = 100;
 /// ```
 #[derive(Debug)]
 pub struct Vertex<P> {
-    point: Arc<Mutex<P>>,
+    point: Arc<RwLock<P>>,
 }
 
 /// Edge, which consists two vertices.
@@ -125,7 +134,7 @@ pub struct Vertex<P> {
 pub struct Edge<P, C> {
     vertices: (Vertex<P>, Vertex<P>),
     orientation: bool,
-    curve: Arc<Mutex<C>>,
+    curve: Arc<RwLock<C>>,
 }
 
 /// Wire, a path or cycle which consists some edges.
@@ -156,7 +165,7 @@ pub struct Wire<P, C> {
 pub struct Face<P, C, S> {
     boundaries: Vec<Wire<P, C>>,
     orientation: bool,
-    surface: Arc<Mutex<S>>,
+    surface: Arc<RwLock<S>>,
 }
 
 /// Shell, a connected compounded faces.
@@ -174,6 +183,18 @@ pub struct Solid<P, C, S> {
     boundaries: Vec<Shell<P, C, S>>,
 }
 
+/// Compound, a collection of solids, shells, wires and vertices.
+///
+/// The elements of a compound may share their boundaries, e.g. two solids sharing a face
+/// in a cellular model. The shells and the wires need not be closed.
+#[derive(Clone, Debug)]
+pub struct Compound<P, C, S> {
+    solids: Vec<Solid<P, C, S>>,
+    shells: Vec<Shell<P, C, S>>,
+    wires: Vec<Wire<P, C>>,
+    vertices: Vec<Vertex<P>>,
+}
+
 /// `Result` with crate's errors.
 pub type Result<T> = std::result::Result<T, crate::errors::Error>;
 
@@ -217,7 +238,7 @@ impl<T> RemoveTry<T> for Result<T> {
 /// assert_ne!(entity, v.point());
 /// assert_eq!(v_id, v.id());
 /// ```
-pub type VertexID<P> = ID<Mutex<P>>;
+pub type VertexID<P> = ID<RwLock<P>>;
 
 /// The id that does not depend on the direction of the edge.
 /// # Examples
@@ -229,7 +250,7 @@ pub type VertexID<P> = ID<Mutex<P>>;
 /// assert_ne!(edge0, edge1);
 /// assert_eq!(edge0.id(), edge1.id());
 /// ```
-pub type EdgeID<C> = ID<Mutex<C>>;
+pub type EdgeID<C> = ID<RwLock<C>>;
 
 /// The id that does not depend on the direction of the face.
 /// # Examples
@@ -249,7 +270,7 @@ pub type EdgeID<C> = ID<Mutex<C>>;
 /// assert_eq!(face0.id(), face1.id());
 /// assert_ne!(face0.id(), face2.id());
 /// ```
-pub type FaceID<S> = ID<Mutex<S>>;
+pub type FaceID<S> = ID<RwLock<S>>;
 
 /// configuration for vertex display format.
 #[derive(Clone, Copy, Debug)]
@@ -383,15 +404,26 @@ pub enum SolidDisplayFormat {
     },
 }
 
+/// assemblies of parts instanced with placements.
+pub mod assembly;
+pub mod attributes;
+pub mod bvh;
+mod compound;
 pub mod compress;
 mod edge;
 /// classifies the errors that can occur in this crate.
 pub mod errors;
+pub mod euler;
 /// Defines the boundary iterator.
 pub mod face;
+pub mod fingerprint;
+pub mod history;
+mod same_domain;
 /// classifies shell conditions and defines the face iterators.
 pub mod shell;
+pub mod snapshot;
 mod solid;
+pub mod validity;
 mod vertex;
 /// define the edge iterators and the vertex iterator.
 pub mod wire;
@@ -409,11 +441,11 @@ pub mod format {
     }
 
     #[derive(Clone)]
-    pub(super) struct MutexFmt<'a, T>(pub &'a Mutex<T>);
+    pub(super) struct RwLockFmt<'a, T>(pub &'a RwLock<T>);
 
-    impl<'a, T: Debug> Debug for MutexFmt<'a, T> {
+    impl<'a, T: Debug> Debug for RwLockFmt<'a, T> {
         fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-            f.write_fmt(format_args!("{:?}", self.0.lock()))
+            f.write_fmt(format_args!("{:?}", self.0.read()))
         }
     }
 }
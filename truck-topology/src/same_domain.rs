@@ -0,0 +1,250 @@
+use crate::face::sample_points;
+use crate::*;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+use truck_base::cgmath64::{InnerSpace, MetricSpace, Vector3};
+
+/// The number of the division of each axis of the parameter range for sampling the surface.
+const SURFACE_DIVISION: usize = 8;
+
+impl<C, S> Shell<Point3, C, S>
+where
+    C: ParametricCurve3D
+        + BoundedCurve
+        + Concat<C, Point = Point3, Output = C>
+        + Invertible
+        + ParameterTransform,
+    S: ParametricSurface3D + SearchNearestParameter<D2, Point = Point3>,
+{
+    /// Unifies the adjacent faces lying on the same surface, and concatenates the edges
+    /// connected smoothly.
+    ///
+    /// # Details
+    /// - Two faces sharing edges are merged if the surface of one face passes through the
+    ///   points of the other surface in the parameter range of the face within the distance `tol`,
+    ///   and the oriented normals have the same direction. The merged face keeps the id and the
+    ///   surface of the first face, and the shared edges are removed.
+    /// - A vertex on only two edges is removed by [`Shell::remove_vertex_by_concat_edges`] if the
+    ///   tangent vectors of the edges at the vertex have the same direction.
+    ///
+    /// # Remarks
+    /// The faces are not merged if the union of the faces is not bounded by simple and disjoint
+    /// wires, or the surface of the first face does not cover the second face, e.g. the faces are
+    /// on the different patches of a B-spline surface.
+    pub fn unify_same_domain(&mut self, tol: f64) {
+        while let Some((i, j, boundaries)) = self.find_mergeable_faces(tol) {
+            self[i].boundaries = boundaries;
+            self.remove(j);
+        }
+        let vertices = smooth_vertices(self);
+        vertices.into_iter().for_each(|vertex_id| {
+            self.remove_vertex_by_concat_edges(vertex_id);
+        });
+    }
+
+    /// Returns the indices of two mergeable faces and the absolute boundaries of the merged face.
+    #[allow(clippy::type_complexity)]
+    fn find_mergeable_faces(&self, tol: f64) -> Option<(usize, usize, Vec<Wire<Point3, C>>)> {
+        let mut edge_faces = HashMap::<EdgeID<C>, Vec<usize>>::default();
+        self.face_iter().enumerate().for_each(|(i, face)| {
+            face.edge_iter()
+                .for_each(|edge| edge_faces.entry(edge.id()).or_default().push(i))
+        });
+        let mut checked = HashSet::default();
+        self.face_iter().enumerate().find_map(|(i, face)| {
+            face.edge_iter().find_map(|edge| {
+                let faces = &edge_faces[&edge.id()];
+                let j = *faces.iter().find(|j| **j != i)?;
+                let (i, j) = (usize::min(i, j), usize::max(i, j));
+                if !checked.insert((i, j)) || !same_domain(&self[i], &self[j], tol) {
+                    return None;
+                }
+                let boundaries = merged_boundaries(&self[i], &self[j])?;
+                Some((i, j, boundaries))
+            })
+        })
+    }
+}
+
+impl<C, S> Solid<Point3, C, S>
+where
+    C: ParametricCurve3D
+        + BoundedCurve
+        + Concat<C, Point = Point3, Output = C>
+        + Invertible
+        + ParameterTransform,
+    S: ParametricSurface3D + SearchNearestParameter<D2, Point = Point3>,
+{
+    /// Unifies the adjacent faces lying on the same surface, and concatenates the edges
+    /// connected smoothly, in each boundary shell.
+    ///
+    /// See [`Shell::unify_same_domain`] for details.
+    pub fn unify_same_domain(&mut self, tol: f64) {
+        self.boundaries
+            .iter_mut()
+            .for_each(|shell| shell.unify_same_domain(tol));
+    }
+}
+
+/// Returns whether the surface of `face0` includes the surface of `face1` in the parameter range
+/// of `face1` with the same orientation.
+fn same_domain<C, S>(face0: &Face<Point3, C, S>, face1: &Face<Point3, C, S>, tol: f64) -> bool
+where
+    C: ParametricCurve3D + BoundedCurve,
+    S: ParametricSurface3D + SearchNearestParameter<D2, Point = Point3>, {
+    if face0.id() == face1.id() {
+        return false;
+    }
+    let (surface0, surface1) = (face0.surface.read(), face1.surface.read());
+    let mut hint: Option<(f64, f64)> = None;
+    let boundary_parameters = face1
+        .boundaries
+        .iter()
+        .flat_map(Wire::edge_iter)
+        .flat_map(|edge| sample_points(edge, BOUNDING_BOX_DIVISION))
+        .map(|point| {
+            let uv = surface1.search_nearest_parameter(point, hint, SEARCH_PARAMETER_TRIALS)?;
+            hint = Some(uv);
+            Some(Point2::new(uv.0, uv.1))
+        })
+        .collect::<Option<Vec<_>>>();
+    let Some(boundary_parameters) = boundary_parameters else {
+        return false;
+    };
+    let range: BoundingBox<Point2> = boundary_parameters.iter().collect();
+    if range.is_empty() {
+        return false;
+    }
+    let (min, max) = (range.min(), range.max());
+    let n = SURFACE_DIVISION as f64;
+    let interior_parameters = (0..SURFACE_DIVISION * SURFACE_DIVISION).map(|k| {
+        let s = (k % SURFACE_DIVISION) as f64 + 0.5;
+        let t = (k / SURFACE_DIVISION) as f64 + 0.5;
+        Point2::new(
+            min.x + (max.x - min.x) * s / n,
+            min.y + (max.y - min.y) * t / n,
+        )
+    });
+    let oriented_normal = |surface: &S, (u, v): (f64, f64), orientation: bool| match orientation {
+        true => surface.normal(u, v),
+        false => -surface.normal(u, v),
+    };
+    let mut hint: Option<(f64, f64)> = None;
+    boundary_parameters
+        .iter()
+        .copied()
+        .chain(interior_parameters)
+        .all(|uv| {
+            let point = surface1.subs(uv.x, uv.y);
+            let Some(uv0) = surface0.search_nearest_parameter(point, hint, SEARCH_PARAMETER_TRIALS)
+            else {
+                return false;
+            };
+            hint = Some(uv0);
+            let normal0: Vector3 = oriented_normal(&surface0, uv0, face0.orientation);
+            let normal1: Vector3 = oriented_normal(&surface1, (uv.x, uv.y), face1.orientation);
+            let dot = normal0.dot(normal1);
+            surface0.subs(uv0.0, uv0.1).distance(point) < tol && (dot.is_nan() || dot > 0.0)
+        })
+}
+
+/// Returns the absolute boundaries of the union of two faces with respect to `face0`.
+fn merged_boundaries<P, C, S>(
+    face0: &Face<P, C, S>,
+    face1: &Face<P, C, S>,
+) -> Option<Vec<Wire<P, C>>> {
+    let (edges0, edges1): (Vec<_>, Vec<_>) =
+        (face0.edge_iter().collect(), face1.edge_iter().collect());
+    let count = |edges: &[Edge<P, C>], edge: &Edge<P, C>| {
+        edges.iter().filter(|e| e.id() == edge.id()).count()
+    };
+    let shared: HashSet<EdgeID<C>> = edges0
+        .iter()
+        .filter(|edge| count(&edges1, edge) > 0)
+        .map(Edge::id)
+        .collect();
+    // the shared edges must be once in both faces in the opposite directions
+    let consistent = edges0
+        .iter()
+        .filter(|e| shared.contains(&e.id()))
+        .all(|edge| {
+            count(&edges0, edge) == 1
+                && count(&edges1, edge) == 1
+                && edges1
+                    .iter()
+                    .any(|e| e.id() == edge.id() && e.orientation() != edge.orientation())
+        });
+    if !consistent {
+        return None;
+    }
+    let mut edges: Vec<Option<Edge<P, C>>> = edges0
+        .into_iter()
+        .chain(edges1)
+        .filter(|edge| !shared.contains(&edge.id()))
+        .map(Some)
+        .collect();
+    let mut wires = Vec::new();
+    for i in 0..edges.len() {
+        let Some(edge) = edges[i].take() else {
+            continue;
+        };
+        let mut wire = Wire::from(vec![edge]);
+        while wire.front_vertex() != wire.back_vertex() {
+            let back = wire.back_vertex()?.id();
+            let next = edges
+                .iter_mut()
+                .find(|edge| matches!(edge, Some(edge) if edge.front().id() == back))?;
+            wire.push_back(next.take()?);
+        }
+        wires.push(wire);
+    }
+    let valid = !wires.is_empty()
+        && wires
+            .iter()
+            .all(|wire| wire.is_closed() && wire.is_simple())
+        && Wire::disjoint_wires(&wires);
+    match (valid, face0.orientation) {
+        (false, _) => None,
+        (true, true) => Some(wires),
+        (true, false) => Some(wires.iter().map(Wire::inverse).collect()),
+    }
+}
+
+/// Returns the vertices on only two edges connected smoothly.
+fn smooth_vertices<C, S>(shell: &Shell<Point3, C, S>) -> Vec<VertexID<Point3>>
+where C: ParametricCurve3D + BoundedCurve {
+    let mut vertex_edges = HashMap::<VertexID<Point3>, HashSet<EdgeID<C>>>::default();
+    shell.edge_iter().for_each(|edge| {
+        [edge.front().id(), edge.back().id()]
+            .into_iter()
+            .for_each(|v| {
+                vertex_edges.entry(v).or_default().insert(edge.id());
+            })
+    });
+    let tangents = |edge: &Edge<Point3, C>| {
+        let curve = edge.curve.read();
+        let (t0, t1) = curve.range_tuple();
+        match edge.orientation() {
+            true => (curve.der(t0), curve.der(t1)),
+            false => (-curve.der(t1), -curve.der(t0)),
+        }
+    };
+    let mut vertices = Vec::new();
+    let mut checked = HashSet::default();
+    shell
+        .face_iter()
+        .flat_map(|face| &face.boundaries)
+        .for_each(|wire| {
+            let pairs = wire.edge_iter().zip(wire.edge_iter().cycle().skip(1));
+            pairs.for_each(|(edge0, edge1)| {
+                let vertex = edge0.back().id();
+                if vertex_edges[&vertex].len() != 2 || !checked.insert(vertex) {
+                    return;
+                }
+                let (der0, der1) = (tangents(edge0).1, tangents(edge1).0);
+                if der0.normalize().near(&der1.normalize()) {
+                    vertices.push(vertex);
+                }
+            })
+        });
+    vertices
+}
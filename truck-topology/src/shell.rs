@@ -2,6 +2,7 @@ use crate::*;
 use rayon::prelude::*;
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 use std::vec::Vec;
+use truck_base::cgmath64::{InnerSpace, MetricSpace, Point3, Vector3};
 use truck_base::entry_map::FxEntryMap as EntryMap;
 
 type FaceAdjacencyMap<'a, P, C, S> = HashMap<&'a Face<P, C, S>, Vec<&'a Face<P, C, S>>>;
@@ -38,9 +39,9 @@ impl<P, C, S> Shell<P, C, S> {
     #[inline(always)]
     pub fn face_par_iter(&self) -> FaceParallelIter<'_, P, C, S>
     where
-        P: Send,
-        C: Send,
-        S: Send, {
+        P: Send + Sync,
+        C: Send + Sync,
+        S: Send + Sync, {
         self.par_iter()
     }
 
@@ -48,9 +49,9 @@ impl<P, C, S> Shell<P, C, S> {
     #[inline(always)]
     pub fn face_par_iter_mut(&mut self) -> FaceParallelIterMut<'_, P, C, S>
     where
-        P: Send,
-        C: Send,
-        S: Send, {
+        P: Send + Sync,
+        C: Send + Sync,
+        S: Send + Sync, {
         self.par_iter_mut()
     }
 
@@ -58,9 +59,9 @@ impl<P, C, S> Shell<P, C, S> {
     #[inline(always)]
     pub fn face_into_par_iter(self) -> FaceParallelIntoIter<P, C, S>
     where
-        P: Send,
-        C: Send,
-        S: Send, {
+        P: Send + Sync,
+        C: Send + Sync,
+        S: Send + Sync, {
         self.into_par_iter()
     }
 
@@ -74,9 +75,9 @@ impl<P, C, S> Shell<P, C, S> {
     #[inline(always)]
     pub fn edge_par_iter(&self) -> impl ParallelIterator<Item = Edge<P, C>> + '_
     where
-        P: Send,
-        C: Send,
-        S: Send, {
+        P: Send + Sync,
+        C: Send + Sync,
+        S: Send + Sync, {
         self.face_par_iter().flat_map(Face::boundaries).flatten()
     }
 
@@ -90,9 +91,9 @@ impl<P, C, S> Shell<P, C, S> {
     #[inline(always)]
     pub fn vertex_par_iter(&self) -> impl ParallelIterator<Item = Vertex<P>> + '_
     where
-        P: Send,
-        C: Send,
-        S: Send, {
+        P: Send + Sync,
+        C: Send + Sync,
+        S: Send + Sync, {
         self.edge_par_iter().map(|edge| edge.front().clone())
     }
 
@@ -479,7 +480,7 @@ impl<P, C, S> Shell<P, C, S> {
                     .iter()
                     .map(|wire| wire.sub_try_mapped(&mut edge_map))
                     .collect::<Option<Vec<_>>>()?;
-                let surface = surface_mapping(&*face.surface.lock())?;
+                let surface = surface_mapping(&*face.surface.read())?;
                 let mut new_face = Face::debug_new(wires, surface);
                 if !face.orientation() {
                     new_face.invert();
@@ -573,7 +574,7 @@ impl<P, C, S> Shell<P, C, S> {
                     .iter()
                     .map(|wire| wire.sub_mapped(&mut edge_map))
                     .collect();
-                let surface = surface_mapping(&*face.surface.lock());
+                let surface = surface_mapping(&*face.surface.read());
                 let mut new_face = Face::debug_new(wires, surface);
                 if !face.orientation() {
                     new_face.invert();
@@ -594,6 +595,54 @@ impl<P, C, S> Shell<P, C, S> {
         self.iter().all(|face| face.is_geometric_consistent())
     }
 
+    /// Returns the continuity between the adjacent faces along each shared edge.
+    ///
+    /// Each shared edge is sampled at `samples` parameters, and the points are projected
+    /// to the oriented surfaces of both faces. The report contains:
+    /// - the G0 gap: the distance between the projected points,
+    /// - the G1 deviation: the angle between the normals, and
+    /// - the G2 deviation: the difference of the normal curvatures in the direction orthogonal to the edge.
+    ///
+    /// If a sampled point cannot be projected to a surface, all deviations at the sample are
+    /// `f64::INFINITY`.
+    pub fn continuity_report(&self, samples: usize) -> Vec<EdgeContinuity<P, C>>
+    where
+        C: ParametricCurve3D + BoundedCurve,
+        S: ParametricSurface3D + SearchNearestParameter<D2, Point = Point3> + Invertible, {
+        let face_index: HashMap<_, _> = self
+            .face_iter()
+            .enumerate()
+            .map(|(i, face)| (face.id(), i))
+            .collect();
+        let adjacency = self.face_adjacency();
+        let mut report = Vec::new();
+        self.face_iter().enumerate().for_each(|(i, face0)| {
+            let mut adjacent_indices: Vec<usize> = adjacency
+                .get(face0)
+                .into_iter()
+                .flatten()
+                .map(|face| face_index[&face.id()])
+                .filter(|j| i < *j)
+                .collect();
+            adjacent_indices.sort_unstable();
+            adjacent_indices.dedup();
+            let surface0 = face0.oriented_surface();
+            adjacent_indices.into_iter().for_each(|j| {
+                let face1 = &self[j];
+                let edge_ids: HashSet<_> = face1.edge_iter().map(|edge| edge.id()).collect();
+                let surface1 = face1.oriented_surface();
+                face0
+                    .edge_iter()
+                    .filter(|edge| edge_ids.contains(&edge.id()))
+                    .for_each(|edge| {
+                        let surfaces = (&surface0, &surface1);
+                        report.push(edge_continuity(&edge, (i, j), surfaces, samples));
+                    });
+            });
+        });
+        report
+    }
+
     /// Cuts one edge into two edges at vertex.
     ///
     /// # Returns
@@ -679,13 +728,17 @@ impl<P, C, S> Shell<P, C, S> {
         } else {
             let (wire0, idx0) = vec.pop().unwrap();
             let (wire1, idx1) = vec.pop().unwrap();
-            if !wire0[idx0].is_same(&wire1[(idx1 + 1) % wire1.len()])
-                || !wire0[(idx0 + 1) % wire0.len()].is_same(&wire1[idx1])
-            {
+            let (next0, next1) = ((idx0 + 1) % wire0.len(), (idx1 + 1) % wire1.len());
+            let edge = wire0[idx0].concat(&wire0[next0]).ok()?;
+            // The boundaries of the faces with the different orientations pass the edges
+            // in the same direction.
+            if wire0[idx0] == wire1[idx1] && wire0[next0] == wire1[next1] {
+                wire1.swap_subwire_into_edges(idx1, edge.clone());
+            } else if wire0[idx0].is_same(&wire1[next1]) && wire0[next0].is_same(&wire1[idx1]) {
+                wire1.swap_subwire_into_edges(idx1, edge.inverse());
+            } else {
                 return None;
             }
-            let edge = wire0[idx0].concat(&wire0[(idx0 + 1) % wire0.len()]).ok()?;
-            wire1.swap_subwire_into_edges(idx1, edge.inverse());
             wire0.swap_subwire_into_edges(idx0, edge.clone());
             Some(edge)
         }
@@ -738,6 +791,19 @@ impl<P, C, S> Shell<P, C, S> {
     }
 }
 
+impl<C, S> Shell<Point3, C, S>
+where
+    C: ParametricCurve3D + BoundedCurve + BoundingBoxed<Point = Point3>,
+    S: ParametricSurface3D + SearchParameter<D2, Point = Point3>,
+{
+    /// Returns the tight bounding box of the faces.
+    /// cf. [`Face::bounding_box`]
+    pub fn bounding_box(&self) -> BoundingBox<Point3> {
+        self.face_iter()
+            .fold(BoundingBox::new(), |bdb, face| bdb + face.bounding_box())
+    }
+}
+
 impl<P, C, S> Clone for Shell<P, C, S> {
     #[inline(always)]
     fn clone(&self) -> Shell<P, C, S> {
@@ -956,6 +1022,101 @@ impl std::ops::BitAnd for ShellCondition {
     }
 }
 
+/// The maximum and the mean of deviations sampled along an edge.
+#[derive(PartialEq, Debug, Clone, Copy, Default)]
+pub struct DeviationStatistics {
+    /// the maximum of the deviations
+    pub max: f64,
+    /// the mean of the deviations
+    pub mean: f64,
+}
+
+impl FromIterator<f64> for DeviationStatistics {
+    fn from_iter<I: IntoIterator<Item = f64>>(iter: I) -> Self {
+        let (max, sum, len) = iter.into_iter().fold((0.0, 0.0, 0), |(max, sum, len), x| {
+            (f64::max(max, x), sum + x, len + 1)
+        });
+        match len {
+            0 => Self::default(),
+            _ => Self {
+                max,
+                mean: sum / len as f64,
+            },
+        }
+    }
+}
+
+/// The continuity between two faces along their shared edge, the output of [`Shell::continuity_report`].
+#[derive(Debug, Clone)]
+pub struct EdgeContinuity<P, C> {
+    /// the shared edge
+    pub edge: Edge<P, C>,
+    /// the indices of the two faces in the shell
+    pub faces: (usize, usize),
+    /// the distance between the two surfaces
+    pub gap: DeviationStatistics,
+    /// the angle between the normals of the two surfaces in radians
+    pub angle: DeviationStatistics,
+    /// the difference of the normal curvatures of the two surfaces in the direction orthogonal to the edge
+    pub curvature: DeviationStatistics,
+}
+
+fn edge_continuity<P, C, S>(
+    edge: &Edge<P, C>,
+    faces: (usize, usize),
+    (surface0, surface1): (&S, &S),
+    samples: usize,
+) -> EdgeContinuity<P, C>
+where
+    C: ParametricCurve3D + BoundedCurve,
+    S: ParametricSurface3D + SearchNearestParameter<D2, Point = Point3>,
+{
+    let curve = edge.curve();
+    let (t0, t1) = curve.range_tuple();
+    let (mut hint0, mut hint1): (Option<(f64, f64)>, Option<(f64, f64)>) = (None, None);
+    let deviations: Vec<(f64, f64, f64)> = (0..samples)
+        .map(|k| {
+            let t = t0 + (t1 - t0) * (k as f64 + 0.5) / samples as f64;
+            let (pt, tangent) = (curve.subs(t), curve.der(t));
+            let uv0 = surface0.search_nearest_parameter(pt, hint0, SEARCH_PARAMETER_TRIALS);
+            let uv1 = surface1.search_nearest_parameter(pt, hint1, SEARCH_PARAMETER_TRIALS);
+            let (Some(uv0), Some(uv1)) = (uv0, uv1) else {
+                return (f64::INFINITY, f64::INFINITY, f64::INFINITY);
+            };
+            (hint0, hint1) = (Some(uv0), Some(uv1));
+            let gap = surface0
+                .subs(uv0.0, uv0.1)
+                .distance(surface1.subs(uv1.0, uv1.1));
+            let normal0 = surface0.normal(uv0.0, uv0.1);
+            let normal1 = surface1.normal(uv1.0, uv1.1);
+            let angle = f64::acos(f64::clamp(normal0.dot(normal1), -1.0, 1.0));
+            let curvature = match tangent.so_small() {
+                true => 0.0,
+                false => {
+                    let kappa0 = normal_curvature(surface0, uv0, normal0.cross(tangent));
+                    let kappa1 = normal_curvature(surface1, uv1, normal1.cross(tangent));
+                    f64::abs(kappa0 - kappa1)
+                }
+            };
+            (gap, angle, curvature)
+        })
+        .collect();
+    EdgeContinuity {
+        edge: edge.clone(),
+        faces,
+        gap: deviations.iter().map(|x| x.0).collect(),
+        angle: deviations.iter().map(|x| x.1).collect(),
+        curvature: deviations.iter().map(|x| x.2).collect(),
+    }
+}
+
+/// Returns the normal curvature in the direction `dir` by Euler's theorem.
+fn normal_curvature<S: ParametricSurface3D>(surface: &S, (u, v): (f64, f64), dir: Vector3) -> f64 {
+    let ((k0, d0), (k1, _)) = surface.principal_directions(u, v);
+    let cos2 = f64::powi(dir.normalize().dot(d0), 2);
+    k0 * cos2 + k1 * (1.0 - cos2)
+}
+
 #[derive(Debug, Clone)]
 struct Boundaries<C> {
     checked: HashSet<EdgeID<C>>,
@@ -1075,34 +1236,40 @@ impl<'a, P: Debug, C: Debug, S: Debug> Debug
     }
 }
 
-impl<P: Send, C: Send, S: Send> FromParallelIterator<Face<P, C, S>> for Shell<P, C, S> {
+impl<P: Send + Sync, C: Send + Sync, S: Send + Sync> FromParallelIterator<Face<P, C, S>>
+    for Shell<P, C, S>
+{
     fn from_par_iter<I>(par_iter: I) -> Self
     where I: IntoParallelIterator<Item = Face<P, C, S>> {
         Self::from(Vec::from_par_iter(par_iter))
     }
 }
 
-impl<P: Send, C: Send, S: Send> IntoParallelIterator for Shell<P, C, S> {
+impl<P: Send + Sync, C: Send + Sync, S: Send + Sync> IntoParallelIterator for Shell<P, C, S> {
     type Item = Face<P, C, S>;
     type Iter = FaceParallelIntoIter<P, C, S>;
     fn into_par_iter(self) -> Self::Iter { self.face_list.into_par_iter() }
 }
 
-impl<'a, P: Send + 'a, C: Send + 'a, S: Send + 'a> IntoParallelRefIterator<'a> for Shell<P, C, S> {
+impl<'a, P: Send + Sync + 'a, C: Send + Sync + 'a, S: Send + Sync + 'a> IntoParallelRefIterator<'a>
+    for Shell<P, C, S>
+{
     type Item = &'a Face<P, C, S>;
     type Iter = FaceParallelIter<'a, P, C, S>;
     fn par_iter(&'a self) -> Self::Iter { self.face_list.par_iter() }
 }
 
-impl<'a, P: Send + 'a, C: Send + 'a, S: Send + 'a> IntoParallelRefMutIterator<'a>
-    for Shell<P, C, S>
+impl<'a, P: Send + Sync + 'a, C: Send + Sync + 'a, S: Send + Sync + 'a>
+    IntoParallelRefMutIterator<'a> for Shell<P, C, S>
 {
     type Item = &'a mut Face<P, C, S>;
     type Iter = FaceParallelIterMut<'a, P, C, S>;
     fn par_iter_mut(&'a mut self) -> Self::Iter { self.face_list.par_iter_mut() }
 }
 
-impl<P: Send, C: Send, S: Send> ParallelExtend<Face<P, C, S>> for Shell<P, C, S> {
+impl<P: Send + Sync, C: Send + Sync, S: Send + Sync> ParallelExtend<Face<P, C, S>>
+    for Shell<P, C, S>
+{
     fn par_extend<I>(&mut self, par_iter: I)
     where I: IntoParallelIterator<Item = Face<P, C, S>> {
         self.face_list.par_extend(par_iter)
@@ -0,0 +1,269 @@
+//! Snapshots and copy-on-write edits of shapes for undo and redo
+//!
+//! The geometries of the topological elements are shared by all clones of the elements, so
+//! [`Vertex::set_point`], [`Edge::set_curve`] and [`Face::set_surface`] change every shape holding
+//! the element. On the other hand, the topological structures are not changed after they are
+//! created, and a clone of a shape shares all its elements with the original.
+//!
+//! A [`Transaction`] collects the new geometries of the elements without changing the elements.
+//! Applying it to a shape by [`Shell::apply`] or [`Solid::apply`] creates a new version of the
+//! shape, in which the edited elements and the elements bounded by them are replaced by new ones
+//! and the other elements are shared with the original. Hence, the original shape and its
+//! snapshots stay intact, and undo and redo are done by keeping the versions.
+
+use crate::history::{ShapeID, TopologyHistory};
+use crate::*;
+use rustc_hash::FxHashMap as HashMap;
+
+/// The new geometries of the elements, applied to shapes by [`Shell::apply`] or [`Solid::apply`].
+/// # Examples
+/// ```
+/// use truck_topology::{snapshot::Transaction, *};
+/// let v = Vertex::news(&[0, 1, 2]);
+/// let wire = Wire::from(vec![
+///     Edge::new(&v[0], &v[1], ()),
+///     Edge::new(&v[1], &v[2], ()),
+///     Edge::new(&v[2], &v[0], ()),
+/// ]);
+/// let shell: Shell<_, _, _> = vec![Face::new(vec![wire], ())].into();
+/// let version0 = shell.snapshot();
+///
+/// let mut transaction = Transaction::new();
+/// transaction.set_point(&v[0], 3);
+/// let (version1, history) = version0.apply(&transaction);
+///
+/// // the old version is not changed
+/// let points: Vec<usize> = version0.vertex_iter().map(|v| v.point()).collect();
+/// assert_eq!(points, vec![0, 1, 2]);
+/// let points: Vec<usize> = version1.vertex_iter().map(|v| v.point()).collect();
+/// assert_eq!(points, vec![3, 1, 2]);
+///
+/// // the vertex, the two edges on it and the face are replaced.
+/// assert_eq!(history.modified_iter().count(), 4);
+/// let edge0 = version0[0].boundaries()[0][1].clone();
+/// let edge1 = version1[0].boundaries()[0][1].clone();
+/// assert_eq!(edge0, edge1);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Transaction<P, C, S> {
+    points: HashMap<VertexID<P>, P>,
+    curves: HashMap<EdgeID<C>, C>,
+    surfaces: HashMap<FaceID<S>, S>,
+}
+
+impl<P, C, S> Default for Transaction<P, C, S> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self {
+            points: HashMap::default(),
+            curves: HashMap::default(),
+            surfaces: HashMap::default(),
+        }
+    }
+}
+
+impl<P, C, S> Transaction<P, C, S> {
+    /// Creates an empty transaction.
+    #[inline(always)]
+    pub fn new() -> Self { Self::default() }
+    /// Returns `true` if no geometry is edited.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty() && self.curves.is_empty() && self.surfaces.is_empty()
+    }
+    /// Sets the new point of `vertex`.
+    /// # Remarks
+    /// The curves of the edges and the surfaces of the faces on `vertex` are not changed
+    /// automatically. Set them by [`Transaction::set_curve`] and [`Transaction::set_surface`]
+    /// to keep the shape geometrically consistent.
+    #[inline(always)]
+    pub fn set_point(&mut self, vertex: &Vertex<P>, point: P) {
+        self.points.insert(vertex.id(), point);
+    }
+    /// Sets the new curve of `edge`.
+    /// # Remarks
+    /// As [`Edge::set_curve`], `curve` is the curve of the absolute edge.
+    #[inline(always)]
+    pub fn set_curve(&mut self, edge: &Edge<P, C>, curve: C) {
+        self.curves.insert(edge.id(), curve);
+    }
+    /// Sets the new surface of `face`.
+    /// # Remarks
+    /// As [`Face::set_surface`], `surface` is the surface of the absolute face.
+    #[inline(always)]
+    pub fn set_surface(&mut self, face: &Face<P, C, S>, surface: S) {
+        self.surfaces.insert(face.id(), surface);
+    }
+}
+
+impl<P: Clone, C: Clone, S: Clone> Shell<P, C, S> {
+    /// Returns a snapshot of the shell, which shares all elements with `self`.
+    /// # Remarks
+    /// The snapshot is not changed by [`Shell::apply`], but is changed by the setters of
+    /// the geometries of the shared elements, e.g. [`Vertex::set_point`].
+    #[inline(always)]
+    pub fn snapshot(&self) -> Self { self.clone() }
+    /// Returns the new version of the shell edited by `transaction`, and the history of the
+    /// replaced elements. `self` is not changed.
+    /// # Remarks
+    /// The geometries are not checked. The replaced edges and faces keep the old curves and
+    /// surfaces unless they are set in `transaction`, even if their vertices are moved.
+    pub fn apply(&self, transaction: &Transaction<P, C, S>) -> (Self, TopologyHistory<P, C, S>) {
+        let mut rebuilder = Rebuilder::new(transaction);
+        let shell = rebuilder.shell(self);
+        (shell, rebuilder.history)
+    }
+}
+
+impl<P: Clone, C: Clone, S: Clone> Solid<P, C, S> {
+    /// Returns a snapshot of the solid, which shares all elements with `self`.
+    /// # Remarks
+    /// The snapshot is not changed by [`Solid::apply`], but is changed by the setters of
+    /// the geometries of the shared elements, e.g. [`Vertex::set_point`].
+    #[inline(always)]
+    pub fn snapshot(&self) -> Self { self.clone() }
+    /// Returns the new version of the solid edited by `transaction`, and the history of the
+    /// replaced elements. `self` is not changed.
+    /// # Remarks
+    /// See [`Shell::apply`] for the geometries of the replaced elements.
+    pub fn apply(&self, transaction: &Transaction<P, C, S>) -> (Self, TopologyHistory<P, C, S>) {
+        let mut rebuilder = Rebuilder::new(transaction);
+        let boundaries = self
+            .boundaries
+            .iter()
+            .map(|shell| rebuilder.shell(shell))
+            .collect();
+        (Solid::new_unchecked(boundaries), rebuilder.history)
+    }
+}
+
+/// Replaces the edited elements and the elements bounded by them.
+/// The caches hold the new absolute elements, or `None` if the elements are not replaced.
+struct Rebuilder<'a, P, C, S> {
+    transaction: &'a Transaction<P, C, S>,
+    vertices: HashMap<VertexID<P>, Option<Vertex<P>>>,
+    edges: HashMap<EdgeID<C>, Option<Edge<P, C>>>,
+    faces: HashMap<FaceID<S>, Option<Face<P, C, S>>>,
+    history: TopologyHistory<P, C, S>,
+}
+
+impl<'a, P: Clone, C: Clone, S: Clone> Rebuilder<'a, P, C, S> {
+    fn new(transaction: &'a Transaction<P, C, S>) -> Self {
+        Self {
+            transaction,
+            vertices: HashMap::default(),
+            edges: HashMap::default(),
+            faces: HashMap::default(),
+            history: TopologyHistory::new(),
+        }
+    }
+
+    fn vertex(&mut self, vertex: &Vertex<P>) -> Option<Vertex<P>> {
+        if let Some(new) = self.vertices.get(&vertex.id()) {
+            return new.clone();
+        }
+        let new = self.transaction.points.get(&vertex.id()).map(|point| {
+            let new = Vertex::new(point.clone());
+            self.history
+                .add_modified(ShapeID::Vertex(vertex.id()), ShapeID::Vertex(new.id()));
+            new
+        });
+        self.vertices.insert(vertex.id(), new.clone());
+        new
+    }
+
+    fn edge(&mut self, edge: &Edge<P, C>) -> Option<Edge<P, C>> {
+        let new = match self.edges.get(&edge.id()) {
+            Some(new) => new.clone(),
+            None => {
+                let transaction = self.transaction;
+                let (v0, v1) = &edge.vertices;
+                let (w0, w1) = (self.vertex(v0), self.vertex(v1));
+                let curve = transaction.curves.get(&edge.id());
+                let new = match w0.is_some() || w1.is_some() || curve.is_some() {
+                    true => {
+                        // the old curve is kept even if the vertices are moved.
+                        let curve = curve.cloned().unwrap_or_else(|| edge.curve.read().clone());
+                        let new = Edge {
+                            vertices: (
+                                w0.unwrap_or_else(|| v0.clone()),
+                                w1.unwrap_or_else(|| v1.clone()),
+                            ),
+                            orientation: true,
+                            curve: Arc::new(RwLock::new(curve)),
+                        };
+                        self.history
+                            .add_modified(ShapeID::Edge(edge.id()), ShapeID::Edge(new.id()));
+                        Some(new)
+                    }
+                    false => None,
+                };
+                self.edges.insert(edge.id(), new.clone());
+                new
+            }
+        }?;
+        match edge.orientation {
+            true => Some(new),
+            false => Some(new.inverse()),
+        }
+    }
+
+    fn wire(&mut self, wire: &Wire<P, C>) -> Option<Wire<P, C>> {
+        let edges: Vec<_> = wire.edge_iter().map(|edge| self.edge(edge)).collect();
+        match edges.iter().any(Option::is_some) {
+            true => Some(
+                edges
+                    .into_iter()
+                    .zip(wire.edge_iter())
+                    .map(|(new, old)| new.unwrap_or_else(|| old.clone()))
+                    .collect(),
+            ),
+            false => None,
+        }
+    }
+
+    fn face(&mut self, face: &Face<P, C, S>) -> Option<Face<P, C, S>> {
+        let new = match self.faces.get(&face.id()) {
+            Some(new) => new.clone(),
+            None => {
+                let transaction = self.transaction;
+                let wires: Vec<_> = face.boundaries.iter().map(|wire| self.wire(wire)).collect();
+                let surface = transaction.surfaces.get(&face.id());
+                let new = match wires.iter().any(Option::is_some) || surface.is_some() {
+                    true => {
+                        let boundaries = wires
+                            .into_iter()
+                            .zip(&face.boundaries)
+                            .map(|(new, old)| new.unwrap_or_else(|| old.clone()))
+                            .collect();
+                        let surface = surface
+                            .cloned()
+                            .unwrap_or_else(|| face.surface.read().clone());
+                        let new = Face {
+                            boundaries,
+                            orientation: true,
+                            surface: Arc::new(RwLock::new(surface)),
+                        };
+                        self.history
+                            .add_modified(ShapeID::Face(face.id()), ShapeID::Face(new.id()));
+                        Some(new)
+                    }
+                    false => None,
+                };
+                self.faces.insert(face.id(), new.clone());
+                new
+            }
+        }?;
+        match face.orientation {
+            true => Some(new),
+            false => Some(new.inverse()),
+        }
+    }
+
+    fn shell(&mut self, shell: &Shell<P, C, S>) -> Shell<P, C, S> {
+        shell
+            .face_iter()
+            .map(|face| self.face(face).unwrap_or_else(|| face.clone()))
+            .collect()
+    }
+}
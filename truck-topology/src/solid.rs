@@ -215,6 +215,20 @@ impl<P, C, S> Solid<P, C, S> {
     }
 }
 
+impl<C, S> Solid<Point3, C, S>
+where
+    C: ParametricCurve3D + BoundedCurve + BoundingBoxed<Point = Point3>,
+    S: ParametricSurface3D + SearchParameter<D2, Point = Point3>,
+{
+    /// Returns the tight bounding box of the boundary shells.
+    /// cf. [`Face::bounding_box`]
+    pub fn bounding_box(&self) -> BoundingBox<Point3> {
+        self.boundaries
+            .iter()
+            .fold(BoundingBox::new(), |bdb, shell| bdb + shell.bounding_box())
+    }
+}
+
 impl<P: Clone, C: Clone, S: Clone> Solid<P, C, Option<S>> {
     /// Returns the value with the Option removed if there is no `None` in the surfaces of the faces.
     #[inline(always)]
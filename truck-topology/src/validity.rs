@@ -0,0 +1,522 @@
+//! Validity checks of shells and solids
+//!
+//! [`Shell::check`] and [`Solid::check`] report all the defects of the boundary representation
+//! in [`ValidityReport`], not only whether the shape is valid or not. Each [`Defect`] has the
+//! ids of the offending vertices, edges and faces.
+//!
+//! # Remarks
+//! The defects of the boundary loops in the parameter domains, i.e. [`Defect::WrongLoopOrientation`]
+//! and [`Defect::InnerLoopOutside`], are checked only for the faces whose boundaries can be
+//! projected to closed polygons in the parameter domain. The same holds for the faces whose
+//! overlaps are checked by [`Defect::OverlappingFaces`].
+
+use crate::face::{parameter_polygon, sample_points, winding_number};
+use crate::*;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+use truck_base::cgmath64::{InnerSpace, MetricSpace};
+
+/// The number of the division of each axis of the parameter domain for sampling interior points.
+const INTERIOR_DIVISION: usize = 8;
+
+/// A defect found by [`Shell::check`] or [`Solid::check`].
+#[derive(Clone, Debug)]
+pub enum Defect<P, C, S> {
+    /// The end of the curve of the edge is off the vertex.
+    VertexOffCurve {
+        /// the vertex
+        vertex: VertexID<P>,
+        /// the edge whose curve does not end at the vertex
+        edge: EdgeID<C>,
+        /// the distance between the point of the vertex and the end of the curve
+        distance: f64,
+    },
+    /// The curve of the edge is off the surface of the face.
+    EdgeOffSurface {
+        /// the edge
+        edge: EdgeID<C>,
+        /// the face bounded by the edge
+        face: FaceID<S>,
+        /// the maximum distance between the curve and the surface, `f64::INFINITY` if a point
+        /// on the curve cannot be projected to the surface
+        distance: f64,
+    },
+    /// Two non-adjacent edges of a boundary wire intersect each other.
+    SelfIntersectingWire {
+        /// the face
+        face: FaceID<S>,
+        /// the index of the wire in the absolute boundaries of the face
+        wire: usize,
+        /// the intersecting edges
+        edges: (EdgeID<C>, EdgeID<C>),
+    },
+    /// The interiors of two faces overlap each other.
+    OverlappingFaces {
+        /// the overlapping faces
+        faces: (FaceID<S>, FaceID<S>),
+    },
+    /// The boundary wire is wrongly oriented in the parameter domain: the outer boundary must be
+    /// counter-clockwise and the inner boundaries must be clockwise.
+    WrongLoopOrientation {
+        /// the face
+        face: FaceID<S>,
+        /// the index of the wire in the absolute boundaries of the face
+        wire: usize,
+    },
+    /// The inner boundary wire is outside the outer boundary wire.
+    InnerLoopOutside {
+        /// the face
+        face: FaceID<S>,
+        /// the index of the wire in the absolute boundaries of the face
+        wire: usize,
+    },
+    /// The edge is shared by more than two faces.
+    NonManifoldEdge {
+        /// the edge
+        edge: EdgeID<C>,
+        /// the faces sharing the edge
+        faces: Vec<FaceID<S>>,
+    },
+    /// The edge is shared by two faces in the same direction.
+    InconsistentOrientation {
+        /// the edge
+        edge: EdgeID<C>,
+        /// the faces sharing the edge
+        faces: (FaceID<S>, FaceID<S>),
+    },
+    /// The edge of a boundary shell of a solid bounds only one face.
+    OpenEdge {
+        /// the edge
+        edge: EdgeID<C>,
+        /// the face bounded by the edge
+        face: FaceID<S>,
+    },
+}
+
+/// The defects found by [`Shell::check`] or [`Solid::check`].
+#[derive(Clone, Debug)]
+pub struct ValidityReport<P, C, S> {
+    /// all the defects
+    pub defects: Vec<Defect<P, C, S>>,
+}
+
+impl<P, C, S> ValidityReport<P, C, S> {
+    /// Returns `true` if no defect is found.
+    #[inline(always)]
+    pub fn is_valid(&self) -> bool { self.defects.is_empty() }
+}
+
+impl<C, S> Shell<Point3, C, S>
+where
+    C: ParametricCurve3D + BoundedCurve,
+    S: ParametricSurface3D
+        + SearchParameter<D2, Point = Point3>
+        + SearchNearestParameter<D2, Point = Point3>,
+{
+    /// Checks the validity of the shell, and reports all the defects.
+    ///
+    /// The geometric defects are checked by the tolerance `tol`:
+    /// - the vertices off the ends of the curves of the edges,
+    /// - the edges off the surfaces of the faces,
+    /// - the boundary wires intersecting themselves,
+    /// - the faces overlapping each other,
+    /// - the boundary wires wrongly oriented in the parameter domains, and
+    /// - the inner boundary wires outside the outer boundary wires.
+    ///
+    /// The topological defects are
+    /// - the non-manifold edges shared by more than two faces, and
+    /// - the edges shared by two faces in the same direction.
+    ///
+    /// Unlike [`Solid::check`], the edges bounding only one face are not reported, since a shell
+    /// may have boundaries.
+    pub fn check(&self, tol: f64) -> ValidityReport<Point3, C, S> {
+        let mut defects = Vec::new();
+        let mut checked_edges = HashSet::default();
+        self.face_iter().for_each(|face| {
+            face.boundaries.iter().flatten().for_each(|edge| {
+                if checked_edges.insert(edge.id()) {
+                    vertex_defects(edge, tol, &mut defects);
+                }
+            });
+            edge_surface_defects(face, tol, &mut defects);
+            wire_defects(face, tol, &mut defects);
+            loop_defects(face, &mut defects);
+        });
+        overlap_defects(self, tol, &mut defects);
+        edge_usages(self)
+            .into_iter()
+            .for_each(|(edge, usages)| match usages.len() {
+                2 if usages[0].1 == usages[1].1 => {
+                    let faces = (usages[0].0, usages[1].0);
+                    defects.push(Defect::InconsistentOrientation { edge, faces });
+                }
+                3.. => {
+                    let mut faces = Vec::<FaceID<S>>::new();
+                    usages.into_iter().for_each(|(face, _)| {
+                        if !faces.contains(&face) {
+                            faces.push(face);
+                        }
+                    });
+                    defects.push(Defect::NonManifoldEdge { edge, faces });
+                }
+                _ => {}
+            });
+        ValidityReport { defects }
+    }
+}
+
+impl<C, S> Solid<Point3, C, S>
+where
+    C: ParametricCurve3D + BoundedCurve,
+    S: ParametricSurface3D
+        + SearchParameter<D2, Point = Point3>
+        + SearchNearestParameter<D2, Point = Point3>,
+{
+    /// Checks the validity of the solid, and reports all the defects.
+    ///
+    /// In addition to the defects reported by [`Shell::check`] for each boundary shell,
+    /// the edges bounding only one face are reported as [`Defect::OpenEdge`].
+    pub fn check(&self, tol: f64) -> ValidityReport<Point3, C, S> {
+        let mut defects = Vec::new();
+        self.boundaries().iter().for_each(|shell| {
+            defects.extend(shell.check(tol).defects);
+            edge_usages(shell)
+                .into_iter()
+                .filter(|(_, usages)| usages.len() == 1)
+                .for_each(|(edge, usages)| {
+                    let face = usages[0].0;
+                    defects.push(Defect::OpenEdge { edge, face });
+                });
+        });
+        ValidityReport { defects }
+    }
+}
+
+/// Returns the faces using each edge with the direction of the edge in the face,
+/// in the order of appearance.
+#[allow(clippy::type_complexity)]
+fn edge_usages<P, C, S>(shell: &Shell<P, C, S>) -> Vec<(EdgeID<C>, Vec<(FaceID<S>, bool)>)> {
+    let mut usages = Vec::<(EdgeID<C>, Vec<(FaceID<S>, bool)>)>::new();
+    let mut indices = HashMap::<EdgeID<C>, usize>::default();
+    shell.face_iter().for_each(|face| {
+        face.edge_iter().for_each(|edge| {
+            let index = *indices.entry(edge.id()).or_insert_with(|| {
+                usages.push((edge.id(), Vec::new()));
+                usages.len() - 1
+            });
+            usages[index].1.push((face.id(), edge.orientation()));
+        })
+    });
+    usages
+}
+
+fn vertex_defects<C, S>(edge: &Edge<Point3, C>, tol: f64, defects: &mut Vec<Defect<Point3, C, S>>)
+where C: BoundedCurve<Point = Point3> {
+    let curve = edge.curve.read();
+    let ends = [
+        (edge.absolute_front(), curve.front()),
+        (edge.absolute_back(), curve.back()),
+    ];
+    ends.into_iter().for_each(|(vertex, point)| {
+        let distance = vertex.point.read().distance(point);
+        if distance > tol {
+            defects.push(Defect::VertexOffCurve {
+                vertex: vertex.id(),
+                edge: edge.id(),
+                distance,
+            });
+        }
+    });
+}
+
+fn edge_surface_defects<C, S>(
+    face: &Face<Point3, C, S>,
+    tol: f64,
+    defects: &mut Vec<Defect<Point3, C, S>>,
+) where
+    C: ParametricCurve3D + BoundedCurve,
+    S: ParametricSurface3D + SearchNearestParameter<D2, Point = Point3>,
+{
+    let surface = face.surface.read();
+    let mut checked_edges = HashSet::default();
+    face.boundaries.iter().flatten().for_each(|edge| {
+        if !checked_edges.insert(edge.id()) {
+            return;
+        }
+        let mut hint: Option<(f64, f64)> = None;
+        let distance = sample_points(edge, BOUNDING_BOX_DIVISION)
+            .into_iter()
+            .try_fold(0.0, |max: f64, point| {
+                let (u, v) =
+                    surface.search_nearest_parameter(point, hint, SEARCH_PARAMETER_TRIALS)?;
+                hint = Some((u, v));
+                Some(f64::max(max, surface.subs(u, v).distance(point)))
+            })
+            .unwrap_or(f64::INFINITY);
+        if distance > tol {
+            defects.push(Defect::EdgeOffSurface {
+                edge: edge.id(),
+                face: face.id(),
+                distance,
+            });
+        }
+    });
+}
+
+fn wire_defects<C, S>(
+    face: &Face<Point3, C, S>,
+    tol: f64,
+    defects: &mut Vec<Defect<Point3, C, S>>,
+) where
+    C: ParametricCurve3D + BoundedCurve,
+{
+    face.boundaries.iter().enumerate().for_each(|(w, wire)| {
+        let polylines: Vec<_> = wire
+            .edge_iter()
+            .map(|edge| {
+                let mut points = sample_points(edge, BOUNDING_BOX_DIVISION);
+                points.push(*edge.back().point.read());
+                let bdb: BoundingBox<Point3> = points.iter().collect();
+                (edge.id(), points, bdb)
+            })
+            .collect();
+        let n = polylines.len();
+        (0..n).for_each(|i| {
+            (i + 1..n).for_each(|j| {
+                let ((id0, points0, bdb0), (id1, points1, bdb1)) = (&polylines[i], &polylines[j]);
+                let adjacent = j == i + 1 || (i == 0 && j == n - 1);
+                if adjacent || id0 == id1 || !boxes_near(*bdb0, *bdb1, tol) {
+                    return;
+                }
+                if polyline_distance(points0, points1) < tol {
+                    defects.push(Defect::SelfIntersectingWire {
+                        face: face.id(),
+                        wire: w,
+                        edges: (*id0, *id1),
+                    });
+                }
+            })
+        });
+    });
+}
+
+fn loop_defects<C, S>(face: &Face<Point3, C, S>, defects: &mut Vec<Defect<Point3, C, S>>)
+where
+    C: ParametricCurve3D + BoundedCurve,
+    S: ParametricSurface3D + SearchParameter<D2, Point = Point3>, {
+    let surface = face.surface.read();
+    let Some(polygons) = face
+        .boundaries
+        .iter()
+        .map(|wire| parameter_polygon(&*surface, wire))
+        .collect::<Option<Vec<_>>>()
+    else {
+        return;
+    };
+    let areas: Vec<f64> = polygons
+        .iter()
+        .map(|polygon| signed_area(polygon))
+        .collect();
+    let outer = (0..areas.len())
+        .filter(|i| areas[*i] > 0.0)
+        .max_by(|i, j| areas[*i].partial_cmp(&areas[*j]).unwrap());
+    let Some(outer) = outer else {
+        // the outer boundary is the whole domain of a bounded surface
+        if let (Some(_), Some(_)) = surface.try_range_tuple() {
+            return;
+        }
+        let largest =
+            (0..areas.len()).max_by(|i, j| areas[*i].abs().partial_cmp(&areas[*j].abs()).unwrap());
+        if let Some(wire) = largest {
+            defects.push(Defect::WrongLoopOrientation {
+                face: face.id(),
+                wire,
+            });
+        }
+        return;
+    };
+    let periods = (surface.u_period(), surface.v_period());
+    (0..polygons.len())
+        .filter(|i| *i != outer)
+        .for_each(|wire| {
+            if areas[wire] > 0.0 {
+                defects.push(Defect::WrongLoopOrientation {
+                    face: face.id(),
+                    wire,
+                });
+            } else if !inside(&polygons[outer..=outer], polygons[wire][0], periods) {
+                defects.push(Defect::InnerLoopOutside {
+                    face: face.id(),
+                    wire,
+                });
+            }
+        });
+}
+
+/// Sampled points of a face for checking overlaps.
+struct FaceSamples<S> {
+    id: FaceID<S>,
+    polygons: Vec<Vec<Point2>>,
+    interior: Vec<Point3>,
+    bdb: BoundingBox<Point3>,
+}
+
+fn face_samples<C, S>(face: &Face<Point3, C, S>) -> Option<FaceSamples<S>>
+where
+    C: ParametricCurve3D + BoundedCurve,
+    S: ParametricSurface3D + SearchParameter<D2, Point = Point3>, {
+    let surface = face.surface.read();
+    let polygons = face
+        .boundaries
+        .iter()
+        .map(|wire| parameter_polygon(&*surface, wire))
+        .collect::<Option<Vec<_>>>()?;
+    let range: BoundingBox<Point2> = polygons.iter().flatten().collect();
+    if range.is_empty() {
+        return None;
+    }
+    let (min, max) = (range.min(), range.max());
+    let periods = (surface.u_period(), surface.v_period());
+    let interior: Vec<Point3> = (0..INTERIOR_DIVISION * INTERIOR_DIVISION)
+        .map(|k| {
+            let s = (k % INTERIOR_DIVISION) as f64 + 0.5;
+            let t = (k / INTERIOR_DIVISION) as f64 + 0.5;
+            let n = INTERIOR_DIVISION as f64;
+            Point2::new(
+                min.x + (max.x - min.x) * s / n,
+                min.y + (max.y - min.y) * t / n,
+            )
+        })
+        .filter(|uv| inside(&polygons, *uv, periods))
+        .map(|uv| surface.subs(uv.x, uv.y))
+        .collect();
+    let mut bdb: BoundingBox<Point3> = interior.iter().collect();
+    face.boundaries
+        .iter()
+        .flat_map(Wire::edge_iter)
+        .flat_map(|edge| sample_points(edge, BOUNDING_BOX_DIVISION))
+        .for_each(|point| bdb.push(point));
+    Some(FaceSamples {
+        id: face.id(),
+        polygons,
+        interior,
+        bdb,
+    })
+}
+
+fn overlap_defects<C, S>(
+    shell: &Shell<Point3, C, S>,
+    tol: f64,
+    defects: &mut Vec<Defect<Point3, C, S>>,
+) where
+    C: ParametricCurve3D + BoundedCurve,
+    S: ParametricSurface3D
+        + SearchParameter<D2, Point = Point3>
+        + SearchNearestParameter<D2, Point = Point3>,
+{
+    let samples: Vec<_> = shell.face_iter().map(face_samples).collect();
+    let on_face = |face: &Face<Point3, C, S>, samples: &FaceSamples<S>, point: Point3| {
+        let surface = face.surface.read();
+        let hint = None::<(f64, f64)>;
+        let Some((u, v)) = surface.search_nearest_parameter(point, hint, SEARCH_PARAMETER_TRIALS)
+        else {
+            return false;
+        };
+        let periods = (surface.u_period(), surface.v_period());
+        surface.subs(u, v).distance(point) < tol
+            && inside(&samples.polygons, Point2::new(u, v), periods)
+    };
+    (0..shell.len()).for_each(|i| {
+        (i + 1..shell.len()).for_each(|j| {
+            let (Some(samples0), Some(samples1)) = (&samples[i], &samples[j]) else {
+                return;
+            };
+            if samples0.id == samples1.id || !boxes_near(samples0.bdb, samples1.bdb, tol) {
+                return;
+            }
+            let overlap = samples0
+                .interior
+                .iter()
+                .any(|point| on_face(&shell[j], samples1, *point))
+                || samples1
+                    .interior
+                    .iter()
+                    .any(|point| on_face(&shell[i], samples0, *point));
+            if overlap {
+                defects.push(Defect::OverlappingFaces {
+                    faces: (samples0.id, samples1.id),
+                });
+            }
+        })
+    });
+}
+
+/// Returns whether the point is inside the domain bounded by the polygons,
+/// considering the periods of the parameters.
+fn inside(
+    polygons: &[Vec<Point2>],
+    uv: Point2,
+    (u_period, v_period): (Option<f64>, Option<f64>),
+) -> bool {
+    let shifts = |period: Option<f64>| match period {
+        Some(p) => vec![-p, 0.0, p],
+        None => vec![0.0],
+    };
+    shifts(u_period).into_iter().any(|du| {
+        shifts(v_period).into_iter().any(|dv| {
+            let uv = (uv.x + du, uv.y + dv);
+            polygons
+                .iter()
+                .map(|polygon| winding_number(polygon, uv))
+                .sum::<i32>()
+                != 0
+        })
+    })
+}
+
+/// Returns the signed area of the polygon, positive if counter-clockwise.
+fn signed_area(polygon: &[Point2]) -> f64 {
+    let edges = polygon.iter().zip(polygon.iter().cycle().skip(1));
+    edges.fold(0.0, |sum, (p, q)| sum + (q.x + p.x) * (q.y - p.y)) / 2.0
+}
+
+fn boxes_near(bdb0: BoundingBox<Point3>, bdb1: BoundingBox<Point3>, tol: f64) -> bool {
+    let (min0, max0, min1, max1) = (bdb0.min(), bdb0.max(), bdb1.min(), bdb1.max());
+    (0..3).all(|k| min0[k] - tol <= max1[k] && min1[k] - tol <= max0[k])
+}
+
+fn polyline_distance(points0: &[Point3], points1: &[Point3]) -> f64 {
+    points0
+        .windows(2)
+        .flat_map(|p| {
+            points1
+                .windows(2)
+                .map(move |q| segment_distance((p[0], p[1]), (q[0], q[1])))
+        })
+        .fold(f64::INFINITY, f64::min)
+}
+
+/// Returns the distance between two segments.
+fn segment_distance((p0, p1): (Point3, Point3), (q0, q1): (Point3, Point3)) -> f64 {
+    let (d0, d1, r) = (p1 - p0, q1 - q0, p0 - q0);
+    let (a, e, f) = (d0.magnitude2(), d1.magnitude2(), d1.dot(r));
+    let (s, t) = match (a <= f64::EPSILON, e <= f64::EPSILON) {
+        (true, true) => (0.0, 0.0),
+        (true, false) => (0.0, f64::clamp(f / e, 0.0, 1.0)),
+        (false, true) => (f64::clamp(-d0.dot(r) / a, 0.0, 1.0), 0.0),
+        (false, false) => {
+            let (b, c) = (d0.dot(d1), d0.dot(r));
+            let denom = a * e - b * b;
+            let s = match denom > f64::EPSILON {
+                true => f64::clamp((b * f - c * e) / denom, 0.0, 1.0),
+                false => 0.0,
+            };
+            let t = (b * s + f) / e;
+            match t {
+                _ if t < 0.0 => (f64::clamp(-c / a, 0.0, 1.0), 0.0),
+                _ if t > 1.0 => (f64::clamp((b - c) / a, 0.0, 1.0), 1.0),
+                _ => (s, t),
+            }
+        }
+    };
+    (p0 + d0 * s).distance(q0 + d1 * t)
+}
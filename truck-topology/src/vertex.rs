@@ -14,7 +14,7 @@ impl<P> Vertex<P> {
     #[inline(always)]
     pub fn new(point: P) -> Vertex<P> {
         Vertex {
-            point: Arc::new(Mutex::new(point)),
+            point: Arc::new(RwLock::new(point)),
         }
     }
 
@@ -36,9 +36,24 @@ impl<P> Vertex<P> {
     #[inline(always)]
     pub fn point(&self) -> P
     where P: Clone {
-        self.point.lock().clone()
+        self.point.read().clone()
     }
 
+    /// Calls `f` with the reference to the point of vertex, without cloning the point.
+    ///
+    /// The point is locked only for reading, so the calls from many threads do not block each other.
+    /// # Remarks
+    /// Setting the point of the same vertex in `f` causes a deadlock.
+    /// # Examples
+    /// ```
+    /// use truck_topology::*;
+    /// let v = Vertex::new(vec![0.0; 1000]);
+    /// let sum = v.with_point(|point| point.iter().sum::<f64>());
+    /// assert_eq!(sum, 0.0);
+    /// ```
+    #[inline(always)]
+    pub fn with_point<R>(&self, f: impl FnOnce(&P) -> R) -> R { f(&self.point.read()) }
+
     /// Sets the point of vertex.
     /// # Examples
     /// ```
@@ -58,7 +73,7 @@ impl<P> Vertex<P> {
     /// assert_eq!(v1.point(), 1);
     /// ```
     #[inline(always)]
-    pub fn set_point(&self, point: P) { *self.point.lock() = point; }
+    pub fn set_point(&self, point: P) { *self.point.write() = point; }
 
     /// Returns vertex whose point is converted by `point_mapping`.
     /// # Remarks
@@ -70,7 +85,7 @@ impl<P> Vertex<P> {
         &self,
         mut point_mapping: impl FnMut(&P) -> Option<Q>,
     ) -> Option<Vertex<Q>> {
-        Some(Vertex::new(point_mapping(&*self.point.lock())?))
+        Some(Vertex::new(point_mapping(&*self.point.read())?))
     }
 
     /// Returns vertex whose point is converted by `point_mapping`.
@@ -87,7 +102,7 @@ impl<P> Vertex<P> {
     #[doc(hidden)]
     #[inline(always)]
     pub fn mapped<Q>(&self, mut point_mapping: impl FnMut(&P) -> Q) -> Vertex<Q> {
-        Vertex::new(point_mapping(&*self.point.lock()))
+        Vertex::new(point_mapping(&*self.point.read()))
     }
 
     /// Returns the id of the vertex.
@@ -178,17 +193,17 @@ impl<'a, P: Debug> Debug for DebugDisplay<'a, Vertex<P>, VertexDisplayFormat> {
             VertexDisplayFormat::Full => f
                 .debug_struct("Vertex")
                 .field("id", &Arc::as_ptr(&self.entity.point))
-                .field("entity", &MutexFmt(&self.entity.point))
+                .field("entity", &RwLockFmt(&self.entity.point))
                 .finish(),
             VertexDisplayFormat::IDTuple => {
                 f.debug_tuple("Vertex").field(&self.entity.id()).finish()
             }
             VertexDisplayFormat::PointTuple => f
                 .debug_tuple("Vertex")
-                .field(&MutexFmt(&self.entity.point))
+                .field(&RwLockFmt(&self.entity.point))
                 .finish(),
             VertexDisplayFormat::AsPoint => {
-                f.write_fmt(format_args!("{:?}", &MutexFmt(&self.entity.point)))
+                f.write_fmt(format_args!("{:?}", &RwLockFmt(&self.entity.point)))
             }
         }
     }
@@ -31,24 +31,24 @@ impl<P, C> Wire<P, C> {
     #[inline(always)]
     pub fn edge_par_iter(&self) -> EdgeParallelIter<'_, P, C>
     where
-        P: Send,
-        C: Send, {
+        P: Send + Sync,
+        C: Send + Sync, {
         self.par_iter()
     }
     /// Returns a mutable parallel iterator over the edges. Practically, an alias of `par_iter_mut()`.
     #[inline(always)]
     pub fn edge_par_iter_mut(&mut self) -> EdgeParallelIterMut<'_, P, C>
     where
-        P: Send,
-        C: Send, {
+        P: Send + Sync,
+        C: Send + Sync, {
         self.par_iter_mut()
     }
     /// Creates a consuming iterator. Practically, an alias of `into_par_iter()`.
     #[inline(always)]
     pub fn edge_into_par_iter(self) -> EdgeParallelIntoIter<P, C>
     where
-        P: Send,
-        C: Send, {
+        P: Send + Sync,
+        C: Send + Sync, {
         self.into_par_iter()
     }
 
@@ -558,7 +558,7 @@ where
         let vertex0 = vertex_map.entry_or_insert(vf).clone()?;
         let vb = edge.absolute_back();
         let vertex1 = vertex_map.entry_or_insert(vb).clone()?;
-        let curve = curve_mapping(&*edge.curve.lock())?;
+        let curve = curve_mapping(&*edge.curve.read())?;
         Some(Edge::debug_new(&vertex0, &vertex1, curve))
     }
 }
@@ -576,7 +576,7 @@ where
         let vertex0 = vertex_map.entry_or_insert(vf).clone();
         let vb = edge.absolute_back();
         let vertex1 = vertex_map.entry_or_insert(vb).clone();
-        let curve = curve_mapping(&*edge.curve.lock());
+        let curve = curve_mapping(&*edge.curve.read());
         Edge::debug_new(&vertex0, &vertex1, curve)
     }
 }
@@ -813,32 +813,32 @@ impl<'a, P: Debug, C: Debug> Debug for DebugDisplay<'a, Wire<P, C>, WireDisplayF
     }
 }
 
-impl<P: Send, C: Send> FromParallelIterator<Edge<P, C>> for Wire<P, C> {
+impl<P: Send + Sync, C: Send + Sync> FromParallelIterator<Edge<P, C>> for Wire<P, C> {
     fn from_par_iter<I>(par_iter: I) -> Self
     where I: IntoParallelIterator<Item = Edge<P, C>> {
         Self::from(VecDeque::from_par_iter(par_iter))
     }
 }
 
-impl<P: Send, C: Send> IntoParallelIterator for Wire<P, C> {
+impl<P: Send + Sync, C: Send + Sync> IntoParallelIterator for Wire<P, C> {
     type Item = Edge<P, C>;
     type Iter = EdgeParallelIntoIter<P, C>;
     fn into_par_iter(self) -> Self::Iter { self.edge_list.into_par_iter() }
 }
 
-impl<'a, P: Send + 'a, C: Send + 'a> IntoParallelRefIterator<'a> for Wire<P, C> {
+impl<'a, P: Send + Sync + 'a, C: Send + Sync + 'a> IntoParallelRefIterator<'a> for Wire<P, C> {
     type Item = &'a Edge<P, C>;
     type Iter = EdgeParallelIter<'a, P, C>;
     fn par_iter(&'a self) -> Self::Iter { self.edge_list.par_iter() }
 }
 
-impl<'a, P: Send + 'a, C: Send + 'a> IntoParallelRefMutIterator<'a> for Wire<P, C> {
+impl<'a, P: Send + Sync + 'a, C: Send + Sync + 'a> IntoParallelRefMutIterator<'a> for Wire<P, C> {
     type Item = &'a mut Edge<P, C>;
     type Iter = EdgeParallelIterMut<'a, P, C>;
     fn par_iter_mut(&'a mut self) -> Self::Iter { self.edge_list.par_iter_mut() }
 }
 
-impl<P: Send, C: Send> ParallelExtend<Edge<P, C>> for Wire<P, C> {
+impl<P: Send + Sync, C: Send + Sync> ParallelExtend<Edge<P, C>> for Wire<P, C> {
     fn par_extend<I>(&mut self, par_iter: I)
     where I: IntoParallelIterator<Item = Edge<P, C>> {
         self.edge_list.par_extend(par_iter)
@@ -201,3 +201,170 @@ fn solid_cut_edge() {
     let count = tri.edge_iter().count();
     assert_eq!(count, 12);
 }
+
+fn face_containing(shell: &Shell<Point3, Segment, ()>, vertices: &[&Vertex<Point3>]) -> FaceID<()> {
+    shell
+        .face_iter()
+        .find(|face| {
+            face.boundaries().iter().any(|wire| {
+                vertices
+                    .iter()
+                    .all(|v| wire.vertex_iter().any(|w| &&w == v))
+            })
+        })
+        .unwrap()
+        .id()
+}
+
+fn counts(shell: &Shell<Point3, Segment, ()>) -> (usize, usize, usize, usize, Option<usize>) {
+    let counts = shell.euler_counts();
+    let (v, e, f, r) = (counts.vertices, counts.edges, counts.faces, counts.rings);
+    (v, e, f, r, counts.holes())
+}
+
+#[test]
+fn euler_operators_cube() {
+    let p = [
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(1.0, 1.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+        Point3::new(0.0, 0.0, 1.0),
+        Point3::new(1.0, 0.0, 1.0),
+        Point3::new(1.0, 1.0, 1.0),
+        Point3::new(0.0, 1.0, 1.0),
+    ];
+    let (mut shell, v0) = Shell::mvfs(p[0], ());
+    assert_eq!(counts(&shell), (1, 0, 1, 0, Some(0)));
+    assert!(shell.is_euler_valid());
+
+    // the bottom square
+    let f0 = shell[0].id();
+    let mut v = vec![v0];
+    let mut made_edges = Vec::new();
+    for i in 1..4 {
+        let edge = shell
+            .mev(f0, &v[i - 1], p[i], Segment::new(p[i - 1], p[i]))
+            .unwrap();
+        v.push(edge.back().clone());
+        made_edges.push(edge);
+    }
+    let (edge, _) = shell
+        .mef(f0, &v[3], &v[0], Segment::new(p[3], p[0]))
+        .unwrap();
+    made_edges.push(edge);
+    assert_eq!(counts(&shell), (4, 4, 2, 0, Some(0)));
+    assert!(shell.is_euler_valid());
+
+    // the vertical edges
+    for i in 0..4 {
+        let face_id = face_containing(&shell, &[&v[i]]);
+        let edge = shell
+            .mev(face_id, &v[i], p[i + 4], Segment::new(p[i], p[i + 4]))
+            .unwrap();
+        v.push(edge.back().clone());
+        made_edges.push(edge);
+        assert!(shell.is_euler_valid());
+    }
+    // the top square
+    for i in 4..8 {
+        let j = if i == 7 { 4 } else { i + 1 };
+        let face_id = face_containing(&shell, &[&v[i], &v[j]]);
+        let (edge, _) = shell
+            .mef(face_id, &v[i], &v[j], Segment::new(p[i], p[j]))
+            .unwrap();
+        made_edges.push(edge);
+        assert!(shell.is_euler_valid());
+    }
+    assert_eq!(counts(&shell), (8, 12, 6, 0, Some(0)));
+    assert_eq!(shell.shell_condition(), shell::ShellCondition::Closed);
+
+    // a through hole from the top to the bottom
+    let top = face_containing(&shell, &[&v[4], &v[5], &v[6], &v[7]]);
+    let bottom = face_containing(&shell, &[&v[0], &v[1], &v[2], &v[3]]);
+    assert!(shell.kfmrh(top, bottom));
+    assert_eq!(counts(&shell), (8, 12, 5, 1, Some(1)));
+    assert!(shell.is_euler_valid());
+    assert!(shell.mfkrh(top, 1).is_some());
+    assert_eq!(counts(&shell), (8, 12, 6, 0, Some(0)));
+    assert!(shell.is_euler_valid());
+
+    // an inner loop in the top face
+    let q = [
+        Point3::new(0.25, 0.25, 1.0),
+        Point3::new(0.75, 0.25, 1.0),
+        Point3::new(0.75, 0.75, 1.0),
+        Point3::new(0.25, 0.75, 1.0),
+    ];
+    let bridge = shell
+        .mev(top, &v[4], q[0], Segment::new(p[4], q[0]))
+        .unwrap();
+    let mut u = vec![bridge.back().clone()];
+    let mut inner_edges = Vec::new();
+    for i in 1..4 {
+        let edge = shell
+            .mev(top, &u[i - 1], q[i], Segment::new(q[i - 1], q[i]))
+            .unwrap();
+        u.push(edge.back().clone());
+        inner_edges.push(edge);
+    }
+    let (inner_edge, _) = shell
+        .mef(top, &u[3], &u[0], Segment::new(q[3], q[0]))
+        .unwrap();
+    assert!(shell.kemr(bridge.id()).is_some());
+    assert_eq!(counts(&shell), (12, 16, 7, 1, Some(0)));
+    assert!(shell.is_euler_valid());
+    let bridge = shell
+        .mekr(top, &v[4], &u[0], Segment::new(p[4], q[0]))
+        .unwrap();
+    assert_eq!(counts(&shell), (12, 17, 7, 0, Some(0)));
+    assert!(shell.is_euler_valid());
+    // the edge of the inner loop is shared by two faces
+    assert!(shell.kemr(inner_edge.id()).is_none());
+    assert!(shell.kev(inner_edge.id()).is_none());
+
+    // kill all elements in reverse order
+    assert!(shell.kef(inner_edge.id()).is_some());
+    inner_edges.reverse();
+    inner_edges
+        .iter()
+        .for_each(|edge| assert!(shell.kev(edge.id()).is_some()));
+    assert!(shell.kev(bridge.id()).is_some());
+    assert_eq!(counts(&shell), (8, 12, 6, 0, Some(0)));
+    made_edges.reverse();
+    made_edges.iter().for_each(|edge| {
+        let killed = shell.kef(edge.id()).is_some() || shell.kev(edge.id()).is_some();
+        assert!(killed);
+        assert!(shell.is_euler_valid());
+    });
+    assert_eq!(counts(&shell), (1, 0, 1, 0, Some(0)));
+    assert!(shell.kvfs());
+    assert!(shell.is_empty());
+}
+
+#[test]
+fn euler_operators_before_first_mev() {
+    let p0 = Point3::new(0.0, 0.0, 0.0);
+    let p1 = Point3::new(1.0, 0.0, 0.0);
+    let (mut shell, v0) = Shell::mvfs(p0, ());
+    assert_eq!(counts(&shell), (1, 0, 1, 0, Some(0)));
+    assert!(shell.is_euler_valid());
+    // the vertex is represented by the empty wire, and not referenced by the shell.
+    assert!(shell[0].boundaries()[0].is_empty());
+    assert_eq!(shell.vertex_iter().count(), 0);
+    assert_eq!(shell.edge_iter().count(), 0);
+
+    let f0 = shell[0].id();
+    let edge = shell.mev(f0, &v0, p1, Segment::new(p0, p1)).unwrap();
+    assert_eq!(edge.front(), &v0);
+    assert_eq!(counts(&shell), (2, 1, 1, 0, Some(0)));
+    assert!(shell.is_euler_valid());
+    assert_eq!(shell.vertex_iter().count(), 2);
+    assert!(!shell.kvfs());
+
+    assert!(shell.kev(edge.id()).is_some());
+    assert_eq!(counts(&shell), (1, 0, 1, 0, Some(0)));
+    assert!(shell[0].boundaries()[0].is_empty());
+    assert!(shell.kvfs());
+    assert!(shell.is_empty());
+}
@@ -0,0 +1,110 @@
+use truck_topology::{shell::ShellCondition, *};
+
+fn square(v: &[Vertex<()>]) -> Face<(), (), ()> {
+    let wire = Wire::from(vec![
+        Edge::new(&v[0], &v[1], ()),
+        Edge::new(&v[1], &v[2], ()),
+        Edge::new(&v[2], &v[3], ()),
+        Edge::new(&v[3], &v[0], ()),
+    ]);
+    Face::new(vec![wire], ())
+}
+
+#[test]
+fn imprint_closed_wire() {
+    let v = Vertex::news([(); 4]);
+    let face = square(&v);
+    let w = Vertex::news([(); 3]);
+    let loop_wire = Wire::from(vec![
+        Edge::new(&w[0], &w[1], ()),
+        Edge::new(&w[1], &w[2], ()),
+        Edge::new(&w[2], &w[0], ()),
+    ]);
+
+    let (outer, inner) = face.imprint(&loop_wire).unwrap();
+    assert!(outer.orientation() && inner.orientation());
+    assert_eq!(
+        outer.boundaries(),
+        vec![face.boundaries()[0].clone(), loop_wire.inverse()]
+    );
+    assert_eq!(inner.boundaries(), vec![loop_wire.clone()]);
+    let shell: Shell<(), (), ()> = vec![outer, inner].into();
+    assert_eq!(shell.shell_condition(), ShellCondition::Oriented);
+
+    // the imprinted wire is oriented with respect to the orientation of the face.
+    let face = face.inverse();
+    let (outer, inner) = face.imprint(&loop_wire).unwrap();
+    assert!(!outer.orientation() && !inner.orientation());
+    assert_eq!(
+        outer.boundaries(),
+        vec![face.boundaries()[0].clone(), loop_wire.inverse()]
+    );
+    assert_eq!(inner.boundaries(), vec![loop_wire]);
+    let shell: Shell<(), (), ()> = vec![outer, inner].into();
+    assert_eq!(shell.shell_condition(), ShellCondition::Oriented);
+}
+
+#[test]
+fn imprint_invalid_closed_wire() {
+    let v = Vertex::news([(); 4]);
+    let face = square(&v);
+    let w = Vertex::news([(); 4]);
+
+    // touching the boundary
+    let touching = Wire::from(vec![
+        Edge::new(&v[0], &w[0], ()),
+        Edge::new(&w[0], &w[1], ()),
+        Edge::new(&w[1], &v[0], ()),
+    ]);
+    assert!(face.imprint(&touching).is_none());
+
+    // not simple
+    let figure_eight = Wire::from(vec![
+        Edge::new(&w[0], &w[1], ()),
+        Edge::new(&w[1], &w[2], ()),
+        Edge::new(&w[2], &w[0], ()),
+        Edge::new(&w[0], &w[3], ()),
+        Edge::new(&w[3], &w[0], ()),
+    ]);
+    assert!(face.imprint(&figure_eight).is_none());
+
+    // not continuous
+    let broken = Wire::from(vec![
+        Edge::new(&w[0], &w[1], ()),
+        Edge::new(&w[2], &w[0], ()),
+    ]);
+    assert!(face.imprint(&broken).is_none());
+}
+
+#[test]
+fn imprint_open_wire() {
+    let v = Vertex::news([(); 4]);
+    let face = square(&v);
+    let w = Vertex::news([(); 2]);
+
+    let cutter = Wire::from(vec![
+        Edge::new(&v[0], &w[0], ()),
+        Edge::new(&w[0], &v[2], ()),
+    ]);
+    let (face0, face1) = face.imprint(&cutter).unwrap();
+    assert_eq!(face0.boundaries()[0].len(), 4);
+    assert_eq!(face1.boundaries()[0].len(), 4);
+    let shell: Shell<(), (), ()> = vec![face0, face1].into();
+    assert_eq!(shell.shell_condition(), ShellCondition::Oriented);
+
+    // the ends are not on the boundary
+    let cutter = Wire::from(vec![
+        Edge::new(&v[0], &w[0], ()),
+        Edge::new(&w[0], &w[1], ()),
+    ]);
+    assert!(face.imprint(&cutter).is_none());
+
+    // the face has a hole
+    let loop_wire = Wire::from(vec![
+        Edge::new(&w[0], &w[1], ()),
+        Edge::new(&w[1], &w[0], ()),
+    ]);
+    let (outer, _) = face.imprint(&loop_wire).unwrap();
+    let cutter = Wire::from(vec![Edge::new(&v[0], &v[2], ())]);
+    assert!(outer.imprint(&cutter).is_none());
+}